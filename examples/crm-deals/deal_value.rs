@@ -13,24 +13,25 @@ async fn get_deal_value() -> Result<(), Error> {
 
     let mut total = 0.0;
     println!("Deals in progress:");
-    // iterate through all deals ...
-    for deal in DealTrackerList::get_items().await?.iter() {
-        // filter for deals where Stage is "In progress"
-        if deal.is_stage_in_progress() {
-            let title = deal.get_title().unwrap_or("untitled");
-            // Probability ranges from 0-100 (a percentage). Use default of 100 if empty.
-            let prob = deal.get_deal_probability_in().unwrap_or(100) as f64;
-            // Multiply "Order Total" formula by probability
-            let weighted = match deal.get_order_total() {
-                Some(val) => {
-                    let weighted_value = prob * val / 100.0;
-                    total += weighted_value;
-                    format!("{:.2}", weighted_value)
-                }
-                None => "(incomplete)".to_string(), // print this if order total isn't calculated
-            };
-            println!("{:55} {}", title, weighted);
-        }
+    // query deals where Stage is "In progress" (filtered by the server)
+    let deals = DealTrackerList::query()
+        .stage_is("In progress")
+        .get_items()
+        .await?;
+    for deal in deals.iter() {
+        let title = deal.get_title().unwrap_or("untitled");
+        // Probability ranges from 0-100 (a percentage). Use default of 100 if empty.
+        let prob = deal.get_deal_probability_in().unwrap_or(100) as f64;
+        // Multiply "Order Total" formula by probability
+        let weighted = match deal.get_order_total() {
+            Some(val) => {
+                let weighted_value = prob * val / 100.0;
+                total += weighted_value;
+                format!("{:.2}", weighted_value)
+            }
+            None => "(incomplete)".to_string(), // print this if order total isn't calculated
+        };
+        println!("{:55} {}", title, weighted);
     }
     println!("{:55} {:.2}", "Total weighted value", total);
     Ok(())
@@ -0,0 +1,121 @@
+//! Compare generated code with the files in an existing output directory
+use crate::{
+    error::Error,
+    gen::{is_banner_line, GeneratedFile, CARGO_TOML},
+};
+use std::fmt;
+
+/// Result of comparing one generated file with the file on disk
+pub enum FileStatus {
+    /// File on disk matches generated output
+    UpToDate,
+    /// File doesn't exist
+    Missing,
+    /// File contents differ. The value is the first line number (1-based) that differs
+    Differs(usize),
+    /// File was not compared. (Cargo.toml is not compared if it exists, because it may have
+    /// been modified locally)
+    Skipped,
+}
+
+/// Comparison result for a file
+pub struct FileCheck {
+    /// file path, relative to output directory
+    pub path: String,
+    /// comparison status
+    pub status: FileStatus,
+}
+
+impl FileCheck {
+    /// Returns true if the file needs to be regenerated
+    pub fn is_stale(&self) -> bool {
+        matches!(self.status, FileStatus::Missing | FileStatus::Differs(_))
+    }
+}
+
+impl fmt::Display for FileCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.status {
+            FileStatus::UpToDate => write!(f, "ok       {}", self.path),
+            FileStatus::Missing => write!(f, "missing  {}", self.path),
+            FileStatus::Differs(line) => {
+                write!(f, "differs  {} (first difference at line {})", self.path, line)
+            }
+            FileStatus::Skipped => write!(f, "skipped  {} (may be locally modified)", self.path),
+        }
+    }
+}
+
+/// Compares generated files with files in the output directory. Nothing is written.
+/// Rust sources are formatted with rustfmt before comparing, and the generated banner
+/// (which contains a timestamp) is ignored.
+pub fn check_files(files: &[GeneratedFile], output_dir: &str) -> Result<Vec<FileCheck>, Error> {
+    let mut results = Vec::new();
+    for file in files.iter() {
+        let fpath = format!("{}/{}", output_dir, file.path);
+        let status = match std::fs::read_to_string(&fpath) {
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => FileStatus::Missing,
+            Err(e) => return Err(e.into()),
+            Ok(_) if file.path == CARGO_TOML => FileStatus::Skipped,
+            Ok(existing) => {
+                let generated = if file.path.ends_with(".rs") {
+                    rustfmt(&file.contents)?
+                } else {
+                    file.contents.clone()
+                };
+                match first_difference(&existing, &generated) {
+                    None => FileStatus::UpToDate,
+                    Some(line) => FileStatus::Differs(line),
+                }
+            }
+        };
+        results.push(FileCheck {
+            path: file.path.clone(),
+            status,
+        });
+    }
+    Ok(results)
+}
+
+/// Returns the line number (1-based) of the first difference, ignoring banner lines,
+/// or None if the texts are the same
+fn first_difference(a: &str, b: &str) -> Option<usize> {
+    let mut a_lines = a.lines().enumerate().filter(|(_, l)| !is_banner_line(l));
+    let mut b_lines = b.lines().filter(|l| !is_banner_line(l));
+    loop {
+        match (a_lines.next(), b_lines.next()) {
+            (None, None) => return None,
+            (Some((n, a_line)), Some(b_line)) => {
+                if a_line != b_line {
+                    return Some(n + 1);
+                }
+            }
+            (Some((n, _)), None) => return Some(n + 1),
+            (None, Some(_)) => return Some(a.lines().count() + 1),
+        }
+    }
+}
+
+/// Formats rust source with rustfmt
+pub fn rustfmt(src: &str) -> Result<String, Error> {
+    use std::{
+        io::Write,
+        process::{Command, Stdio},
+    };
+
+    let mut child_proc = Command::new("rustfmt")
+        .args(["--edition", "2018"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()?;
+    child_proc
+        .stdin
+        .take()
+        .ok_or_else(|| Error::Message("rustfmt: can't open stdin".to_string()))?
+        .write_all(src.as_bytes())?;
+    let output = child_proc.wait_with_output()?;
+    if !output.status.success() {
+        return Err(Error::Message("rustfmt exited with error".to_string()));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
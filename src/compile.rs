@@ -0,0 +1,153 @@
+//! Compile the generated crate, and map compile errors back to the list, field,
+//! and template that generated the code
+use crate::{
+    error::Error,
+    gen::{definition_name, SymbolOrigin},
+};
+use serde_json::Value;
+use std::{
+    fmt,
+    io::{BufRead, BufReader},
+    path::Path,
+    process::{Command, Stdio},
+};
+
+/// Cargo command used to build the generated crate
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BuildMode {
+    /// `cargo check`
+    Check,
+    /// `cargo test`
+    Test,
+}
+
+/// A compile error in generated code
+#[derive(Debug)]
+pub struct CompileError {
+    /// Compiler message, as it would be displayed by cargo
+    pub message: String,
+    /// File containing the error, relative to the crate directory
+    pub file: Option<String>,
+    /// Line number (1-based) of the error
+    pub line: usize,
+    /// Origin of the definition containing the error, if known
+    pub origin: Option<SymbolOrigin>,
+}
+
+impl fmt::Display for CompileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(ref origin) = self.origin {
+            write!(f, "error in generated {}", origin.symbol)?;
+            if let Some(ref list) = origin.list {
+                write!(f, " for '{}'", list)?;
+                if let Some(ref field) = origin.field {
+                    write!(f, ".'{}'", field)?;
+                }
+                if let Some(ref label) = origin.label {
+                    write!(f, " label '{}'", label)?;
+                }
+            }
+            writeln!(f, " (template {})", origin.template)?;
+        } else if let Some(ref file) = self.file {
+            writeln!(f, "error in generated {}:{}", file, self.line)?;
+        }
+        write!(f, "{}", self.message.trim_end())
+    }
+}
+
+/// Runs `cargo check` or `cargo test` in the crate directory, with all features enabled
+/// (so that optional generated code, such as the mock backend, is compiled),
+/// and returns compile errors. Non-compiler output (such as test results) is passed through to stdout.
+/// Returns an error if cargo fails for any other reason, for example, a test failure.
+pub fn build_crate(
+    crate_dir: &str,
+    mode: BuildMode,
+    origins: &[SymbolOrigin],
+) -> Result<Vec<CompileError>, Error> {
+    let command = match mode {
+        BuildMode::Check => "check",
+        BuildMode::Test => "test",
+    };
+    let mut child_proc = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".into()))
+        .args([command, "--all-features", "--message-format=json"])
+        .current_dir(crate_dir)
+        .stdout(Stdio::piped())
+        .spawn()?;
+    let stdout = child_proc
+        .stdout
+        .take()
+        .ok_or_else(|| Error::Message("cargo: can't open stdout".to_string()))?;
+
+    let mut errors = Vec::new();
+    for line in BufReader::new(stdout).lines() {
+        let line = line?;
+        let msg = match serde_json::from_str::<Value>(&line) {
+            Ok(msg) => msg,
+            Err(_) => {
+                // test harness output
+                println!("{}", line);
+                continue;
+            }
+        };
+        if msg["reason"] == "compiler-message" && msg["message"]["level"] == "error" {
+            errors.push(compile_error(crate_dir, &msg["message"], origins));
+        }
+    }
+    let status = child_proc.wait()?;
+    if !status.success() && errors.is_empty() {
+        return Err(Error::Message(format!(
+            "cargo {} failed in {}",
+            command, crate_dir
+        )));
+    }
+    Ok(errors)
+}
+
+/// Creates CompileError from cargo diagnostic message
+fn compile_error(crate_dir: &str, message: &Value, origins: &[SymbolOrigin]) -> CompileError {
+    let primary_span = message["spans"]
+        .as_array()
+        .and_then(|spans| spans.iter().find(|span| span["is_primary"] == true));
+    let file = primary_span
+        .and_then(|span| span["file_name"].as_str())
+        .map(|s| s.to_string());
+    let line = primary_span
+        .and_then(|span| span["line_start"].as_u64())
+        .unwrap_or_default() as usize;
+    let origin = file
+        .as_ref()
+        .and_then(|file| find_origin(crate_dir, file, line, origins));
+    CompileError {
+        message: message["rendered"]
+            .as_str()
+            .or_else(|| message["message"].as_str())
+            .unwrap_or_default()
+            .to_string(),
+        file,
+        line,
+        origin,
+    }
+}
+
+/// Finds the definition containing the line, by searching backward from the line
+/// for the nearest definition with a recorded origin
+fn find_origin(
+    crate_dir: &str,
+    file: &str,
+    line: usize,
+    origins: &[SymbolOrigin],
+) -> Option<SymbolOrigin> {
+    let src = std::fs::read_to_string(Path::new(crate_dir).join(file)).ok()?;
+    src.lines()
+        .take(line)
+        .collect::<Vec<&str>>()
+        .iter()
+        .rev()
+        .filter_map(|l| definition_name(l))
+        .find_map(|symbol| {
+            origins
+                .iter()
+                .find(|o| o.file == file && o.symbol == symbol)
+                .cloned()
+        })
+}
@@ -0,0 +1,352 @@
+//! Compare two workspace schemas and report changes that affect the generated api
+use crate::{
+    error::Error,
+    gen::{Generator, Options},
+    schema::{copy_value, ListSchema, WorkspaceSchema},
+};
+use std::{
+    collections::{BTreeMap as Map, BTreeSet as Set},
+    fmt,
+};
+use zenkit::types::{Element, ElementCategoryId, NumericType};
+
+/// Kind of schema change
+#[derive(Debug, PartialEq)]
+pub enum ChangeKind {
+    /// List added to workspace
+    ListAdded,
+    /// List removed from workspace
+    ListRemoved,
+    /// List name changed (old name, new name)
+    ListRenamed(String, String),
+    /// List item name changed (old name, new name)
+    ItemRenamed(String, String),
+    /// Field added to list
+    FieldAdded,
+    /// Field removed (or deprecated)
+    FieldRemoved,
+    /// Field name changed (old name, new name)
+    FieldRenamed(String, String),
+    /// Field type changed (old type, new type)
+    FieldTypeChanged(String, String),
+    /// Field changed between single and multiple values. Value is true if the new field is multi-valued
+    MultipleChanged(bool),
+    /// Category label added
+    LabelAdded(String),
+    /// Category label removed
+    LabelRemoved(String),
+    /// Category label renamed (old name, new name)
+    LabelRenamed(String, String),
+    /// Generated names changed, but the field didn't, for example, names that were numbered
+    /// to avoid a collision lose their suffix when the other field is removed
+    SymbolsRenamed,
+}
+
+/// A schema change, with the generated symbols that no longer exist because of it
+#[derive(Debug)]
+pub struct Change {
+    /// List name (the new name, if the list was renamed)
+    pub list: String,
+    /// Field name (the new name, if the field was renamed), or None for list-level changes
+    pub field: Option<String>,
+    /// What changed
+    pub kind: ChangeKind,
+    /// Generated symbols (modules, types, methods, and constants) that are no longer generated
+    pub removed: Vec<String>,
+}
+
+impl Change {
+    /// Returns true if code that uses the previously-generated api may no longer compile
+    pub fn is_breaking(&self) -> bool {
+        !self.removed.is_empty()
+            || matches!(
+                self.kind,
+                ChangeKind::FieldTypeChanged(_, _) | ChangeKind::MultipleChanged(_)
+            )
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tag = if self.is_breaking() {
+            "BREAKING"
+        } else {
+            "ok"
+        };
+        let location = match &self.field {
+            Some(field) => format!("'{}'.'{}'", self.list, field),
+            None => format!("'{}'", self.list),
+        };
+        let description = match &self.kind {
+            ChangeKind::ListAdded => "list added".to_string(),
+            ChangeKind::ListRemoved => "list removed".to_string(),
+            ChangeKind::ListRenamed(old, new) => format!("list renamed '{}' -> '{}'", old, new),
+            ChangeKind::ItemRenamed(old, new) => {
+                format!("item name changed '{}' -> '{}'", old, new)
+            }
+            ChangeKind::FieldAdded => "field added".to_string(),
+            ChangeKind::FieldRemoved => "field removed".to_string(),
+            ChangeKind::FieldRenamed(old, new) => {
+                format!("field renamed '{}' -> '{}'", old, new)
+            }
+            ChangeKind::FieldTypeChanged(old, new) => {
+                format!("field type changed {} -> {}", old, new)
+            }
+            ChangeKind::MultipleChanged(true) => {
+                "field changed from single value to multiple values".to_string()
+            }
+            ChangeKind::MultipleChanged(false) => {
+                "field changed from multiple values to single value".to_string()
+            }
+            ChangeKind::LabelAdded(label) => format!("label '{}' added", label),
+            ChangeKind::LabelRemoved(label) => format!("label '{}' removed", label),
+            ChangeKind::LabelRenamed(old, new) => {
+                format!("label renamed '{}' -> '{}'", old, new)
+            }
+            ChangeKind::SymbolsRenamed => "generated names changed".to_string(),
+        };
+        write!(f, "[{}] {}: {}", tag, location, description)?;
+        if !self.removed.is_empty() {
+            write!(f, "\n    removed: {}", self.removed.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Compares two workspace schemas. Lists, fields, and labels are matched by uuid (or id),
+/// so renames are detected as renames rather than a removal and addition.
+/// Both schemas are rendered with the options, so that reported symbols match
+/// the generated code.
+pub fn diff_schemas(
+    old: &WorkspaceSchema,
+    new: &WorkspaceSchema,
+    options: &Options,
+) -> Result<Vec<Change>, Error> {
+    let mut gens = Generators {
+        old: Generator::with_options(options.clone())?,
+        new: Generator::with_options(options.clone())?,
+    };
+    gens.old.assign_names(old)?;
+    gens.new.assign_names(new)?;
+    let mut changes = Vec::new();
+
+    for old_list in old.lists.iter() {
+        match new.lists.iter().find(|l| l.list.uuid == old_list.list.uuid) {
+            Some(new_list) => diff_list(&mut gens, old_list, new_list, &mut changes)?,
+            None => changes.push(Change {
+                list: old_list.list.name.clone(),
+                field: None,
+                kind: ChangeKind::ListRemoved,
+                removed: gens.old.list_symbols(&old_list.list),
+            }),
+        }
+    }
+    for new_list in new.lists.iter() {
+        if !old.lists.iter().any(|l| l.list.uuid == new_list.list.uuid) {
+            changes.push(Change {
+                list: new_list.list.name.clone(),
+                field: None,
+                kind: ChangeKind::ListAdded,
+                removed: Vec::new(),
+            });
+        }
+    }
+    Ok(changes)
+}
+
+/// Generators for the old and new schemas, with names assigned
+struct Generators<'gen> {
+    old: Generator<'gen>,
+    new: Generator<'gen>,
+}
+
+/// Compares lists with the same uuid
+fn diff_list(
+    gens: &mut Generators,
+    old: &ListSchema,
+    new: &ListSchema,
+    changes: &mut Vec<Change>,
+) -> Result<(), Error> {
+    let list_name = &new.list.name;
+    let old_types = gens.old.list_symbols(&old.list);
+    let new_types = gens.new.list_symbols(&new.list);
+    let removed_types = subtract(old_types.clone(), &new_types);
+    // the item struct is the last type
+    let old_item = old_types.last().cloned().unwrap_or_default();
+    let new_item = new_types.last().cloned().unwrap_or_default();
+    // old type -> type at the same position in the new list, to qualify old field symbols
+    let types: Map<String, String> = old_types.into_iter().zip(new_types).collect();
+    if old.list.name != new.list.name {
+        changes.push(Change {
+            list: list_name.clone(),
+            field: None,
+            kind: ChangeKind::ListRenamed(old.list.name.clone(), new.list.name.clone()),
+            removed: removed_types,
+        });
+    } else if old_item != new_item {
+        changes.push(Change {
+            list: list_name.clone(),
+            field: None,
+            kind: ChangeKind::ItemRenamed(old_item, new_item),
+            removed: removed_types,
+        });
+    }
+
+    let active = |f: &&Element| f.deprecated_at.is_none();
+    for old_field in old.fields.iter().filter(active) {
+        match new
+            .fields
+            .iter()
+            .filter(active)
+            .find(|f| f.uuid == old_field.uuid)
+        {
+            Some(new_field) => diff_field(gens, &types, old, new, old_field, new_field, changes)?,
+            None => changes.push(Change {
+                list: list_name.clone(),
+                field: Some(old_field.name.clone()),
+                kind: ChangeKind::FieldRemoved,
+                removed: old_field_symbols(&mut gens.old, &types, old, old_field)?,
+            }),
+        }
+    }
+    for new_field in new.fields.iter().filter(active) {
+        if !old
+            .fields
+            .iter()
+            .filter(active)
+            .any(|f| f.uuid == new_field.uuid)
+        {
+            changes.push(Change {
+                list: list_name.clone(),
+                field: Some(new_field.name.clone()),
+                kind: ChangeKind::FieldAdded,
+                removed: Vec::new(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Compares fields with the same uuid
+fn diff_field(
+    gens: &mut Generators,
+    types: &Map<String, String>,
+    old_list: &ListSchema,
+    list: &ListSchema,
+    old: &Element,
+    new: &Element,
+    changes: &mut Vec<Change>,
+) -> Result<(), Error> {
+    let new_symbols = gens.new.field_symbols(list, new)?;
+    let old_symbols = old_field_symbols(&mut gens.old, types, old_list, old)?;
+    let mut removed = subtract(old_symbols.clone(), &new_symbols);
+    let change = |kind: ChangeKind, removed: Vec<String>| Change {
+        list: list.list.name.clone(),
+        field: Some(new.name.clone()),
+        kind,
+        removed,
+    };
+
+    // label changes. Symbols removed because of a label change are reported with that
+    // change; anything left over is attributed to the field-level change.
+    let old_labels = labels(old);
+    let new_labels = labels(new);
+    for (id, old_name) in old_labels.iter() {
+        let kind = match new_labels.iter().find(|(new_id, _)| new_id == id) {
+            Some((_, new_name)) if new_name == old_name => continue,
+            Some((_, new_name)) => ChangeKind::LabelRenamed(old_name.clone(), new_name.clone()),
+            None => ChangeKind::LabelRemoved(old_name.clone()),
+        };
+        let without_label =
+            old_field_symbols(&mut gens.old, types, old_list, &remove_label(old, *id)?)?;
+        let label_symbols = subtract(old_symbols.clone(), &without_label);
+        let label_removed: Vec<String> = removed
+            .iter()
+            .filter(|sym| label_symbols.contains(sym))
+            .cloned()
+            .collect();
+        removed = subtract(removed, &label_removed);
+        changes.push(change(kind, label_removed));
+    }
+    for (id, new_name) in new_labels.iter() {
+        if !old_labels.iter().any(|(old_id, _)| old_id == id) {
+            changes.push(change(ChangeKind::LabelAdded(new_name.clone()), Vec::new()));
+        }
+    }
+
+    // field-level changes. If there is more than one, removed symbols are reported
+    // with the first. If there is none, symbols may still be removed, e.g., when a
+    // colliding field is removed and this field's numbered names lose their suffix.
+    let mut field_changes = Vec::new();
+    if old.name != new.name {
+        field_changes.push(ChangeKind::FieldRenamed(old.name.clone(), new.name.clone()));
+    }
+    if field_type_name(old) != field_type_name(new) {
+        field_changes.push(ChangeKind::FieldTypeChanged(
+            field_type_name(old),
+            field_type_name(new),
+        ));
+    } else if old.element_data.multiple != new.element_data.multiple {
+        field_changes.push(ChangeKind::MultipleChanged(new.element_data.multiple));
+    }
+    if field_changes.is_empty() && !removed.is_empty() {
+        field_changes.push(ChangeKind::SymbolsRenamed);
+    }
+    for kind in field_changes.into_iter() {
+        changes.push(change(kind, std::mem::take(&mut removed)));
+    }
+    Ok(())
+}
+
+/// Returns the symbols generated for a field in the old schema, qualified with the
+/// corresponding types of the new schema, so that an item rename is only reported once,
+/// as a list change
+fn old_field_symbols(
+    gen: &mut Generator,
+    types: &Map<String, String>,
+    list: &ListSchema,
+    field: &Element,
+) -> Result<Vec<String>, Error> {
+    Ok(gen
+        .field_symbols(list, field)?
+        .into_iter()
+        .map(|sym| match sym.split_once("::") {
+            Some((ty, name)) if types.contains_key(ty) => format!("{}::{}", types[ty], name),
+            _ => sym,
+        })
+        .collect())
+}
+
+/// Returns field type, including numeric type for number fields
+fn field_type_name(field: &Element) -> String {
+    match field.element_category {
+        ElementCategoryId::Number => match field.numeric_type() {
+            Some(NumericType::Integer) => "Number(integer)".to_string(),
+            _ => "Number(decimal)".to_string(),
+        },
+        cat => cat.to_string(),
+    }
+}
+
+/// Returns (id, name) of category labels
+fn labels(field: &Element) -> Vec<(u64, String)> {
+    match field.element_data.predefined_categories {
+        Some(ref categories) => categories.iter().map(|c| (c.id, c.name.clone())).collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Returns copy of field without the label
+fn remove_label(field: &Element, label_id: u64) -> Result<Element, Error> {
+    let mut field = copy_value(field)?;
+    if let Some(ref mut categories) = field.element_data.predefined_categories {
+        categories.retain(|c| c.id != label_id);
+    }
+    Ok(field)
+}
+
+/// Returns items in `a` that are not in `b`, preserving order
+fn subtract(a: Vec<String>, b: &[String]) -> Vec<String> {
+    let b: Set<&String> = b.iter().collect();
+    a.into_iter().filter(|s| !b.contains(s)).collect()
+}
@@ -20,28 +20,33 @@ impl fmt::Display for Error {
 
 impl From<config::ConfigError> for Error {
     fn from(e: config::ConfigError) -> Error {
-        Error::Message(format!("configuration: {}",e.to_string()))
+        Error::Message(format!("configuration: {}",e))
     }
 }
 
 impl From<handlebars::TemplateError> for Error {
     fn from(e: handlebars::TemplateError) -> Error {
-        Error::Message(format!("template: {}",e.to_string()))
+        Error::Message(format!("template: {}",e))
     }
 }
 impl From<handlebars::RenderError> for Error {
     fn from(e: handlebars::RenderError) -> Error {
-        Error::Message(format!("template render: {}",e.to_string()))
+        Error::Message(format!("template render: {}",e))
     }
 }
 impl From<zenkit::Error> for Error {
     fn from(e: zenkit::Error) -> Error {
-        Error::Message(format!("zenkit: {}",e.to_string()))
+        Error::Message(format!("zenkit: {}",e))
+    }
+}
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Error {
+        Error::Message(format!("json: {}",e))
     }
 }
 impl From<std::io::Error> for Error {
     fn from(e: std::io::Error) -> Error {
-        Error::Message(format!("IO: {}",e.to_string()))
+        Error::Message(format!("IO: {}",e))
     }
 }
 
@@ -0,0 +1,42 @@
+//! Escaping of workspace content (names and descriptions) in generated code
+//!
+//! Names and descriptions are entered by Zenkit users, and may contain quotes,
+//! backslashes, newlines, or markdown. These functions make them safe to include
+//! in string literals and doc comments.
+
+/// Escapes text for the inside of a Rust string literal (between the quotes).
+/// Printable non-ascii characters are kept as-is.
+pub fn rust_string(s: &str) -> String {
+    s.escape_debug().to_string()
+}
+
+/// Escapes text for the inside of a TOML basic string (between the quotes)
+pub fn toml_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04X}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Formats text for a doc comment that starts on a line beginning with `prefix`
+/// ("///" or "//!", or "//" for a plain comment). Line breaks continue the comment on a new line with the same prefix.
+/// Leading whitespace and code fences are escaped so that the text can't start
+/// a code block, which rustdoc would compile as a doctest.
+pub fn doc_comment(s: &str, prefix: &str) -> String {
+    // a carriage return that doesn't end a line is not allowed in doc comments
+    s.replace("\r\n", "\n")
+        .replace('\r', "\n")
+        .lines()
+        .map(|line| line.trim().replace("```", "\\`\\`\\`").replace("~~~", "\\~\\~\\~"))
+        .collect::<Vec<String>>()
+        .join(&format!("\n{} ", prefix))
+}
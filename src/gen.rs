@@ -1,24 +1,133 @@
 use crate::{
     error::Error,
+    naming::{
+        class_ident, leading_fragment, numbered, pascal_ident, snake_fragment, snake_ident,
+        valid_ident,
+    },
+    schema::{copy_value, ListSchema, WorkspaceSchema},
+    sink::{DirSink, OutputSink},
+    source::SchemaSource,
     templates::{add_helpers, add_templates},
+    PROGRAM_NAME,
 };
 use bytes::BytesMut;
 use handlebars::Handlebars;
 use inflector::{
-    cases::{classcase::to_class_case, pascalcase::to_pascal_case, snakecase::to_snake_case},
+    cases::{pascalcase::to_pascal_case, screamingsnakecase::to_screaming_snake_case},
     string::pluralize::to_plural,
-    string::singularize::to_singular,
 };
 use serde_json::{json, Value};
-use std::{collections::BTreeMap as Map, sync::Arc};
-use zenkit::{
-    types::{ChildList, Element, ElementCategoryId, ListInfo, NumericType, Workspace},
-    ApiClient,
-};
+use std::collections::{BTreeMap as Map, BTreeSet as Set};
+use zenkit::types::{ChildList, Element, ElementCategoryId, List, NumericType};
+
+/// Type names used in generated modules, that can't be used for generated list types
+const RESERVED_TYPES: &[&str] = &[
+    "AllId", "Api", "ApiClient", "ApiConfig", "Arc", "AsRef", "Backend", "Box", "Clone", "Copy",
+    "DateTime", "Default", "Duration", "Entry", "EntryPages", "EntryQuery", "Err", "Error",
+    "ErrorResult", "File", "FromStr", "Future", "GetEntriesRequest", "GlobalApi", "HttpApi", "ID",
+    "Into", "From", "JsonMap", "None", "Ok", "Option", "Result", "RetryPolicy", "RwLock", "Some",
+    "String", "TextFormat", "ToString", "Utc", "Value", "Vec", "WorkspaceClient",
+];
+
+/// Module names used in the generated crate, and methods of WorkspaceClient
+/// (which has a method for each list module), that can't be used for list modules
+const RESERVED_MODULES: &[&str] = &[
+    "alloc", "async_trait", "clone", "connect", "core", "fmt", "futures", "global", "mock",
+    "reqwest", "serde", "serde_json", "std", "tokio", "with_backend", "with_http_client",
+    "with_retry_policy", "zenkit",
+];
+
+/// Name of generated crate manifest
+pub const CARGO_TOML: &str = "Cargo.toml";
+
+/// Name of the generated file for Layout::SingleFile
+pub const SINGLE_FILE_NAME: &str = "zenkit_client.rs";
+
+/// Arrangement of generated files
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Layout {
+    /// A library crate: Cargo.toml, src/lib.rs, and a module file in src/ for each list
+    #[default]
+    Crate,
+    /// A single source file (SINGLE_FILE_NAME) containing all modules, without Cargo.toml,
+    /// that can be added to another crate with `include!`, for example, from OUT_DIR
+    /// in a build script. The including crate needs the same dependencies as the
+    /// generated Cargo.toml.
+    SingleFile,
+}
+
+/// A generated file
+pub struct GeneratedFile {
+    /// Path relative to the crate directory, e.g., "src/lib.rs"
+    pub path: String,
+    /// File contents
+    pub contents: String,
+}
+
+/// Where a generated definition (function, constant, type, or module) came from.
+/// Used to map compile errors in generated code back to the schema and template.
+#[derive(Clone, Debug)]
+pub struct SymbolOrigin {
+    /// Generated file path, relative to the crate directory, e.g., "src/deal_tracker.rs"
+    pub file: String,
+    /// Name of the definition, e.g., "get_stage"
+    pub symbol: String,
+    /// List name, if the definition was generated for a list
+    pub list: Option<String>,
+    /// Field name, if the definition was generated for a field
+    pub field: Option<String>,
+    /// Category label, if the definition was generated for a label
+    pub label: Option<String>,
+    /// Name of the template that generated the definition
+    pub template: String,
+}
 
 /// template variables
 type RenderMap = Map<&'static str, Value>;
 
+/// Generated names for a list
+#[derive(Clone, Debug)]
+struct ListNames {
+    /// module name, e.g., "deal_tracker"
+    module: String,
+    /// list struct name, e.g., "DealTrackerList"
+    list_struct: String,
+    /// item struct name, e.g., "Deal"
+    item: String,
+    /// plural item name, e.g., "Deals"
+    item_plural: String,
+}
+
+/// Identifiers assigned to lists, fields, and labels, after resolving collisions.
+/// Fields and labels that aren't in the maps use the default name.
+#[derive(Default)]
+struct Names {
+    /// list uuid -> list names
+    lists: Map<String, ListNames>,
+    /// field uuid -> snake-case identifier fragment, e.g., "due_date"
+    fields: Map<String, String>,
+    /// (field uuid, label id) -> snake-case identifier fragment
+    labels: Map<(String, u64), String>,
+    /// field uuid -> name of the label enum for a category field
+    enums: Map<String, String>,
+    /// type names used by lists (and category enums) -> what uses them
+    types: Map<String, String>,
+}
+
+/// Code generation options
+#[derive(Clone, Debug, Default)]
+pub struct Options {
+    /// If true, the generated banner contains a hash of the schema (and the generator version)
+    /// instead of the current time, so that output only changes when the schema
+    /// or generator changes.
+    pub reproducible: bool,
+    /// Layout of generated files
+    pub layout: Layout,
+    /// If true, an enum is generated for the labels of each category field,
+    /// and category getters and setters use the enum instead of label names
+    pub category_enums: bool,
+}
+
 /// Instance data for generator
 pub struct Generator<'gen> {
     /// Handlebars processor
@@ -27,9 +136,18 @@ pub struct Generator<'gen> {
     buf: BytesMut,
     /// Template variables
     data: RenderMap,
+    /// Generation options
+    options: Options,
+    /// Origins of the definitions in generated files
+    origins: Vec<SymbolOrigin>,
+    /// Identifiers for lists, fields, and labels
+    names: Names,
+    /// Warnings about name collisions and unsupported fields
+    warnings: Vec<String>,
 }
 
 /// Types of mutable objects in builder patterns
+#[derive(Clone, Copy)]
 enum BuilderType {
     /// New list entry
     New,
@@ -37,11 +155,18 @@ enum BuilderType {
     Update,
 }
 
+const BUILDER_TYPES: [BuilderType; 2] = [BuilderType::New, BuilderType::Update];
+
 impl<'gen> Generator<'gen> {
     /// Initialize handlebars in strict mode
     /// Load handlebars_misc_helpers for string conversions.
     /// Add our custom helpers and templates (all defined in src/templates.rs)
     pub fn init() -> Result<Self, crate::error::Error> {
+        Self::with_options(Options::default())
+    }
+
+    /// Initialize generator with options
+    pub fn with_options(options: Options) -> Result<Self, crate::error::Error> {
         let mut hb = Handlebars::new();
         handlebars_misc_helpers::setup_handlebars(&mut hb);
         hb.set_strict_mode(true);
@@ -52,6 +177,10 @@ impl<'gen> Generator<'gen> {
             hb,
             buf: BytesMut::new(),
             data: RenderMap::new(),
+            options,
+            origins: Vec::new(),
+            names: Names::default(),
+            warnings: Vec::new(),
         })
     }
     /// sets template value to string
@@ -83,70 +212,401 @@ impl<'gen> Generator<'gen> {
     ///  - common getters: (get_id,get_uuid)
     ///  - item getters and label lookups in gen_item_impl
     ///  - builder initializers create() and update()
-    pub fn gen_list(&mut self, list_info: &Arc<ListInfo>) -> Result<(), Error> {
-        let list = list_info.list();
-        // define list template vars
+    pub fn gen_list(&mut self, list_info: &ListSchema) -> Result<(), Error> {
+        self.assign_field_names(list_info)?;
+        self.set_list_vars(list_info);
+
+        self.render("start_list_impl")?;
+
+        // item impl (all getters, and label lookups)
+        self.gen_item_impl(list_info)?;
+
+        // create item builder
+        self.gen_builder(list_info, BuilderType::New)?;
+        // update item builder
+        self.gen_builder(list_info, BuilderType::Update)?;
+
+        // query with filters for each field
+        self.gen_query(list_info)?;
+
+        if self.options.category_enums {
+            self.gen_category_enums(list_info)?;
+        }
+
+        self.gen_record(list_info)?;
+        self.render("assert_send_sync")?;
+
+        self.clear_list_vars();
+        Ok(())
+    }
+
+    /// Generates label enums for category fields. List template vars must be set.
+    fn gen_category_enums(&mut self, list_info: &ListSchema) -> Result<(), Error> {
+        for field in list_info.fields.iter().filter(|f| {
+            f.deprecated_at.is_none() && f.element_category == ElementCategoryId::Categories
+        }) {
+            self.set_field_vars(field);
+            let variants = self.enum_variants(field);
+            self.set_id("variant_count", variants.len() as u64);
+            self.data.insert("variants", Value::Array(variants));
+            self.render("category_enum")?;
+        }
+        self.data.remove("variants");
+        self.data.remove("variant_count");
+        self.clear_field_vars();
+        Ok(())
+    }
+
+    /// Generates the record struct for list items. List template vars must be set.
+    fn gen_record(&mut self, list_info: &ListSchema) -> Result<(), Error> {
+        let mut fields = Vec::new();
+        for field in list_info.fields.iter().filter(|f| f.deprecated_at.is_none()) {
+            self.set_field_vars(field);
+            if let Some(record_field) = self.record_field(field) {
+                fields.push(record_field);
+            }
+        }
+        self.clear_field_vars();
+        self.data.insert("record_fields", Value::Array(fields));
+        self.render("record_struct")?;
+        self.data.remove("record_fields");
+        Ok(())
+    }
+
+    /// Returns the record field for a Zenkit field, as json with the field name (`name`),
+    /// Rust type (`ty`), expression that reads the value from `item` (`get`),
+    /// and builder method (`set`) and its argument (`arg`, in terms of `v`, a reference
+    /// to the value, or to the inner value if `optional`). Read-only fields have no `set`.
+    /// Returns None for fields that aren't included in records. Field template vars must be set.
+    fn record_field(&self, field: &Element) -> Option<Value> {
+        let var = |k: &str| self.data[k].as_str().unwrap_or_default().to_string();
+        let ident = var("field_ident");
+        let multiple = field.element_data.multiple;
+        let enums = self.options.category_enums;
+        let (ty, get, set, arg) = match field.element_category {
+            ElementCategoryId::Text | ElementCategoryId::URL => (
+                "Option<String>".to_string(),
+                format!("item.get_{}().map(String::from)", ident),
+                format!("set_{}", ident),
+                "v",
+            ),
+            ElementCategoryId::Number => match field.numeric_type() {
+                Some(NumericType::Integer) => (
+                    "Option<i64>".to_string(),
+                    format!("item.get_{}()", ident),
+                    format!("set_{}", ident),
+                    "*v",
+                ),
+                _ => (
+                    "Option<f64>".to_string(),
+                    format!("item.get_{}()", ident),
+                    format!("set_{}", ident),
+                    "*v",
+                ),
+            },
+            ElementCategoryId::Formula => (
+                "Option<f64>".to_string(),
+                format!("item.get_{}()", ident),
+                String::new(),
+                "",
+            ),
+            ElementCategoryId::Checkbox => (
+                "bool".to_string(),
+                format!("item.is_checked_{}()", ident),
+                format!("check_{}", ident),
+                "*v",
+            ),
+            ElementCategoryId::Date => (
+                "Option<DateTime<Utc>>".to_string(),
+                format!("item.get_{}()", ident),
+                format!("set_{}", ident),
+                "v",
+            ),
+            ElementCategoryId::Persons if multiple => (
+                "Vec<ID>".to_string(),
+                format!("item.get_{}_ids()", ident),
+                format!("set_{}", ident),
+                "v.clone()",
+            ),
+            ElementCategoryId::Persons => (
+                "Option<ID>".to_string(),
+                format!("item.get_{}_id()", ident),
+                format!("set_{}", ident),
+                "*v",
+            ),
+            ElementCategoryId::Categories => match (multiple, enums) {
+                (false, false) => (
+                    "Option<ID>".to_string(),
+                    format!("item.get_{}_id()", ident),
+                    format!("set_{}_id", ident),
+                    "*v",
+                ),
+                (false, true) => (
+                    format!("Option<{}>", var("enum_type")),
+                    format!("item.get_{}()", ident),
+                    format!("set_{}", ident),
+                    "*v",
+                ),
+                (true, false) => (
+                    "Vec<ID>".to_string(),
+                    format!("item.get_{}_ids()", var("field_plural")),
+                    format!("set_{}", ident),
+                    "v.clone()",
+                ),
+                (true, true) => (
+                    format!("std::collections::BTreeSet<{}>", var("enum_type")),
+                    format!("item.get_{}()", var("field_plural")),
+                    format!("set_{}", ident),
+                    "v",
+                ),
+            },
+            ElementCategoryId::References if self.data["ref_typed"] == true => {
+                let uuid_type = format!("super::{}::{}", var("ref_module"), var("ref_uuid_type"));
+                if multiple {
+                    (
+                        format!("Vec<{}>", uuid_type),
+                        format!(
+                            "item.get_{}_uuids().into_iter().map({}::new).collect()",
+                            ident, uuid_type
+                        ),
+                        format!("set_{}", ident),
+                        "v",
+                    )
+                } else {
+                    (
+                        format!("Option<{}>", uuid_type),
+                        format!("item.get_{}_uuid().map({}::new)", ident, uuid_type),
+                        format!("set_{}", ident),
+                        "v",
+                    )
+                }
+            }
+            ElementCategoryId::References if !multiple => (
+                "Option<String>".to_string(),
+                format!("item.get_{}_uuid().map(String::from)", ident),
+                format!("set_{}", ident),
+                "v",
+            ),
+            ElementCategoryId::References | ElementCategoryId::SubEntries => (
+                "Vec<String>".to_string(),
+                format!(
+                    "item.get_{}_uuids().into_iter().map(String::from).collect()",
+                    ident
+                ),
+                format!("set_{}", ident),
+                "v.iter().map(|s| s.as_str()).collect()",
+            ),
+            // files aren't Clone or PartialEq; metadata is in the record's item
+            _ => return None,
+        };
+        Some(json!({
+            "name": valid_ident(ident),
+            "field": field.name,
+            "ty": ty,
+            "optional": ty.starts_with("Option<"),
+            "get": get,
+            "set": set,
+            "arg": arg,
+        }))
+    }
+
+    /// Returns enum variants for the labels of a category field,
+    /// as json objects with the variant identifier, label name, and label constant name
+    fn enum_variants(&mut self, field: &Element) -> Vec<Value> {
+        let mut variants = Vec::new();
+        let mut used = Set::new();
+        if let Some(ref categories) = field.element_data.predefined_categories {
+            for c in categories.iter() {
+                self.set_label_vars(field, c.id, &c.name);
+                // label identifiers are unique, but may differ only in underscores
+                let base = pascal_ident(
+                    self.data["label_ident"].as_str().unwrap_or_default(),
+                    "label",
+                );
+                let variant = (1..)
+                    .map(|n| numbered(&base, "", n))
+                    .find(|v| !used.contains(v))
+                    .unwrap_or_default();
+                used.insert(variant.clone());
+                variants.push(json!({
+                    "variant": variant,
+                    "name": c.name,
+                    "upper": self.data["label_upper"],
+                }));
+            }
+            self.clear_label_vars();
+        }
+        variants
+    }
+
+    /// Define list template vars
+    fn set_list_vars(&mut self, list_info: &ListSchema) {
+        let list = &list_info.list;
         self.set("list", &list.name);
         self.set_id("list_id", list.id);
         self.set("list_short_id", &list.short_id);
         self.set("list_uuid", &list.uuid);
         self.set("list_desc", &list.description);
-        self.set("list_struct", format!("{}List", to_pascal_case(&list.name)));
 
-        // generate item name, singular and plural
-        let item = if let Some(ref item_name) = list.item_name {
-            // they specified item name in ui
-            to_class_case(&item_name)
-        } else {
-            to_class_case(&list.name)
-        };
-        let item_plural = if let Some(ref item_name_plural) = list.item_name_plural {
-            to_class_case(&item_name_plural)
+        let names = self.list_names(list);
+        self.set("list_ident", &names.module);
+        self.set("list_struct", &names.list_struct);
+        self.set("list_client", client_type_name(&names.list_struct));
+        self.set("item_ident", snake_ident(&names.item, "item"));
+        self.set("id_type", id_type_name(&names.item));
+        self.set("uuid_type", uuid_type_name(&names.item));
+        self.set("record_type", record_type_name(&names.item));
+        self.set("query_type", query_type_name(&names.item));
+        self.set("item", names.item);
+        self.set("item_plural", names.item_plural);
+    }
+
+    /// Clear list template vars
+    fn clear_list_vars(&mut self) {
+        for f in [
+            "list",
+            "list_id",
+            "list_short_id",
+            "list_uuid",
+            "list_ident",
+            "list_client",
+            "item",
+            "item_ident",
+            "id_type",
+            "uuid_type",
+            "record_type",
+            "query_type",
+        ]
+        .iter()
+        {
+            self.data.remove(f);
+        }
+    }
+
+    /// Define field template vars
+    fn set_field_vars(&mut self, field: &Element) {
+        let ident = self.field_ident(field);
+        self.set("field_upper", to_screaming_snake_case(&ident));
+        self.set("field_plural", to_plural(&ident));
+        self.set("field_lead", leading_fragment(&ident));
+        self.set("field_ident", ident);
+        self.set("enum_type", self.enum_name(field));
+        self.set("field", &field.name);
+        self.set("field_uuid", &field.uuid);
+        self.set_id("field_id", field.id);
+        self.set("field_desc", field.get_description());
+        self.set_bool("field_single_value", !field.element_data.multiple);
+        self.set_bool("field_multiple_value", field.element_data.multiple);
+        if let Some(ChildList::Child(ref child_list)) = field.element_data.child_list {
+            self.set("ref_list", &child_list.name);
         } else {
-            to_plural(&item)
+            self.set("ref_list", "");
+        }
+        // typed references, if the referenced list is in the workspace
+        let ref_names = match field.element_data.child_list {
+            Some(ChildList::Child(ref child_list)) => self.names.lists.get(&child_list.uuid).cloned(),
+            _ => None,
         };
-        self.set("item", &item);
-        self.set("item_plural", &item_plural);
-
-        self.render("start_list_impl")?;
+        self.set_bool("ref_typed", ref_names.is_some());
+        if let Some(ref_names) = ref_names {
+            self.set("ref_module", ref_names.module);
+            self.set("ref_client", client_type_name(&ref_names.list_struct));
+            self.set("ref_list_struct", ref_names.list_struct);
+            self.set("ref_uuid_type", uuid_type_name(&ref_names.item));
+            self.set("ref_item", ref_names.item);
+        }
+    }
 
-        // item impl (all getters, and label lookups)
-        self.gen_item_impl(&list_info)?;
+    /// Clear field template vars
+    fn clear_field_vars(&mut self) {
+        for f in [
+            "field",
+            "field_ident",
+            "field_upper",
+            "field_plural",
+            "field_lead",
+            "enum_type",
+            "field_uuid",
+            "field_id",
+            "field_desc",
+            "field_single_value",
+            "field_multiple_value",
+            "ref_list",
+            "ref_typed",
+            "ref_module",
+            "ref_list_struct",
+            "ref_client",
+            "ref_item",
+            "ref_uuid_type",
+        ]
+        .iter()
+        {
+            self.data.remove(f);
+        }
+    }
 
-        // create item builder
-        self.gen_builder(&list_info, BuilderType::New)?;
-        // update item builder
-        self.gen_builder(&list_info, BuilderType::Update)?;
+    /// Define builder template vars
+    fn set_builder_vars(&mut self, list_info: &ListSchema, builder_type: BuilderType) {
+        match builder_type {
+            BuilderType::New => {
+                self.set_bool("is_new_builder", true);
+                self.set_bool("is_update_builder", false);
+            }
+            BuilderType::Update => {
+                self.set_bool("is_new_builder", false);
+                self.set_bool("is_update_builder", true);
+            }
+        }
+        let item = self.list_names(&list_info.list).item;
+        self.set("builder", builder_name(&item, builder_type));
+    }
 
-        for f in ["list", "list_id", "list_short_id", "list_uuid", "item"].iter() {
+    /// Clear builder template vars
+    fn clear_builder_vars(&mut self) {
+        for f in ["is_update_builder", "is_new_builder", "builder"].iter() {
             self.data.remove(f);
         }
+    }
 
-        Ok(())
+    /// Define label template vars
+    fn set_label_vars(&mut self, field: &Element, label_id: u64, label: &str) {
+        let ident = match self.names.labels.get(&(field.uuid.clone(), label_id)) {
+            Some(ident) => ident.clone(),
+            None => snake_fragment(label, &format!("label_{}", label_id)),
+        };
+        self.set("label_upper", to_screaming_snake_case(&ident));
+        self.set("label_ident", ident);
+        self.set("label", label);
+        self.set_id("label_id", label_id);
+    }
+
+    /// Clear label template vars
+    fn clear_label_vars(&mut self) {
+        for f in ["label", "label_id", "label_ident", "label_upper"].iter() {
+            self.data.remove(f);
+        }
     }
 
     /// Generate label id and lookup. (per category field)
     fn gen_get_category(&mut self, field: &Element) -> Result<(), Error> {
         if let Some(ref categories) = field.element_data.predefined_categories {
-            let mut labels: Vec<&String> = Vec::new();
+            let mut labels: Vec<Value> = Vec::new();
             for c in categories {
                 // define label template vars
-                self.set("label", &c.name);
-                self.set_id("label_id", c.id);
+                self.set_label_vars(field, c.id, &c.name);
                 // generate "fn is_LABEL() -> bool" for each label
                 self.render("category_getter_per_label")?;
-                labels.push(&c.name);
+                labels.push(json!({ "name": c.name, "upper": self.data["label_upper"] }));
             }
             // clear label vars
-            self.data.remove("label");
-            self.data.remove("label_id");
+            self.clear_label_vars();
 
             // Generate lookup (label_id_for_FIELD)
             // and constant label->id lookup table
             // These are used by setters but we only need to generate once
             // and setters can access them with Item:: qualifier
             self.set_id("len", labels.len() as u64);
-            labels.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            labels.sort_unstable_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
             self.data.insert("labels", json!(labels));
             self.render("category_label_lookup")?;
 
@@ -166,14 +626,12 @@ impl<'gen> Generator<'gen> {
         if let Some(ref categories) = field.element_data.predefined_categories {
             for c in categories {
                 // define label template vars
-                self.set("label", &c.name);
-                self.set_id("label_id", c.id);
+                self.set_label_vars(field, c.id, &c.name);
                 // render label-specific setter
                 self.render("category_setter_per_label")?;
             }
             // clear label template vars
-            self.data.remove("label");
-            self.data.remove("label_id");
+            self.clear_label_vars();
         }
         // field-specific setter
         // get_Field(s)() -> Vec<String>
@@ -183,85 +641,16 @@ impl<'gen> Generator<'gen> {
     }
 
     /// The main list-item wrapper with all getters, and label lookups
-    fn gen_item_impl(&mut self, list_info: &Arc<ListInfo>) -> Result<(), Error> {
+    fn gen_item_impl(&mut self, list_info: &ListSchema) -> Result<(), Error> {
         for field in list_info
-            .fields()
+            .fields
             .iter()
             .filter(|f| f.deprecated_at.is_none())
         {
-            // define field template vars
-            self.set("field", &field.name);
-            self.set("field_uuid", &field.uuid);
-            self.set_id("field_id", field.id);
-            self.set("field_desc", field.get_description());
-            self.set_bool("field_single_value", !field.element_data.multiple);
-            self.set_bool("field_multiple_value", field.element_data.multiple);
-
-            // define field id,uuid, and name constants
-            self.render("field_const")?;
-
-            match field.element_category {
-                ElementCategoryId::Text => self.render("get_text_field"),
-                ElementCategoryId::URL => self.render("get_url_field"),
-                ElementCategoryId::Date => self.render("get_date_field"),
-                ElementCategoryId::Persons => self.render("get_person_field"),
-                ElementCategoryId::Number => {
-                    match field.numeric_type() {
-                        Some(NumericType::Integer) => self.render("get_int_field"),
-                        //Some(NumericType::Decimal) => {}
-                        //None =>
-                        _ => self.render("get_float_field"),
-                    }
-                }
-                ElementCategoryId::Checkbox => self.render("get_checkbox_field"),
-                ElementCategoryId::Categories => self.gen_get_category(&field),
-                ElementCategoryId::Files => self.render("get_files_field"),
-                ElementCategoryId::References => {
-                    if let Some(ChildList::Child(ref child_list)) = field.element_data.child_list {
-                        self.set("ref_list", &child_list.name);
-                    } else {
-                        self.set("ref_list", "");
-                    }
-                    self.render("get_references")?;
-                    self.data.remove("ref_list");
-                    Ok(())
-                }
-                ElementCategoryId::SubEntries => self.render("get_subitems_field"),
-                ElementCategoryId::Formula => self.render("get_formula_field"),
-
-                // handled in misc_getters
-                ElementCategoryId::UserCreatedBy
-                | ElementCategoryId::UserUpdatedBy
-                | ElementCategoryId::DateCreated
-                | ElementCategoryId::DateUpdated
-                | ElementCategoryId::DateDeprecated
-                | ElementCategoryId::UserDeprecatedBy => Ok(()),
-
-                // unimplemented ...
-                ElementCategoryId::Hierarchy | ElementCategoryId::Dependencies => {
-                    println!(
-                        "Warning: Field getters not implemented for {}.{} type {}",
-                        list_info.list().name,
-                        field.name,
-                        field.element_category
-                    );
-                    continue;
-                }
-            }?;
-        }
-
-        // clear field template vars
-        for f in [
-            "field",
-            "field_uuid",
-            "field_id",
-            "field_single_value",
-            "field_multiple_value",
-        ]
-        .iter()
-        {
-            self.data.remove(f);
+            self.set_field_vars(field);
+            self.gen_field_getters(&list_info.list.name, field)?;
         }
+        self.clear_field_vars();
 
         // end impl MyList
         self.render("end_list_impl")?;
@@ -269,211 +658,951 @@ impl<'gen> Generator<'gen> {
         Ok(())
     }
 
+    /// Field constants and getters (inside item impl). Field template vars must be set.
+    fn gen_field_getters(&mut self, list_name: &str, field: &Element) -> Result<(), Error> {
+        match field.element_category {
+            // unimplemented ...
+            ElementCategoryId::Hierarchy | ElementCategoryId::Dependencies => {
+                self.warn(format!(
+                    "Field getters not implemented for {}.{} type {}",
+                    list_name, field.name, field.element_category
+                ));
+                return Ok(());
+            }
+            _ => {}
+        }
+
+        // define field id,uuid, and name constants
+        self.render("field_const")?;
+
+        match field.element_category {
+            ElementCategoryId::Text => self.render("get_text_field"),
+            ElementCategoryId::URL => self.render("get_url_field"),
+            ElementCategoryId::Date => self.render("get_date_field"),
+            ElementCategoryId::Persons => self.render("get_person_field"),
+            ElementCategoryId::Number => {
+                match field.numeric_type() {
+                    Some(NumericType::Integer) => self.render("get_int_field"),
+                    //Some(NumericType::Decimal) => {}
+                    //None =>
+                    _ => self.render("get_float_field"),
+                }
+            }
+            ElementCategoryId::Checkbox => self.render("get_checkbox_field"),
+            ElementCategoryId::Categories => self.gen_get_category(field),
+            ElementCategoryId::Files => self.render("get_files_field"),
+            ElementCategoryId::References => self.render("get_references"),
+            ElementCategoryId::SubEntries => self.render("get_subitems_field"),
+            ElementCategoryId::Formula => self.render("get_formula_field"),
+
+            // handled in misc_getters
+            ElementCategoryId::UserCreatedBy
+            | ElementCategoryId::UserUpdatedBy
+            | ElementCategoryId::DateCreated
+            | ElementCategoryId::DateUpdated
+            | ElementCategoryId::DateDeprecated
+            | ElementCategoryId::UserDeprecatedBy
+            | ElementCategoryId::Hierarchy
+            | ElementCategoryId::Dependencies => Ok(()),
+        }
+    }
+
     /// Generates builders
     /// This is called twice: once for NewitemBuilder, once for UpdateItemBuilder
     fn gen_builder(
         &mut self,
-        list_info: &Arc<ListInfo>,
+        list_info: &ListSchema,
         builder_type: BuilderType, // either new or update
     ) -> Result<(), Error> {
         // set template vars for builder type
-        match builder_type {
-            BuilderType::New => {
-                self.set_bool("is_new_builder", true);
-                self.set_bool("is_update_builder", false);
+        self.set_builder_vars(list_info, builder_type);
+
+        self.render("start_item_builder")?;
+
+        for field in list_info
+            .fields
+            .iter()
+            .filter(|f| f.deprecated_at.is_none())
+        {
+            self.set_field_vars(field);
+            self.gen_field_setters(&list_info.list.name, field)?;
+        }
+        self.clear_field_vars();
+
+        self.render("builder_execute")?;
+        self.render("end_item_builder")?;
+
+        self.clear_builder_vars();
+        Ok(())
+    }
+
+    /// Generates the query for list items, with conditions and sort order for each field.
+    /// List template vars must be set.
+    fn gen_query(&mut self, list_info: &ListSchema) -> Result<(), Error> {
+        self.render("start_query")?;
+        for field in list_info
+            .fields
+            .iter()
+            .filter(|f| f.deprecated_at.is_none())
+        {
+            self.set_field_vars(field);
+            self.gen_field_filters(field)?;
+        }
+        self.clear_field_vars();
+        self.render("end_query")?;
+        Ok(())
+    }
+
+    /// Query conditions and sort order for a field (inside query impl).
+    /// Field template vars must be set.
+    fn gen_field_filters(&mut self, field: &Element) -> Result<(), Error> {
+        // suffix of the field's value in entries, for sorting
+        let sort_key = match field.element_category {
+            ElementCategoryId::Text => {
+                self.render("query_text_field")?;
+                Some("text")
             }
-            BuilderType::Update => {
-                self.set_bool("is_new_builder", false);
-                self.set_bool("is_update_builder", true);
+            ElementCategoryId::URL => {
+                self.render("query_text_field")?;
+                Some("link")
+            }
+            ElementCategoryId::Number => {
+                match field.numeric_type() {
+                    Some(NumericType::Integer) => self.render("query_int_field")?,
+                    _ => self.render("query_float_field")?,
+                }
+                Some("number")
+            }
+            ElementCategoryId::Date => {
+                self.render("query_date_field")?;
+                Some("date")
+            }
+            ElementCategoryId::Checkbox => {
+                self.render("query_checkbox_field")?;
+                Some("checked")
+            }
+            ElementCategoryId::Categories => {
+                self.render("query_category_field")?;
+                None
+            }
+            ElementCategoryId::Persons => {
+                self.render("query_person_field")?;
+                None
+            }
+            // no filters for these
+            _ => return Ok(()),
+        };
+        // unchecked checkboxes are empty
+        if field.element_category != ElementCategoryId::Checkbox {
+            self.render("query_empty_field")?;
+        }
+        if let Some(sort_key) = sort_key {
+            self.set("sort_key", sort_key);
+            self.render("query_order_by")?;
+            self.data.remove("sort_key");
+        }
+        Ok(())
+    }
+
+    /// Field setters (inside builder impl). Field and builder template vars must be set.
+    fn gen_field_setters(&mut self, list_name: &str, field: &Element) -> Result<(), Error> {
+        match field.element_category {
+            ElementCategoryId::Text => self.render("set_text_field"),
+            ElementCategoryId::URL => self.render("set_url_field"),
+            ElementCategoryId::Date => self.render("set_date_field"),
+            ElementCategoryId::Persons => self.render("set_person_field"),
+
+            ElementCategoryId::Number => {
+                match field.numeric_type() {
+                    Some(NumericType::Integer) => self.render("set_int_field"),
+                    //Some(NumericType::Decimal) => {}
+                    //None =>
+                    _ => self.render("set_float_field"),
+                }
+            }
+            ElementCategoryId::Checkbox => self.render("set_checkbox_field"),
+            ElementCategoryId::Categories => self.gen_set_labels(list_name, field),
+            ElementCategoryId::References => self.render("set_references"),
+            ElementCategoryId::SubEntries => self.render("set_subitems"),
+
+            // No api support for setting or updating these
+            ElementCategoryId::DateCreated
+            | ElementCategoryId::DateUpdated
+            | ElementCategoryId::DateDeprecated
+            | ElementCategoryId::UserCreatedBy
+            | ElementCategoryId::UserUpdatedBy
+            | ElementCategoryId::UserDeprecatedBy
+            | ElementCategoryId::Formula
+            | ElementCategoryId::Files
+            | ElementCategoryId::Hierarchy // deprecatd?
+            | ElementCategoryId::Dependencies // deprecated?
+               => Ok(()),
+        }
+    }
+
+    /// Returns the public symbols generated for a field, qualified with the type
+    /// that contains them, e.g., "Deal::get_stage" or "UpdateDealBuilder::set_stage".
+    /// Used to report api changes caused by schema changes.
+    pub fn field_symbols(
+        &mut self,
+        list_info: &ListSchema,
+        field: &Element,
+    ) -> Result<Vec<String>, Error> {
+        self.field_definitions(list_info, field, true)
+    }
+
+    /// Returns the definitions generated for a field, qualified with the type that
+    /// contains them. If public_only is true, only public definitions are returned.
+    fn field_definitions(
+        &mut self,
+        list_info: &ListSchema,
+        field: &Element,
+        public_only: bool,
+    ) -> Result<Vec<String>, Error> {
+        // save any pending output
+        let pending = self.clone_reset();
+        let num_origins = self.origins.len();
+        let mut symbols = Vec::new();
+        let scan = |buf: &[u8]| {
+            if public_only {
+                pub_symbols(buf)
+            } else {
+                String::from_utf8_lossy(buf)
+                    .lines()
+                    .filter_map(definition_name)
+                    .collect()
+            }
+        };
+
+        self.set_list_vars(list_info);
+        self.set_field_vars(field);
+        let item = self.list_names(&list_info.list).item;
+        self.gen_field_getters(&list_info.list.name, field)?;
+        symbols.extend(
+            scan(&self.clone_reset())
+                .into_iter()
+                .map(|sym| format!("{}::{}", item, sym)),
+        );
+        for builder_type in BUILDER_TYPES.iter() {
+            self.set_builder_vars(list_info, *builder_type);
+            self.gen_field_setters(&list_info.list.name, field)?;
+            let builder = builder_name(&item, *builder_type);
+            symbols.extend(
+                scan(&self.clone_reset())
+                    .into_iter()
+                    .map(|sym| format!("{}::{}", builder, sym)),
+            );
+        }
+        self.clear_builder_vars();
+        self.gen_field_filters(field)?;
+        let query = query_type_name(&item);
+        symbols.extend(
+            scan(&self.clone_reset())
+                .into_iter()
+                .map(|sym| format!("{}::{}", query, sym)),
+        );
+        self.clear_field_vars();
+        self.clear_list_vars();
+
+        self.buf = pending;
+        self.origins.truncate(num_origins);
+        Ok(symbols)
+    }
+
+    /// Returns names of the definitions in a template, rendered with the current variables
+    fn render_definitions(&mut self, tmpl: &str) -> Result<Vec<String>, Error> {
+        Ok(self
+            .hb
+            .render(tmpl, &self.data)?
+            .lines()
+            .filter_map(definition_name)
+            .collect())
+    }
+
+    /// Returns the names for a list, resolved by assign_list_names, or the default names
+    fn list_names(&self, list: &List) -> ListNames {
+        match self.names.lists.get(&list.uuid) {
+            Some(names) => names.clone(),
+            None => {
+                let (item, item_plural) = item_names(list);
+                ListNames {
+                    module: list_module_name(list),
+                    list_struct: list_struct_name(list),
+                    item,
+                    item_plural,
+                }
             }
         }
-        self.set(
-            "builder",
-            format!(
-                "{}{}Builder",
-                match builder_type {
-                    BuilderType::Update => "Update",
-                    BuilderType::New => "New",
-                },
-                // "item" was already set to be the preferred name for object
+    }
+
+    /// Assigns names to the lists, fields, and labels of a schema, as render_workspace does,
+    /// so that list_symbols and field_symbols return the names that would be generated.
+    pub fn assign_names(&mut self, schema: &WorkspaceSchema) -> Result<(), Error> {
+        self.set_bool("category_enums", self.options.category_enums);
+        self.assign_list_names(schema);
+        for list_info in schema.lists.iter() {
+            self.assign_field_names(list_info)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the public module and types generated for a list, e.g., "mod deal_tracker"
+    /// and "DealTrackerList". Types are listed in the same order for every list.
+    pub fn list_symbols(&self, list: &List) -> Vec<String> {
+        let names = self.list_names(list);
+        let mut symbols = vec![format!("mod {}", names.module)];
+        symbols.extend(list_type_names(&names));
+        symbols
+    }
+
+    /// Returns the identifier fragment for a field, resolved by assign_field_names,
+    /// or the default name
+    fn field_ident(&self, field: &Element) -> String {
+        match self.names.fields.get(&field.uuid) {
+            Some(ident) => ident.clone(),
+            None => snake_fragment(&field.name, &format!("field_{}", field.id)),
+        }
+    }
+
+    /// Returns the name of the label enum for a category field, resolved by
+    /// assign_field_names, or the default name (item name + field name).
+    /// List template vars must be set.
+    fn enum_name(&self, field: &Element) -> String {
+        match self.names.enums.get(&field.uuid) {
+            Some(name) => name.clone(),
+            None => format!(
+                "{}{}",
                 self.data
                     .get("item")
-                    .map(|s| s.as_str())
-                    .unwrap_or_default()
-                    .unwrap_or(&to_singular(&to_class_case(&list_info.list().name)))
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default(),
+                to_pascal_case(&self.field_ident(field))
             ),
-        );
+        }
+    }
 
-        self.render("start_item_builder")?;
+    /// Assigns module and type names to lists, so that lists with similar names
+    /// don't generate the same modules or types, or use reserved names.
+    /// Lists are processed in order, so the first list keeps its name.
+    fn assign_list_names(&mut self, schema: &WorkspaceSchema) {
+        self.names = Names::default();
+        self.warnings.clear();
+        let builtin = |names: &[&str]| -> Map<String, String> {
+            names
+                .iter()
+                .map(|n| (n.to_string(), "a built-in name".to_string()))
+                .collect()
+        };
+        let mut modules = builtin(RESERVED_MODULES);
+        let mut types = builtin(RESERVED_TYPES);
+
+        for list in schema.workspace.lists.iter() {
+            let defaults = self.list_names(list);
+            let owner = format!("list '{}'", list.name);
+
+            let mut conflict = None;
+            let mut module = defaults.module.clone();
+            for n in 1.. {
+                module = numbered(&defaults.module, "_", n);
+                match modules.get(&module) {
+                    Some(other) => {
+                        conflict.get_or_insert(format!("{} (mod {})", other, module));
+                    }
+                    None => break,
+                }
+            }
+            if let Some(conflict) = conflict.take() {
+                self.warn(format!(
+                    "list '{}' conflicts with {}; using mod {}",
+                    list.name, conflict, module
+                ));
+            }
+            modules.insert(module.clone(), owner.clone());
+
+            for n in 1.. {
+                let names = ListNames {
+                    module: module.clone(),
+                    list_struct: if n == 1 {
+                        defaults.list_struct.clone()
+                    } else {
+                        format!("{}{}List", defaults.list_struct.trim_end_matches("List"), n)
+                    },
+                    item: numbered(&defaults.item, "", n),
+                    item_plural: numbered(&defaults.item_plural, "", n),
+                };
+                let type_names = list_type_names(&names);
+                if let Some(clash) = type_names
+                    .iter()
+                    .find_map(|t| types.get(t).map(|other| format!("{} ({})", other, t)))
+                {
+                    conflict.get_or_insert(clash);
+                    continue;
+                }
+                if let Some(conflict) = conflict {
+                    self.warn(format!(
+                        "list '{}' conflicts with {}; using types {} and {}",
+                        list.name, conflict, names.list_struct, names.item
+                    ));
+                }
+                for t in type_names.into_iter() {
+                    types.insert(t, owner.clone());
+                }
+                self.names.lists.insert(list.uuid.clone(), names);
+                break;
+            }
+        }
+        self.names.types = types;
+    }
+
+    /// Assigns identifiers to the fields and labels of a list, so that no two generated
+    /// definitions in an item or builder have the same name. Fields are processed
+    /// in order, so the first field keeps its name. Clears list template vars.
+    fn assign_field_names(&mut self, list_info: &ListSchema) -> Result<(), Error> {
+        self.set_list_vars(list_info);
+        let list_name = &list_info.list.name;
+        let item = self.list_names(&list_info.list).item;
+
+        // qualified symbol -> what generated it
+        let mut taken: Map<String, String> = Map::new();
+        let builtin = "a built-in method".to_string();
+        for sym in self.render_definitions("end_list_impl")? {
+            taken.insert(format!("{}::{}", item, sym), builtin.clone());
+        }
+        taken.insert(format!("{}::new", item), builtin.clone());
+        for builder_type in BUILDER_TYPES.iter() {
+            self.set_builder_vars(list_info, *builder_type);
+            let builder = builder_name(&item, *builder_type);
+            for tmpl in ["start_item_builder", "builder_execute"].iter() {
+                for sym in self.render_definitions(tmpl)? {
+                    taken.insert(format!("{}::{}", builder, sym), builtin.clone());
+                }
+            }
+        }
+        self.clear_builder_vars();
+        let query = query_type_name(&item);
+        for sym in self.render_definitions("start_query")? {
+            taken.insert(format!("{}::{}", query, sym), builtin.clone());
+        }
 
         for field in list_info
-            .fields()
+            .fields
             .iter()
             .filter(|f| f.deprecated_at.is_none())
         {
-            // set field template vars
-            self.set("field", &field.name);
-            self.set("field_uuid", &field.uuid);
-            self.set_id("field_id", field.id);
-            self.set_bool("field_single_value", !field.element_data.multiple);
-            self.set_bool("field_multiple_value", field.element_data.multiple);
-            self.set("field_desc", field.get_description());
-
-            match field.element_category {
-                ElementCategoryId::Text => self.render("set_text_field"),
-                ElementCategoryId::URL => self.render("set_url_field"),
-                ElementCategoryId::Date => self.render("set_date_field"),
-                ElementCategoryId::Persons => self.render("set_person_field"),
-
-                ElementCategoryId::Number => {
-                    match field.numeric_type() {
-                        Some(NumericType::Integer) => self.render("set_int_field"),
-                        //Some(NumericType::Decimal) => {}
-                        //None =>
-                        _ => self.render("set_float_field"),
-                    }
+            let base = snake_fragment(&field.name, &format!("field_{}", field.id));
+            self.names.fields.insert(field.uuid.clone(), base.clone());
+            self.assign_label_names(list_info, field)?;
+            let mut conflict = None;
+            for n in 1.. {
+                let ident = numbered(&base, "_", n);
+                self.names.fields.insert(field.uuid.clone(), ident.clone());
+                let symbols = self.field_definitions(list_info, field, false)?;
+                let clash = symbols.iter().find_map(|sym| {
+                    taken
+                        .get(sym)
+                        .map(|owner| format!("{} ({})", owner, sym))
+                });
+                if let Some(clash) = clash {
+                    conflict.get_or_insert(clash);
+                    continue;
                 }
-                ElementCategoryId::Checkbox => self.render("set_checkbox_field"),
-                ElementCategoryId::Categories => {
-                    self.gen_set_labels(&list_info.list().name, &field)
+                if let Some(conflict) = conflict {
+                    self.warn(format!(
+                        "field '{}'.'{}' conflicts with {}; using name {}",
+                        list_name, field.name, conflict, ident
+                    ));
                 }
-                ElementCategoryId::References => {
-                    if let Some(ChildList::Child(ref child_list)) = field.element_data.child_list {
-                        self.set("ref_list", &child_list.name);
-                    } else {
-                        self.set("ref_list", "");
-                    }
-                    self.render("set_references")
+                for sym in symbols.into_iter() {
+                    taken.insert(sym, format!("field '{}'", field.name));
                 }
-                ElementCategoryId::SubEntries => self.render("set_subitems"),
-
-                // No api support for setting or updating these
-                ElementCategoryId::DateCreated
-                | ElementCategoryId::DateUpdated
-                | ElementCategoryId::DateDeprecated
-                | ElementCategoryId::UserCreatedBy
-                | ElementCategoryId::UserUpdatedBy
-                | ElementCategoryId::UserDeprecatedBy
-                | ElementCategoryId::Formula
-                | ElementCategoryId::Files
-                | ElementCategoryId::Hierarchy // deprecatd?
-                | ElementCategoryId::Dependencies // deprecated?
-                   => Ok(()),
-            }?;
-            self.data.remove("ref_list");
+                break;
+            }
+            if self.options.category_enums
+                && field.element_category == ElementCategoryId::Categories
+            {
+                self.assign_enum_name(list_info, field);
+            }
         }
+        Ok(())
+    }
 
-        self.render("builder_execute")?;
-        self.render("end_item_builder")?;
+    /// Assigns the name of the label enum for a category field, so that it doesn't
+    /// conflict with other generated types. The field name must be assigned.
+    fn assign_enum_name(&mut self, list_info: &ListSchema, field: &Element) {
+        self.set_list_vars(list_info);
+        let base = self.enum_name(field);
+        self.clear_list_vars();
+        let name = (1..)
+            .map(|n| numbered(&base, "", n))
+            .find(|name| !self.names.types.contains_key(name))
+            .unwrap_or_default();
+        if name != base {
+            self.warn(format!(
+                "enum for '{}'.'{}' conflicts with {} ({}); using name {}",
+                list_info.list.name, field.name, self.names.types[&base], base, name
+            ));
+        }
+        self.names.types.insert(
+            name.clone(),
+            format!("field '{}'.'{}'", list_info.list.name, field.name),
+        );
+        self.names.enums.insert(field.uuid.clone(), name);
+    }
 
-        // clear field template vars
-        for f in [
-            "field",
-            "field_uuid",
-            "field_id",
-            "field_single_value",
-            "field_multiple_value",
-            "is_update_builder",
-            "is_new_builder",
-            "builder",
-        ]
-        .iter()
-        {
-            self.data.remove(f);
+    /// Assigns identifiers to the labels of a category field, so that labels don't
+    /// generate the same definitions as other labels or the field
+    fn assign_label_names(&mut self, list_info: &ListSchema, field: &Element) -> Result<(), Error> {
+        let categories = match field.element_data.predefined_categories {
+            Some(ref categories) if !categories.is_empty() => categories,
+            _ => return Ok(()),
+        };
+        // field with the labels assigned so far
+        let mut partial = copy_value(field)?;
+        partial.element_data.predefined_categories = Some(Vec::new());
+        // symbol -> what generated it
+        let mut owners: Map<String, String> = Map::new();
+        for sym in self.field_definitions(list_info, &partial, false)? {
+            owners.insert(sym, format!("field '{}'", field.name));
+        }
+        let prev_dups = duplicates(&self.field_definitions(list_info, &partial, false)?);
+
+        for label in categories.iter() {
+            let base = snake_fragment(&label.name, &format!("label_{}", label.id));
+            let mut candidate = copy_value(&partial)?;
+            if let Some(ref mut labels) = candidate.element_data.predefined_categories {
+                labels.push(copy_value(label)?);
+            }
+            let mut conflict = None;
+            for n in 1.. {
+                let ident = numbered(&base, "_", n);
+                self.names
+                    .labels
+                    .insert((field.uuid.clone(), label.id), ident.clone());
+                let symbols = self.field_definitions(list_info, &candidate, false)?;
+                let clash = duplicates(&symbols)
+                    .difference(&prev_dups)
+                    .next()
+                    .map(|sym| match owners.get(sym) {
+                        Some(owner) => format!("{} ({})", owner, sym),
+                        None => sym.clone(),
+                    });
+                if let Some(clash) = clash {
+                    conflict.get_or_insert(clash);
+                    continue;
+                }
+                if let Some(conflict) = conflict {
+                    self.warn(format!(
+                        "label '{}' of '{}'.'{}' conflicts with {}; using name {}",
+                        label.name, list_info.list.name, field.name, conflict, ident
+                    ));
+                }
+                for sym in symbols.into_iter() {
+                    owners
+                        .entry(sym)
+                        .or_insert_with(|| format!("label '{}'", label.name));
+                }
+                break;
+            }
+            partial = candidate;
         }
         Ok(())
     }
 
-    /// Writes the internal buffer to the module output file
-    fn write_to(&mut self, fpath: &str) -> Result<(), Error> {
+    /// Returns the contents of the internal buffer as a generated file, and empties the buffer
+    fn take_file(&mut self, path: String) -> GeneratedFile {
         let buf = self.clone_reset();
-        std::fs::write(&fpath, &buf.as_ref())?;
-        Ok(())
+        // origins recorded since the last file are in this file
+        for origin in self.origins.iter_mut().rev() {
+            if !origin.file.is_empty() {
+                break;
+            }
+            origin.file = path.clone();
+        }
+        GeneratedFile {
+            path,
+            contents: String::from_utf8_lossy(buf.as_ref()).to_string(),
+        }
     }
 
     /// Generates Rust library crate for business data model based on Zenkit workspace.
     /// To support a continuous integration workflow, source *.rs files are always overwritten,
     /// so they reflect the most recent schema; but if Cargo.toml exists, Cargo.toml.gen
     /// is created, so that crate-level modifications are not overwritten.
-    pub async fn gen_workspace<'ws>(
+    /// Returns the paths of files written.
+    pub fn gen_workspace(
         &mut self,
-        api: &ApiClient,
-        workspace: Arc<Workspace>,
+        schema: &WorkspaceSchema,
         output_dir: &str,
     ) -> Result<Vec<String>, Error> {
+        let mut sink = DirSink::new(output_dir);
+        self.write_workspace(schema, &mut sink)?;
+        Ok(sink.written().to_vec())
+    }
+
+    /// Loads the schema from the source, and sends generated files to the output sink
+    pub async fn generate(
+        &mut self,
+        source: &dyn SchemaSource,
+        sink: &mut dyn OutputSink,
+    ) -> Result<(), Error> {
+        let schema = source.load_schema().await?;
+        self.write_workspace(&schema, sink)
+    }
+
+    /// Renders all files for the workspace and sends them to the output sink
+    pub fn write_workspace(
+        &mut self,
+        schema: &WorkspaceSchema,
+        sink: &mut dyn OutputSink,
+    ) -> Result<(), Error> {
+        for file in self.render_workspace(schema)?.into_iter() {
+            sink.write_file(file)?;
+        }
+        Ok(())
+    }
+
+    /// Renders all files for the crate, without writing them.
+    /// Paths of the returned files are relative to the crate directory.
+    /// Rust sources are returned as generated, before formatting with rustfmt.
+    pub fn render_workspace(
+        &mut self,
+        schema: &WorkspaceSchema,
+    ) -> Result<Vec<GeneratedFile>, Error> {
+        let workspace = &schema.workspace;
+        let single_file = self.options.layout == Layout::SingleFile;
         // define workspace template vars
-        self.set("generated_banner", get_generated_banner());
+        let banner = if self.options.reproducible {
+            get_reproducible_banner(&schema.content_hash_with_version(env!("CARGO_PKG_VERSION"))?)
+        } else {
+            get_generated_banner()
+        };
+        self.set("generated_banner", banner);
         self.set("workspace", &workspace.name);
+        self.set("workspace_ident", snake_fragment(&workspace.name, "workspace"));
         self.set("workspace_uuid", &workspace.uuid);
         self.set_id("workspace_id", workspace.id);
         self.set_opt("workspace_desc", &workspace.description);
+        self.set_bool("single_file", single_file);
+        self.set_bool("category_enums", self.options.category_enums);
+        self.origins.clear();
+        self.assign_list_names(schema);
 
-        // list of file paths generated
-        let mut files: Vec<String> = Vec::new();
+        let mut files: Vec<GeneratedFile> = Vec::new();
 
-        // module files created
-        let mut modules: Vec<String> = Vec::new();
+        // modules created. In single-file layout, module source is inlined in the lib template
+        let mut modules: Vec<Value> = Vec::new();
 
         // generate file for each List
         for list in workspace.lists.iter() {
-            let list_info = api.get_list_info(workspace.id, &list.uuid).await?;
-            self.gen_list(&list_info)?;
-            let mod_name = to_snake_case(&list.name);
-            let fpath = format!("{}/src/{}.rs", output_dir, mod_name);
-            self.write_to(&fpath)?;
-            modules.push(mod_name);
-            files.push(fpath);
-        }
-        println!("Modules: {:#?}", &modules);
-
-        // write the crate lib.rs
-        self.data.insert("modules", json!(modules));
+            let list_info = schema
+                .lists
+                .iter()
+                .find(|li| li.list.uuid == list.uuid)
+                .ok_or_else(|| {
+                    Error::Message(format!(
+                        "schema is missing field definitions for list '{}'",
+                        list.name
+                    ))
+                })?;
+            self.gen_list(list_info)?;
+            let names = self.list_names(list);
+            let file = self.take_file(format!("src/{}.rs", names.module));
+            let mut module = json!({
+                "name": names.module,
+                "list": list.name,
+                "client": client_type_name(&names.list_struct),
+                "list_id": list.id,
+                "list_uuid": list.uuid,
+                "source": "",
+            });
+            if single_file {
+                module["source"] = Value::String(file.contents);
+            } else {
+                files.push(file);
+            }
+            modules.push(module);
+        }
+
+        // the crate lib.rs
+        self.data.insert("modules", Value::Array(modules));
+        self.data.insert("labels", Value::Array(workspace_labels(schema)));
+        self.data
+            .insert("workspace_fields", Value::Array(workspace_fields(schema)));
         self.render("lib_main")?;
-        let fpath = format!("{}/src/lib.rs", output_dir);
-        self.write_to(&fpath)?;
-        files.push(fpath);
+        if single_file {
+            files.push(self.take_file(SINGLE_FILE_NAME.to_string()));
+            // modules are inlined, so all definitions are in the single file
+            for origin in self.origins.iter_mut() {
+                origin.file = SINGLE_FILE_NAME.to_string();
+            }
+            return Ok(files);
+        }
+        files.push(self.take_file("src/lib.rs".to_string()));
 
-        let crate_name = to_snake_case(&workspace.name);
+        let crate_name = snake_ident(&workspace.name, "zenkit_client");
         self.set("crate", &crate_name);
         self.render("cargo_toml")?;
-
-        // For lib.rs and all list modules, we overwrite any existing files,
-        // but if Cargo.toml exists don't overwrite in case it was locally modified.
-        let mut fpath = format!("{}/Cargo.toml", output_dir);
-        if std::fs::metadata(&fpath).is_ok() {
-            fpath.push_str(".gen");
-        }
-        self.write_to(&fpath)?;
-        files.push(fpath);
+        files.push(self.take_file(CARGO_TOML.to_string()));
 
         Ok(files)
     }
 
+    /// Returns the origins of definitions in the files generated by the most recent
+    /// call to render_workspace (or gen_workspace, write_workspace, or generate).
+    /// In the single-file layout, module definitions also appear with template "lib_main",
+    /// after the entry for the template that generated them.
+    pub fn origins(&self) -> &[SymbolOrigin] {
+        &self.origins
+    }
+
+    /// Returns warnings from the most recent call to render_workspace (or gen_workspace,
+    /// write_workspace, generate, or assign_names), such as names that were changed
+    /// to avoid a collision. Each warning is reported once.
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
+    /// Adds a warning, unless it was already reported
+    fn warn(&mut self, msg: String) {
+        if !self.warnings.contains(&msg) {
+            self.warnings.push(msg);
+        }
+    }
+
     /// Render a template with the current dictionary
     fn render(&mut self, tmpl: &str) -> Result<(), Error> {
-        self.buf
-            .extend(self.hb.render(tmpl, &self.data)?.as_bytes());
+        let output = self.hb.render(tmpl, &self.data)?;
+        self.record_origins(tmpl, &output);
+        self.buf.extend(output.as_bytes());
         Ok(())
     }
 
+    /// Records the origin of each definition in rendered template output.
+    /// The file path is filled in by take_file.
+    fn record_origins(&mut self, tmpl: &str, output: &str) {
+        let var = |data: &RenderMap, key: &str| {
+            data.get(key)
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string())
+        };
+        for symbol in output.lines().filter_map(definition_name) {
+            self.origins.push(SymbolOrigin {
+                file: String::new(),
+                symbol,
+                list: var(&self.data, "list"),
+                field: var(&self.data, "field"),
+                label: var(&self.data, "label"),
+                template: tmpl.to_string(),
+            });
+        }
+    }
+
     /// Returns copy of current buffer and empties own buffer
     pub fn clone_reset(&mut self) -> BytesMut {
         self.buf.split()
     }
 }
 
+/// Types generated for a list
+fn list_type_names(names: &ListNames) -> Vec<String> {
+    vec![
+        names.list_struct.clone(),
+        client_type_name(&names.list_struct),
+        builder_name(&names.item, BuilderType::New),
+        builder_name(&names.item, BuilderType::Update),
+        id_type_name(&names.item),
+        uuid_type_name(&names.item),
+        record_type_name(&names.item),
+        query_type_name(&names.item),
+        names.item.clone(),
+    ]
+}
+
+/// Returns true if the line contains the generated banner. The banner includes the time
+/// of generation, so it is ignored when comparing generated files.
+pub fn is_banner_line(line: &str) -> bool {
+    line.contains(&format!("Generated by {} v", PROGRAM_NAME))
+}
+
 /// Banner for file header indicating automatic generation
 fn get_generated_banner() -> String {
     format!(
         "Generated by {} v{} on {}",
-        env!("CARGO_BIN_NAME"),
+        PROGRAM_NAME,
         env!("CARGO_PKG_VERSION"),
         get_display_time()
     )
 }
 
+/// Banner for file header, with hash of the schema and generator version instead of timestamp
+fn get_reproducible_banner(schema_hash: &str) -> String {
+    format!(
+        "Generated by {} v{} from schema {}",
+        PROGRAM_NAME,
+        env!("CARGO_PKG_VERSION"),
+        &schema_hash[..16]
+    )
+}
+
 /// Returns current time UTC in RFC 3339 and ISO 8601 format, e.g., "2020-01-01T14:00:00Z"
 fn get_display_time() -> String {
     use chrono::{SecondsFormat, Utc};
     Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true)
 }
+
+/// Name of the generated list struct, e.g., "DealTrackerList"
+pub fn list_struct_name(list: &List) -> String {
+    format!("{}List", pascal_ident(&list.name, &format!("list_{}", list.id)))
+}
+
+/// Name of the generated module for the list, e.g., "deal_tracker"
+pub fn list_module_name(list: &List) -> String {
+    snake_ident(&list.name, &format!("list_{}", list.id))
+}
+
+/// Returns the generated item struct name, singular and plural,
+/// using the item name from the list settings if one was provided
+pub fn item_names(list: &List) -> (String, String) {
+    let fallback = format!("item_{}", list.id);
+    let item = if let Some(ref item_name) = list.item_name {
+        // they specified item name in ui
+        class_ident(item_name, &fallback)
+    } else {
+        class_ident(&list.name, &fallback)
+    };
+    let item_plural = if let Some(ref item_name_plural) = list.item_name_plural {
+        class_ident(item_name_plural, &to_plural(&item))
+    } else {
+        to_plural(&item)
+    };
+    (item, item_plural)
+}
+
+/// Returns the category labels of all lists in the workspace, as `{id, name}`, sorted by id
+fn workspace_labels(schema: &WorkspaceSchema) -> Vec<Value> {
+    let labels: Map<u64, &str> = schema
+        .lists
+        .iter()
+        .flat_map(|list| list.fields.iter())
+        .filter_map(|field| field.element_data.predefined_categories.as_ref())
+        .flat_map(|categories| categories.iter())
+        .map(|c| (c.id, c.name.as_str()))
+        .collect();
+    labels
+        .into_iter()
+        .map(|(id, name)| json!({ "id": id, "name": name }))
+        .collect()
+}
+
+/// Returns the fields of all lists in the workspace, as `{id, uuid}`, sorted by id
+fn workspace_fields(schema: &WorkspaceSchema) -> Vec<Value> {
+    let fields: Map<u64, &str> = schema
+        .lists
+        .iter()
+        .flat_map(|list| list.fields.iter())
+        .map(|field| (field.id, field.uuid.as_str()))
+        .collect();
+    fields
+        .into_iter()
+        .map(|(id, uuid)| json!({ "id": id, "uuid": uuid }))
+        .collect()
+}
+
+/// Name of the generated client struct for a list, e.g., "DealTrackerClient"
+fn client_type_name(list_struct: &str) -> String {
+    format!("{}Client", list_struct.trim_end_matches("List"))
+}
+
+/// Name of the generated id type for list items, e.g., "DealId"
+fn id_type_name(item: &str) -> String {
+    format!("{}Id", item)
+}
+
+/// Name of the generated uuid type for list items, e.g., "DealUuid"
+fn uuid_type_name(item: &str) -> String {
+    format!("{}Uuid", item)
+}
+
+/// Name of the generated record struct for list items, e.g., "DealRecord"
+fn record_type_name(item: &str) -> String {
+    format!("{}Record", item)
+}
+
+/// Name of the generated query struct for list items, e.g., "DealQuery"
+fn query_type_name(item: &str) -> String {
+    format!("{}Query", item)
+}
+
+/// Name of the generated builder struct, e.g., "NewDealBuilder"
+fn builder_name(item: &str, builder_type: BuilderType) -> String {
+    format!(
+        "{}{}Builder",
+        match builder_type {
+            BuilderType::Update => "Update",
+            BuilderType::New => "New",
+        },
+        item
+    )
+}
+
+/// Returns symbols that occur more than once
+fn duplicates(symbols: &[String]) -> Set<String> {
+    let mut seen = Set::new();
+    symbols
+        .iter()
+        .filter(|sym| !seen.insert(*sym))
+        .cloned()
+        .collect()
+}
+
+/// If the line starts a definition (fn, const, static, struct, enum, trait, type, or mod),
+/// returns the name of the definition
+pub fn definition_name(line: &str) -> Option<String> {
+    let mut words = line.split_whitespace().skip_while(|w| {
+        *w == "pub" || w.starts_with("pub(") || *w == "async" || *w == "unsafe" || *w == "extern"
+    });
+    match words.next() {
+        Some("fn") | Some("const") | Some("static") | Some("struct") | Some("enum")
+        | Some("trait") | Some("type") | Some("mod") => {
+            let name: String = words
+                .next()?
+                .chars()
+                .take_while(|c| c.is_alphanumeric() || *c == '_')
+                .collect();
+            if name.is_empty() {
+                None
+            } else {
+                Some(name)
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns names of public functions and constants in generated source
+fn pub_symbols(src: &[u8]) -> Vec<String> {
+    let src = String::from_utf8_lossy(src);
+    let mut symbols = Vec::new();
+    let mut words = src.split_whitespace();
+    while let Some(word) = words.next() {
+        if word != "pub" {
+            continue;
+        }
+        let mut kind = words.next().unwrap_or_default();
+        if kind == "async" {
+            kind = words.next().unwrap_or_default();
+        }
+        if kind == "fn" || kind == "const" || kind == "struct" || kind == "enum" {
+            if let Some(name) = words.next() {
+                let name: String = name
+                    .chars()
+                    .take_while(|c| c.is_alphanumeric() || *c == '_')
+                    .collect();
+                if !name.is_empty() {
+                    symbols.push(name);
+                }
+            }
+        }
+    }
+    symbols
+}
\ No newline at end of file
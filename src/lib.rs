@@ -0,0 +1,57 @@
+//! Generate Zenkit ORM client library in Rust, based on user-defined workspace schema.
+//!
+//! This crate is the library behind the `zk-codegen` program. It can also be used
+//! from a build script to generate the client from a committed schema snapshot
+//! into `OUT_DIR`:
+//!
+//! ```no_run
+//! // build.rs
+//! use zenkit_codegen::{sink::DirSink, Generator, Layout, Options, WorkspaceSchema};
+//!
+//! fn main() -> Result<(), zenkit_codegen::Error> {
+//!     println!("cargo:rerun-if-changed=zenkit-schema.json");
+//!     let schema = WorkspaceSchema::load("zenkit-schema.json")?;
+//!     let mut gen = Generator::with_options(Options {
+//!         layout: Layout::SingleFile,
+//!         reproducible: true,
+//!         ..Default::default()
+//!     })?;
+//!     let out_dir = std::env::var("OUT_DIR").expect("OUT_DIR");
+//!     gen.write_workspace(&schema, &mut DirSink::new(out_dir))?;
+//!     // name collisions are reported as warnings, which cargo shows for a build script
+//!     // only as `cargo:warning=` lines
+//!     for warning in gen.warnings() {
+//!         println!("cargo:warning={}", warning);
+//!     }
+//!     Ok(())
+//! }
+//! ```
+//!
+//! and then, in the app,
+//!
+//! ```ignore
+//! mod crm {
+//!     include!(concat!(env!("OUT_DIR"), "/zenkit_client.rs"));
+//! }
+//! ```
+//!
+//! The including crate needs the dependencies listed in the generated Cargo.toml.
+
+pub mod check;
+pub mod compile;
+pub mod diff;
+mod error;
+pub use error::Error;
+pub mod escape;
+pub mod gen;
+pub mod naming;
+pub use gen::{GeneratedFile, Generator, Layout, Options};
+pub mod schema;
+pub use schema::{ListSchema, WorkspaceSchema};
+pub mod server;
+pub mod sink;
+pub mod source;
+mod templates;
+
+/// Program name used in the banner of generated files
+pub const PROGRAM_NAME: &str = "zk-codegen";
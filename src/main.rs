@@ -1,12 +1,16 @@
 use clap::Clap;
-use zenkit::{init_api, ApiConfig};
+use zenkit::{get_api, init_api, ApiClient, ApiConfig};
 use config::Config;
+use zenkit_codegen::{
+    check,
+    compile::{self, BuildMode},
+    diff,
+    source::{SchemaSource, SnapshotFile, ZenkitApi},
+    Error, Generator, Options, WorkspaceSchema,
+};
 
-mod error;
-use error::Error;
-mod gen;
-use gen::Generator;
-mod templates;
+/// Environment variable for the api endpoint
+const ENDPOINT_VAR: &str = "ZENKIT_ENDPOINT";
 
 /// Zenkit Rust-client code generator. Source and docs at https://github.com/stevelr/zenkit-codegen
 #[derive(Clap, PartialEq, Debug)]
@@ -23,7 +27,8 @@ struct Opt {
     #[clap(short, long)]
     workspace: Option<String>,
 
-    /// path to config file containing keys zenkit.token and zenkit.workspace
+    /// path to config file containing keys zenkit.token, zenkit.workspace,
+    /// and (optionally) zenkit.endpoint
     /// Example (TOML format):
     /// ```toml
     /// [zenkit]
@@ -33,13 +38,70 @@ struct Opt {
     #[clap(short, long)]
     config: Option<String>,
 
-    /// Test builder
+    /// Zenkit api endpoint, for a proxy or test server. Can be set in config file
+    /// or in environment variable ZENKIT_ENDPOINT. Default: https://zenkit.com/api/v1
+    #[clap(long, value_name = "URL")]
+    endpoint: Option<String>,
+
+    /// After generating, run `cargo check` on the generated crate. Compile errors
+    /// are reported with the list, field, and template that generated the code.
     #[clap(long)]
     build: bool,
 
+    /// After generating, run `cargo test` on the generated crate (implies --build)
+    #[clap(long)]
+    test: bool,
+
+    /// Save the workspace schema (lists and field definitions) to a json file.
+    /// If no output directory is given, the program exits after writing the file.
+    #[clap(long, value_name = "FILE")]
+    dump_schema: Option<String>,
+
+    /// Generate code from a schema file previously saved with --dump-schema,
+    /// instead of querying Zenkit. No api token or network access is needed.
+    #[clap(long, value_name = "FILE")]
+    from_schema: Option<String>,
+
     /// Output directory for generated file
     #[clap(short, long)]
-    output: String,
+    output: Option<String>,
+
+    /// Check whether the files in the output directory are up to date, without writing anything.
+    /// Exits with status 1 if any generated file is missing or different.
+    #[clap(long)]
+    check: bool,
+
+    /// Make output reproducible: the banner at the top of generated files contains
+    /// a hash of the schema and the version of this program instead of the current time,
+    /// so files only change when the schema or the version changes.
+    #[clap(long)]
+    reproducible: bool,
+
+    /// Generate an enum for the labels of each category field. Category getters
+    /// return the enum, and builder setters accept it.
+    #[clap(long)]
+    category_enums: bool,
+
+    #[clap(subcommand)]
+    cmd: Option<Command>,
+}
+
+#[derive(Clap, PartialEq, Debug)]
+enum Command {
+    /// Compare two workspace schemas and report changes that break the generated api.
+    /// Exits with status 2 if there are breaking changes.
+    Diff(DiffOpt),
+}
+
+/// Schemas may be snapshot files (created with --dump-schema), or "zenkit:WORKSPACE"
+/// to load the current schema from Zenkit. If WORKSPACE is omitted ("zenkit:"),
+/// the workspace from the command line, config file, or environment is used.
+#[derive(Clap, PartialEq, Debug)]
+struct DiffOpt {
+    /// Previous schema
+    old: String,
+    /// New schema
+    new: String,
 }
 
 /// main entry point
@@ -54,35 +116,176 @@ async fn main() {
 
 async fn run(opt: Opt) -> Result<(), Error> {
 
-    let settings = load_config(opt.config)?;
+    let settings = load_config(opt.config.clone())?;
 
-    // attempt to create output directory with src subdirectory
-    let src_dir = format!("{}/src", &opt.output);
-    std::fs::create_dir_all(&src_dir)?;
+    if let Some(Command::Diff(ref diff_opt)) = opt.cmd {
+        return run_diff(&opt, diff_opt, &settings).await;
+    }
 
-    let token = match settings.get_str("zenkit.token") {
-        Ok(token) => token,
-        Err(_) => settings.get_str("zenkit.api.token")
-            .map_err(|_| Error::Message(
-                "Missing zenkit token. add to config file with `-c` option or set in environment as ZENKIT_TOKEN".into()))?,
-    };
-    let api = init_api(ApiConfig { token, ..Default::default() })?;
-    let workspace = match opt.workspace {
-        Some(name) => name,
-        None => settings.get_str("zenkit.workspace").map_err(|_| Error::Message(
-                "Workspace must be specified in config file with `-c` option or in environment as ZENKIT_WORKSPACE".into())
-        )?,
+    let source: Box<dyn SchemaSource> = match opt.from_schema {
+        Some(ref path) => Box::new(SnapshotFile::new(path)),
+        None => Box::new(ZenkitApi::new(
+            get_or_init_api(&opt, &settings)?,
+            get_workspace_name(&opt, &settings)?,
+        )),
     };
-    let ws = api.get_workspace(&workspace).await?;
+    let schema = source.load_schema().await?;
+    let options = gen_options(&opt);
 
-    let mut gen = Generator::init()?;
-    let files = gen.gen_workspace(&api, ws, &opt.output).await?;
+    if let Some(path) = opt.dump_schema {
+        schema.save(&path)?;
+        println!("Schema saved to {}", path);
+        if opt.output.is_none() {
+            return Ok(());
+        }
+    }
+
+    let output = opt.output.ok_or_else(|| {
+        Error::Message("Output directory must be specified with `-o`".into())
+    })?;
+
+    let mut gen = Generator::with_options(options)?;
+
+    if opt.check {
+        let files = gen.render_workspace(&schema)?;
+        print_warnings(&gen);
+        let results = check::check_files(&files, &output)?;
+        for result in results.iter() {
+            println!("{}", result);
+        }
+        let stale = results.iter().filter(|r| r.is_stale()).count();
+        if stale > 0 {
+            return Err(Error::Message(format!(
+                "{} file(s) in {} are out of date. Run {} to regenerate",
+                stale,
+                output,
+                env!("CARGO_BIN_NAME")
+            )));
+        }
+        return Ok(());
+    }
+
+    // attempt to create output directory with src subdirectory
+    let src_dir = format!("{}/src", &output);
+    std::fs::create_dir_all(&src_dir)?;
+
+    let files = gen.gen_workspace(&schema, &output)?;
+    print_warnings(&gen);
 
     // Run rustfmt
     format_results(files)?;
+
+    if opt.build || opt.test {
+        let mode = if opt.test {
+            BuildMode::Test
+        } else {
+            BuildMode::Check
+        };
+        let errors = compile::build_crate(&output, mode, gen.origins())?;
+        for error in errors.iter() {
+            eprintln!("{}\n", error);
+        }
+        if !errors.is_empty() {
+            return Err(Error::Message(format!(
+                "{} compile error(s) in generated code. This is a bug in {}: please file an issue",
+                errors.len(),
+                env!("CARGO_BIN_NAME")
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Prints generator warnings to stderr, so they don't mix with other output
+fn print_warnings(gen: &Generator) {
+    for warning in gen.warnings().iter() {
+        eprintln!("Warning: {}", warning);
+    }
+}
+
+/// Code generation options from the command line
+fn gen_options(opt: &Opt) -> Options {
+    Options {
+        reproducible: opt.reproducible,
+        category_enums: opt.category_enums,
+        ..Default::default()
+    }
+}
+
+/// Compares two schemas and prints the changes.
+async fn run_diff(opt: &Opt, diff_opt: &DiffOpt, settings: &Config) -> Result<(), Error> {
+    let old = load_schema(&diff_opt.old, opt, settings).await?;
+    let new = load_schema(&diff_opt.new, opt, settings).await?;
+    let changes = diff::diff_schemas(&old, &new, &gen_options(opt))?;
+    for change in changes.iter() {
+        println!("{}", change);
+    }
+    let breaking = changes.iter().filter(|c| c.is_breaking()).count();
+    println!(
+        "{} change(s), {} breaking",
+        changes.len(),
+        breaking
+    );
+    if breaking > 0 {
+        std::process::exit(2);
+    }
     Ok(())
 }
 
+/// Loads schema from snapshot file, or from Zenkit if the source is "zenkit:WORKSPACE"
+async fn load_schema(source: &str, opt: &Opt, settings: &Config) -> Result<WorkspaceSchema, Error> {
+    match source.strip_prefix("zenkit:") {
+        Some(workspace) => {
+            let api = get_or_init_api(opt, settings)?;
+            let workspace = if workspace.is_empty() {
+                get_workspace_name(opt, settings)?
+            } else {
+                workspace.to_string()
+            };
+            ZenkitApi::new(api, workspace).load_schema().await
+        }
+        None => SnapshotFile::new(source).load_schema().await,
+    }
+}
+
+/// Returns api client, initializing it with token from command line or config on first use
+fn get_or_init_api(opt: &Opt, settings: &Config) -> Result<&'static ApiClient, Error> {
+    if let Ok(api) = get_api() {
+        return Ok(api);
+    }
+    let token = match opt.token {
+        Some(ref token) => token.clone(),
+        None => match settings.get_str("zenkit.token") {
+            Ok(token) => token,
+            Err(_) => settings.get_str("zenkit.api.token")
+                .map_err(|_| Error::Message(
+                    "Missing zenkit token. add to config file with `-c` option or set in environment as ZENKIT_TOKEN".into()))?,
+        }
+    };
+    let endpoint = get_endpoint(opt, settings);
+    Ok(init_api(ApiConfig { token, endpoint })?)
+}
+
+/// Returns api endpoint from command line, environment, or config, or the default endpoint
+fn get_endpoint(opt: &Opt, settings: &Config) -> String {
+    opt.endpoint
+        .clone()
+        .or_else(|| std::env::var(ENDPOINT_VAR).ok().filter(|s| !s.is_empty()))
+        .or_else(|| settings.get_str("zenkit.endpoint").ok())
+        .map(|s| s.trim_end_matches('/').to_string())
+        .unwrap_or_else(|| ApiConfig::default().endpoint)
+}
+
+/// Returns workspace name from command line or config
+fn get_workspace_name(opt: &Opt, settings: &Config) -> Result<String, Error> {
+    match opt.workspace {
+        Some(ref name) => Ok(name.clone()),
+        None => settings.get_str("zenkit.workspace").map_err(|_| Error::Message(
+                "Workspace must be specified in config file with `-c` option or in environment as ZENKIT_WORKSPACE".into())
+        ),
+    }
+}
+
 /// Build config from
 ///  - cli option "-c CONFIG-FILE"
 ///  - environment overrides of the form "ZENKIT_"
@@ -0,0 +1,115 @@
+//! Rust identifiers for user-entered Zenkit names
+//!
+//! List, field, and label names may contain anything: punctuation, emoji, non-ascii text,
+//! or Rust keywords. Non-ascii characters are transliterated, other characters that can't
+//! be part of an identifier are dropped, and names that would not be valid identifiers
+//! on their own (keywords, or names starting with a digit) are adjusted.
+//! Names that have no usable characters get a fallback name, such as "field_123".
+use deunicode::deunicode;
+use inflector::cases::{
+    classcase::to_class_case, pascalcase::to_pascal_case,
+    screamingsnakecase::to_screaming_snake_case, snakecase::to_snake_case,
+};
+
+/// Strict and reserved keywords (2018 edition), and names reserved for later editions
+const KEYWORDS: &[&str] = &[
+    "Self", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+    "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if",
+    "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv",
+    "pub", "ref", "return", "self", "static", "struct", "super", "trait", "true", "try", "type",
+    "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Returns true if the word can't be used as an identifier (without `r#`)
+pub fn is_keyword(word: &str) -> bool {
+    KEYWORDS.contains(&word)
+}
+
+/// Transliterates non-ascii characters, e.g., "Größe" -> "Grosse", "🎉" -> "tada"
+fn to_ascii(name: &str) -> String {
+    if name.is_ascii() {
+        name.to_string()
+    } else {
+        deunicode(name)
+    }
+}
+
+/// Returns snake-case fragment of an identifier, e.g., "Due Date" -> "due_date".
+/// The fragment is only valid as part of an identifier with a prefix, such as "get_".
+/// If the name has no usable characters, returns the fallback.
+pub fn snake_fragment(name: &str, fallback: &str) -> String {
+    let s = to_snake_case(&to_ascii(name));
+    if s.is_empty() {
+        fallback.to_string()
+    } else {
+        s
+    }
+}
+
+/// Returns screaming-snake-case fragment of an identifier, e.g., "Due Date" -> "DUE_DATE".
+/// The fragment is only valid as part of an identifier with a prefix, such as "FIELD_".
+pub fn screaming_fragment(name: &str, fallback: &str) -> String {
+    to_screaming_snake_case(&snake_fragment(name, fallback))
+}
+
+/// Returns snake-case identifier that is valid on its own, e.g., for a module name.
+/// Keywords get a "_" suffix ("type" -> "type_"), and names starting with a digit
+/// get a "_" prefix ("2nd round" -> "_2nd_round").
+pub fn snake_ident(name: &str, fallback: &str) -> String {
+    valid_ident(snake_fragment(name, fallback))
+}
+
+/// Returns class-case identifier for a type name. Like pascal case, but the last word
+/// is singularized, e.g., "deal items" -> "DealItem"
+pub fn class_ident(name: &str, fallback: &str) -> String {
+    let s = to_class_case(&to_ascii(name));
+    if s.is_empty() {
+        valid_ident(to_class_case(fallback))
+    } else {
+        valid_ident(s)
+    }
+}
+
+/// Returns pascal-case (upper camel case) identifier, without singularizing,
+/// e.g., "deal tracker" -> "DealTracker"
+pub fn pascal_ident(name: &str, fallback: &str) -> String {
+    let s = to_pascal_case(&to_ascii(name));
+    if s.is_empty() {
+        valid_ident(to_pascal_case(fallback))
+    } else {
+        valid_ident(s)
+    }
+}
+
+/// Adjusts keywords and leading digits, so that an identifier fragment
+/// (e.g., from snake_fragment) is valid on its own
+pub fn valid_ident(s: String) -> String {
+    if is_keyword(&s) {
+        format!("{}_", s)
+    } else if s.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", s)
+    } else {
+        s
+    }
+}
+
+/// Adjusts an identifier fragment to begin an identifier that has a suffix, such as "_is".
+/// Fragments starting with a digit get a "_" prefix ("2nd_round" -> "_2nd_round");
+/// keywords are unchanged, since the suffix makes them valid.
+pub fn leading_fragment(s: &str) -> String {
+    if s.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", s)
+    } else {
+        s.to_string()
+    }
+}
+
+/// Returns name with numeric suffix, used to disambiguate names that collide.
+/// The first name (n=1) has no suffix; later names are "name{sep}2", "name{sep}3", ...
+pub fn numbered(name: &str, sep: &str, n: usize) -> String {
+    if n <= 1 {
+        name.to_string()
+    } else {
+        format!("{}{}{}", name, sep, n)
+    }
+}
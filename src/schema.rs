@@ -0,0 +1,140 @@
+//! Workspace schema snapshots
+//!
+//! A snapshot holds everything the generator needs from Zenkit - the workspace,
+//! its lists, and the field definitions (including category labels) of each list -
+//! so that code can be generated without an api token or network access.
+use crate::error::Error;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::path::Path;
+use zenkit::types::{Element, List, Workspace};
+
+/// Version of the snapshot file format. Incremented whenever the layout changes
+/// in a way that older versions of zk-codegen can't read.
+pub const SCHEMA_FORMAT_VERSION: u32 = 1;
+
+/// Workspace schema: the workspace and the field definitions for each of its lists
+#[derive(Serialize, Deserialize, Debug)]
+pub struct WorkspaceSchema {
+    /// snapshot format version (SCHEMA_FORMAT_VERSION when written)
+    pub format_version: u32,
+    /// name and version of the program that created the snapshot
+    pub generator: String,
+    /// the workspace
+    pub workspace: Workspace,
+    /// lists, with field definitions, in the same order as workspace.lists
+    pub lists: Vec<ListSchema>,
+}
+
+/// A list and its field definitions
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ListSchema {
+    /// the list
+    pub list: List,
+    /// field definitions
+    pub fields: Vec<Element>,
+}
+
+impl WorkspaceSchema {
+    /// Creates a schema from a workspace and its lists
+    pub fn new(workspace: Workspace, lists: Vec<ListSchema>) -> Self {
+        let mut schema = Self {
+            format_version: SCHEMA_FORMAT_VERSION,
+            generator: format!("{} v{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION")),
+            workspace,
+            lists,
+        };
+        schema.sort();
+        schema
+    }
+
+    /// Sorts lists, fields, and category labels by id, so that generated code
+    /// and snapshots don't depend on the order returned by the api
+    pub fn sort(&mut self) {
+        self.workspace.lists.sort_by_key(|l| l.id);
+        self.lists.sort_by_key(|l| l.list.id);
+        for list in self.lists.iter_mut() {
+            list.fields.sort_by_key(|f| f.id);
+            for field in list.fields.iter_mut() {
+                if let Some(ref mut categories) = field.element_data.predefined_categories {
+                    categories.sort_by_key(|c| c.id);
+                }
+            }
+        }
+    }
+
+    /// Returns hex-encoded sha256 hash of the schema content. The hash doesn't depend
+    /// on the order of lists, fields, or labels, or on the program that created the snapshot,
+    /// so it identifies the schema across versions of zk-codegen.
+    pub fn content_hash(&self) -> Result<String, Error> {
+        let mut sorted = copy_value(self)?;
+        sorted.sort();
+        Ok(sha256_hex(&[
+            &serde_json::to_vec(&sorted.workspace)?,
+            &serde_json::to_vec(&sorted.lists)?,
+        ]))
+    }
+
+    /// Returns hex-encoded sha256 hash of the schema content and a generator version,
+    /// used in the reproducible banner, so that generated files change when either does.
+    pub fn content_hash_with_version(&self, version: &str) -> Result<String, Error> {
+        Ok(sha256_hex(&[
+            self.content_hash()?.as_bytes(),
+            version.as_bytes(),
+        ]))
+    }
+
+    /// Reads schema snapshot from json file
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let buf = std::fs::read(path)?;
+        Self::from_slice(&buf)
+    }
+
+    /// Parses schema snapshot from json
+    pub fn from_slice(buf: &[u8]) -> Result<Self, Error> {
+        // check the version before parsing the whole thing so that the user gets
+        // a meaningful error if the file was created by a newer version
+        #[derive(Deserialize)]
+        struct Version {
+            format_version: u32,
+        }
+        let version: Version = serde_json::from_slice(buf)?;
+        if version.format_version > SCHEMA_FORMAT_VERSION {
+            return Err(Error::Message(format!(
+                "schema snapshot format version {} is not supported by this version of {} (max {})",
+                version.format_version,
+                env!("CARGO_PKG_NAME"),
+                SCHEMA_FORMAT_VERSION
+            )));
+        }
+        let mut schema: Self = serde_json::from_slice(buf)?;
+        schema.sort();
+        Ok(schema)
+    }
+
+    /// Writes schema snapshot to json file
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        let buf = serde_json::to_vec_pretty(self)?;
+        std::fs::write(path, buf)?;
+        Ok(())
+    }
+}
+
+/// Makes a deep copy of a value by serializing and deserializing it
+pub(crate) fn copy_value<T: Serialize + DeserializeOwned>(val: &T) -> Result<T, Error> {
+    Ok(serde_json::from_value(serde_json::to_value(val)?)?)
+}
+
+/// Returns hex-encoded sha256 hash of the parts, in order
+fn sha256_hex(parts: &[&[u8]]) -> String {
+    use sha2::{Digest, Sha256};
+
+    let mut hasher = Sha256::new();
+    for part in parts.iter() {
+        hasher.update(part);
+    }
+    hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
@@ -0,0 +1,813 @@
+//! Stand-in Zenkit api server for offline tests
+//!
+//! Serves the workspaces, list fields, and entries of schema snapshots over http, so that
+//! `zk-codegen` and generated clients can be tested without access to Zenkit. Entries can be
+//! created, updated, and deleted; they are kept in memory. Every request is recorded,
+//! so that tests can check what a client sent.
+//!
+//! Fixtures are loaded from a directory of schema snapshots (`NAME.json`, as written by
+//! `zk-codegen --dump-schema`), each with optional entries in `NAME.entries.json`: a json
+//! object whose keys are list ids, uuids, or names, and whose values are arrays of entries.
+//! Entries may leave out properties such as uuid and timestamps, which are filled in,
+//! and multi-value fields may be given as ids only (e.g., `"<field uuid>_categories": [12]`).
+//!
+//! ```no_run
+//! use zenkit_codegen::server::StandInServer;
+//!
+//! # fn main() -> Result<(), zenkit_codegen::Error> {
+//! let server = StandInServer::from_dir("tests/fixtures")?;
+//! // zk-codegen --endpoint <endpoint>, or initialize_zenkit_api(token, Some(endpoint))
+//! println!("endpoint: {}", server.endpoint());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Supported requests: workspaces (`/users/me/workspacesWithLists`, `/workspaces/{id}`),
+//! list fields (`/lists/{id}/elements`), and entries (get, filter, create, update, archive,
+//! restore, and delete). Archived entries are kept, and only returned by queries with
+//! `allowDeprecated`.
+//! Entry queries apply filter terms (`{"AND": {"TERMS": [...]}}`) on text, number, date,
+//! checkbox, category, and person fields, and sort by entry properties or field values
+//! (`orderBy` columns such as "created_at" or "<field uuid>_number"). The server accepts any api key,
+//! but returns an error if the `Zenkit-API-Key` header is missing. To test error handling,
+//! [StandInServer::fail_next] makes the next requests fail with a given status, such as
+//! 429 Too Many Requests with a Retry-After header.
+use crate::{error::Error, schema::WorkspaceSchema};
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, VecDeque},
+    net::SocketAddr,
+    path::Path,
+    sync::{Arc, Mutex, MutexGuard},
+    thread,
+};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+    net::{TcpListener, TcpStream},
+    sync::oneshot,
+};
+
+/// Path of the api on the server, as in the Zenkit endpoint "https://zenkit.com/api/v1"
+const API_PATH: &str = "/api/v1";
+
+type JsonMap = Map<String, Value>;
+
+/// A request received by the server
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct RecordedRequest {
+    /// http method, e.g., "POST"
+    pub method: String,
+    /// path, relative to the endpoint, e.g., "/lists/12/entries"
+    pub path: String,
+    /// value of the Zenkit-API-Key header
+    pub api_key: Option<String>,
+    /// request body, if it was json
+    pub body: Option<Value>,
+}
+
+/// Workspaces and entries served by the stand-in server
+#[derive(Default)]
+pub struct Fixtures {
+    workspaces: Vec<WorkspaceSchema>,
+    /// entries of each list, in the order added
+    entries: BTreeMap<u64, Vec<JsonMap>>,
+    /// category label names, for the details of category values
+    labels: BTreeMap<u64, String>,
+    /// highest entry id in use
+    last_id: u64,
+}
+
+impl Fixtures {
+    /// Creates empty fixtures
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads schema snapshots (`*.json`) and their entries (`*.entries.json`) from a directory
+    pub fn load_dir<P: AsRef<Path>>(dir: P) -> Result<Self, Error> {
+        let mut paths = Vec::new();
+        for entry in std::fs::read_dir(dir.as_ref())? {
+            let path = entry?.path();
+            if path.extension().map(|ext| ext == "json").unwrap_or(false) {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+        let (entry_files, snapshots): (Vec<_>, Vec<_>) = paths
+            .into_iter()
+            .partition(|path| path.to_string_lossy().ends_with(".entries.json"));
+
+        let mut fixtures = Self::new();
+        for path in snapshots.iter() {
+            fixtures.add_workspace(WorkspaceSchema::load(path)?);
+        }
+        for path in entry_files.iter() {
+            let lists: BTreeMap<String, Vec<Value>> =
+                serde_json::from_str(&std::fs::read_to_string(path)?).map_err(|e| {
+                    Error::Message(format!("{}: invalid entries: {}", path.display(), e))
+                })?;
+            for (list, entries) in lists.into_iter() {
+                fixtures.add_entries(&list, entries)?;
+            }
+        }
+        Ok(fixtures)
+    }
+
+    /// Adds a workspace, with its lists and fields
+    pub fn add_workspace(&mut self, schema: WorkspaceSchema) {
+        for field in schema.lists.iter().flat_map(|list| list.fields.iter()) {
+            if let Some(ref categories) = field.element_data.predefined_categories {
+                for c in categories.iter() {
+                    self.labels.insert(c.id, c.name.clone());
+                }
+            }
+        }
+        self.workspaces.push(schema);
+    }
+
+    /// Adds entries to a list, which may be the list id, uuid, or name.
+    /// The list's workspace must have been added.
+    pub fn add_entries(&mut self, list: &str, entries: Vec<Value>) -> Result<(), Error> {
+        let list_id = self
+            .find_list(list)
+            .ok_or_else(|| Error::Message(format!("entries for unknown list '{}'", list)))?;
+        // entries without an id are numbered after the highest id
+        self.last_id = entries
+            .iter()
+            .filter_map(|entry| entry.get("id").and_then(|v| v.as_u64()))
+            .fold(self.last_id, u64::max);
+        for entry in entries.into_iter() {
+            let fields = match entry {
+                Value::Object(map) => map,
+                _ => {
+                    return Err(Error::Message(format!(
+                        "entry in '{}' is not an object",
+                        list
+                    )))
+                }
+            };
+            let id = match fields.get("id").and_then(|v| v.as_u64()) {
+                Some(id) => id,
+                None => {
+                    self.last_id += 1;
+                    self.last_id
+                }
+            };
+            let mut obj = new_entry(list_id, id);
+            self.apply_fields(&mut obj, &fields, "replace");
+            self.entries.entry(list_id).or_default().push(obj);
+        }
+        Ok(())
+    }
+
+    /// Returns uuid of the field with the id
+    fn field_uuid(&self, id: u64) -> Option<&str> {
+        self.workspaces
+            .iter()
+            .flat_map(|ws| ws.lists.iter())
+            .flat_map(|list| list.fields.iter())
+            .find(|field| field.id == id)
+            .map(|field| field.uuid.as_str())
+    }
+
+    /// Returns true if the entry matches all terms of a filter
+    fn matches_filter(&self, obj: &JsonMap, filter: &Value) -> bool {
+        filter["AND"]["TERMS"]
+            .as_array()
+            .map(|terms| terms.iter().all(|term| self.matches_term(obj, term)))
+            .unwrap_or(true)
+    }
+
+    /// Returns true if the entry matches a filter term. Terms name the field with
+    /// `elementId`, and have a `modus` and values for the field's filter keys.
+    fn matches_term(&self, obj: &JsonMap, term: &Value) -> bool {
+        let uuid = match term["elementId"]
+            .as_u64()
+            .and_then(|id| self.field_uuid(id))
+        {
+            Some(uuid) => uuid,
+            None => return false,
+        };
+        let value = |kind: &str| {
+            obj.get(&format!("{}_{}", uuid, kind))
+                .filter(|v| !is_empty(v))
+        };
+        let modus = term["modus"].as_str().unwrap_or_default();
+        let is_set = [
+            "text",
+            "link",
+            "number",
+            "date",
+            "checked",
+            "categories",
+            "persons",
+            "references",
+        ]
+        .iter()
+        .any(|kind| value(kind).is_some());
+        let any_of = |kind: &str, key: &str| match (value(kind), term[key].as_array()) {
+            (Some(Value::Array(values)), Some(wanted)) => values.iter().any(|v| wanted.contains(v)),
+            _ => false,
+        };
+        if modus == "isEmpty" {
+            !is_set
+        } else if modus == "isNotEmpty" {
+            is_set
+        } else if let Some(wanted) = term["text"].as_str() {
+            let text = value("text")
+                .or_else(|| value("link"))
+                .and_then(|v| v.as_str())
+                .unwrap_or_default();
+            match modus {
+                "contains" => text.contains(wanted),
+                "notContains" => !text.contains(wanted),
+                "startsWith" => text.starts_with(wanted),
+                "endsWith" => text.ends_with(wanted),
+                "notEquals" => text != wanted,
+                _ => text == wanted,
+            }
+        } else if term.get("numberFrom").is_some() || term.get("numberTo").is_some() {
+            let in_range = match value("number").and_then(|v| v.as_f64()) {
+                Some(n) => {
+                    term["numberFrom"].as_f64().is_none_or(|from| n >= from)
+                        && term["numberTo"].as_f64().is_none_or(|to| n <= to)
+                }
+                None => false,
+            };
+            in_range != (modus == "notInRange")
+        } else if term.get("dateFrom").is_some() || term.get("dateTo").is_some() {
+            let date = |v: &Value| {
+                v.as_str()
+                    .and_then(|s| s.parse::<chrono::DateTime<chrono::Utc>>().ok())
+            };
+            match value("date").and_then(date) {
+                Some(d) => {
+                    date(&term["dateFrom"]).is_none_or(|from| d >= from)
+                        && date(&term["dateTo"]).is_none_or(|to| d <= to)
+                }
+                None => false,
+            }
+        } else if let Some(checked) = term["checked"].as_bool() {
+            value("checked")
+                .and_then(|v| v.as_bool())
+                .unwrap_or_default()
+                == checked
+        } else if term.get("filterCategories").is_some() {
+            any_of("categories", "filterCategories")
+        } else if term.get("filterPersons").is_some() {
+            any_of("persons", "filterPersons")
+        } else {
+            true
+        }
+    }
+
+    /// Returns id of the list with the id, short id, uuid, or name
+    fn find_list(&self, key: &str) -> Option<u64> {
+        self.workspaces
+            .iter()
+            .flat_map(|ws| ws.workspace.lists.iter())
+            .find(|l| {
+                l.id.to_string() == key || l.short_id == key || l.uuid == key || l.name == key
+            })
+            .map(|l| l.id)
+    }
+
+    /// Applies fields of a create or update request to an entry. For array values,
+    /// the updateAction "append" adds values, "remove" removes them, and "replace" sets the field.
+    fn apply_fields(&self, obj: &mut JsonMap, fields: &JsonMap, action: &str) {
+        for (key, value) in fields.iter() {
+            if key == "updateAction" {
+                continue;
+            }
+            let value = match (action, obj.get(key), value) {
+                ("append", Some(Value::Array(old)), Value::Array(added)) => {
+                    let mut values = old.clone();
+                    values.extend(added.iter().filter(|v| !old.contains(v)).cloned());
+                    Value::Array(values)
+                }
+                ("remove", Some(Value::Array(old)), Value::Array(removed)) => Value::Array(
+                    old.iter()
+                        .filter(|v| !removed.contains(v))
+                        .cloned()
+                        .collect(),
+                ),
+                ("remove", None, Value::Array(_)) => Value::Array(Vec::new()),
+                _ => value.clone(),
+            };
+            if let Some(details) = self.sort_details(key, &value) {
+                obj.insert(format!("{}_sort", key), details);
+            }
+            obj.insert(key.clone(), value);
+        }
+    }
+
+    /// Returns the "_sort" property of a multi-value field, which has the details
+    /// of each value, or None for other fields
+    fn sort_details(&self, key: &str, value: &Value) -> Option<Value> {
+        let values = value.as_array()?;
+        let details = if key.ends_with("_categories") {
+            values
+                .iter()
+                .map(|id| {
+                    let name = id.as_u64().and_then(|id| self.labels.get(&id));
+                    json!({ "id": id, "name": name })
+                })
+                .collect()
+        } else if key.ends_with("_persons") {
+            values.iter().map(|id| json!({ "id": id })).collect()
+        } else if key.ends_with("_references") {
+            values.iter().map(|uuid| json!({ "uuid": uuid })).collect()
+        } else {
+            return None;
+        };
+        Some(Value::Array(details))
+    }
+}
+
+/// Creates entry with no field values
+fn new_entry(list_id: u64, id: u64) -> JsonMap {
+    let now = json!(chrono::Utc::now());
+    let mut obj = JsonMap::new();
+    obj.insert("id".to_string(), json!(id));
+    obj.insert("shortId".to_string(), json!(format!("stand-in-{}", id)));
+    obj.insert(
+        "uuid".to_string(),
+        json!(format!("00000000-0000-4000-8000-{:012x}", id)),
+    );
+    obj.insert("listId".to_string(), json!(list_id));
+    obj.insert("created_at".to_string(), now.clone());
+    obj.insert("updated_at".to_string(), now);
+    obj.insert("deprecated_at".to_string(), Value::Null);
+    obj.insert("created_by".to_string(), json!(0));
+    obj.insert("updated_by".to_string(), json!(0));
+    obj.insert("displayString".to_string(), json!(""));
+    obj.insert("sortOrder".to_string(), json!(id));
+    obj.insert("comment_count".to_string(), json!(0));
+    obj.insert("checklists".to_string(), json!([]));
+    obj
+}
+
+/// Returns true for null, empty strings, and empty arrays
+fn is_empty(val: &Value) -> bool {
+    match val {
+        Value::Null => true,
+        Value::String(s) => s.is_empty(),
+        Value::Array(a) => a.is_empty(),
+        _ => false,
+    }
+}
+
+/// Compares values of a sort column. Missing values are last in either direction.
+fn compare_values(a: Option<&Value>, b: Option<&Value>, descending: bool) -> Ordering {
+    let ord = match (a.filter(|v| !is_empty(v)), b.filter(|v| !is_empty(v))) {
+        (Some(Value::Number(a)), Some(Value::Number(b))) => a
+            .as_f64()
+            .partial_cmp(&b.as_f64())
+            .unwrap_or(Ordering::Equal),
+        (Some(Value::String(a)), Some(Value::String(b))) => a.cmp(b),
+        (Some(Value::Bool(a)), Some(Value::Bool(b))) => a.cmp(b),
+        (Some(_), None) => return Ordering::Less,
+        (None, Some(_)) => return Ordering::Greater,
+        _ => Ordering::Equal,
+    };
+    if descending {
+        ord.reverse()
+    } else {
+        ord
+    }
+}
+
+/// Returns true if the entry's id, uuid, or short id is the key
+fn has_key(obj: &JsonMap, key: &str) -> bool {
+    ["id", "uuid", "shortId"]
+        .iter()
+        .any(|prop| match obj.get(*prop) {
+            Some(Value::String(s)) => s == key,
+            Some(Value::Number(n)) => n.to_string() == key,
+            _ => false,
+        })
+}
+
+/// Error response, in the format returned by Zenkit
+#[derive(Clone)]
+struct ApiError {
+    status: u16,
+    code: &'static str,
+    message: String,
+    /// value of the Retry-After header, in seconds
+    retry_after: Option<u64>,
+}
+
+impl ApiError {
+    fn not_found<S: Into<String>>(message: S) -> Self {
+        ApiError {
+            status: 404,
+            code: "C2",
+            message: message.into(),
+            retry_after: None,
+        }
+    }
+
+    fn bad_request<S: Into<String>>(message: S) -> Self {
+        ApiError {
+            status: 400,
+            code: "C1",
+            message: message.into(),
+            retry_after: None,
+        }
+    }
+
+    /// Error for a failure injected with [StandInServer::fail_next]
+    fn injected(status: u16, retry_after: Option<u64>) -> Self {
+        ApiError {
+            status,
+            code: if status == 429 { "D2" } else { "S1" },
+            message: format!("injected failure with status {}", status),
+            retry_after,
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        json!({ "error": {
+            "name": "ZenkitError",
+            "code": self.code,
+            "statusCode": self.status,
+            "message": self.message,
+            "description": "",
+        }})
+    }
+}
+
+/// Server state, shared by the connections
+struct State {
+    fixtures: Fixtures,
+    requests: Vec<RecordedRequest>,
+    /// errors returned for the next requests, instead of handling them
+    failures: VecDeque<ApiError>,
+}
+
+impl State {
+    /// Handles a request, and returns the response body
+    fn route(&mut self, method: &str, path: &str, body: Option<&Value>) -> Result<Value, ApiError> {
+        let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+        match (method, segments.as_slice()) {
+            ("GET", ["users", "me", "workspacesWithLists"]) => {
+                let workspaces: Vec<_> = self
+                    .fixtures
+                    .workspaces
+                    .iter()
+                    .map(|ws| &ws.workspace)
+                    .collect();
+                Ok(json!(workspaces))
+            }
+            ("GET", ["workspaces", key]) => self
+                .fixtures
+                .workspaces
+                .iter()
+                .map(|ws| &ws.workspace)
+                .find(|ws| ws.id.to_string() == *key || ws.uuid == *key || ws.short_id == *key)
+                .map(|ws| json!(ws))
+                .ok_or_else(|| ApiError::not_found(format!("workspace '{}' not found", key))),
+            ("GET", ["lists", list, "elements"]) => {
+                let list_id = self.list_id(list)?;
+                let fields = self
+                    .fixtures
+                    .workspaces
+                    .iter()
+                    .flat_map(|ws| ws.lists.iter())
+                    .find(|l| l.list.id == list_id)
+                    .map(|l| json!(l.fields))
+                    .unwrap_or_else(|| json!([]));
+                Ok(fields)
+            }
+            ("GET", ["lists", list, "entries", key]) => {
+                let list_id = self.list_id(list)?;
+                let obj = self.find_entry(list_id, key)?;
+                Ok(Value::Object(obj.clone()))
+            }
+            ("POST", ["lists", list, "entries", "filter"]) => {
+                let list_id = self.list_id(list)?;
+                let params = body.cloned().unwrap_or_else(|| json!({}));
+                let limit = match params["limit"].as_u64().unwrap_or_default() as usize {
+                    0 => usize::MAX,
+                    n => n,
+                };
+                let skip = params["skip"].as_u64().unwrap_or_default() as usize;
+                let allow_deprecated = params["allowDeprecated"].as_bool().unwrap_or_default();
+                let mut entries: Vec<&JsonMap> = self
+                    .fixtures
+                    .entries
+                    .get(&list_id)
+                    .map(|entries| entries.as_slice())
+                    .unwrap_or_default()
+                    .iter()
+                    .filter(|obj| {
+                        allow_deprecated
+                            || obj.get("deprecated_at").unwrap_or(&Value::Null).is_null()
+                    })
+                    .filter(|obj| self.fixtures.matches_filter(obj, &params["filter"]))
+                    .collect();
+                // sort by the last column first, so that earlier columns take precedence
+                let order_by = params["orderBy"].as_array().cloned().unwrap_or_default();
+                for order in order_by.iter().rev() {
+                    let column = order["column"].as_str().unwrap_or("sortOrder");
+                    let descending = order["direction"] == "desc";
+                    entries
+                        .sort_by(|a, b| compare_values(a.get(column), b.get(column), descending));
+                }
+                let entries: Vec<Value> = entries
+                    .into_iter()
+                    .skip(skip)
+                    .take(limit)
+                    .cloned()
+                    .map(Value::Object)
+                    .collect();
+                Ok(Value::Array(entries))
+            }
+            ("POST", ["lists", list, "entries"]) => {
+                let list_id = self.list_id(list)?;
+                let fields = as_object(body)?;
+                self.fixtures.last_id += 1;
+                let mut obj = new_entry(list_id, self.fixtures.last_id);
+                self.fixtures
+                    .apply_fields(&mut obj, &fields, update_action(&fields));
+                self.fixtures
+                    .entries
+                    .entry(list_id)
+                    .or_default()
+                    .push(obj.clone());
+                Ok(Value::Object(obj))
+            }
+            ("PUT", ["lists", list, "entries", key]) => {
+                let list_id = self.list_id(list)?;
+                let fields = as_object(body)?;
+                let mut obj = self.find_entry(list_id, key)?.clone();
+                self.fixtures
+                    .apply_fields(&mut obj, &fields, update_action(&fields));
+                obj.insert("updated_at".to_string(), json!(chrono::Utc::now()));
+                *self.find_entry(list_id, key)? = obj.clone();
+                Ok(Value::Object(obj))
+            }
+            ("DELETE", ["lists", list, "entries", key]) => {
+                let list_id = self.list_id(list)?;
+                let obj = self.find_entry(list_id, key)?;
+                obj.insert("deprecated_at".to_string(), json!(chrono::Utc::now()));
+                Ok(Value::Object(obj.clone()))
+            }
+            ("POST", ["lists", list, "deprecated-entries", key, "restore"]) => {
+                let list_id = self.list_id(list)?;
+                let obj = self.find_entry(list_id, key)?;
+                obj.insert("deprecated_at".to_string(), Value::Null);
+                Ok(Value::Object(obj.clone()))
+            }
+            ("DELETE", ["lists", list, "deprecated-entries", key]) => {
+                let list_id = self.list_id(list)?;
+                let entries = self.fixtures.entries.entry(list_id).or_default();
+                let index = entries
+                    .iter()
+                    .position(|obj| has_key(obj, key))
+                    .ok_or_else(|| ApiError::not_found(format!("entry '{}' not found", key)))?;
+                let obj = entries.remove(index);
+                Ok(json!({
+                    "action": "delete",
+                    "listEntry": { "id": obj["id"], "uuid": obj["uuid"], "shortId": obj["shortId"] },
+                }))
+            }
+            _ => Err(ApiError::not_found(format!(
+                "no route for {} {}",
+                method, path
+            ))),
+        }
+    }
+
+    fn list_id(&self, key: &str) -> Result<u64, ApiError> {
+        self.fixtures
+            .find_list(key)
+            .ok_or_else(|| ApiError::not_found(format!("list '{}' not found", key)))
+    }
+
+    fn find_entry(&mut self, list_id: u64, key: &str) -> Result<&mut JsonMap, ApiError> {
+        self.fixtures
+            .entries
+            .get_mut(&list_id)
+            .and_then(|entries| entries.iter_mut().find(|obj| has_key(obj, key)))
+            .ok_or_else(|| ApiError::not_found(format!("entry '{}' not found", key)))
+    }
+}
+
+fn as_object(body: Option<&Value>) -> Result<JsonMap, ApiError> {
+    match body {
+        Some(Value::Object(map)) => Ok(map.clone()),
+        _ => Err(ApiError::bad_request("request body must be a json object")),
+    }
+}
+
+/// Returns the updateAction of a request: "append", "remove", or "replace"
+fn update_action(fields: &JsonMap) -> &str {
+    fields
+        .get("updateAction")
+        .and_then(|v| v.as_str())
+        .unwrap_or("replace")
+}
+
+/// Stand-in Zenkit api server, listening on a local port. The server runs
+/// on a background thread, and stops when dropped.
+pub struct StandInServer {
+    addr: SocketAddr,
+    state: Arc<Mutex<State>>,
+    shutdown: Option<oneshot::Sender<()>>,
+    thread: Option<thread::JoinHandle<()>>,
+}
+
+impl StandInServer {
+    /// Starts server with the fixtures in the directory (see [Fixtures::load_dir])
+    pub fn from_dir<P: AsRef<Path>>(dir: P) -> Result<Self, Error> {
+        Self::start(Fixtures::load_dir(dir)?)
+    }
+
+    /// Starts server on a free port of 127.0.0.1
+    pub fn start(fixtures: Fixtures) -> Result<Self, Error> {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+        listener.set_nonblocking(true)?;
+        let addr = listener.local_addr()?;
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_io()
+            .build()?;
+        let state = Arc::new(Mutex::new(State {
+            fixtures,
+            requests: Vec::new(),
+            failures: VecDeque::new(),
+        }));
+        let (shutdown, shutdown_rx) = oneshot::channel();
+        let server_state = state.clone();
+        let thread = thread::spawn(move || {
+            runtime.block_on(async move {
+                if let Ok(listener) = TcpListener::from_std(listener) {
+                    serve(listener, server_state, shutdown_rx).await
+                }
+            })
+        });
+        Ok(Self {
+            addr,
+            state,
+            shutdown: Some(shutdown),
+            thread: Some(thread),
+        })
+    }
+
+    /// Returns the api endpoint, e.g., "http://127.0.0.1:12345/api/v1"
+    pub fn endpoint(&self) -> String {
+        format!("http://{}{}", self.addr, API_PATH)
+    }
+
+    /// Returns the requests received so far, oldest first
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.lock().requests.clone()
+    }
+
+    /// Makes the next `count` requests fail with the http status (e.g., 429 or 503),
+    /// for testing how clients handle errors. If `retry_after` is set, the responses
+    /// have a Retry-After header with the delay in seconds. Failed requests are recorded,
+    /// but have no other effect.
+    pub fn fail_next(&self, count: usize, status: u16, retry_after: Option<u64>) {
+        let failure = ApiError::injected(status, retry_after);
+        self.lock()
+            .failures
+            .extend(std::iter::repeat_n(failure, count));
+    }
+
+    /// Returns the json of the entries in a list, including changes made by requests
+    pub fn entries(&self, list_id: u64) -> Vec<Value> {
+        self.lock()
+            .fixtures
+            .entries
+            .get(&list_id)
+            .map(|entries| entries.iter().cloned().map(Value::Object).collect())
+            .unwrap_or_default()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Drop for StandInServer {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Accepts connections until shutdown
+async fn serve(
+    listener: TcpListener,
+    state: Arc<Mutex<State>>,
+    mut shutdown: oneshot::Receiver<()>,
+) {
+    loop {
+        tokio::select! {
+            _ = &mut shutdown => break,
+            conn = listener.accept() => {
+                if let Ok((stream, _)) = conn {
+                    tokio::spawn(handle_connection(stream, state.clone()));
+                }
+            }
+        }
+    }
+}
+
+/// Reads one request from the connection, and sends the response.
+/// The connection is closed after the response.
+async fn handle_connection(stream: TcpStream, state: Arc<Mutex<State>>) -> std::io::Result<()> {
+    let mut stream = BufReader::new(stream);
+    let mut request_line = String::new();
+    if stream.read_line(&mut request_line).await? == 0 {
+        return Ok(());
+    }
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let target = parts.next().unwrap_or_default();
+    let path = target.split('?').next().unwrap_or_default().to_string();
+
+    let mut content_length = 0usize;
+    let mut api_key = None;
+    loop {
+        let mut header = String::new();
+        if stream.read_line(&mut header).await? == 0 {
+            break;
+        }
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            match name.trim().to_ascii_lowercase().as_str() {
+                "content-length" => content_length = value.trim().parse().unwrap_or_default(),
+                "zenkit-api-key" => api_key = Some(value.trim().to_string()),
+                _ => {}
+            }
+        }
+    }
+    let mut body = vec![0u8; content_length];
+    stream.read_exact(&mut body).await?;
+    let body: Option<Value> = serde_json::from_slice(&body).ok();
+
+    let (status, response, retry_after) = {
+        let mut state = state.lock().unwrap_or_else(|e| e.into_inner());
+        let path = path.strip_prefix(API_PATH).unwrap_or(&path).to_string();
+        state.requests.push(RecordedRequest {
+            method: method.clone(),
+            path: path.clone(),
+            api_key: api_key.clone(),
+            body: body.clone(),
+        });
+        let result = if api_key.map(|key| key.is_empty()).unwrap_or(true) {
+            Err(ApiError {
+                status: 401,
+                code: "A6",
+                message: "missing api key".to_string(),
+                retry_after: None,
+            })
+        } else if let Some(failure) = state.failures.pop_front() {
+            Err(failure)
+        } else {
+            state.route(&method, &path, body.as_ref())
+        };
+        match result {
+            Ok(value) => (200, value, None),
+            Err(e) => (e.status, e.to_json(), e.retry_after),
+        }
+    };
+    let response = response.to_string();
+    let retry_after = match retry_after {
+        Some(secs) => format!("Retry-After: {}\r\n", secs),
+        None => String::new(),
+    };
+    let head = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n{}Connection: close\r\n\r\n",
+        status,
+        match status {
+            200 => "OK",
+            400 => "Bad Request",
+            401 => "Unauthorized",
+            404 => "Not Found",
+            429 => "Too Many Requests",
+            502 => "Bad Gateway",
+            503 => "Service Unavailable",
+            504 => "Gateway Timeout",
+            _ => "Internal Server Error",
+        },
+        response.len(),
+        retry_after
+    );
+    let stream = stream.get_mut();
+    stream.write_all(head.as_bytes()).await?;
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
@@ -0,0 +1,79 @@
+//! Destinations for generated files
+use crate::{
+    error::Error,
+    gen::{GeneratedFile, CARGO_TOML},
+};
+use std::path::PathBuf;
+
+/// Receives generated files
+pub trait OutputSink {
+    /// Stores a generated file. The file path is relative to the output root, e.g., "src/lib.rs"
+    fn write_file(&mut self, file: GeneratedFile) -> Result<(), Error>;
+}
+
+/// Writes generated files to a directory, creating subdirectories as needed.
+/// Existing files are overwritten, except Cargo.toml: if it exists,
+/// the generated manifest is written to Cargo.toml.gen, so that local changes are kept.
+pub struct DirSink {
+    dir: PathBuf,
+    written: Vec<String>,
+}
+
+impl DirSink {
+    /// Creates sink for output directory
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Self {
+        Self {
+            dir: dir.into(),
+            written: Vec::new(),
+        }
+    }
+
+    /// Returns paths of files written
+    pub fn written(&self) -> &[String] {
+        &self.written
+    }
+}
+
+impl OutputSink for DirSink {
+    fn write_file(&mut self, file: GeneratedFile) -> Result<(), Error> {
+        let mut fpath = self.dir.join(&file.path);
+        if let Some(parent) = fpath.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        if file.path == CARGO_TOML && fpath.exists() {
+            fpath.set_extension("toml.gen");
+        }
+        std::fs::write(&fpath, file.contents.as_bytes())?;
+        self.written.push(fpath.to_string_lossy().to_string());
+        Ok(())
+    }
+}
+
+/// Keeps generated files in memory
+#[derive(Default)]
+pub struct MemorySink {
+    /// generated files, in the order they were generated
+    pub files: Vec<GeneratedFile>,
+}
+
+impl MemorySink {
+    /// Creates empty sink
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the contents of the file, if it was generated
+    pub fn get(&self, path: &str) -> Option<&str> {
+        self.files
+            .iter()
+            .find(|f| f.path == path)
+            .map(|f| f.contents.as_str())
+    }
+}
+
+impl OutputSink for MemorySink {
+    fn write_file(&mut self, file: GeneratedFile) -> Result<(), Error> {
+        self.files.push(file);
+        Ok(())
+    }
+}
@@ -0,0 +1,157 @@
+//! Sources of workspace schema
+//!
+//! The generator gets lists and field definitions from a [SchemaSource]. Implementations
+//! are provided for the Zenkit api ([ZenkitApi]), schema snapshot files ([SnapshotFile]),
+//! and schemas constructed in memory, for example, from test fixtures ([MemorySource]).
+use crate::{
+    error::Error,
+    schema::{copy_value, ListSchema, WorkspaceSchema},
+};
+use async_trait::async_trait;
+use std::path::PathBuf;
+use zenkit::{
+    types::{Element, List, Workspace},
+    ApiClient,
+};
+
+/// Provides the workspace schema used for code generation
+#[async_trait]
+pub trait SchemaSource: Sync {
+    /// Returns the workspace, including its lists (without field definitions)
+    async fn workspace(&self) -> Result<Workspace, Error>;
+
+    /// Returns the field definitions for a list in the workspace
+    async fn list_fields(&self, workspace: &Workspace, list: &List) -> Result<Vec<Element>, Error>;
+
+    /// Loads the workspace and field definitions of all its lists
+    async fn load_schema(&self) -> Result<WorkspaceSchema, Error> {
+        let workspace = self.workspace().await?;
+        let mut lists = Vec::new();
+        for list in workspace.lists.iter() {
+            let fields = self.list_fields(&workspace, list).await?;
+            lists.push(ListSchema {
+                list: list.clone(),
+                fields,
+            });
+        }
+        Ok(WorkspaceSchema::new(workspace, lists))
+    }
+}
+
+/// Loads schema from Zenkit
+pub struct ZenkitApi<'api> {
+    api: &'api ApiClient,
+    workspace: String,
+}
+
+impl<'api> ZenkitApi<'api> {
+    /// Creates source for the workspace, which may be the workspace name, id, or uuid
+    pub fn new<S: Into<String>>(api: &'api ApiClient, workspace: S) -> Self {
+        Self {
+            api,
+            workspace: workspace.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl<'api> SchemaSource for ZenkitApi<'api> {
+    async fn workspace(&self) -> Result<Workspace, Error> {
+        Ok(self.api.get_workspace(&self.workspace).await?.as_ref().clone())
+    }
+
+    async fn list_fields(&self, workspace: &Workspace, list: &List) -> Result<Vec<Element>, Error> {
+        let list_info = self.api.get_list_info(workspace.id, &list.uuid).await?;
+        // Element doesn't implement Clone, so copy it through serde
+        list_info.fields().iter().map(copy_value).collect()
+    }
+}
+
+/// Schema snapshot file, created with `zk-codegen --dump-schema` or WorkspaceSchema::save
+pub struct SnapshotFile {
+    path: PathBuf,
+}
+
+impl SnapshotFile {
+    /// Creates source for snapshot file
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+#[async_trait]
+impl SchemaSource for SnapshotFile {
+    async fn workspace(&self) -> Result<Workspace, Error> {
+        Ok(WorkspaceSchema::load(&self.path)?.workspace)
+    }
+
+    async fn list_fields(&self, workspace: &Workspace, list: &List) -> Result<Vec<Element>, Error> {
+        MemorySource::from(WorkspaceSchema::load(&self.path)?)
+            .list_fields(workspace, list)
+            .await
+    }
+
+    // reads the file once, instead of once per list
+    async fn load_schema(&self) -> Result<WorkspaceSchema, Error> {
+        WorkspaceSchema::load(&self.path)
+    }
+}
+
+/// Schema held in memory, for example, built from test fixtures
+pub struct MemorySource {
+    workspace: Workspace,
+    lists: Vec<ListSchema>,
+}
+
+impl MemorySource {
+    /// Creates source for workspace. Field definitions must be added
+    /// with `add_list` for each list in the workspace
+    pub fn new(workspace: Workspace) -> Self {
+        Self {
+            workspace,
+            lists: Vec::new(),
+        }
+    }
+
+    /// Creates source from json: the workspace (as returned by the Zenkit api),
+    /// and a json object `{ "list": {..}, "fields": [..] }` for each list
+    pub fn from_json(workspace: &str, lists: &[&str]) -> Result<Self, Error> {
+        let mut source = Self::new(serde_json::from_str(workspace)?);
+        for list in lists.iter() {
+            source.add_list(serde_json::from_str(list)?);
+        }
+        Ok(source)
+    }
+
+    /// Adds list and its field definitions
+    pub fn add_list(&mut self, list: ListSchema) -> &mut Self {
+        self.lists.push(list);
+        self
+    }
+}
+
+impl From<WorkspaceSchema> for MemorySource {
+    fn from(schema: WorkspaceSchema) -> Self {
+        Self {
+            workspace: schema.workspace,
+            lists: schema.lists,
+        }
+    }
+}
+
+#[async_trait]
+impl SchemaSource for MemorySource {
+    async fn workspace(&self) -> Result<Workspace, Error> {
+        Ok(self.workspace.clone())
+    }
+
+    async fn list_fields(&self, _workspace: &Workspace, list: &List) -> Result<Vec<Element>, Error> {
+        match self.lists.iter().find(|l| l.list.uuid == list.uuid) {
+            Some(l) => l.fields.iter().map(copy_value).collect(),
+            None => Err(Error::Message(format!(
+                "schema is missing field definitions for list '{}'",
+                list.name
+            ))),
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::Error;
+use crate::{escape, Error};
 use handlebars::{Context, Handlebars, Helper, HelperResult, Output, RenderContext, RenderError};
 use inflector::cases::{pascalcase::to_pascal_case, screamingsnakecase::to_screaming_snake_case};
 
@@ -271,6 +271,80 @@ pub fn add_helpers(hb: &mut Handlebars) -> Result<(), Error> {
         ),
     );
 
+    // rust-string escapes text for a Rust string literal, e.g., "{{ rust-string list }}"
+    hb.register_helper(
+        "rust-string",
+        Box::new(
+            |h: &Helper,
+             _r: &Handlebars,
+             _: &Context,
+             _rc: &mut RenderContext,
+             out: &mut dyn Output|
+             -> HelperResult {
+                out.write(&escape::rust_string(
+                    h.param(0)
+                        .ok_or_else(|| RenderError::new("param not found"))?
+                        .value()
+                        .as_str()
+                        .ok_or_else(|| RenderError::new("not string"))?,
+                ))?;
+                Ok(())
+            },
+        ),
+    );
+    // toml-string escapes text for a TOML string, e.g., "{{ toml-string workspace }}"
+    hb.register_helper(
+        "toml-string",
+        Box::new(
+            |h: &Helper,
+             _r: &Handlebars,
+             _: &Context,
+             _rc: &mut RenderContext,
+             out: &mut dyn Output|
+             -> HelperResult {
+                out.write(&escape::toml_string(
+                    h.param(0)
+                        .ok_or_else(|| RenderError::new("param not found"))?
+                        .value()
+                        .as_str()
+                        .ok_or_else(|| RenderError::new("not string"))?,
+                ))?;
+                Ok(())
+            },
+        ),
+    );
+    // doc-comment formats (possibly multi-line) text for a doc comment. The optional
+    // second parameter is the comment prefix for continuation lines (default "///"),
+    // e.g., //! {{ doc-comment list_desc "//!" }}
+    hb.register_helper(
+        "doc-comment",
+        Box::new(
+            |h: &Helper,
+             _r: &Handlebars,
+             _: &Context,
+             _rc: &mut RenderContext,
+             out: &mut dyn Output|
+             -> HelperResult {
+                let prefix = match h.param(1) {
+                    Some(p) => p
+                        .value()
+                        .as_str()
+                        .ok_or_else(|| RenderError::new("not string"))?,
+                    None => "///",
+                };
+                out.write(&escape::doc_comment(
+                    h.param(0)
+                        .ok_or_else(|| RenderError::new("param not found"))?
+                        .value()
+                        .as_str()
+                        .ok_or_else(|| RenderError::new("not string"))?,
+                    prefix,
+                ))?;
+                Ok(())
+            },
+        ),
+    );
+
     Ok(())
 }
 
@@ -279,23 +353,60 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
         (
             // begin the main crate source file lib.rs
             "lib_main",
-            r#"#![allow(dead_code, unused_imports)]
-            /// Zenkit Workspace {{ workspace }}
-            /// {{ workspace_desc }}
+            r#"{{#if single_file ~}}
+            // Zenkit Workspace {{ doc-comment workspace "//" }}
+            // {{ doc-comment workspace_desc "//" }}
+            {{else ~}}
+            #![allow(dead_code, unused_imports)]
+            //! Zenkit Workspace {{ doc-comment workspace "//!" }}
+            //! {{ doc-comment workspace_desc "//!" }}
+            {{/if ~}}
             //  {{ generated_banner }}
 
-            use std::fmt;
+            use std::{fmt, future::Future, sync::{Arc, RwLock}, time::Duration};
             // use and re-export
             pub use zenkit::{init_api, get_api, ApiClient, ApiConfig,
-                             types::{ID,DateTime,Entry,GetEntriesRequest,JsonMap,TextFormat,Utc}};
+                             types::{AllId,ID,DateTime,Entry,GetEntriesRequest,JsonMap,TextFormat,Utc}};
 
             {{#each modules ~}}
-            mod {{ this }};
-            pub use {{ this }}::*;
+            {{#if ../single_file ~}}
+            mod {{ this.name }} {
+            {{ this.source }}
+            }
+            {{else ~}}
+            mod {{ this.name }};
+            {{/if ~}}
+            pub use {{ this.name }}::*;
             {{/each}}
 
             const ZENKIT_API_TOKEN_VAR: &str = "ZENKIT_API_TOKEN";
-            pub(crate) const ZENKIT_ITEM_URL_BASE: &str = "https://base.zenkit.com/i/";
+            const ZENKIT_ITEM_URL_BASE_VAR: &str = "ZENKIT_ITEM_URL_BASE";
+
+            /// Default base of the item urls returned by `get_zenkit_url`
+            pub const DEFAULT_ITEM_URL_BASE: &str = "https://base.zenkit.com/i/";
+
+            /// Base of item urls, if set with `set_item_url_base`
+            static ITEM_URL_BASE: RwLock<Option<String>> = RwLock::new(None);
+
+            /// Sets the base of the item urls returned by `get_zenkit_url`, for on-premises
+            /// or proxied deployments, e.g., "https://zenkit.example.com/i/".
+            /// `None` restores the default.
+            pub fn set_item_url_base(base: Option<&str>) {
+                let mut item_url_base = ITEM_URL_BASE.write().unwrap_or_else(|e| e.into_inner());
+                *item_url_base = base.map(|s| s.to_string());
+            }
+
+            /// Returns the base of item urls: the value set with `set_item_url_base`,
+            /// or the environment variable ZENKIT_ITEM_URL_BASE, or DEFAULT_ITEM_URL_BASE
+            pub fn item_url_base() -> String {
+                if let Some(ref base) = *ITEM_URL_BASE.read().unwrap_or_else(|e| e.into_inner()) {
+                    return base.clone();
+                }
+                std::env::var(ZENKIT_ITEM_URL_BASE_VAR)
+                    .ok()
+                    .filter(|s| !s.is_empty())
+                    .unwrap_or_else(|| DEFAULT_ITEM_URL_BASE.to_string())
+            }
 
             /// Errors returned by this crate
             #[derive(Debug)]
@@ -303,6 +414,23 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
                 Message(String),
                 Zenkit(String),
                 NoApi,
+                /// Error status returned by Zenkit, with the delay requested by the server's
+                /// Retry-After header, if any
+                Status { status: u16, message: String, retry_after: Option<Duration> },
+                /// The request could not be sent, or the response was not received
+                Network(String),
+            }
+
+            impl Error {
+                /// Returns true if the call may succeed when repeated: rate limits (429),
+                /// timeouts (408), server errors (500, 502, 503, 504), and network errors
+                pub fn is_transient(&self) -> bool {
+                    match self {
+                        Error::Status { status, .. } => matches!(status, 408 | 429 | 500 | 502 | 503 | 504),
+                        Error::Network(_) => true,
+                        _ => false,
+                    }
+                }
             }
 
             impl fmt::Display for Error {
@@ -313,6 +441,8 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
                         match self {
                             Error::Message(s) => s,
                             Error::Zenkit(s) => s,
+                            Error::Status { message, .. } => message,
+                            Error::Network(s) => s,
                             Error::NoApi => "Api must be initialized with 'initialize_zenkit_api()' before use",
                         }
                     )
@@ -321,7 +451,13 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
 
             impl From<zenkit::Error> for Error {
                 fn from(e: zenkit::Error) -> Error {
-                    Error::Zenkit(e.to_string())
+                    match e {
+                        zenkit::Error::ApiError(status, _) => Error::Status { status, message: e.to_string(), retry_after: None },
+                        zenkit::Error::Reqwest(ref re) if re.is_connect() || re.is_timeout() || re.is_request() => {
+                            Error::Network(e.to_string())
+                        }
+                        _ => Error::Zenkit(e.to_string()),
+                    }
                 }
             }
 
@@ -341,7 +477,11 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
                 let defaults = ApiConfig::default();
                 let token = token.unwrap_or(&defaults.token).to_string();
                 let endpoint = endpoint.unwrap_or(&defaults.endpoint).to_string();
+                // connect first: once init_api has set the zenkit client it can't be set again
+                let http = HttpApi::connect(&token, Some(&endpoint))?;
                 let zk = init_api(ApiConfig{token, endpoint}).map_err(|e| Error::Message(e.to_string()))?;
+                let mut global_http = GLOBAL_HTTP.write().unwrap_or_else(|e| e.into_inner());
+                *global_http = Some(Arc::new(http));
                 Ok(zk)
             }
 
@@ -353,103 +493,1356 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
                 }
             }
 
+            /// Returns true if the string is formatted as a uuid (8-4-4-4-12 hex digits)
+            pub(crate) fn is_uuid(s: &str) -> bool {
+                s.len() == 36
+                    && s.char_indices().all(|(i, c)| match i {
+                        8 | 13 | 18 | 23 => c == '-',
+                        _ => c.is_ascii_hexdigit(),
+                    })
+            }
+
             ///
-            /// Workspace "{{ workspace }}"
-            /// {{ workspace_desc }}
+            /// Workspace "{{ doc-comment workspace }}"
+            /// {{ doc-comment workspace_desc }}
             ///
-            pub const {{ to-workspace-id workspace }}: ID = {{ workspace_id }};
-            pub const {{ to-workspace-uuid workspace }}: &str = "{{ workspace_uuid }}";
-            pub const {{ to-workspace-name workspace }}: &str = "{{ workspace }}";
-
-            // Load all entries for a list into memory
-            pub(crate) async fn load_entries(list_id: ID) -> Result<Vec<Entry>, Error> {
-                let mut ooo_entries: Vec<Entry> = Vec::new();
-                let max_items = 500usize;
-                let mut start_index = 0usize;
-                let api = get_api()?;
-                loop {
-                    // get the items and build the index
-                    let mut results: Vec<Entry> = api
-                        .get_list_entries(
-                            list_id,
-                            &GetEntriesRequest{limit:max_items, skip:start_index, ..Default::default()}
-                        ).await?;
-                    if results.is_empty() {
-                        break;
+            pub const {{ to-workspace-id workspace_ident }}: ID = {{ workspace_id }};
+            pub const {{ to-workspace-uuid workspace_ident }}: &str = "{{ workspace_uuid }}";
+            pub const {{ to-workspace-name workspace_ident }}: &str = "{{ rust-string workspace }}";
+
+            /// Client for the lists in workspace "{{ doc-comment workspace }}", with its own
+            /// connection to Zenkit. A program can have clients for several Zenkit accounts
+            /// or endpoints. The functions of the `*List` types use the global client
+            /// (see `initialize_zenkit_api`), which is also available as `WorkspaceClient::global()`.
+            #[derive(Clone)]
+            pub struct WorkspaceClient {
+                api: Api,
+            }
+
+            impl WorkspaceClient {
+                /// Creates client with a new connection. If endpoint is None, the default
+                /// Zenkit endpoint is used.
+                pub fn connect(token: &str, endpoint: Option<&str>) -> Result<Self, Error> {
+                    Ok(Self::with_backend(Arc::new(HttpApi::connect(token, endpoint)?)))
+                }
+
+                /// Creates client that sends requests with the http client, for example,
+                /// one configured with a proxy or for a test server. The http client must
+                /// add the `Zenkit-API-Key` header. If endpoint is None, the default Zenkit
+                /// endpoint is used.
+                pub fn with_http_client(client: reqwest::Client, endpoint: Option<&str>) -> Self {
+                    Self::with_backend(Arc::new(HttpApi::new(client, endpoint)))
+                }
+
+                /// Creates client that sends requests to the backend, for example,
+                /// a `mock::MockBackend` in tests
+                pub fn with_backend(backend: Arc<dyn Backend>) -> Self {
+                    Self { api: Api::with_backend(backend) }
+                }
+
+                /// Returns client that uses the global api client, which must be initialized
+                /// with `initialize_zenkit_api` before use
+                pub fn global() -> Self {
+                    Self { api: Api::global() }
+                }
+
+                /// Sets the retry policy for calls made with this client, instead of the global
+                /// policy (see `set_retry_policy`). Use `RetryPolicy::none()` to disable retries.
+                pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+                    self.api.retry_policy = Some(policy);
+                    self
+                }
+                {{#each modules}}
+
+                /// Returns client for List '{{ doc-comment this.list }}'
+                pub fn {{ this.name }}(&self) -> {{ this.client }} {
+                    {{ this.client }}::new(self.api.clone())
+                }
+                {{~/each}}
+            }
+
+            /// Operations on list entries used by the generated clients. Implemented by the Zenkit
+            /// api client, by the connection of `WorkspaceClient::connect`, and, with the "mock"
+            /// feature, by the in-memory `mock::MockBackend`.
+            #[async_trait::async_trait]
+            pub trait Backend: Send + Sync {
+                /// Fetches an entry by id, short id, or uuid
+                async fn get_entry(&self, list_id: ID, entry: AllId) -> Result<Entry, Error>;
+
+                /// Fetches a page of entries
+                async fn get_list_entries(&self, list_id: ID, params: &GetEntriesRequest) -> Result<Vec<Entry>, Error>;
+
+                /// Creates an entry from the fields set by a builder
+                async fn create_entry(&self, list_id: ID, val: serde_json::Value) -> Result<Entry, Error>;
+
+                /// Updates an entry with the fields set by a builder
+                async fn update_entry(&self, list_id: ID, entry_id: ID, val: serde_json::Value) -> Result<Entry, Error>;
+
+                /// Deletes an entry permanently
+                async fn delete_entry(&self, list_id: ID, entry_id: ID) -> Result<(), Error>;
+
+                /// Archives (deprecates) an entry, and returns the archived entry.
+                /// The default implementation returns an error, for backends that can't archive.
+                async fn archive_entry(&self, list_id: ID, entry_id: ID) -> Result<Entry, Error> {
+                    let _ = (list_id, entry_id);
+                    Err(Error::Message("archive is not supported by this backend".to_string()))
+                }
+
+                /// Restores an archived entry, and returns the restored entry.
+                /// The default implementation returns an error, for backends that can't restore.
+                async fn restore_entry(&self, list_id: ID, entry_id: ID) -> Result<Entry, Error> {
+                    let _ = (list_id, entry_id);
+                    Err(Error::Message("restore is not supported by this backend".to_string()))
+                }
+            }
+
+            /// The zenkit api client has no calls to archive or restore entries, so `archive_entry`
+            /// and `restore_entry` return an error. The global client (after `initialize_zenkit_api`)
+            /// and clients from `WorkspaceClient::connect` or `with_http_client` support them.
+            #[async_trait::async_trait]
+            impl Backend for ApiClient {
+                async fn get_entry(&self, list_id: ID, entry: AllId) -> Result<Entry, Error> {
+                    Ok(ApiClient::get_entry(self, list_id, entry).await?)
+                }
+
+                async fn get_list_entries(&self, list_id: ID, params: &GetEntriesRequest) -> Result<Vec<Entry>, Error> {
+                    Ok(ApiClient::get_list_entries(self, list_id, params).await?)
+                }
+
+                async fn create_entry(&self, list_id: ID, val: serde_json::Value) -> Result<Entry, Error> {
+                    Ok(ApiClient::create_entry(self, list_id, val).await?)
+                }
+
+                async fn update_entry(&self, list_id: ID, entry_id: ID, val: serde_json::Value) -> Result<Entry, Error> {
+                    Ok(ApiClient::update_entry(self, list_id, entry_id, val).await?)
+                }
+
+                async fn delete_entry(&self, list_id: ID, entry_id: ID) -> Result<(), Error> {
+                    ApiClient::delete_entry(self, list_id, entry_id).await?;
+                    Ok(())
+                }
+            }
+
+            /// Backend used by the global client, if set with `set_global_backend`
+            static GLOBAL_BACKEND: RwLock<Option<Arc<dyn Backend>>> = RwLock::new(None);
+
+            /// Sets the backend used by the `*List` functions and `WorkspaceClient::global()`
+            /// instead of the api client from `initialize_zenkit_api`, for example, to run
+            /// tests with a `mock::MockBackend`. `None` restores the api client.
+            /// Tests that run in parallel share the global backend; to give each test its own
+            /// backend, use `WorkspaceClient::with_backend`.
+            pub fn set_global_backend(backend: Option<Arc<dyn Backend>>) {
+                let mut global = GLOBAL_BACKEND.write().unwrap_or_else(|e| e.into_inner());
+                *global = backend;
+            }
+
+            /// Retry policy for api calls that fail with a transient error (see `Error::is_transient`).
+            /// Calls are repeated after an exponential backoff, or after the delay requested
+            /// by the server's Retry-After header. Only calls that are safe to repeat are retried
+            /// automatically: fetching entries by id, and entry queries. Creates, updates, and
+            /// deletes are sent once, since a failed response doesn't mean the change wasn't made;
+            /// to retry them anyway, wrap the call in `RetryPolicy::retry`.
+            #[derive(Clone, Debug, PartialEq)]
+            pub struct RetryPolicy {
+                /// Maximum number of attempts, including the first call. 1 disables retries.
+                pub max_attempts: u32,
+                /// Delay before the first retry. The delay doubles for each later retry.
+                pub initial_backoff: Duration,
+                /// Maximum delay before a retry, including delays requested with Retry-After
+                pub max_backoff: Duration,
+                /// If true, each backoff is randomized between half and all of its length,
+                /// so that clients that failed at the same time don't retry at the same time
+                pub jitter: bool,
+            }
+
+            impl Default for RetryPolicy {
+                /// Up to 4 attempts, with backoff starting at 500ms, and at most 30s between attempts
+                fn default() -> Self {
+                    Self {
+                        max_attempts: 4,
+                        initial_backoff: Duration::from_millis(500),
+                        max_backoff: Duration::from_secs(30),
+                        jitter: true,
+                    }
+                }
+            }
+
+            impl RetryPolicy {
+                /// Returns policy that never retries
+                pub fn none() -> Self {
+                    Self { max_attempts: 1, ..Default::default() }
+                }
+
+                /// Calls `f` until it succeeds, returns an error that is not transient,
+                /// or the attempts are used up. Returns the result of the last call.
+                pub async fn retry<T, F, Fut>(&self, mut f: F) -> Result<T, Error>
+                where
+                    F: FnMut() -> Fut,
+                    Fut: Future<Output = Result<T, Error>>,
+                {
+                    let mut attempt = 1;
+                    loop {
+                        match f().await {
+                            Err(e) if e.is_transient() && attempt < self.max_attempts => {
+                                tokio::time::sleep(self.delay(attempt, &e)).await;
+                                attempt += 1;
+                            }
+                            res => return res,
+                        }
+                    }
+                }
+
+                /// Returns the delay before the next call, after `attempt` calls have failed
+                fn delay(&self, attempt: u32, err: &Error) -> Duration {
+                    if let Error::Status { retry_after: Some(retry_after), .. } = err {
+                        return (*retry_after).min(self.max_backoff);
+                    }
+                    let backoff = self
+                        .initial_backoff
+                        .checked_mul(1 << (attempt - 1).min(31))
+                        .unwrap_or(self.max_backoff)
+                        .min(self.max_backoff);
+                    if self.jitter {
+                        backoff / 2 + backoff.mul_f64(random_fraction() / 2.0)
+                    } else {
+                        backoff
+                    }
+                }
+            }
+
+            /// Returns a pseudo-random number in [0, 1), for backoff jitter
+            fn random_fraction() -> f64 {
+                use std::hash::{BuildHasher, Hasher};
+                let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+                hasher.write_u128(
+                    std::time::SystemTime::now()
+                        .duration_since(std::time::UNIX_EPOCH)
+                        .unwrap_or_default()
+                        .as_nanos(),
+                );
+                (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+            }
+
+            /// Retry policy of the global client and of clients without their own policy,
+            /// if set with `set_retry_policy`
+            static RETRY_POLICY: RwLock<Option<RetryPolicy>> = RwLock::new(None);
+
+            /// Sets the retry policy used by the `*List` functions and by clients that don't have
+            /// their own (see `WorkspaceClient::with_retry_policy`). `None` restores the default.
+            pub fn set_retry_policy(policy: Option<RetryPolicy>) {
+                let mut global = RETRY_POLICY.write().unwrap_or_else(|e| e.into_inner());
+                *global = policy;
+            }
+
+            /// Global api client, from `get_api()`
+            struct GlobalApi;
+
+            #[async_trait::async_trait]
+            impl Backend for GlobalApi {
+                // reads use the connection made by `initialize_zenkit_api`, which reports the
+                // Retry-After header of errors; the zenkit api client is used if the api
+                // was initialized with `init_api`
+                async fn get_entry(&self, list_id: ID, entry: AllId) -> Result<Entry, Error> {
+                    match global_http() {
+                        Ok(http) => http.get_entry(list_id, entry).await,
+                        Err(_) => Backend::get_entry(get_api()?, list_id, entry).await,
+                    }
+                }
+
+                async fn get_list_entries(&self, list_id: ID, params: &GetEntriesRequest) -> Result<Vec<Entry>, Error> {
+                    match global_http() {
+                        Ok(http) => http.get_list_entries(list_id, params).await,
+                        Err(_) => Backend::get_list_entries(get_api()?, list_id, params).await,
+                    }
+                }
+
+                async fn create_entry(&self, list_id: ID, val: serde_json::Value) -> Result<Entry, Error> {
+                    Backend::create_entry(get_api()?, list_id, val).await
+                }
+
+                async fn update_entry(&self, list_id: ID, entry_id: ID, val: serde_json::Value) -> Result<Entry, Error> {
+                    Backend::update_entry(get_api()?, list_id, entry_id, val).await
+                }
+
+                async fn delete_entry(&self, list_id: ID, entry_id: ID) -> Result<(), Error> {
+                    Backend::delete_entry(get_api()?, list_id, entry_id).await
+                }
+
+                // the zenkit api client can't archive or restore entries,
+                // so these use the connection made by `initialize_zenkit_api`
+                async fn archive_entry(&self, list_id: ID, entry_id: ID) -> Result<Entry, Error> {
+                    global_http()?.archive_entry(list_id, entry_id).await
+                }
+
+                async fn restore_entry(&self, list_id: ID, entry_id: ID) -> Result<Entry, Error> {
+                    global_http()?.restore_entry(list_id, entry_id).await
+                }
+            }
+
+            /// Http connection with the token and endpoint of `initialize_zenkit_api`
+            static GLOBAL_HTTP: RwLock<Option<Arc<HttpApi>>> = RwLock::new(None);
+
+            /// Returns the connection made by `initialize_zenkit_api`
+            fn global_http() -> Result<Arc<HttpApi>, Error> {
+                GLOBAL_HTTP.read().unwrap_or_else(|e| e.into_inner()).clone().ok_or(Error::NoApi)
+            }
+
+            /// Backend and retry policy used for api calls
+            #[derive(Clone)]
+            pub(crate) struct Api {
+                /// backend owned by a WorkspaceClient, or None for the global backend
+                /// (see `set_global_backend`) or the api client from `get_api()`
+                backend: Option<Arc<dyn Backend>>,
+                /// retry policy of a WorkspaceClient, or None for the global policy
+                retry_policy: Option<RetryPolicy>,
+            }
+
+            impl Api {
+                /// Returns api that uses the global backend
+                pub(crate) fn global() -> Self {
+                    Self { backend: None, retry_policy: None }
+                }
+
+                /// Returns api that uses a separate backend
+                pub(crate) fn with_backend(backend: Arc<dyn Backend>) -> Self {
+                    Self { backend: Some(backend), retry_policy: None }
+                }
+
+                /// Returns the backend for the next call
+                fn backend(&self) -> Arc<dyn Backend> {
+                    match &self.backend {
+                        None => GLOBAL_BACKEND
+                            .read()
+                            .unwrap_or_else(|e| e.into_inner())
+                            .clone()
+                            .unwrap_or_else(|| Arc::new(GlobalApi)),
+                        Some(backend) => backend.clone(),
+                    }
+                }
+
+                /// Returns the retry policy for the next call
+                fn retry_policy(&self) -> RetryPolicy {
+                    match &self.retry_policy {
+                        None => RETRY_POLICY.read().unwrap_or_else(|e| e.into_inner()).clone().unwrap_or_default(),
+                        Some(policy) => policy.clone(),
+                    }
+                }
+
+                /// Fetches an entry by id or uuid, with retries
+                pub(crate) async fn get_entry<E: Into<AllId>>(&self, list_id: ID, entry: E) -> Result<Entry, Error> {
+                    let backend = self.backend();
+                    let entry = entry.into();
+                    self.retry_policy()
+                        .retry(|| backend.get_entry(list_id, copy_id(&entry)))
+                        .await
+                }
+
+                /// Fetches a page of entries, with retries
+                pub(crate) async fn get_list_entries(&self, list_id: ID, params: &GetEntriesRequest) -> Result<Vec<Entry>, Error> {
+                    let backend = self.backend();
+                    self.retry_policy()
+                        .retry(|| backend.get_list_entries(list_id, params))
+                        .await
+                }
+
+                /// Creates an entry
+                pub(crate) async fn create_entry(&self, list_id: ID, val: serde_json::Value) -> Result<Entry, Error> {
+                    self.backend().create_entry(list_id, val).await
+                }
+
+                /// Updates an entry
+                pub(crate) async fn update_entry(&self, list_id: ID, entry_id: ID, val: serde_json::Value) -> Result<Entry, Error> {
+                    self.backend().update_entry(list_id, entry_id, val).await
+                }
+
+                /// Deletes an entry permanently
+                pub(crate) async fn delete_entry(&self, list_id: ID, entry_id: ID) -> Result<(), Error> {
+                    self.backend().delete_entry(list_id, entry_id).await
+                }
+
+                /// Archives an entry
+                pub(crate) async fn archive_entry(&self, list_id: ID, entry_id: ID) -> Result<Entry, Error> {
+                    self.backend().archive_entry(list_id, entry_id).await
+                }
+
+                /// Restores an archived entry
+                pub(crate) async fn restore_entry(&self, list_id: ID, entry_id: ID) -> Result<Entry, Error> {
+                    self.backend().restore_entry(list_id, entry_id).await
+                }
+
+                /// Loads all entries for a list into memory
+                pub(crate) async fn load_entries(&self, list_id: ID) -> Result<Vec<Entry>, Error> {
+                    EntryQuery::default().load(self, list_id).await
+                }
+            }
+
+            /// Returns a copy of the id (AllId isn't Clone)
+            fn copy_id(id: &AllId) -> AllId {
+                match id {
+                    AllId::ID(id) => AllId::ID(*id),
+                    AllId::ShortId(s) => AllId::ShortId(s.clone()),
+                    AllId::UUID(s) => AllId::UUID(s.clone()),
+                    AllId::Any(s) => AllId::Any(s.clone()),
+                }
+            }
+
+            /// Runs the calls with at most `concurrency` in progress at a time (at least 1),
+            /// and returns their results in the order of the calls
+            pub(crate) async fn run_batch<T, I>(calls: I, concurrency: usize) -> Vec<Result<T, Error>>
+            where
+                I: IntoIterator,
+                I::Item: Future<Output = Result<T, Error>>,
+            {
+                use futures::StreamExt;
+                futures::stream::iter(calls).buffered(concurrency.max(1)).collect().await
+            }
+
+            /// Returns the next f64 after the value, toward positive infinity if `up`, otherwise
+            /// toward negative infinity. Filters only have inclusive bounds, so query conditions
+            /// like `_gt` use the next value. Returns NaN for NaN.
+            pub(crate) fn next_f64(value: f64, up: bool) -> f64 {
+                if value.is_nan() || (value.is_infinite() && (value > 0.0) == up) {
+                    value
+                } else if value == 0.0 {
+                    let tiny = f64::from_bits(1);
+                    if up { tiny } else { -tiny }
+                } else if (value > 0.0) == up {
+                    // away from zero
+                    f64::from_bits(value.to_bits() + 1)
+                } else {
+                    f64::from_bits(value.to_bits() - 1)
+                }
+            }
+
+            /// Filters, sort order, and range of a list query, used by the generated `*Query` types.
+            /// Filters are sent as `{"AND": {"TERMS": [...]}}`. Each term has the field's `elementId`,
+            /// an optional `modus` (e.g., "contains" or "isEmpty"), and values for the field's
+            /// filter keys (e.g., "text", "numberFrom" and "numberTo", or "filterCategories").
+            #[derive(Clone, Debug, Default)]
+            pub(crate) struct EntryQuery {
+                terms: Vec<serde_json::Value>,
+                /// (column, descending)
+                order_by: Vec<(&'static str, bool)>,
+                /// maximum number of entries, or 0 for all
+                pub(crate) limit: usize,
+                /// number of matching entries to skip
+                pub(crate) skip: usize,
+                /// number of entries requested per call, or 0 for PAGE_SIZE
+                pub(crate) page_size: usize,
+                /// if true, archived (deprecated) entries are included
+                pub(crate) include_archived: bool,
+            }
+
+            impl EntryQuery {
+                /// Default number of entries requested per call
+                pub(crate) const PAGE_SIZE: usize = 500;
+
+                /// Adds a filter term for a field
+                pub(crate) fn filter(&mut self, element_id: ID, mut term: serde_json::Value) {
+                    term["elementId"] = serde_json::json!(element_id);
+                    self.terms.push(term);
+                }
+
+                /// Adds a sort column, e.g., "created_at" or "<field uuid>_number".
+                /// Earlier columns take precedence.
+                pub(crate) fn order_by(&mut self, column: &'static str, descending: bool) {
+                    self.order_by.push((column, descending));
+                }
+
+                /// Returns the request parameters, with the query's limit and skip
+                pub(crate) fn request(&self) -> GetEntriesRequest {
+                    self.page(self.skip, self.limit)
+                }
+
+                /// Returns the request parameters for a page of results
+                fn page(&self, skip: usize, limit: usize) -> GetEntriesRequest {
+                    use zenkit::types::{OrderBy, SortDirection};
+                    let filter = if self.terms.is_empty() {
+                        serde_json::json!({})
+                    } else {
+                        serde_json::json!({ "AND": { "TERMS": self.terms } })
+                    };
+                    let order_by = self
+                        .order_by
+                        .iter()
+                        .map(|(column, descending)| OrderBy {
+                            column: Some(column.to_string()),
+                            direction: if *descending { SortDirection::Desc } else { SortDirection::Asc },
+                        })
+                        .collect();
+                    GetEntriesRequest {
+                        filter,
+                        limit,
+                        skip,
+                        order_by,
+                        allow_deprecated: self.include_archived,
+                        ..Default::default()
+                    }
+                }
+
+                /// Returns the number of entries to request after `fetched` entries,
+                /// or 0 if the limit has been reached
+                fn next_page_size(&self, fetched: usize) -> usize {
+                    let page_size = match self.page_size {
+                        0 => Self::PAGE_SIZE,
+                        n => n,
+                    };
+                    match self.limit {
+                        0 => page_size,
+                        limit => page_size.min(limit.saturating_sub(fetched)),
+                    }
+                }
+
+                /// Loads all matching entries into memory
+                pub(crate) async fn load(&self, api: &Api, list_id: ID) -> Result<Vec<Entry>, Error> {
+                    use futures::TryStreamExt;
+                    self.stream(api.clone(), list_id).try_collect().await
+                }
+
+                /// Returns stream of the matching entries. Pages are requested as the stream
+                /// is read, so only one page is in memory at a time. The stream ends after
+                /// the first error.
+                pub(crate) fn stream(&self, api: Api, list_id: ID) -> impl futures::Stream<Item = Result<Entry, Error>> + Send {
+                    let pages = EntryPages {
+                        api,
+                        query: self.clone(),
+                        list_id,
+                        fetched: 0,
+                        page: std::collections::VecDeque::new(),
+                        done: false,
+                    };
+                    futures::stream::unfold(pages, |mut pages| async move {
+                        loop {
+                            if let Some(entry) = pages.page.pop_front() {
+                                return Some((Ok(entry), pages));
+                            }
+                            let page_size = pages.query.next_page_size(pages.fetched);
+                            if pages.done || page_size == 0 {
+                                return None;
+                            }
+                            let params = pages.query.page(pages.query.skip + pages.fetched, page_size);
+                            match pages.api.get_list_entries(pages.list_id, &params).await {
+                                Ok(mut entries) => {
+                                    // a page with fewer entries than requested is the last
+                                    pages.done = entries.len() < page_size;
+                                    entries.truncate(page_size);
+                                    pages.fetched += entries.len();
+                                    pages.page.extend(entries);
+                                }
+                                Err(e) => {
+                                    pages.done = true;
+                                    return Some((Err(e), pages));
+                                }
+                            }
+                        }
+                    })
+                }
+            }
+
+            /// State of an entry stream
+            struct EntryPages {
+                api: Api,
+                query: EntryQuery,
+                list_id: ID,
+                /// number of entries received
+                fetched: usize,
+                /// entries received but not yet returned
+                page: std::collections::VecDeque<Entry>,
+                /// true after the last page (one with fewer entries than requested) or an error
+                done: bool,
+            }
+
+            /// Http connection to the Zenkit api
+            pub(crate) struct HttpApi {
+                client: reqwest::Client,
+                endpoint: String,
+            }
+
+            impl HttpApi {
+                /// Creates connection that sends the token with each request.
+                /// If endpoint is None, the default Zenkit endpoint is used.
+                fn connect(token: &str, endpoint: Option<&str>) -> Result<Self, Error> {
+                    use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+                    if token.is_empty() {
+                        return Err(Error::Message("Missing API token".to_string()));
+                    }
+                    let mut headers = HeaderMap::new();
+                    headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+                    headers.insert(
+                        "Zenkit-API-Key",
+                        HeaderValue::from_str(token)
+                            .map_err(|_| Error::Message("token has non-ascii chars".to_string()))?,
+                    );
+                    let client = reqwest::Client::builder()
+                        .default_headers(headers)
+                        .build()
+                        .map_err(zenkit::Error::from)?;
+                    Ok(Self::new(client, endpoint))
+                }
+
+                /// Creates connection that sends requests with the http client
+                fn new(client: reqwest::Client, endpoint: Option<&str>) -> Self {
+                    let endpoint = match endpoint {
+                        Some(endpoint) => endpoint.trim_end_matches('/').to_string(),
+                        None => ApiConfig::default().endpoint,
+                    };
+                    Self { client, endpoint }
+                }
+            }
+
+            #[async_trait::async_trait]
+            impl Backend for HttpApi {
+                async fn get_entry(&self, list_id: ID, entry: AllId) -> Result<Entry, Error> {
+                    let url = format!("{}/lists/{}/entries/{}", self.endpoint, list_id, entry);
+                    HttpApi::json(self.client.get(&url).send().await).await
+                }
+
+                async fn get_list_entries(&self, list_id: ID, params: &GetEntriesRequest) -> Result<Vec<Entry>, Error> {
+                    let url = format!("{}/lists/{}/entries/filter", self.endpoint, list_id);
+                    HttpApi::json(self.client.post(&url).json(params).send().await).await
+                }
+
+                async fn create_entry(&self, list_id: ID, val: serde_json::Value) -> Result<Entry, Error> {
+                    let url = format!("{}/lists/{}/entries", self.endpoint, list_id);
+                    HttpApi::json(self.client.post(&url).json(&val).send().await).await
+                }
+
+                async fn update_entry(&self, list_id: ID, entry_id: ID, val: serde_json::Value) -> Result<Entry, Error> {
+                    let url = format!("{}/lists/{}/entries/{}", self.endpoint, list_id, entry_id);
+                    HttpApi::json(self.client.put(&url).json(&val).send().await).await
+                }
+
+                async fn delete_entry(&self, list_id: ID, entry_id: ID) -> Result<(), Error> {
+                    let url = format!("{}/lists/{}/deprecated-entries/{}", self.endpoint, list_id, entry_id);
+                    let _: serde_json::Value = HttpApi::json(self.client.delete(&url).send().await).await?;
+                    Ok(())
+                }
+
+                async fn archive_entry(&self, list_id: ID, entry_id: ID) -> Result<Entry, Error> {
+                    let url = format!("{}/lists/{}/entries/{}", self.endpoint, list_id, entry_id);
+                    let _: serde_json::Value = HttpApi::json(self.client.delete(&url).send().await).await?;
+                    self.get_entry(list_id, AllId::ID(entry_id)).await
+                }
+
+                async fn restore_entry(&self, list_id: ID, entry_id: ID) -> Result<Entry, Error> {
+                    let url = format!("{}/lists/{}/deprecated-entries/{}/restore", self.endpoint, list_id, entry_id);
+                    let _: serde_json::Value = HttpApi::json(self.client.post(&url).send().await).await?;
+                    self.get_entry(list_id, AllId::ID(entry_id)).await
+                }
+            }
+
+            impl HttpApi {
+                /// Checks response for http errors, and deserializes the response body
+                async fn json<T: serde::de::DeserializeOwned>(resp: Result<reqwest::Response, reqwest::Error>) -> Result<T, Error> {
+                    let resp = resp.map_err(zenkit::Error::from)?;
+                    let status = resp.status();
+                    // Retry-After in seconds (the http-date form is ignored)
+                    let retry_after = resp
+                        .headers()
+                        .get(reqwest::header::RETRY_AFTER)
+                        .and_then(|v| v.to_str().ok())
+                        .and_then(|v| v.trim().parse().ok())
+                        .map(Duration::from_secs);
+                    let bytes = resp.bytes().await.map_err(zenkit::Error::from)?;
+                    if !status.is_success() {
+                        let message = match serde_json::from_slice::<zenkit::types::ErrorResult>(&bytes) {
+                            Ok(res) => zenkit::Error::ApiError(status.as_u16(), Some(res.error)).to_string(),
+                            Err(_) => format!("Server returned status {}:{}", status.as_u16(), String::from_utf8_lossy(&bytes)),
+                        };
+                        return Err(Error::Status { status: status.as_u16(), message, retry_after });
+                    }
+                    serde_json::from_slice(&bytes).map_err(|e| Error::Zenkit(format!("invalid response: {}", e)))
+                }
+            }
+
+            /// In-memory backend for tests, enabled with the "mock" feature
+            #[cfg(feature = "mock")]
+            pub mod mock {
+                use super::{AllId, Backend, DateTime, Entry, Error, GetEntriesRequest, JsonMap, Utc, ID};
+                use serde_json::{json, Value};
+                use std::{cmp::Ordering, collections::BTreeMap, sync::{Mutex, MutexGuard}};
+
+                /// Lists in the workspace, as (id, uuid)
+                const LISTS: &[(ID, &str)] = &[
+                    {{#each modules ~}}
+                    ({{ this.list_id }}, "{{ this.list_uuid }}"),
+                    {{/each ~}}
+                ];
+
+                /// Fields of the lists, as (id, uuid), sorted by id
+                const FIELDS: &[(ID, &str)] = &[
+                    {{#each workspace_fields ~}}
+                    ({{ this.id }}, "{{ this.uuid }}"),
+                    {{/each ~}}
+                ];
+
+                /// Names of category labels, sorted by id
+                const LABELS: &[(ID, &str)] = &[
+                    {{#each labels ~}}
+                    ({{ this.id }}, "{{ rust-string this.name }}"),
+                    {{/each ~}}
+                ];
+
+                /// Backend that keeps entries in memory, so that code using this crate can be tested
+                /// without network access. Creates and updates apply the fields set by builders,
+                /// including the `updateAction` of multi-value fields: "append" adds values,
+                /// "remove" removes them, and "replace" sets the field.
+                /// Entry queries apply the filters and sort order of the generated `*Query` types.
+                /// Archived entries are kept, and only returned by queries that include them.
+                #[derive(Default)]
+                pub struct MockBackend {
+                    state: Mutex<MockState>,
+                }
+
+                #[derive(Default)]
+                struct MockState {
+                    /// entries of each list, in the order added
+                    lists: BTreeMap<ID, Vec<JsonMap>>,
+                    /// highest entry id in use
+                    last_id: ID,
+                }
+
+                impl MockBackend {
+                    /// Creates backend with no entries
+                    pub fn new() -> Self {
+                        Self::default()
                     }
-                    start_index += results.len();
-                    ooo_entries.append(&mut results);
+
+                    /// Creates backend with entries from a json fixture: an object whose keys are
+                    /// list ids or uuids, and whose values are arrays of entries (see `insert`)
+                    pub fn from_json(json: &str) -> Result<Self, Error> {
+                        let fixture: BTreeMap<String, Vec<Value>> = serde_json::from_str(json)
+                            .map_err(|e| Error::Message(format!("invalid mock fixture: {}", e)))?;
+                        let backend = Self::new();
+                        // entries without an id are numbered after the highest id in the fixture
+                        backend.lock().last_id = fixture
+                            .values()
+                            .flatten()
+                            .filter_map(|entry| entry.get("id").and_then(|v| v.as_u64()))
+                            .max()
+                            .unwrap_or_default();
+                        for (key, entries) in fixture.into_iter() {
+                            let list_id = list_id(&key).ok_or_else(|| {
+                                Error::Message(format!("invalid mock fixture: unknown list '{}'", key))
+                            })?;
+                            for entry in entries.into_iter() {
+                                backend.insert(list_id, entry)?;
+                            }
+                        }
+                        Ok(backend)
+                    }
+
+                    /// Adds an entry to a list. The entry is json in the format returned by Zenkit.
+                    /// Missing properties, such as id, uuid, and timestamps, are filled in,
+                    /// and multi-value fields may be given as ids only
+                    /// (e.g., `"<field uuid>_categories": [12]`).
+                    pub fn insert(&self, list_id: ID, entry: Value) -> Result<Entry, Error> {
+                        let fields = as_object(entry)?;
+                        let mut state = self.lock();
+                        let id = match fields.get("id").and_then(|v| v.as_u64()) {
+                            Some(id) => id,
+                            None => state.last_id + 1,
+                        };
+                        state.last_id = state.last_id.max(id);
+                        let mut obj = new_entry(list_id, id);
+                        apply_fields(&mut obj, &fields, "replace");
+                        state.lists.entry(list_id).or_default().push(obj.clone());
+                        to_entry(obj)
+                    }
+
+                    /// Returns the json of the entries in a list, for checking the results of a test
+                    pub fn entries(&self, list_id: ID) -> Vec<Value> {
+                        self.lock()
+                            .lists
+                            .get(&list_id)
+                            .map(|entries| entries.iter().cloned().map(Value::Object).collect())
+                            .unwrap_or_default()
+                    }
+
+                    fn lock(&self) -> MutexGuard<'_, MockState> {
+                        self.state.lock().unwrap_or_else(|e| e.into_inner())
+                    }
+                }
+
+                #[async_trait::async_trait]
+                impl Backend for MockBackend {
+                    async fn get_entry(&self, list_id: ID, entry: AllId) -> Result<Entry, Error> {
+                        let key = entry.to_string();
+                        let obj = self
+                            .lock()
+                            .lists
+                            .get(&list_id)
+                            .and_then(|entries| entries.iter().find(|obj| has_key(obj, &key)))
+                            .cloned()
+                            .ok_or_else(|| not_found(list_id, &key))?;
+                        to_entry(obj)
+                    }
+
+                    async fn get_list_entries(&self, list_id: ID, params: &GetEntriesRequest) -> Result<Vec<Entry>, Error> {
+                        let limit = if params.limit == 0 { usize::MAX } else { params.limit };
+                        let state = self.lock();
+                        let mut entries: Vec<&JsonMap> = state
+                            .lists
+                            .get(&list_id)
+                            .map(|entries| entries.as_slice())
+                            .unwrap_or_default()
+                            .iter()
+                            .filter(|obj| params.allow_deprecated || obj.get("deprecated_at").map_or(true, Value::is_null))
+                            .filter(|obj| matches_filter(obj, &params.filter))
+                            .collect();
+                        for order in params.order_by.iter().rev() {
+                            let column = order.column.as_deref().unwrap_or("sortOrder");
+                            let descending = matches!(order.direction, zenkit::types::SortDirection::Desc);
+                            entries.sort_by(|a, b| compare_values(a.get(column), b.get(column), descending));
+                        }
+                        entries
+                            .into_iter()
+                            .skip(params.skip)
+                            .take(limit)
+                            .cloned()
+                            .map(to_entry)
+                            .collect()
+                    }
+
+                    async fn create_entry(&self, list_id: ID, val: Value) -> Result<Entry, Error> {
+                        let fields = as_object(val)?;
+                        let mut state = self.lock();
+                        state.last_id += 1;
+                        let mut obj = new_entry(list_id, state.last_id);
+                        apply_fields(&mut obj, &fields, update_action(&fields));
+                        state.lists.entry(list_id).or_default().push(obj.clone());
+                        to_entry(obj)
+                    }
+
+                    async fn update_entry(&self, list_id: ID, entry_id: ID, val: Value) -> Result<Entry, Error> {
+                        let fields = as_object(val)?;
+                        let mut state = self.lock();
+                        let obj = state
+                            .lists
+                            .get_mut(&list_id)
+                            .and_then(|entries| entries.iter_mut().find(|obj| has_key(obj, &entry_id.to_string())))
+                            .ok_or_else(|| not_found(list_id, &entry_id.to_string()))?;
+                        apply_fields(obj, &fields, update_action(&fields));
+                        obj.insert("updated_at".to_string(), json!(Utc::now()));
+                        to_entry(obj.clone())
+                    }
+
+                    async fn delete_entry(&self, list_id: ID, entry_id: ID) -> Result<(), Error> {
+                        let mut state = self.lock();
+                        let key = entry_id.to_string();
+                        let entries = state.lists.get_mut(&list_id).ok_or_else(|| not_found(list_id, &key))?;
+                        let index = entries
+                            .iter()
+                            .position(|obj| has_key(obj, &key))
+                            .ok_or_else(|| not_found(list_id, &key))?;
+                        entries.remove(index);
+                        Ok(())
+                    }
+
+                    async fn archive_entry(&self, list_id: ID, entry_id: ID) -> Result<Entry, Error> {
+                        self.set_deprecated(list_id, entry_id, json!(Utc::now()))
+                    }
+
+                    async fn restore_entry(&self, list_id: ID, entry_id: ID) -> Result<Entry, Error> {
+                        self.set_deprecated(list_id, entry_id, Value::Null)
+                    }
+                }
+
+                impl MockBackend {
+                    /// Sets the date an entry was archived, or null to restore it
+                    fn set_deprecated(&self, list_id: ID, entry_id: ID, deprecated_at: Value) -> Result<Entry, Error> {
+                        let mut state = self.lock();
+                        let obj = state
+                            .lists
+                            .get_mut(&list_id)
+                            .and_then(|entries| entries.iter_mut().find(|obj| has_key(obj, &entry_id.to_string())))
+                            .ok_or_else(|| not_found(list_id, &entry_id.to_string()))?;
+                        obj.insert("deprecated_at".to_string(), deprecated_at);
+                        to_entry(obj.clone())
+                    }
+                }
+
+                /// Returns id of a list in the workspace, from its id or uuid
+                fn list_id(key: &str) -> Option<ID> {
+                    LISTS
+                        .iter()
+                        .find(|(id, uuid)| id.to_string() == key || *uuid == key)
+                        .map(|(id, _)| *id)
+                }
+
+                /// Returns uuid of a field
+                fn field_uuid(id: ID) -> Option<&'static str> {
+                    FIELDS
+                        .binary_search_by_key(&id, |(id, _)| *id)
+                        .ok()
+                        .map(|index| FIELDS[index].1)
+                }
+
+                /// Returns true if the entry matches all terms of a filter (see `EntryQuery`)
+                fn matches_filter(obj: &JsonMap, filter: &Value) -> bool {
+                    filter["AND"]["TERMS"]
+                        .as_array()
+                        .map(|terms| terms.iter().all(|term| matches_term(obj, term)))
+                        .unwrap_or(true)
+                }
+
+                /// Returns true if the entry matches a filter term
+                fn matches_term(obj: &JsonMap, term: &Value) -> bool {
+                    let uuid = match term["elementId"].as_u64().and_then(field_uuid) {
+                        Some(uuid) => uuid,
+                        None => return false,
+                    };
+                    let value = |kind: &str| obj.get(&format!("{}_{}", uuid, kind)).filter(|v| !is_empty(v));
+                    let modus = term["modus"].as_str().unwrap_or_default();
+                    let is_set = ["text", "link", "number", "date", "checked", "categories", "persons", "references"]
+                        .iter()
+                        .any(|kind| value(kind).is_some());
+                    let any_of = |kind: &str, key: &str| match (value(kind), term[key].as_array()) {
+                        (Some(Value::Array(values)), Some(wanted)) => values.iter().any(|v| wanted.contains(v)),
+                        _ => false,
+                    };
+                    if modus == "isEmpty" {
+                        !is_set
+                    } else if modus == "isNotEmpty" {
+                        is_set
+                    } else if let Some(wanted) = term["text"].as_str() {
+                        let text = value("text").or_else(|| value("link")).and_then(|v| v.as_str()).unwrap_or_default();
+                        match modus {
+                            "contains" => text.contains(wanted),
+                            "notContains" => !text.contains(wanted),
+                            "startsWith" => text.starts_with(wanted),
+                            "endsWith" => text.ends_with(wanted),
+                            "notEquals" => text != wanted,
+                            _ => text == wanted,
+                        }
+                    } else if term.get("numberFrom").is_some() || term.get("numberTo").is_some() {
+                        let in_range = match value("number").and_then(|v| v.as_f64()) {
+                            Some(n) => term["numberFrom"].as_f64().map_or(true, |from| n >= from)
+                                && term["numberTo"].as_f64().map_or(true, |to| n <= to),
+                            None => false,
+                        };
+                        in_range != (modus == "notInRange")
+                    } else if term.get("dateFrom").is_some() || term.get("dateTo").is_some() {
+                        let date = |v: &Value| {
+                            v.as_str().and_then(|s| s.parse::<DateTime<Utc>>().ok()).map(|d| d.timestamp_millis())
+                        };
+                        match value("date").and_then(date) {
+                            Some(d) => date(&term["dateFrom"]).map_or(true, |from| d >= from)
+                                && date(&term["dateTo"]).map_or(true, |to| d <= to),
+                            None => false,
+                        }
+                    } else if let Some(checked) = term["checked"].as_bool() {
+                        value("checked").and_then(|v| v.as_bool()).unwrap_or_default() == checked
+                    } else if term.get("filterCategories").is_some() {
+                        any_of("categories", "filterCategories")
+                    } else if term.get("filterPersons").is_some() {
+                        any_of("persons", "filterPersons")
+                    } else {
+                        true
+                    }
+                }
+
+                /// Returns true for null, empty strings, and empty arrays
+                fn is_empty(val: &Value) -> bool {
+                    match val {
+                        Value::Null => true,
+                        Value::String(s) => s.is_empty(),
+                        Value::Array(a) => a.is_empty(),
+                        _ => false,
+                    }
+                }
+
+                /// Compares values of a sort column. Missing values are last in either direction.
+                fn compare_values(a: Option<&Value>, b: Option<&Value>, descending: bool) -> Ordering {
+                    let ord = match (a.filter(|v| !is_empty(v)), b.filter(|v| !is_empty(v))) {
+                        (Some(Value::Number(a)), Some(Value::Number(b))) => {
+                            a.as_f64().partial_cmp(&b.as_f64()).unwrap_or(Ordering::Equal)
+                        }
+                        (Some(Value::String(a)), Some(Value::String(b))) => a.cmp(b),
+                        (Some(Value::Bool(a)), Some(Value::Bool(b))) => a.cmp(b),
+                        (Some(_), None) => return Ordering::Less,
+                        (None, Some(_)) => return Ordering::Greater,
+                        _ => Ordering::Equal,
+                    };
+                    if descending { ord.reverse() } else { ord }
+                }
+
+                /// Returns name of a category label
+                fn label_name(id: ID) -> Option<&'static str> {
+                    LABELS
+                        .binary_search_by_key(&id, |(id, _)| *id)
+                        .ok()
+                        .map(|index| LABELS[index].1)
+                }
+
+                /// Returns true if the entry's id, uuid, or short id is the key
+                fn has_key(obj: &JsonMap, key: &str) -> bool {
+                    ["id", "uuid", "shortId"].iter().any(|prop| match obj.get(*prop) {
+                        Some(Value::String(s)) => s == key,
+                        Some(Value::Number(n)) => n.to_string() == key,
+                        _ => false,
+                    })
+                }
+
+                /// Returns the updateAction of a request: "append", "remove", or "replace"
+                fn update_action(fields: &JsonMap) -> &str {
+                    fields.get("updateAction").and_then(|v| v.as_str()).unwrap_or("replace")
+                }
+
+                /// Creates entry with no field values
+                fn new_entry(list_id: ID, id: ID) -> JsonMap {
+                    let now = json!(Utc::now());
+                    let mut obj = JsonMap::new();
+                    obj.insert("id".to_string(), json!(id));
+                    obj.insert("shortId".to_string(), json!(format!("mock-{}", id)));
+                    obj.insert("uuid".to_string(), json!(format!("00000000-0000-4000-8000-{:012x}", id)));
+                    obj.insert("listId".to_string(), json!(list_id));
+                    obj.insert("created_at".to_string(), now.clone());
+                    obj.insert("updated_at".to_string(), now);
+                    obj.insert("deprecated_at".to_string(), Value::Null);
+                    obj.insert("created_by".to_string(), json!(0));
+                    obj.insert("updated_by".to_string(), json!(0));
+                    obj.insert("displayString".to_string(), json!(""));
+                    obj.insert("sortOrder".to_string(), json!(id));
+                    obj.insert("comment_count".to_string(), json!(0));
+                    obj.insert("checklists".to_string(), json!([]));
+                    obj
+                }
+
+                /// Applies the fields set by a builder (or from a fixture) to an entry
+                fn apply_fields(obj: &mut JsonMap, fields: &JsonMap, action: &str) {
+                    for (key, value) in fields.iter() {
+                        if key == "updateAction" {
+                            continue;
+                        }
+                        let value = match (action, obj.get(key), value) {
+                            ("append", Some(Value::Array(old)), Value::Array(added)) => {
+                                let mut values = old.clone();
+                                values.extend(added.iter().filter(|v| !old.contains(v)).cloned());
+                                Value::Array(values)
+                            }
+                            ("remove", Some(Value::Array(old)), Value::Array(removed)) => {
+                                Value::Array(old.iter().filter(|v| !removed.contains(v)).cloned().collect())
+                            }
+                            ("remove", None, Value::Array(_)) => Value::Array(Vec::new()),
+                            _ => value.clone(),
+                        };
+                        if let Some(details) = sort_details(key, &value) {
+                            obj.insert(format!("{}_sort", key), details);
+                        }
+                        obj.insert(key.clone(), value);
+                    }
+                }
+
+                /// Returns the "_sort" property of a multi-value field, which has the details
+                /// of each value, or None for other fields
+                fn sort_details(key: &str, value: &Value) -> Option<Value> {
+                    let values = value.as_array()?;
+                    let details = if key.ends_with("_categories") {
+                        values
+                            .iter()
+                            .map(|id| json!({ "id": id, "name": id.as_u64().and_then(label_name) }))
+                            .collect()
+                    } else if key.ends_with("_persons") {
+                        values.iter().map(|id| json!({ "id": id })).collect()
+                    } else if key.ends_with("_references") {
+                        values.iter().map(|uuid| json!({ "uuid": uuid })).collect()
+                    } else {
+                        return None;
+                    };
+                    Some(Value::Array(details))
+                }
+
+                fn as_object(val: Value) -> Result<JsonMap, Error> {
+                    match val {
+                        Value::Object(map) => Ok(map),
+                        _ => Err(Error::Message("mock entry must be a json object".to_string())),
+                    }
+                }
+
+                fn to_entry(obj: JsonMap) -> Result<Entry, Error> {
+                    serde_json::from_value(Value::Object(obj))
+                        .map_err(|e| Error::Message(format!("invalid mock entry: {}", e)))
+                }
+
+                fn not_found(list_id: ID, key: &str) -> Error {
+                    Error::Zenkit(format!("entry {} not found in list {}", key, list_id))
                 }
-                Ok(ooo_entries)
             }
 "#,
         ),
         (
             "start_list_impl",
             r#"#![allow(dead_code, unused_imports)]
-            //! {{ list_struct }} {{list_desc}}
+            //! {{ list_struct }} {{ doc-comment list_desc "//!" }}
             //  {{ generated_banner }}
-            use crate::{lookup_label, Error};
+            use super::{lookup_label, run_batch, Api, EntryQuery, Error};
             use serde_json::{self, json, Value};
-            use std::{convert::AsRef, rc::Rc, str::FromStr};
-            use zenkit::{get_api, types::{DateTime, Entry, File, ID, JsonMap, TextFormat, Utc}};
+            use std::{convert::AsRef, str::FromStr, sync::Arc};
+            use zenkit::types::{DateTime, Entry, File, GetEntriesRequest, ID, JsonMap, TextFormat, Utc};
 
-            /// {{ list_struct }} {{list_desc}}
+            /// {{ list_struct }} {{ doc-comment list_desc }}
             ///
-            pub const {{ to-list-id list }}: ID = {{ list_id }};
-            pub const {{ to-list-short-id list }}: &str = "{{ list_short_id }}";
-            pub const {{ to-list-uuid list }}: &str = "{{ list_uuid }}";
-            pub const {{ to-list-name list }}: &str = "{{ list }}";
+            pub const {{ to-list-id list_ident }}: ID = {{ list_id }};
+            pub const {{ to-list-short-id list_ident }}: &str = "{{ list_short_id }}";
+            pub const {{ to-list-uuid list_ident }}: &str = "{{ list_uuid }}";
+            pub const {{ to-list-name list_ident }}: &str = "{{ rust-string list }}";
+
+            /// ID of a {{ item }} (item of List '{{ doc-comment list }}')
+            #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+            #[serde(transparent)]
+            pub struct {{ id_type }}(ID);
+
+            impl {{ id_type }} {
+                /// Creates {{ id_type }} from the item's numeric id
+                pub fn new(id: ID) -> Self {
+                    Self(id)
+                }
+
+                /// Returns the numeric id
+                pub fn value(&self) -> ID {
+                    self.0
+                }
+            }
+
+            impl std::fmt::Display for {{ id_type }} {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    write!(f, "{}", self.0)
+                }
+            }
+
+            impl FromStr for {{ id_type }} {
+                type Err = Error;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    s.parse::<ID>()
+                        .map(Self)
+                        .map_err(|_| Error::Message(format!("invalid {{ item }} id '{}'", s)))
+                }
+            }
+
+            impl From<{{ id_type }}> for ID {
+                fn from(id: {{ id_type }}) -> ID {
+                    id.0
+                }
+            }
+
+            /// UUID of a {{ item }} (item of List '{{ doc-comment list }}').
+            /// Reference setters accept this type or a `&{{ item }}`.
+            #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+            #[serde(transparent)]
+            pub struct {{ uuid_type }}(String);
+
+            impl {{ uuid_type }} {
+                /// Creates {{ uuid_type }} from a uuid string. The uuid is not validated;
+                /// use `parse` to check the format.
+                pub fn new<S: Into<String>>(uuid: S) -> Self {
+                    Self(uuid.into())
+                }
+
+                /// Returns the uuid string
+                pub fn as_str(&self) -> &str {
+                    &self.0
+                }
+            }
+
+            impl AsRef<str> for {{ uuid_type }} {
+                fn as_ref(&self) -> &str {
+                    &self.0
+                }
+            }
 
-            /// {{ list_struct }} - List of {{ item_plural }}
-            /// {{list_desc}}
+            impl std::fmt::Display for {{ uuid_type }} {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    f.write_str(&self.0)
+                }
+            }
+
+            impl FromStr for {{ uuid_type }} {
+                type Err = Error;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    if super::is_uuid(s) {
+                        Ok(Self(s.to_string()))
+                    } else {
+                        Err(Error::Message(format!("invalid {{ item }} uuid '{}'", s)))
+                    }
+                }
+            }
+
+            impl From<&{{ item }}> for {{ uuid_type }} {
+                fn from(item: &{{ item }}) -> Self {
+                    item.get_uuid()
+                }
+            }
+
+            impl From<&{{ uuid_type }}> for {{ uuid_type }} {
+                fn from(uuid: &{{ uuid_type }}) -> Self {
+                    uuid.clone()
+                }
+            }
+
+            /// {{ list_struct }} - List of {{ item_plural }}, using the global client.
+            /// For a separate connection, use `WorkspaceClient::{{ list_ident }}()`.
+            /// {{ doc-comment list_desc }}
             pub struct {{ list_struct }} { }
 
             impl {{ list_struct }} {
 
                 /// fetch {{ item }} by its ID
-                pub async fn get( {{ to_snake_case item }}_id: ID) -> Result<{{ item }},Error> {
-                    let obj = get_api()?.get_entry({{ to-list-id list }}, {{ to_snake_case item }}_id).await?;
-                    Ok({{ item }}::new( Rc::new(obj)))
+                pub async fn get( {{ item_ident }}_id: {{ id_type }}) -> Result<{{ item }},Error> {
+                    {{ list_client }}::new(Api::global()).get({{ item_ident }}_id).await
                 }
 
                 /// fetch {{ item }} by its UUID
-                pub async fn get_by_uuid ( {{ to_snake_case ( item ) }}_uuid: &str) -> Result<{{ item }},Error> {
-                    let obj = get_api()?.get_entry({{ to-list-id list }}, {{ to_snake_case item }}_uuid).await?;
-                    Ok({{ item }}::new(Rc::new(obj)))
+                pub async fn get_by_uuid ( {{ item_ident }}_uuid: &{{ uuid_type }}) -> Result<{{ item }},Error> {
+                    {{ list_client }}::new(Api::global()).get_by_uuid({{ item_ident }}_uuid).await
                 }
 
                 /// Returns all {{ item_plural }}
                 pub async fn get_items() -> Result<Vec<{{ item }}>, Error> {
-                    let entries = crate::load_entries({{ to-list-id list }}).await?;
+                    {{ list_client }}::new(Api::global()).get_items().await
+                }
+
+                /// Returns stream of all {{ item_plural }}, fetched a page at a time as the stream is read.
+                /// For other page sizes, or to resume at an offset, use `query()`.
+                pub fn stream_items() -> impl futures::Stream<Item = Result<{{ item }}, Error>> + Send {
+                    {{ list_client }}::new(Api::global()).stream_items()
+                }
+
+                /// Creates query for {{ item_plural }} matching conditions, which are checked by the server
+                pub fn query() -> {{ query_type }} {
+                    {{ query_type }}::new(Api::global())
+                }
+
+                /// Initialize builder for creating a new {{item}}
+                pub fn create() -> New{{ item }}Builder {
+                    New{{ item }}Builder::new(Api::global())
+                }
+
+                /// Creates builder for updating an existing {{item}}
+                pub fn update( {{ item_ident }}_id: {{ id_type }}) -> Update{{ item }}Builder {
+                    Update{{ item }}Builder::new(Api::global(), {{ item_ident }}_id)
+                }
+
+                /// Archives {{ item }}, and returns the archived {{ item }}
+                pub async fn archive({{ item_ident }}_id: {{ id_type }}) -> Result<{{ item }}, Error> {
+                    {{ list_client }}::new(Api::global()).archive({{ item_ident }}_id).await
+                }
+
+                /// Restores archived {{ item }}, and returns the restored {{ item }}
+                pub async fn restore({{ item_ident }}_id: {{ id_type }}) -> Result<{{ item }}, Error> {
+                    {{ list_client }}::new(Api::global()).restore({{ item_ident }}_id).await
+                }
+
+                /// Deletes {{ item }} permanently
+                pub async fn delete({{ item_ident }}_id: {{ id_type }}) -> Result<(), Error> {
+                    {{ list_client }}::new(Api::global()).delete({{ item_ident }}_id).await
+                }
+
+                /// Creates {{ item_plural }}, sending up to `concurrency` requests at a time.
+                /// Returns the result of each builder, in the order of `builders`, so that
+                /// a failure doesn't affect the other items. Each builder uses the client
+                /// it was created with. Creates are not retried (see `RetryPolicy`).
+                pub async fn create_many(builders: Vec<New{{ item }}Builder>, concurrency: usize) -> Vec<Result<{{ item }}, Error>> {
+                    run_batch(builders.into_iter().map(|mut b| async move { b.execute().await }), concurrency).await
+                }
+
+                /// Updates {{ item_plural }}, sending up to `concurrency` requests at a time.
+                /// Returns the result of each builder, in the order of `builders`, so that
+                /// a failure doesn't affect the other items. Each builder uses the client
+                /// it was created with. Updates are not retried (see `RetryPolicy`).
+                pub async fn update_many(builders: Vec<Update{{ item }}Builder>, concurrency: usize) -> Vec<Result<{{ item }}, Error>> {
+                    run_batch(builders.into_iter().map(|mut b| async move { b.execute().await }), concurrency).await
+                }
+            }
+
+            /// Client for List '{{ doc-comment list }}', from `WorkspaceClient::{{ list_ident }}()`.
+            /// Items and builders from this client use the client's connection.
+            #[derive(Clone)]
+            pub struct {{ list_client }} {
+                api: Api,
+            }
+
+            impl {{ list_client }} {
+
+                pub(crate) fn new(api: Api) -> Self {
+                    Self { api }
+                }
+
+                /// fetch {{ item }} by its ID
+                pub async fn get(&self, {{ item_ident }}_id: {{ id_type }}) -> Result<{{ item }},Error> {
+                    let obj = self.api.get_entry({{ to-list-id list_ident }}, {{ item_ident }}_id.value()).await?;
+                    Ok({{ item }}::new(Arc::new(obj), self.api.clone()))
+                }
+
+                /// fetch {{ item }} by its UUID
+                pub async fn get_by_uuid(&self, {{ item_ident }}_uuid: &{{ uuid_type }}) -> Result<{{ item }},Error> {
+                    let obj = self.api.get_entry({{ to-list-id list_ident }}, {{ item_ident }}_uuid.as_str()).await?;
+                    Ok({{ item }}::new(Arc::new(obj), self.api.clone()))
+                }
+
+                /// Returns all {{ item_plural }}
+                pub async fn get_items(&self) -> Result<Vec<{{ item }}>, Error> {
+                    let entries = self.api.load_entries({{ to-list-id list_ident }}).await?;
                     let items: Vec<{{ item }}> = entries
                         .into_iter()
-                        .map(|e| {{item}}::new(Rc::new(e)))
+                        .map(|e| {{item}}::new(Arc::new(e), self.api.clone()))
                         .collect();
                     Ok(items)
                 }
 
+                /// Returns stream of all {{ item_plural }}, fetched a page at a time as the stream is read.
+                /// For other page sizes, or to resume at an offset, use `query()`.
+                pub fn stream_items(&self) -> impl futures::Stream<Item = Result<{{ item }}, Error>> + Send {
+                    self.query().stream_items()
+                }
+
+                /// Creates query for {{ item_plural }} matching conditions, which are checked by the server
+                pub fn query(&self) -> {{ query_type }} {
+                    {{ query_type }}::new(self.api.clone())
+                }
+
                 /// Initialize builder for creating a new {{item}}
-                pub fn create() -> New{{ item }}Builder {
-                    New{{ item }}Builder::new()
+                pub fn create(&self) -> New{{ item }}Builder {
+                    New{{ item }}Builder::new(self.api.clone())
                 }
 
                 /// Creates builder for updating an existing {{item}}
-                pub fn update( {{ to_snake_case ( item ) }}_id: ID) -> Update{{ item }}Builder {
-                    Update{{ item }}Builder::new({{ to_snake_case ( item ) }}_id)
+                pub fn update(&self, {{ item_ident }}_id: {{ id_type }}) -> Update{{ item }}Builder {
+                    Update{{ item }}Builder::new(self.api.clone(), {{ item_ident }}_id)
+                }
+
+                /// Archives {{ item }}, and returns the archived {{ item }}. Archived items are
+                /// left out of `get_items` and queries, unless a query has `include_archived(true)`.
+                pub async fn archive(&self, {{ item_ident }}_id: {{ id_type }}) -> Result<{{ item }}, Error> {
+                    let obj = self.api.archive_entry({{ to-list-id list_ident }}, {{ item_ident }}_id.value()).await?;
+                    Ok({{ item }}::new(Arc::new(obj), self.api.clone()))
+                }
+
+                /// Restores archived {{ item }}, and returns the restored {{ item }}
+                pub async fn restore(&self, {{ item_ident }}_id: {{ id_type }}) -> Result<{{ item }}, Error> {
+                    let obj = self.api.restore_entry({{ to-list-id list_ident }}, {{ item_ident }}_id.value()).await?;
+                    Ok({{ item }}::new(Arc::new(obj), self.api.clone()))
+                }
+
+                /// Deletes {{ item }} permanently
+                pub async fn delete(&self, {{ item_ident }}_id: {{ id_type }}) -> Result<(), Error> {
+                    self.api.delete_entry({{ to-list-id list_ident }}, {{ item_ident }}_id.value()).await
                 }
             }
 
-            /// {{ item }} (item of List '{{ list }}') {{list_desc}}
+            /// {{ item }} (item of List '{{ doc-comment list }}') {{ doc-comment list_desc }}
             pub struct {{ item }} {
-                obj: Rc<Entry>
+                obj: Arc<Entry>,
+                api: Api,
             }
 
             impl {{ item }} {
 
                 /// Creates {{ item }} wrapping Zenkit Entry
-                fn new(obj: Rc<Entry>) -> Self {
-                    Self{ obj }
+                fn new(obj: Arc<Entry>, api: Api) -> Self {
+                    Self{ obj, api }
                 }
             "#,
         ),
@@ -458,14 +1851,14 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
             "category_label_lookup",
             r#"
             /// Returns the id for a label, or None if not valid for this field
-            pub fn label_id_for_{{ to_snake_case field }}(label: &str) -> Option<ID> {
-                lookup_label(&{{item}}::LABELS_{{ to_screaming_snake_case field }}, label)
+            pub fn label_id_for_{{ field_ident }}(label: &str) -> Option<ID> {
+                lookup_label(&{{item}}::LABELS_{{ field_upper }}, label)
             }
 
             /// label index used for converting from label name to id (sorted)
-            const LABELS_{{ to_screaming_snake_case field }}: [(&'static str,u64);{{ len }}] = [
+            const LABELS_{{ field_upper }}: [(&'static str,u64);{{ len }}] = [
                 {{#each labels ~}}
-                ("{{ this }}", {{../item}}::LABEL_{{ to_screaming_snake_case ../field }}_{{ to_screaming_snake_case this }}_ID),
+                ("{{ rust-string this.name }}", {{../item}}::LABEL_{{ ../field_upper }}_{{ this.upper }}_ID),
                 {{/each ~}}
             ];
             "#,
@@ -477,8 +1870,8 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
 
             /// Returns url to zenkit item
             pub fn get_zenkit_url(&self) -> String {
-                format!("{}{}/{}/", crate::ZENKIT_ITEM_URL_BASE,
-                    {{ to-list-short-id list }}, self.obj.short_id)
+                format!("{}{}/{}/", super::item_url_base(),
+                    {{ to-list-short-id list_ident }}, self.obj.short_id)
             }
 
             /// Returns the item's display string (based on list's primary field)
@@ -531,19 +1924,34 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
                 self.obj.sort_order
             }
 
-            /// Returns the item's id within the list
-            pub fn get_id(&self) -> ID {
-                self.obj.id
+            /// Returns the item's id within the list
+            pub fn get_id(&self) -> {{ id_type }} {
+                {{ id_type }}::new(self.obj.id)
+            }
+
+            /// Returns the item's uuid
+            pub fn get_uuid(&self) -> {{ uuid_type }} {
+                {{ uuid_type }}::new(self.obj.uuid.as_str())
+            }
+
+            /// Returns the underlying Entry object
+            pub fn get_entry(&self) -> Arc<Entry> {
+                self.obj.clone()
+            }
+
+            /// Archives the item, and returns the archived item
+            pub async fn archive(&self) -> Result<{{ item }}, Error> {
+                {{ list_client }}::new(self.api.clone()).archive(self.get_id()).await
             }
 
-            /// Returns the item's uuid
-            pub fn get_uuid(&self) -> &str {
-                &self.obj.uuid
+            /// Restores the archived item, and returns the restored item
+            pub async fn restore(&self) -> Result<{{ item }}, Error> {
+                {{ list_client }}::new(self.api.clone()).restore(self.get_id()).await
             }
 
-            /// Returns the underlying Entry object
-            pub fn get_entry(&self) -> Rc<Entry> {
-                self.obj.clone()
+            /// Deletes the item permanently
+            pub async fn delete(&self) -> Result<(), Error> {
+                {{ list_client }}::new(self.api.clone()).delete(self.get_id()).await
             }
 
             } // impl {{ item }}
@@ -559,23 +1967,42 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
             /// Builder for creating new {{ item }}
             {{/if ~}}
             pub struct {{ builder }} {
+                api: Api,
                 fields: JsonMap,
                 errs: Vec<String>,
                 {{#if is_update_builder~}}
-                item_id: ID,
+                item_id: {{ id_type }},
                 {{/if~}}
             }
 
             impl {{ builder }} {
                 {{#if is_update_builder}}
                 /// Create update builder for {{item}} with id
-                fn new(item_id: ID) -> Self {
-                    Self { fields: JsonMap::new(), errs: Vec::new(), item_id }
+                fn new(api: Api, item_id: {{ id_type }}) -> Self {
+                    Self { api, fields: JsonMap::new(), errs: Vec::new(), item_id }
                 }
                 {{else}}
                 /// Create builder for new {{item}}
-                fn new() -> Self {
-                    Self { fields: JsonMap::new(), errs: Vec::new() }
+                fn new(api: Api) -> Self {
+                    Self { api, fields: JsonMap::new(), errs: Vec::new() }
+                }
+                {{/if}}
+                {{#if is_update_builder}}
+                // internal helper for setting the updateAction of multi-value fields.
+                // Zenkit applies one action to all fields of a request, so a request can't
+                // append or remove values of one field and replace another.
+                fn set_update_action(&mut self, action: &str) {
+                    if let Some(Value::String(prev)) = self.fields.get("updateAction") {
+                        if prev != action {
+                            self.errs.push(format!(
+                                "Conflicting update actions '{}' and '{}': fields that add or remove values \
+                                 must be updated separately from fields that are set",
+                                prev, action
+                            ));
+                            return;
+                        }
+                    }
+                    self.set_s("updateAction", action.to_string());
                 }
                 {{/if}}
 
@@ -603,25 +2030,25 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
         (
             "field_const",
             r#"
-            /// '{{ field }}' in '{{ list }}' {{field_desc}}
-            pub const {{ to-field-id field }}: ID = {{ field_id }};
-            pub const {{ to-field-uuid field }}: &'static str = "{{ field_uuid }}";
-            pub const {{ to-field-name field }}: &'static str = "{{ field }}";
+            /// '{{ doc-comment field }}' in '{{ doc-comment list }}' {{ doc-comment field_desc }}
+            pub const {{ to-field-id field_ident }}: ID = {{ field_id }};
+            pub const {{ to-field-uuid field_ident }}: &'static str = "{{ field_uuid }}";
+            pub const {{ to-field-name field_ident }}: &'static str = "{{ rust-string field }}";
             "#,
         ),
         // get text field
         (
             "get_text_field",
             r#"
-            /// Returns '{{ field }}' - {{ field_desc }} or None if unset
-            pub fn get_{{ to_snake_case field }}(&self) -> Option<&str> {
+            /// Returns '{{ doc-comment field }}' - {{ doc-comment field_desc }} or None if unset
+            pub fn get_{{ field_ident }}(&self) -> Option<&str> {
                 self.obj.fields.get("{{ field_uuid }}_text")
                     .map(|v| v.as_str())
                     .unwrap_or_default()
             }
 
-            /// Returns the text format for '{{ field }}'
-            pub fn get_{{ to_snake_case field }}_format(&self) -> TextFormat {
+            /// Returns the text format for '{{ doc-comment field }}'
+            pub fn get_{{ field_ident }}_format(&self) -> TextFormat {
                 self.obj.fields.get("{{ field_uuid }}_textType")
                     .map(|v| v.as_str())
                     .unwrap_or_default()
@@ -635,8 +2062,8 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
         (
             "get_url_field",
             r#"
-            /// Returns '{{ field }}' - {{ field_desc }} or None if unset
-            pub fn get_{{ to_snake_case field }}(&self) -> Option<&str> {
+            /// Returns '{{ doc-comment field }}' - {{ doc-comment field_desc }} or None if unset
+            pub fn get_{{ field_ident }}(&self) -> Option<&str> {
                 self.obj.fields.get("{{ field_uuid }}_link")
                     .map(|v| v.as_str())
                     .unwrap_or_default()
@@ -647,8 +2074,8 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
         (
             "get_int_field",
             r#"
-            /// Returns '{{ field }}' - {{ field_desc }} or None if unset
-            pub fn get_{{ to_snake_case field }}(&self) -> Option<i64> {
+            /// Returns '{{ doc-comment field }}' - {{ doc-comment field_desc }} or None if unset
+            pub fn get_{{ field_ident }}(&self) -> Option<i64> {
                 self.obj.fields.get("{{ field_uuid }}_number")
                     .map(|n| n.as_i64())
                     .unwrap_or_default()
@@ -659,8 +2086,8 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
         (
             "get_float_field",
             r#"
-            /// Returns '{{ field }}' - {{ field_desc }} or None if unset
-            pub fn get_{{ to_snake_case field }}(&self) -> Option<f64> {
+            /// Returns '{{ doc-comment field }}' - {{ doc-comment field_desc }} or None if unset
+            pub fn get_{{ field_ident }}(&self) -> Option<f64> {
                 self.obj.fields.get("{{ field_uuid }}_number")
                     .map(|n| n.as_f64())
                     .unwrap_or_default()
@@ -671,13 +2098,13 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
         (
             "get_formula_field",
             r#"
-            /// Returns value of formula field '{{ field }}', or None
+            /// Returns value of formula field '{{ doc-comment field }}', or None
             /// if the field isn't calculated, or if there was an error calculating the value.
-            /// For description of the error, use get_{{ to_snake_case field }}_error()
-            pub fn get_{{ to_snake_case field }}(&self) -> Option<f64> {
+            /// For description of the error, use get_{{ field_ident }}_error()
+            pub fn get_{{ field_ident }}(&self) -> Option<f64> {
                 // Only return value if there's no error
                 // or if there is some other reason formula value can be null
-                if let None = self.get_{{ to_snake_case field}}_error() {
+                if let None = self.get_{{ field_ident }}_error() {
                         return self.obj.fields.get("{{ field_uuid }}_value")
                             .map(|n| n.as_f64())
                             .unwrap_or_default();
@@ -685,8 +2112,8 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
                 None
             }
 
-            /// Returns error message for formula {{ field }}, if any
-            pub fn get_{{ to_snake_case field }}_error(&self) -> Option<&String> {
+            /// Returns error message for formula {{ doc-comment field }}, if any
+            pub fn get_{{ field_ident }}_error(&self) -> Option<&String> {
                 if let Some(Value::String(s)) = self.obj.fields.get("{{ field_uuid }}_valueErrorMessage") {
                     if s.len() > 0 {
                         return Some(s)
@@ -700,8 +2127,8 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
         (
             "get_checkbox_field",
             r#"
-            /// Returns true if '{{ field }}' is checked.  {{ field_desc }}
-            pub fn is_checked_{{ to_snake_case field }}(&self) -> bool {
+            /// Returns true if '{{ doc-comment field }}' is checked.  {{ doc-comment field_desc }}
+            pub fn is_checked_{{ field_ident }}(&self) -> bool {
                 self.obj.fields.get("{{ field_uuid }}_checked")
                     .map(|n| n.as_bool())
                     .unwrap_or_default()
@@ -713,8 +2140,8 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
         (
             "get_date_field",
             r#"
-            /// Returns '{{ field }}' in UTC {{ field_desc }}, or None if unset
-            pub fn get_{{ to_snake_case field }}(&self) -> Option<DateTime<Utc>> {
+            /// Returns '{{ doc-comment field }}' in UTC {{ doc-comment field_desc }}, or None if unset
+            pub fn get_{{ field_ident }}(&self) -> Option<DateTime<Utc>> {
                 self.obj.fields.get("{{ field_uuid }}_date")
                     .map(|v| v.as_str())
                     .unwrap_or_default()
@@ -727,8 +2154,8 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
         (
             "get_date_x_field",
             r#"
-            /// Returns '{{ field }}' in UTC {{ field_desc }}, or None if unset
-            pub fn get_date_{{ to_snake_case field }}(&self) -> Option<DateTime<Utc>> {
+            /// Returns '{{ doc-comment field }}' in UTC {{ doc-comment field_desc }}, or None if unset
+            pub fn get_date_{{ field_ident }}(&self) -> Option<DateTime<Utc>> {
                 self.obj.fields.get("{{ field_uuid }}_date")
                     .map(|v| v.as_str())
                     .unwrap_or_default()
@@ -741,16 +2168,16 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
         (
             "category_getter_per_label",
             r#"
-            pub const LABEL_{{ to_screaming_snake_case field }}_{{ to_screaming_snake_case label }}_ID : ID = {{ label_id }};
+            pub const LABEL_{{ field_upper }}_{{ label_upper }}_ID : ID = {{ label_id }};
 
-            /// Returns true if '{{ label }}' is set on '{{field}}'
-            /// {{field_desc}}
-            pub fn is_{{ to_snake_case field }}_{{ to_snake_case label }}(&self) -> bool {
+            /// Returns true if '{{ doc-comment label }}' is set on '{{ doc-comment field }}'
+            /// {{ doc-comment field_desc }}
+            pub fn is_{{ field_ident }}_{{ label_ident }}(&self) -> bool {
                 self.obj.fields.get("{{ field_uuid }}_categories")
                     .map(|v| v.as_array())
                     .unwrap_or_default()
                     .map(|v| v.iter()
-                        .any(|n| n == {{ item }}::LABEL_{{ to_screaming_snake_case field }}_{{ to_screaming_snake_case label }}_ID))
+                        .any(|n| n == {{ item }}::LABEL_{{ field_upper }}_{{ label_upper }}_ID))
                     .unwrap_or_default()
             }
             "#,
@@ -760,9 +2187,22 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
             "category_getters_per_field",
             r#"
             {{#if field_single_value}}
-            /// Returns label value of '{{ field }}', or None if field is unset
-            /// {{field_desc}}
-            pub fn get_{{ to_snake_case field }}(&self) -> Option<&str> {
+            {{#if category_enums ~}}
+            /// Returns value of '{{ doc-comment field }}', or None if field is unset
+            /// or its label is not a variant of {{ enum_type }}
+            /// {{ doc-comment field_desc }}
+            pub fn get_{{ field_ident }}(&self) -> Option<{{ enum_type }}> {
+                self.get_{{ field_ident }}_id().and_then({{ enum_type }}::from_id)
+            }
+
+            /// Returns label value of '{{ doc-comment field }}', or None if field is unset
+            /// {{ doc-comment field_desc }}
+            pub fn get_{{ field_ident }}_label(&self) -> Option<&str> {
+            {{else ~}}
+            /// Returns label value of '{{ doc-comment field }}', or None if field is unset
+            /// {{ doc-comment field_desc }}
+            pub fn get_{{ field_ident }}(&self) -> Option<&str> {
+            {{/if ~}}
                 self.obj.fields.get("{{ field_uuid }}_categories_sort")
                     .map(|v| v.as_array())
                     .unwrap_or_default()
@@ -776,9 +2216,9 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
                     )
             }
 
-            /// Returns id value of '{{ field }}', or None if field is unset
-            /// {{field_desc}}
-            pub fn get_{{ to_snake_case field }}_id(&self) -> Option<ID> {
+            /// Returns id value of '{{ doc-comment field }}', or None if field is unset
+            /// {{ doc-comment field_desc }}
+            pub fn get_{{ field_ident }}_id(&self) -> Option<ID> {
                 self.obj.fields.get("{{ field_uuid }}_categories")
                     .map(|v| v.as_array())
                     .unwrap_or_default()
@@ -791,9 +2231,21 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
                     .unwrap_or_default()
             }
             {{else}}
-            /// Returns list of labels (as ids) set on '{{ field }}'
-            /// {{field_desc}}
-            pub fn get_{{ to_plural ( to_snake_case field ) }}_ids(&self) -> Vec<ID> {
+            {{#if category_enums ~}}
+            /// Returns set of labels set on '{{ doc-comment field }}'.
+            /// Labels that are not variants of {{ enum_type }} are skipped.
+            /// {{ doc-comment field_desc }}
+            pub fn get_{{ field_plural }}(&self) -> std::collections::BTreeSet<{{ enum_type }}> {
+                self.get_{{ field_plural }}_ids()
+                    .into_iter()
+                    .filter_map({{ enum_type }}::from_id)
+                    .collect()
+            }
+
+            {{/if ~}}
+            /// Returns list of labels (as ids) set on '{{ doc-comment field }}'
+            /// {{ doc-comment field_desc }}
+            pub fn get_{{ field_plural }}_ids(&self) -> Vec<ID> {
                 self.obj.fields.get("{{ field_uuid }}_categories_sort")
                     .map(|v| v.as_array())
                     .unwrap_or_default()
@@ -805,9 +2257,9 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
                     )
                     .unwrap_or_else(Vec::new)
             }
-            /// returns list of values set on '{{ field }}'
-            /// {{field_desc}}
-            pub fn get_{{ to_plural ( to_snake_case field ) }}_labels(&self) -> Vec<&str> {
+            /// returns list of values set on '{{ doc-comment field }}'
+            /// {{ doc-comment field_desc }}
+            pub fn get_{{ field_plural }}_labels(&self) -> Vec<&str> {
                 self.obj.fields.get("{{ field_uuid }}_categories_sort")
                     .map(|v| v.as_array())
                     .unwrap_or_default()
@@ -827,9 +2279,9 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
             "get_person_field",
             r#"
             {{#if field_single_value}}
-            /// Returns the person's name in {{ field }}, or None if unset
-            /// {{field_desc}}
-            pub fn get_{{ to_snake_case field }}_name(&self) -> Option<&str> {
+            /// Returns the person's name in {{ doc-comment field }}, or None if unset
+            /// {{ doc-comment field_desc }}
+            pub fn get_{{ field_ident }}_name(&self) -> Option<&str> {
                 self.obj.fields.get("{{ field_uuid }}_persons_sort")
                     .map(|v| v.as_array())
                     .unwrap_or_default()
@@ -840,11 +2292,12 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
                             .filter_map(|val| val.as_str())
                             .next()
                     )
+                    .unwrap_or_default()
             }
 
-            /// Returns the person's id in {{ field }}, or None if unset
-            /// {{field_desc}}
-            pub fn get_{{ to_snake_case field }}_id(&self) -> ID {
+            /// Returns the person's id in {{ doc-comment field }}, or None if unset
+            /// {{ doc-comment field_desc }}
+            pub fn get_{{ field_ident }}_id(&self) -> Option<ID> {
                 self.obj.fields.get("{{ field_uuid }}_persons_sort")
                     .map(|v| v.as_array())
                     .unwrap_or_default()
@@ -855,11 +2308,12 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
                             .filter_map(|val| val.as_u64())
                             .next()
                     )
+                    .unwrap_or_default()
             }
             {{else}}
-            /// Returns the names of persons in {{ field }}
-            /// {{field_desc}}
-            pub fn get_{{ to_snake_case field }}_names(&self) -> Vec<&str> {
+            /// Returns the names of persons in {{ doc-comment field }}
+            /// {{ doc-comment field_desc }}
+            pub fn get_{{ field_ident }}_names(&self) -> Vec<&str> {
                 self.obj.fields.get("{{ field_uuid }}_persons_sort")
                     .map(|v| v.as_array())
                     .unwrap_or_default()
@@ -871,9 +2325,9 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
                     )
                     .unwrap_or_else(Vec::new)
             }
-            /// Returns the ids of persons in {{ field }}
-            /// {{field_desc}}
-            pub fn get_{{ to_snake_case field }}_ids(&self) -> Vec<ID> {
+            /// Returns the ids of persons in {{ doc-comment field }}
+            /// {{ doc-comment field_desc }}
+            pub fn get_{{ field_ident }}_ids(&self) -> Vec<ID> {
                 self.obj.fields.get("{{ field_uuid }}_persons_sort")
                     .map(|v| v.as_array())
                     .unwrap_or_default()
@@ -893,9 +2347,9 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
             "get_references",
             r#"
             {{#if field_single_value}}
-            /// Returns the reference in {{field}} (to {{ref_list}}, or None if unset
-            /// {{field_desc}}
-            pub fn get_{{ to_snake_case field }}_uuid(&self) -> Option<&str> {
+            /// Returns the reference in {{ doc-comment field }} (to {{ doc-comment ref_list }}, or None if unset
+            /// {{ doc-comment field_desc }}
+            pub fn get_{{ field_ident }}_uuid(&self) -> Option<&str> {
                 self.obj.fields.get("{{ field_uuid }}_references_sort")
                     .map(|v| v.as_array())
                     .unwrap_or_default()
@@ -906,11 +2360,27 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
                             .filter_map(|val| val.as_str())
                             .next()
                     )
+                    .unwrap_or_default()
+            }
+            {{#if ref_typed}}
+
+            /// Fetches the {{ ref_item }} referenced in {{ doc-comment field }}, or None if unset
+            /// {{ doc-comment field_desc }}
+            pub async fn get_{{ field_ident }}(&self) -> Result<Option<super::{{ ref_module }}::{{ ref_item }}>, Error> {
+                match self.get_{{ field_ident }}_uuid() {
+                    Some(uuid) => {
+                        let uuid = super::{{ ref_module }}::{{ ref_uuid_type }}::new(uuid);
+                        let client = super::{{ ref_module }}::{{ ref_client }}::new(self.api.clone());
+                        Ok(Some(client.get_by_uuid(&uuid).await?))
+                    }
+                    None => Ok(None),
+                }
             }
+            {{/if}}
             {{else}}
-            /// Returns the references in {{ field }} to {{ref_list}}
-            /// {{field_desc}}
-            pub fn get_{{ to_snake_case field }}_uuids(&self) -> Vec<&str> {
+            /// Returns the references in {{ doc-comment field }} to {{ doc-comment ref_list }}
+            /// {{ doc-comment field_desc }}
+            pub fn get_{{ field_ident }}_uuids(&self) -> Vec<&str> {
                 self.obj.fields.get("{{ field_uuid }}_references_sort")
                     .map(|v| v.as_array())
                     .unwrap_or_default()
@@ -922,6 +2392,20 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
                     )
                     .unwrap_or_else(Vec::new)
             }
+            {{#if ref_typed}}
+
+            /// Fetches the {{ ref_item }} items referenced in {{ doc-comment field }}
+            /// {{ doc-comment field_desc }}
+            pub async fn get_{{ field_ident }}(&self) -> Result<Vec<super::{{ ref_module }}::{{ ref_item }}>, Error> {
+                let client = super::{{ ref_module }}::{{ ref_client }}::new(self.api.clone());
+                let mut items = Vec::new();
+                for uuid in self.get_{{ field_ident }}_uuids() {
+                    let uuid = super::{{ ref_module }}::{{ ref_uuid_type }}::new(uuid);
+                    items.push(client.get_by_uuid(&uuid).await?);
+                }
+                Ok(items)
+            }
+            {{/if}}
             {{/if}}
             "#,
         ),
@@ -929,10 +2413,10 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
         (
             "get_subitems_field",
             r#"
-            /// Returns subitem references in {{ field }} hierarchy
-            /// {{field_desc}}
-            pub fn get_{{ to_snake_case field }}_uuids(&self) -> Vec<&str> {
-                if self.is_connected_{{ to_snake_case field }}() {
+            /// Returns subitem references in {{ doc-comment field }} hierarchy
+            /// {{ doc-comment field_desc }}
+            pub fn get_{{ field_ident }}_uuids(&self) -> Vec<&str> {
+                if self.is_connected_{{ field_ident }}() {
                     self.obj.fields.get("{{ field_uuid }}_references_sort")
                         .map(|v| v.as_array())
                         .unwrap_or_default()
@@ -948,10 +2432,10 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
                 }
             }
 
-            /// Returns parent references in {{ field }} hierarchy
-            /// {{field_desc}}
-            pub fn get_{{ to_snake_case field }}_parents(&self) -> Vec<&str> {
-                if self.is_connected_{{ to_snake_case field }}() {
+            /// Returns parent references in {{ doc-comment field }} hierarchy
+            /// {{ doc-comment field_desc }}
+            pub fn get_{{ field_ident }}_parents(&self) -> Vec<&str> {
+                if self.is_connected_{{ field_ident }}() {
                     self.obj.fields.get("{{ field_uuid }}_parents")
                         .map(|v| v.as_array())
                         .unwrap_or_default()
@@ -962,9 +2446,9 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
                 }
             }
 
-            /// Returns true if this item is connected in the {{ field }} hierarchy
-            /// {{field_desc}}
-            pub fn is_connected_{{ to_snake_case field }}(&self) -> bool {
+            /// Returns true if this item is connected in the {{ doc-comment field }} hierarchy
+            /// {{ doc-comment field_desc }}
+            pub fn is_connected_{{ field_ident }}(&self) -> bool {
                 self.obj.fields.get("{{ field_uuid }}_connected")
                     .map(|v| v.as_bool())
                     .unwrap_or_default()
@@ -976,8 +2460,8 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
         (
             "get_files_field",
             r#"
-            /// Returns files from '{{ field }}' - {{ field_desc }}
-            pub fn get_{{ to_snake_case field }}(&self) -> Vec<File> {
+            /// Returns files from '{{ doc-comment field }}' - {{ doc-comment field_desc }}
+            pub fn get_{{ field_ident }}(&self) -> Vec<File> {
                 match self.obj.fields.get("{{ field_uuid }}_filesData") {
                     Some(v) => match serde_json::from_value::<Vec<File>>(v.clone()) {
                         Ok(files) => files,
@@ -996,20 +2480,20 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
         (
             "set_text_field",
             r#"
-            /// Sets text value for {{ field }}. {{field_desc}}
+            /// Sets text value for {{ doc-comment field }}. {{ doc-comment field_desc }}
             {{#if is_update_builder ~}}
             /// TextFormat is unchanged (to specify format, use _with_format())
             {{else ~}}
             /// Uses the field's default TextFormat. To specify format, use _with_format()
             {{/if ~}}
-            pub fn set_{{ to_snake_case field }}<T:AsRef<str>>(&mut self, s: T) -> &mut {{ builder }} {
+            pub fn set_{{ field_ident }}<T:AsRef<str>>(&mut self, s: T) -> &mut {{ builder }} {
                 self.set_s("{{ field_uuid }}_text", s.as_ref().to_string());
                 self
             }
 
-            /// Sets text value for {{ field }} with format
-            /// {{field_desc}}
-            pub fn set_{{ to_snake_case field }}_with_format<T:AsRef<str>>(&mut self, s: T, tf: TextFormat) -> &mut {{ builder }} {
+            /// Sets text value for {{ doc-comment field }} with format
+            /// {{ doc-comment field_desc }}
+            pub fn set_{{ field_ident }}_with_format<T:AsRef<str>>(&mut self, s: T, tf: TextFormat) -> &mut {{ builder }} {
                 self.set_s("{{ field_uuid }}_text", s.as_ref().to_string());
                 self.set_s("{{ field_uuid }}_textType", tf.to_string());
                 self
@@ -1020,9 +2504,9 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
         (
             "set_int_field",
             r#"
-            /// Sets {{ field }} with integer value.
-            /// {{field_desc}}
-            pub fn set_{{ to_snake_case field }}(&mut self, i: i64) -> &mut {{ builder }} {
+            /// Sets {{ doc-comment field }} with integer value.
+            /// {{ doc-comment field_desc }}
+            pub fn set_{{ field_ident }}(&mut self, i: i64) -> &mut {{ builder }} {
                 let jn = serde_json::Number::from(i);
                 self.set_v("{{ field_uuid }}_number", Value::Number(jn));
                 self
@@ -1033,10 +2517,10 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
         (
             "set_float_field",
             r#"
-            /// Sets {{ field }} with float value.
-            /// {{field_desc}}
+            /// Sets {{ doc-comment field }} with float value.
+            /// {{ doc-comment field_desc }}
             /// Generates error if float value is Infinite or NaN.
-            pub fn set_{{ to_snake_case field }}(&mut self, f: f64) -> &mut {{ builder }} {
+            pub fn set_{{ field_ident }}(&mut self, f: f64) -> &mut {{ builder }} {
                 match serde_json::Number::from_f64(f) {
                     Some(n) => self.set_v("{{ field_uuid }}_number", Value::Number(n)),
                     None => self.errs.push("Float values cannot be Infinite or NaN".to_string())
@@ -1049,9 +2533,9 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
         (
             "set_checkbox_field",
             r#"
-            /// sets checkbox {{ field }}
-            /// {{field_desc}}
-            pub fn check_{{ to_snake_case field }}(&mut self, b: bool) -> &mut {{ builder }} {
+            /// sets checkbox {{ doc-comment field }}
+            /// {{ doc-comment field_desc }}
+            pub fn check_{{ field_ident }}(&mut self, b: bool) -> &mut {{ builder }} {
                 self.set_v("{{ field_uuid }}_checked", Value::Bool(b));
                 self
             }
@@ -1061,8 +2545,8 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
         (
             "set_url_field",
             r#"
-            /// Sets URL {{ field }}. {{field_desc}}
-            pub fn set_{{ to_snake_case field }}<T:AsRef<str>>(&mut self, url: T) -> &mut {{ builder }} {
+            /// Sets URL {{ doc-comment field }}. {{ doc-comment field_desc }}
+            pub fn set_{{ field_ident }}<T:AsRef<str>>(&mut self, url: T) -> &mut {{ builder }} {
                 self.set_s("{{ field_uuid }}_link", url.as_ref().to_string());
                 self
             }
@@ -1072,8 +2556,8 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
         (
             "set_date_field",
             r#"
-            /// Sets date {{ field }}. {{field_desc}}
-            pub fn set_{{ to_snake_case field }}(&mut self, date: &DateTime<Utc>) -> &mut {{ builder }} {
+            /// Sets date {{ doc-comment field }}. {{ doc-comment field_desc }}
+            pub fn set_{{ field_ident }}(&mut self, date: &DateTime<Utc>) -> &mut {{ builder }} {
                 self.set_s("{{ field_uuid }}_date", date.to_string());
                 self
             }
@@ -1084,22 +2568,22 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
             "set_person_field",
             r#"
             {{#if field_single_value ~}}
-            /// Sets person {{ field }}. {{#if is_update_builder}}Replaces previous value{{/if}}
-            /// {{field_desc}}
-            pub fn set_{{ to_snake_case field }}(&mut self, id: ID) -> &mut {{ builder }} {
+            /// Sets person {{ doc-comment field }}. {{#if is_update_builder}}Replaces previous value{{/if}}
+            /// {{ doc-comment field_desc }}
+            pub fn set_{{ field_ident }}(&mut self, id: ID) -> &mut {{ builder }} {
                 self.set_v("{{ field_uuid }}_persons", json!(vec![id]));
                 {{#if is_update_builder~}}
-                self.set_s("updateAction", "replace".to_string());
+                self.set_update_action("replace");
                 {{/if~}}
                 self
             }
             {{else ~}}
-            /// Sets person(s) {{ field }}. {{#if is_update_builder}}Replaces previous value(s){{/if}}
-            /// {{field_desc}}
-            pub fn set_{{ to_snake_case field }}(&mut self, ids: Vec<ID>) -> &mut {{ builder }} {
+            /// Sets person(s) {{ doc-comment field }}. {{#if is_update_builder}}Replaces previous value(s){{/if}}
+            /// {{ doc-comment field_desc }}
+            pub fn set_{{ field_ident }}(&mut self, ids: Vec<ID>) -> &mut {{ builder }} {
                 self.set_v("{{ field_uuid }}_persons", json!(ids));
                 {{#if is_update_builder~}}
-                self.set_s("updateAction", "replace".to_string());
+                self.set_update_action("replace");
                 {{/if~}}
                 self
             }
@@ -1107,26 +2591,26 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
 
             {{#if is_update_builder ~}}
             {{#if field_multiple_value ~}}
-            /// Adds person(s) {{ field }}
-            /// {{field_desc}}
-            pub fn add_{{ to_snake_case field }}(&mut self, ids: Vec<ID>) -> &mut {{ builder }} {
+            /// Adds person(s) {{ doc-comment field }}
+            /// {{ doc-comment field_desc }}
+            pub fn add_{{ field_ident }}(&mut self, ids: Vec<ID>) -> &mut {{ builder }} {
                 self.set_v("{{ field_uuid }}_persons", json!(ids));
-                self.set_s("updateAction", "append".to_string());
+                self.set_update_action("append");
                 self
             }
-            /// Remove person(s) {{ field }}
-            /// {{field_desc}}
-            pub fn remove_{{ to_snake_case field }}(&mut self, ids: Vec<ID>) -> &mut {{ builder }} {
+            /// Remove person(s) {{ doc-comment field }}
+            /// {{ doc-comment field_desc }}
+            pub fn remove_{{ field_ident }}(&mut self, ids: Vec<ID>) -> &mut {{ builder }} {
                 self.set_v("{{ field_uuid }}_persons", json!(ids));
-                self.set_s("updateAction", "remove".to_string());
+                self.set_update_action("remove");
                 self
             }
             {{/if ~}}
-            /// Remove person{{#if field_multiple_values}}(s){{/if}} {{ field }}
-            /// {{field_desc}}
-            pub fn unset_{{ to_snake_case field }}(&mut self) -> &mut {{ builder }} {
+            /// Remove person{{#if field_multiple_values}}(s){{/if}} {{ doc-comment field }}
+            /// {{ doc-comment field_desc }}
+            pub fn unset_{{ field_ident }}(&mut self) -> &mut {{ builder }} {
                 self.set_v("{{ field_uuid }}_persons", json!(Vec::<String>::new()));
-                self.set_s("updateAction", "replace".to_string());
+                self.set_update_action("replace");
                 self
             }
             {{/if}}
@@ -1137,55 +2621,99 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
             "set_references",
             r#"
             {{#if field_single_value ~}}
-            /// Sets reference {{ field }} to item in list {{ref_list}}
-            /// {{field_desc}}
-            pub fn set_{{ to_snake_case field }}(&mut self, uuid: &str) -> &mut {{ builder }} {
+            /// Sets reference {{ doc-comment field }} to item in list {{ doc-comment ref_list }}
+            /// {{ doc-comment field_desc }}
+            pub fn set_{{ field_ident }}{{#if ref_typed}}_uuid{{/if}}(&mut self, uuid: &str) -> &mut {{ builder }} {
                 self.set_v("{{ field_uuid }}_references", json!(vec![uuid]));
                 {{#if is_update_builder~}}
-                self.set_s("updateAction", "replace".to_string());
+                self.set_update_action("replace");
                 {{/if~}}
                 self
             }
+            {{#if ref_typed ~}}
+            /// Sets reference {{ doc-comment field }} to a {{ ref_item }}
+            /// {{ doc-comment field_desc }}
+            pub fn set_{{ field_ident }}<T: Into<super::{{ ref_module }}::{{ ref_uuid_type }}>>(&mut self, value: T) -> &mut {{ builder }} {
+                let uuid = value.into();
+                self.set_{{ field_ident }}_uuid(uuid.as_str())
+            }
+            {{/if ~}}
             {{else ~}}
-            /// Sets reference {{ field }} to item in list {{ref_list}}. {{#if is_update}}Replaces previous value(s){{/if}}
-            /// {{field_desc}}
-            pub fn set_{{ to_snake_case field }}(&mut self, uuids: Vec<&'_ str>) -> &mut {{ builder }} {
+            /// Sets reference {{ doc-comment field }} to item in list {{ doc-comment ref_list }}. {{#if is_update}}Replaces previous value(s){{/if}}
+            /// {{ doc-comment field_desc }}
+            pub fn set_{{ field_ident }}{{#if ref_typed}}_uuids{{/if}}(&mut self, uuids: Vec<&'_ str>) -> &mut {{ builder }} {
                 self.set_v("{{ field_uuid }}_references", json!(uuids));
                 {{#if is_update_builder~}}
-                self.set_s("updateAction", "replace".to_string());
+                self.set_update_action("replace");
                 {{/if~}}
                 self
             }
+            {{#if ref_typed ~}}
+            /// Sets reference {{ doc-comment field }} to {{ ref_item }} items. {{#if is_update}}Replaces previous value(s){{/if}}
+            /// {{ doc-comment field_desc }}
+            pub fn set_{{ field_ident }}<I, T>(&mut self, values: I) -> &mut {{ builder }}
+            where
+                I: IntoIterator<Item = T>,
+                T: Into<super::{{ ref_module }}::{{ ref_uuid_type }}>,
+            {
+                let uuids: Vec<super::{{ ref_module }}::{{ ref_uuid_type }}> = values.into_iter().map(Into::into).collect();
+                self.set_{{ field_ident }}_uuids(uuids.iter().map(|u| u.as_str()).collect())
+            }
+            {{/if ~}}
             {{/if ~}}
 
             {{#if is_update_builder ~}}
             {{#if field_multiple_value ~}}
-            /// Adds references to {{ field }} to item(s) in list {{ref_list}}
-            /// {{field_desc}}
-            pub fn add_{{ to_snake_case field }}(&mut self, uuids: Vec<&'_ str>) -> &mut {{ builder }} {
+            /// Adds references to {{ doc-comment field }} to item(s) in list {{ doc-comment ref_list }}
+            /// {{ doc-comment field_desc }}
+            pub fn add_{{ field_ident }}{{#if ref_typed}}_uuids{{/if}}(&mut self, uuids: Vec<&'_ str>) -> &mut {{ builder }} {
                 self.set_v("{{ field_uuid }}_references", json!(uuids));
                 {{#if is_update_builder~}}
-                self.set_s("updateAction", "append".to_string());
+                self.set_update_action("append");
                 {{/if~}}
                 self
             }
+            {{#if ref_typed ~}}
+            /// Adds references to {{ ref_item }} items to {{ doc-comment field }}
+            /// {{ doc-comment field_desc }}
+            pub fn add_{{ field_ident }}<I, T>(&mut self, values: I) -> &mut {{ builder }}
+            where
+                I: IntoIterator<Item = T>,
+                T: Into<super::{{ ref_module }}::{{ ref_uuid_type }}>,
+            {
+                let uuids: Vec<super::{{ ref_module }}::{{ ref_uuid_type }}> = values.into_iter().map(Into::into).collect();
+                self.add_{{ field_ident }}_uuids(uuids.iter().map(|u| u.as_str()).collect())
+            }
+            {{/if ~}}
 
-            /// Removes references to {{ field }}
-            /// {{field_desc}}
-            pub fn remove_{{ to_snake_case field }}(&mut self, uuids: Vec<&'_ str>) -> &mut {{ builder }} {
+            /// Removes references to {{ doc-comment field }}
+            /// {{ doc-comment field_desc }}
+            pub fn remove_{{ field_ident }}{{#if ref_typed}}_uuids{{/if}}(&mut self, uuids: Vec<&'_ str>) -> &mut {{ builder }} {
                 self.set_v("{{ field_uuid }}_references", json!(uuids));
                 {{#if is_update_builder~}}
-                self.set_s("updateAction", "remove".to_string());
+                self.set_update_action("remove");
                 {{/if~}}
                 self
             }
+            {{#if ref_typed ~}}
+            /// Removes references to {{ ref_item }} items from {{ doc-comment field }}
+            /// {{ doc-comment field_desc }}
+            pub fn remove_{{ field_ident }}<I, T>(&mut self, values: I) -> &mut {{ builder }}
+            where
+                I: IntoIterator<Item = T>,
+                T: Into<super::{{ ref_module }}::{{ ref_uuid_type }}>,
+            {
+                let uuids: Vec<super::{{ ref_module }}::{{ ref_uuid_type }}> = values.into_iter().map(Into::into).collect();
+                self.remove_{{ field_ident }}_uuids(uuids.iter().map(|u| u.as_str()).collect())
+            }
+            {{/if ~}}
             {{/if ~}}
 
-            /// Removes references to {{ field }}
-            /// {{field_desc}}
-            pub fn unset_{{ to_snake_case field }}(&mut self) -> &mut {{ builder }} {
+            /// Removes references to {{ doc-comment field }}
+            /// {{ doc-comment field_desc }}
+            pub fn unset_{{ field_ident }}(&mut self) -> &mut {{ builder }} {
                 self.set_v("{{ field_uuid }}_references", json!(Vec::<String>::new()));
-                self.set_s("updateAction", "replace".to_string());
+                self.set_update_action("replace");
                 self
             }
             {{/if ~}}
@@ -1195,42 +2723,42 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
         (
             "set_subitems",
             r#"
-            /// Sets subitems in {{ field }} hierarchy. {{#if is_update}}Replaces previous value(s){{/if}}
-            /// {{field_desc}}
-            pub fn set_{{ to_snake_case field }}(&mut self, uuids: Vec<&'_ str>) -> &mut {{ builder }} {
+            /// Sets subitems in {{ doc-comment field }} hierarchy. {{#if is_update}}Replaces previous value(s){{/if}}
+            /// {{ doc-comment field_desc }}
+            pub fn set_{{ field_ident }}(&mut self, uuids: Vec<&'_ str>) -> &mut {{ builder }} {
                 self.set_v("{{ field_uuid }}_references", json!(uuids));
                 {{#if is_update_builder~}}
-                self.set_s("updateAction", "replace".to_string());
+                self.set_update_action("replace");
                 {{/if~}}
                 self
             }
 
             {{#if is_update_builder ~}}
-            /// Adds subitems to {{ field }} hierarchy
-            /// {{field_desc}}
-            pub fn add_{{ to_snake_case field }}(&mut self, uuids: Vec<&'_ str>) -> &mut {{ builder }} {
+            /// Adds subitems to {{ doc-comment field }} hierarchy
+            /// {{ doc-comment field_desc }}
+            pub fn add_{{ field_ident }}(&mut self, uuids: Vec<&'_ str>) -> &mut {{ builder }} {
                 self.set_v("{{ field_uuid }}_references", json!(uuids));
                 {{#if is_update_builder~}}
-                self.set_s("updateAction", "append".to_string());
+                self.set_update_action("append");
                 {{/if~}}
                 self
             }
 
-            /// Removes subitems in {{ field }} hierarchy
-            /// {{field_desc}}
-            pub fn remove_{{ to_snake_case field }}(&mut self, uuids: Vec<&'_ str>) -> &mut {{ builder }} {
+            /// Removes subitems in {{ doc-comment field }} hierarchy
+            /// {{ doc-comment field_desc }}
+            pub fn remove_{{ field_ident }}(&mut self, uuids: Vec<&'_ str>) -> &mut {{ builder }} {
                 self.set_v("{{ field_uuid }}_references", json!(uuids));
                 {{#if is_update_builder~}}
-                self.set_s("updateAction", "remove".to_string());
+                self.set_update_action("remove");
                 {{/if~}}
                 self
             }
 
-            /// Removes all subitems in {{ field }} hierarchy
-            /// {{field_desc}}
-            pub fn unset_{{ to_snake_case field }}(&mut self) -> &mut {{ builder }} {
+            /// Removes all subitems in {{ doc-comment field }} hierarchy
+            /// {{ doc-comment field_desc }}
+            pub fn unset_{{ field_ident }}(&mut self) -> &mut {{ builder }} {
                 self.set_v("{{ field_uuid }}_references", json!(Vec::<String>::new()));
-                self.set_s("updateAction", "replace".to_string());
+                self.set_update_action("replace");
                 self
             }
             {{/if ~}}
@@ -1242,13 +2770,13 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
             "category_setter_per_label",
             r#"
             {{#if field_single_value ~}}
-            /// Sets {{field}} to {{label}}. {{field_desc}}
-            pub fn set_{{ to_snake_case field }}_{{ to_snake_case label }}(&mut self) -> &mut {{ builder }} {
+            /// Sets {{ doc-comment field }} to {{ doc-comment label }}. {{ doc-comment field_desc }}
+            pub fn set_{{ field_ident }}_{{ label_ident }}(&mut self) -> &mut {{ builder }} {
                 self.set_v("{{ field_uuid }}_categories", json!(vec![
-                    {{ item}}::LABEL_{{ to_screaming_snake_case field }}_{{ to_screaming_snake_case label }}_ID
+                    {{ item}}::LABEL_{{ field_upper }}_{{ label_upper }}_ID
                 ]));
                 {{#if is_update_builder ~}}
-                self.set_s("updateAction", "replace".to_string());
+                self.set_update_action("replace");
                 {{/if ~}}
                 self
             }
@@ -1260,73 +2788,317 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
             "category_setter_per_field",
             r#"
             {{#if field_single_value ~}}
-            /// Set {{ field }} by label-id. {{field_desc}}
-            pub fn set_{{ to_snake_case field }}_id(&mut self, id: ID) -> &mut {{ builder }} {
+            /// Set {{ doc-comment field }} by label-id. {{ doc-comment field_desc }}
+            pub fn set_{{ field_ident }}_id(&mut self, id: ID) -> &mut {{ builder }} {
                 self.set_v("{{ field_uuid }}_categories", json!(vec![id]));
                 {{#if is_update_builder~}}
-                self.set_s("updateAction", "replace".to_string());
+                self.set_update_action("replace");
                 {{/if~}}
                 self
             }
-            /// Set {{ field }} by label-name. {{field_desc}}
-            pub fn set_{{ to_snake_case field }}(&mut self, label: &str) -> &mut {{ builder }} {
-                match lookup_label(&{{ item }}::LABELS_{{ to_screaming_snake_case field }},label) {
+            {{#if category_enums ~}}
+            /// Set {{ doc-comment field }}. {{ doc-comment field_desc }}
+            pub fn set_{{ field_ident }}(&mut self, value: {{ enum_type }}) -> &mut {{ builder }} {
+                self.set_{{ field_ident }}_id(value.id())
+            }
+            /// Set {{ doc-comment field }} by label-name. {{ doc-comment field_desc }}
+            pub fn set_{{ field_ident }}_label(&mut self, label: &str) -> &mut {{ builder }} {
+            {{else ~}}
+            /// Set {{ doc-comment field }} by label-name. {{ doc-comment field_desc }}
+            pub fn set_{{ field_ident }}(&mut self, label: &str) -> &mut {{ builder }} {
+            {{/if ~}}
+                match lookup_label(&{{ item }}::LABELS_{{ field_upper }},label) {
                         Some(id) => {
                             self.set_v("{{ field_uuid }}_categories", json!(vec![id]));
                             {{#if is_update_builder~}}
-                            self.set_s("updateAction", "replace".to_string());
+                            self.set_update_action("replace");
                             {{/if~}}
                         },
                         None => {
-                            self.errs.push(format!("Label '{}' not found for set_{{ to_snake_case field }}_label",
+                            self.errs.push(format!("Label '{}' not found for set_{{ field_ident }}_label",
                                     label));
                         }
                 }
                 self
             }
             {{else ~}}
-            /// Sets {{ field }} with label ids. {{#if is_update_builder}}Replaces any previous values{{/if}}
-            /// {{field_desc}}
-            pub fn set_{{ to_snake_case field }}(&mut self, ids: Vec<ID>) -> &mut {{ builder }} {
+            /// Sets {{ doc-comment field }} with label ids. {{#if is_update_builder}}Replaces any previous values{{/if}}
+            /// {{ doc-comment field_desc }}
+            pub fn set_{{ field_ident }}{{#if category_enums}}_ids{{/if}}(&mut self, ids: Vec<ID>) -> &mut {{ builder }} {
                 self.set_v("{{ field_uuid }}_categories", json!(ids));
                 {{#if is_update_builder~}}
-                self.set_s("updateAction", "replace".to_string());
+                self.set_update_action("replace");
                 {{/if~}}
                 self
             }
+            {{#if category_enums ~}}
+            /// Sets {{ doc-comment field }}. {{#if is_update_builder}}Replaces any previous values{{/if}}
+            /// {{ doc-comment field_desc }}
+            pub fn set_{{ field_ident }}(&mut self, values: &std::collections::BTreeSet<{{ enum_type }}>) -> &mut {{ builder }} {
+                self.set_{{ field_ident }}_ids(values.iter().map({{ enum_type }}::id).collect())
+            }
+            {{/if ~}}
             {{/if ~}}
 
             {{#if is_update_builder ~}}
             {{#if field_multiple_value ~}}
-            /// Adds label ids to {{field}}. {{#if is_update_builder}}Appends any previous values{{/if}}
-            /// {{field_desc}}
-            pub fn add_{{ to_snake_case field }}(&mut self, ids: Vec<ID>) -> &mut {{ builder }} {
+            /// Adds label ids to {{ doc-comment field }}. {{#if is_update_builder}}Appends any previous values{{/if}}
+            /// {{ doc-comment field_desc }}
+            pub fn add_{{ field_ident }}{{#if category_enums}}_ids{{/if}}(&mut self, ids: Vec<ID>) -> &mut {{ builder }} {
                 self.set_v("{{ field_uuid }}_categories", json!(ids));
                 {{#if is_update_builder~}}
-                self.set_s("updateAction", "append".to_string());
+                self.set_update_action("append");
                 {{/if~}}
                 self
             }
-            /// Removes label ids from {{field}}.
-            /// {{field_desc}}
-            pub fn remove_{{ to_snake_case field }}(&mut self, ids: Vec<ID>) -> &mut {{ builder }} {
+            /// Removes label ids from {{ doc-comment field }}.
+            /// {{ doc-comment field_desc }}
+            pub fn remove_{{ field_ident }}{{#if category_enums}}_ids{{/if}}(&mut self, ids: Vec<ID>) -> &mut {{ builder }} {
                 self.set_v("{{ field_uuid }}_categories", json!(ids));
                 {{#if is_update_builder~}}
-                self.set_s("updateAction", "remove".to_string());
+                self.set_update_action("remove");
                 {{/if~}}
                 self
             }
+            {{#if category_enums ~}}
+            /// Adds labels to {{ doc-comment field }}. Appends any previous values
+            /// {{ doc-comment field_desc }}
+            pub fn add_{{ field_ident }}(&mut self, values: &std::collections::BTreeSet<{{ enum_type }}>) -> &mut {{ builder }} {
+                self.add_{{ field_ident }}_ids(values.iter().map({{ enum_type }}::id).collect())
+            }
+            /// Removes labels from {{ doc-comment field }}.
+            /// {{ doc-comment field_desc }}
+            pub fn remove_{{ field_ident }}(&mut self, values: &std::collections::BTreeSet<{{ enum_type }}>) -> &mut {{ builder }} {
+                self.remove_{{ field_ident }}_ids(values.iter().map({{ enum_type }}::id).collect())
+            }
+            /// Changes {{ doc-comment field }} from the labels of `item` to `new`.
+            /// If labels are only added or only removed, just the difference is sent,
+            /// so that concurrent changes to other labels are kept. Otherwise the value is replaced,
+            /// keeping the item's labels that are not variants of {{ enum_type }}.
+            /// Zenkit applies one update action to the whole request, so if only the difference
+            /// is sent, setting other fields in the same update is reported as an error.
+            /// {{ doc-comment field_desc }}
+            pub fn change_{{ field_ident }}(
+                &mut self,
+                item: &{{ item }},
+                new: &std::collections::BTreeSet<{{ enum_type }}>,
+            ) -> &mut {{ builder }} {
+                let old = item.get_{{ field_plural }}();
+                if &old == new {
+                    self
+                } else if new.is_superset(&old) {
+                    self.add_{{ field_ident }}(&new.difference(&old).copied().collect())
+                } else if new.is_subset(&old) {
+                    self.remove_{{ field_ident }}(&old.difference(new).copied().collect())
+                } else {
+                    let removed: Vec<ID> = old.difference(new).map({{ enum_type }}::id).collect();
+                    let mut ids = item.get_{{ field_plural }}_ids();
+                    ids.retain(|id| !removed.contains(id));
+                    ids.extend(new.difference(&old).map({{ enum_type }}::id));
+                    self.set_{{ field_ident }}_ids(ids)
+                }
+            }
+            {{/if ~}}
             {{/if ~}}
 
-            /// Clears (unsets) {{ field }} {{field_desc}}
-            pub fn unset_{{ to_snake_case field }}(&mut self) -> &mut {{ builder }} {
+            /// Clears (unsets) {{ doc-comment field }} {{ doc-comment field_desc }}
+            pub fn unset_{{ field_ident }}(&mut self) -> &mut {{ builder }} {
                 self.set_v("{{ field_uuid }}_categories", json!(Vec::<String>::new()));
-                self.set_s("updateAction", "replace".to_string());
+                self.set_update_action("replace");
                 self
             }
             {{/if ~}}
             "#,
         ),
+        // label enum for category field (module level)
+        (
+            "category_enum",
+            r#"
+            /// Labels of '{{ doc-comment field }}' in List '{{ doc-comment list }}'
+            /// {{ doc-comment field_desc }}
+            #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+            pub enum {{ enum_type }} {
+                {{#each variants ~}}
+                /// {{ doc-comment this.name }}
+                {{ this.variant }},
+                {{/each ~}}
+            }
+
+            impl {{ enum_type }} {
+                /// All labels, in Zenkit order
+                pub const ALL: [{{ enum_type }}; {{ variant_count }}] = [
+                    {{#each variants ~}}
+                    {{ ../enum_type }}::{{ this.variant }},
+                    {{/each ~}}
+                ];
+
+                /// Returns the label id
+                pub fn id(&self) -> ID {
+                    match *self {
+                        {{#each variants ~}}
+                        {{ ../enum_type }}::{{ this.variant }} => {{ ../item }}::LABEL_{{ ../field_upper }}_{{ this.upper }}_ID,
+                        {{/each ~}}
+                    }
+                }
+
+                /// Returns the label name
+                pub fn name(&self) -> &'static str {
+                    match *self {
+                        {{#each variants ~}}
+                        {{ ../enum_type }}::{{ this.variant }} => "{{ rust-string this.name }}",
+                        {{/each ~}}
+                    }
+                }
+
+                /// Returns the label with the id, or None if the id is not a label of this field
+                pub fn from_id(id: ID) -> Option<Self> {
+                    Self::ALL.iter().find(|v| v.id() == id).copied()
+                }
+            }
+
+            impl std::fmt::Display for {{ enum_type }} {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    f.write_str(self.name())
+                }
+            }
+
+            impl FromStr for {{ enum_type }} {
+                type Err = Error;
+
+                /// Parses label name
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    Self::ALL.iter().find(|v| v.name() == s).copied().ok_or_else(|| {
+                        Error::Message(format!("'{}' is not a label of '{}'", s, "{{ rust-string field }}"))
+                    })
+                }
+            }
+
+            impl std::convert::TryFrom<ID> for {{ enum_type }} {
+                type Error = Error;
+
+                fn try_from(id: ID) -> Result<Self, Self::Error> {
+                    Self::from_id(id).ok_or_else(|| {
+                        Error::Message(format!("{} is not a label id of '{}'", id, "{{ rust-string field }}"))
+                    })
+                }
+            }
+
+            impl From<{{ enum_type }}> for ID {
+                fn from(value: {{ enum_type }}) -> ID {
+                    value.id()
+                }
+            }
+            "#,
+        ),
+        // record struct (module level)
+        (
+            "record_struct",
+            r#"
+            /// {{ item }} data, with one field per Zenkit field, that can be serialized,
+            /// cached, or sent between threads. Create from an item with `From<&{{ item }}>`,
+            /// and save with `to_new_builder` or `to_update_builder` (or the `*_with` versions,
+            /// which take the client to save with).
+            #[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+            pub struct {{ record_type }} {
+                /// Item id, or None if the item hasn't been created
+                pub id: Option<{{ id_type }}>,
+                /// Item uuid, or None if the item hasn't been created
+                pub uuid: Option<{{ uuid_type }}>,
+                {{#each record_fields ~}}
+                /// {{ doc-comment this.field }}{{#unless this.set}} (read-only){{/unless}}
+                pub {{ this.name }}: {{ this.ty }},
+                {{/each ~}}
+            }
+
+            impl From<&{{ item }}> for {{ record_type }} {
+                fn from(item: &{{ item }}) -> Self {
+                    Self {
+                        id: Some(item.get_id()),
+                        uuid: Some(item.get_uuid()),
+                        {{#each record_fields ~}}
+                        {{ this.name }}: {{ this.get }},
+                        {{/each ~}}
+                    }
+                }
+            }
+
+            impl {{ record_type }} {
+                /// Returns builder for creating a new {{ item }} with the record's values,
+                /// using the global api client. Fields that are None, and read-only fields, are not set.
+                pub fn to_new_builder(&self) -> New{{ item }}Builder {
+                    self.to_new_builder_with(&{{ list_client }}::new(Api::global()))
+                }
+
+                /// Returns builder for creating a new {{ item }} with the record's values,
+                /// using the client. Fields that are None, and read-only fields, are not set.
+                pub fn to_new_builder_with(&self, client: &{{ list_client }}) -> New{{ item }}Builder {
+                    let mut builder = client.create();
+                {{#each record_fields ~}}
+                {{#if this.set ~}}
+                {{#if this.optional ~}}
+                if let Some(ref v) = self.{{ this.name }} {
+                    builder.{{ this.set }}({{ this.arg }});
+                }
+                {{else ~}}
+                {
+                    let v = &self.{{ this.name }};
+                    builder.{{ this.set }}({{ this.arg }});
+                }
+                {{/if ~}}
+                {{/if ~}}
+                {{/each ~}}
+                    builder
+                }
+
+                /// Returns builder for updating the {{ item }} with the record's id to the record's values,
+                /// using the global api client. Fields that are None, and read-only fields, are not changed.
+                /// Multi-value fields are replaced. Returns an error if the record has no id.
+                pub fn to_update_builder(&self) -> Result<Update{{ item }}Builder, Error> {
+                    self.to_update_builder_with(&{{ list_client }}::new(Api::global()))
+                }
+
+                /// Returns builder for updating the {{ item }} with the record's id to the record's values,
+                /// using the client. Fields that are None, and read-only fields, are not changed.
+                /// Multi-value fields are replaced. Returns an error if the record has no id.
+                pub fn to_update_builder_with(&self, client: &{{ list_client }}) -> Result<Update{{ item }}Builder, Error> {
+                    let id = self.id.ok_or_else(|| Error::Message("{{ record_type }} has no id".to_string()))?;
+                    let mut builder = client.update(id);
+                {{#each record_fields ~}}
+                {{#if this.set ~}}
+                {{#if this.optional ~}}
+                if let Some(ref v) = self.{{ this.name }} {
+                    builder.{{ this.set }}({{ this.arg }});
+                }
+                {{else ~}}
+                {
+                    let v = &self.{{ this.name }};
+                    builder.{{ this.set }}({{ this.arg }});
+                }
+                {{/if ~}}
+                {{/if ~}}
+                {{/each ~}}
+                    Ok(builder)
+                }
+            }
+            "#,
+        ),
+        // compile-time check that items, builders, and futures can be sent between threads
+        (
+            "assert_send_sync",
+            r#"
+            const _: fn() = || {
+                fn assert_send_sync<T: Send + Sync>() {}
+                fn assert_send<T: Send>(_: &T) {}
+                assert_send_sync::<{{ item }}>();
+                assert_send_sync::<New{{ item }}Builder>();
+                assert_send_sync::<Update{{ item }}Builder>();
+                assert_send_sync::<{{ record_type }}>();
+                assert_send(&{{ list_struct }}::get_items());
+                assert_send(&{{ list_struct }}::create().execute());
+                assert_send(&{{ list_struct }}::create_many(Vec::new(), 1));
+            };
+            "#,
+        ),
         // execute - final builder method
         (
             "builder_execute",
@@ -1342,14 +3114,356 @@ pub fn add_templates(hb: &mut Handlebars) -> Result<(), Error> {
                             format!("Errors occurred in {{ builder }}: {:?}", &self.errs)));
                 }
                 let map = self.fields.to_owned();
-                let {{ to_snake_case item }} = get_api()?
+                let {{ item_ident }} = self.api
                 {{#if is_update_builder}}
-                    .update_entry({{ list_id }}, self.item_id, Value::Object(map))
+                    .update_entry({{ list_id }}, self.item_id.value(), Value::Object(map))
                 {{else}}
                     .create_entry({{ list_id }}, Value::Object(map))
                 {{/if}}
                     .await?;
-                Ok({{item }}::new(Rc::new({{ to_snake_case item }})))
+                Ok({{item }}::new(Arc::new({{ item_ident }}), self.api.clone()))
+            }
+            "#,
+        ),
+        // Define query struct and beginning of impl
+        (
+            "start_query",
+            r#"
+            /// Query for {{ item_plural }} in List '{{ doc-comment list }}'. Filters and sort order
+            /// are sent with the request, so only matching items are downloaded.
+            /// All conditions must match.
+            #[derive(Clone)]
+            pub struct {{ query_type }} {
+                api: Api,
+                q: EntryQuery,
+                errs: Vec<String>,
+            }
+
+            impl {{ query_type }} {
+                /// Creates query with no conditions
+                fn new(api: Api) -> Self {
+                    Self { api, q: EntryQuery::default(), errs: Vec::new() }
+                }
+
+                /// Returns at most n {{ item_plural }}. The default (0) returns all matching items.
+                pub fn limit(mut self, n: usize) -> Self {
+                    self.q.limit = n;
+                    self
+                }
+
+                /// Skips the first n matching {{ item_plural }}, for example, to resume
+                /// a stream after the items already read
+                pub fn skip(mut self, n: usize) -> Self {
+                    self.q.skip = n;
+                    self
+                }
+
+                /// Sets the number of {{ item_plural }} requested per call. The default is 500.
+                pub fn page_size(mut self, n: usize) -> Self {
+                    self.q.page_size = n;
+                    self
+                }
+
+                /// Includes archived {{ item_plural }} if true. By default, they are left out.
+                pub fn include_archived(mut self, include: bool) -> Self {
+                    self.q.include_archived = include;
+                    self
+                }
+
+                /// Sorts by date created, oldest first
+                pub fn order_by_created_asc(mut self) -> Self {
+                    self.q.order_by("created_at", false);
+                    self
+                }
+
+                /// Sorts by date created, newest first
+                pub fn order_by_created_desc(mut self) -> Self {
+                    self.q.order_by("created_at", true);
+                    self
+                }
+
+                /// Sorts by date last updated, oldest first
+                pub fn order_by_updated_asc(mut self) -> Self {
+                    self.q.order_by("updated_at", false);
+                    self
+                }
+
+                /// Sorts by date last updated, newest first
+                pub fn order_by_updated_desc(mut self) -> Self {
+                    self.q.order_by("updated_at", true);
+                    self
+                }
+
+                /// Returns the request parameters for all matching items. `get_items` and
+                /// `stream_items` send these in pages of up to `page_size` items.
+                pub fn request(&self) -> GetEntriesRequest {
+                    self.q.request()
+                }
+
+                /// Returns the matching {{ item_plural }}
+                pub async fn get_items(&self) -> Result<Vec<{{ item }}>, Error> {
+                    self.check()?;
+                    let entries = self.q.load(&self.api, {{ to-list-id list_ident }}).await?;
+                    Ok(entries
+                        .into_iter()
+                        .map(|e| {{ item }}::new(Arc::new(e), self.api.clone()))
+                        .collect())
+                }
+
+                /// Returns stream of the matching {{ item_plural }}. Pages are fetched as the stream
+                /// is read, so only one page is in memory at a time. The stream ends after the first
+                /// error; to resume, create the query again with `skip` set to the number of items read.
+                /// Items added or removed while reading can shift the offsets.
+                pub fn stream_items(&self) -> impl futures::Stream<Item = Result<{{ item }}, Error>> + Send {
+                    use futures::StreamExt;
+                    if let Err(e) = self.check() {
+                        return futures::stream::once(futures::future::ready(Err(e))).left_stream();
+                    }
+                    let api = self.api.clone();
+                    self.q
+                        .stream(self.api.clone(), {{ to-list-id list_ident }})
+                        .map(move |r| r.map(|e| {{ item }}::new(Arc::new(e), api.clone())))
+                        .right_stream()
+                }
+
+                /// Returns error if a condition was invalid, e.g., an unknown label
+                fn check(&self) -> Result<(), Error> {
+                    if self.errs.is_empty() {
+                        Ok(())
+                    } else {
+                        Err(Error::Message(
+                                format!("Errors occurred in {{ query_type }}: {:?}", &self.errs)))
+                    }
+                }
+            "#,
+        ),
+        // } end of query impl
+        (
+            "end_query",
+            r#"
+            } // end impl {{ query_type }}
+            "#,
+        ),
+        // query conditions for text and url fields
+        (
+            "query_text_field",
+            r#"
+            /// Matches {{ item_plural }} where '{{ doc-comment field }}' is the text
+            pub fn {{ field_lead }}_is(mut self, text: &str) -> Self {
+                self.q.filter({{ item }}::{{ to-field-id field_ident }}, json!({ "modus": "equals", "text": text }));
+                self
+            }
+
+            /// Matches {{ item_plural }} where '{{ doc-comment field }}' contains the text
+            pub fn {{ field_lead }}_contains(mut self, text: &str) -> Self {
+                self.q.filter({{ item }}::{{ to-field-id field_ident }}, json!({ "modus": "contains", "text": text }));
+                self
+            }
+
+            /// Matches {{ item_plural }} where '{{ doc-comment field }}' starts with the text
+            pub fn {{ field_lead }}_starts_with(mut self, text: &str) -> Self {
+                self.q.filter({{ item }}::{{ to-field-id field_ident }}, json!({ "modus": "startsWith", "text": text }));
+                self
+            }
+            "#,
+        ),
+        // query conditions for integer fields
+        (
+            "query_int_field",
+            r#"
+            /// Matches {{ item_plural }} where '{{ doc-comment field }}' is the value
+            pub fn {{ field_lead }}_eq(self, value: i64) -> Self {
+                self.{{ field_lead }}_between(value, value)
+            }
+
+            /// Matches {{ item_plural }} where '{{ doc-comment field }}' is greater than the value
+            pub fn {{ field_lead }}_gt(self, value: i64) -> Self {
+                match value.checked_add(1) {
+                    Some(value) => self.{{ field_lead }}_ge(value),
+                    // nothing is greater than i64::MAX. An empty range matches nothing.
+                    None => self.{{ field_lead }}_between(1, 0),
+                }
+            }
+
+            /// Matches {{ item_plural }} where '{{ doc-comment field }}' is greater than or equal to the value
+            pub fn {{ field_lead }}_ge(mut self, value: i64) -> Self {
+                self.q.filter({{ item }}::{{ to-field-id field_ident }}, json!({ "modus": "greaterOrEqual", "numberFrom": value }));
+                self
+            }
+
+            /// Matches {{ item_plural }} where '{{ doc-comment field }}' is less than the value
+            pub fn {{ field_lead }}_lt(self, value: i64) -> Self {
+                match value.checked_sub(1) {
+                    Some(value) => self.{{ field_lead }}_le(value),
+                    // nothing is less than i64::MIN. An empty range matches nothing.
+                    None => self.{{ field_lead }}_between(1, 0),
+                }
+            }
+
+            /// Matches {{ item_plural }} where '{{ doc-comment field }}' is less than or equal to the value
+            pub fn {{ field_lead }}_le(mut self, value: i64) -> Self {
+                self.q.filter({{ item }}::{{ to-field-id field_ident }}, json!({ "modus": "lessOrEqual", "numberTo": value }));
+                self
+            }
+
+            /// Matches {{ item_plural }} where '{{ doc-comment field }}' is between the values (inclusive)
+            pub fn {{ field_lead }}_between(mut self, from: i64, to: i64) -> Self {
+                self.q.filter({{ item }}::{{ to-field-id field_ident }}, json!({ "modus": "inRange", "numberFrom": from, "numberTo": to }));
+                self
+            }
+            "#,
+        ),
+        // query conditions for decimal fields
+        (
+            "query_float_field",
+            r#"
+            /// Matches {{ item_plural }} where '{{ doc-comment field }}' is greater than the value
+            pub fn {{ field_lead }}_gt(self, value: f64) -> Self {
+                match super::next_f64(value, true) {
+                    next if next.is_finite() => self.{{ field_lead }}_ge(next),
+                    // nothing is greater than f64::MAX, or NaN. An empty range matches nothing.
+                    _ => self.{{ field_lead }}_between(1.0, 0.0),
+                }
+            }
+
+            /// Matches {{ item_plural }} where '{{ doc-comment field }}' is greater than or equal to the value
+            pub fn {{ field_lead }}_ge(mut self, value: f64) -> Self {
+                self.q.filter({{ item }}::{{ to-field-id field_ident }}, json!({ "modus": "greaterOrEqual", "numberFrom": value }));
+                self
+            }
+
+            /// Matches {{ item_plural }} where '{{ doc-comment field }}' is less than the value
+            pub fn {{ field_lead }}_lt(self, value: f64) -> Self {
+                match super::next_f64(value, false) {
+                    next if next.is_finite() => self.{{ field_lead }}_le(next),
+                    // nothing is less than f64::MIN, or NaN. An empty range matches nothing.
+                    _ => self.{{ field_lead }}_between(1.0, 0.0),
+                }
+            }
+
+            /// Matches {{ item_plural }} where '{{ doc-comment field }}' is less than or equal to the value
+            pub fn {{ field_lead }}_le(mut self, value: f64) -> Self {
+                self.q.filter({{ item }}::{{ to-field-id field_ident }}, json!({ "modus": "lessOrEqual", "numberTo": value }));
+                self
+            }
+
+            /// Matches {{ item_plural }} where '{{ doc-comment field }}' is between the values (inclusive)
+            pub fn {{ field_lead }}_between(mut self, from: f64, to: f64) -> Self {
+                self.q.filter({{ item }}::{{ to-field-id field_ident }}, json!({ "modus": "inRange", "numberFrom": from, "numberTo": to }));
+                self
+            }
+            "#,
+        ),
+        // query conditions for date fields
+        (
+            "query_date_field",
+            r#"
+            /// Matches {{ item_plural }} where '{{ doc-comment field }}' is at or after the time
+            pub fn {{ field_lead }}_since(mut self, from: DateTime<Utc>) -> Self {
+                // dateType 10: custom range
+                self.q.filter({{ item }}::{{ to-field-id field_ident }}, json!({ "modus": "inRange", "dateType": 10, "dateFrom": from }));
+                self
+            }
+
+            /// Matches {{ item_plural }} where '{{ doc-comment field }}' is at or before the time
+            pub fn {{ field_lead }}_until(mut self, to: DateTime<Utc>) -> Self {
+                self.q.filter({{ item }}::{{ to-field-id field_ident }}, json!({ "modus": "inRange", "dateType": 10, "dateTo": to }));
+                self
+            }
+
+            /// Matches {{ item_plural }} where '{{ doc-comment field }}' is in the time range (inclusive)
+            pub fn {{ field_lead }}_between(mut self, from: DateTime<Utc>, to: DateTime<Utc>) -> Self {
+                self.q.filter({{ item }}::{{ to-field-id field_ident }},
+                    json!({ "modus": "inRange", "dateType": 10, "dateFrom": from, "dateTo": to }));
+                self
+            }
+            "#,
+        ),
+        // query condition for checkbox fields
+        (
+            "query_checkbox_field",
+            r#"
+            /// Matches {{ item_plural }} where '{{ doc-comment field }}' is checked (true) or unchecked (false)
+            pub fn {{ field_lead }}_is(mut self, checked: bool) -> Self {
+                self.q.filter({{ item }}::{{ to-field-id field_ident }}, json!({ "checked": checked }));
+                self
+            }
+            "#,
+        ),
+        // query conditions for category fields
+        (
+            "query_category_field",
+            r#"
+            /// Matches {{ item_plural }} where '{{ doc-comment field }}' has the label id
+            pub fn {{ field_lead }}_is_id(mut self, id: ID) -> Self {
+                self.q.filter({{ item }}::{{ to-field-id field_ident }}, json!({ "filterCategories": [id] }));
+                self
+            }
+
+            {{#if category_enums ~}}
+            /// Matches {{ item_plural }} where '{{ doc-comment field }}' has the label
+            pub fn {{ field_lead }}_is(self, value: {{ enum_type }}) -> Self {
+                self.{{ field_lead }}_is_id(value.id())
+            }
+
+            /// Matches {{ item_plural }} where '{{ doc-comment field }}' has the label name
+            pub fn {{ field_lead }}_is_label(mut self, label: &str) -> Self {
+            {{else ~}}
+            /// Matches {{ item_plural }} where '{{ doc-comment field }}' has the label name
+            pub fn {{ field_lead }}_is(mut self, label: &str) -> Self {
+            {{/if ~}}
+                match {{ item }}::label_id_for_{{ field_ident }}(label) {
+                    Some(id) => self.{{ field_lead }}_is_id(id),
+                    None => {
+                        self.errs.push(format!("Label '{}' not found for {{ field_ident }}", label));
+                        self
+                    }
+                }
+            }
+            "#,
+        ),
+        // query condition for person fields
+        (
+            "query_person_field",
+            r#"
+            /// Matches {{ item_plural }} where '{{ doc-comment field }}' includes the user id
+            pub fn {{ field_lead }}_is(mut self, person_id: ID) -> Self {
+                self.q.filter({{ item }}::{{ to-field-id field_ident }}, json!({ "filterPersons": [person_id] }));
+                self
+            }
+            "#,
+        ),
+        // query conditions for empty fields
+        (
+            "query_empty_field",
+            r#"
+            /// Matches {{ item_plural }} where '{{ doc-comment field }}' is not set
+            pub fn {{ field_lead }}_is_empty(mut self) -> Self {
+                self.q.filter({{ item }}::{{ to-field-id field_ident }}, json!({ "modus": "isEmpty" }));
+                self
+            }
+
+            /// Matches {{ item_plural }} where '{{ doc-comment field }}' is set
+            pub fn {{ field_lead }}_is_not_empty(mut self) -> Self {
+                self.q.filter({{ item }}::{{ to-field-id field_ident }}, json!({ "modus": "isNotEmpty" }));
+                self
+            }
+            "#,
+        ),
+        // sort order for a field
+        (
+            "query_order_by",
+            r#"
+            /// Sorts by '{{ doc-comment field }}', ascending
+            pub fn order_by_{{ field_ident }}_asc(mut self) -> Self {
+                self.q.order_by("{{ field_uuid }}_{{ sort_key }}", false);
+                self
+            }
+
+            /// Sorts by '{{ doc-comment field }}', descending
+            pub fn order_by_{{ field_ident }}_desc(mut self) -> Self {
+                self.q.order_by("{{ field_uuid }}_{{ sort_key }}", true);
+                self
             }
             "#,
         ),
@@ -1363,14 +3477,22 @@ name = "{{ crate }}"
 version = "0.1.0"
 authors = ["author <mail@example.com>"]
 edition = "2018"
-description = "Zenkit client library for {{ workspace }}"
+description = "Zenkit client library for {{ toml-string workspace }}"
 keywords = ["zenkit","bindings"]
 categories = ["api-bindings"]
 
 [dependencies]
+serde = { version="1.0", features=["derive"] }
 serde_json = "1.0"
-zenkit = { version="0.5" }
+zenkit = { version="0.6" }
 reqwest = { version="0.11", features=["json"] }
+async-trait = "0.1"
+futures = "0.3"
+tokio = { version="1", features=["time"] }
+
+[features]
+# in-memory backend for tests (mock::MockBackend)
+mock = []
 
 [lib]
 path = "src/lib.rs"
@@ -0,0 +1,145 @@
+//! `--check`: compare generated code with an existing output directory
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
+use zenkit_codegen::{
+    check::{check_files, FileCheck, FileStatus},
+    gen::is_banner_line,
+    Generator, WorkspaceSchema,
+};
+
+fn fixture_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/todo.json")
+}
+
+/// Runs zk-codegen on the todo fixture, with additional args
+fn zk_codegen(out_dir: &Path, args: &[&str]) -> Command {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_zk-codegen"));
+    cmd.arg("--from-schema")
+        .arg(fixture_path())
+        .arg("-o")
+        .arg(out_dir)
+        .args(args);
+    cmd
+}
+
+/// Generates the todo crate in a new directory
+fn generate(name: &str) -> PathBuf {
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR"))
+        .join("check")
+        .join(name);
+    let _ = std::fs::remove_dir_all(&out_dir);
+    let status = zk_codegen(&out_dir, &[]).status().expect("run zk-codegen");
+    assert!(status.success());
+    out_dir
+}
+
+/// Returns the exit code of zk-codegen --check
+fn check_status(out_dir: &Path, args: &[&str]) -> i32 {
+    let mut cmd = zk_codegen(out_dir, args);
+    cmd.arg("--check");
+    cmd.output()
+        .expect("run zk-codegen")
+        .status
+        .code()
+        .expect("exit code")
+}
+
+/// Compares the todo fixture with the files in the directory
+fn check(out_dir: &Path) -> Vec<FileCheck> {
+    let schema = WorkspaceSchema::load(fixture_path()).expect("load fixture");
+    let files = Generator::init()
+        .unwrap()
+        .render_workspace(&schema)
+        .expect("render");
+    check_files(&files, out_dir.to_str().unwrap()).expect("check")
+}
+
+fn status<'c>(results: &'c [FileCheck], path: &str) -> &'c FileStatus {
+    &results
+        .iter()
+        .find(|r| r.path == path)
+        .expect("checked file")
+        .status
+}
+
+#[test]
+fn up_to_date() {
+    let out_dir = generate("up_to_date");
+    let results = check(&out_dir);
+    assert!(results.iter().all(|r| !r.is_stale()));
+    assert!(matches!(
+        status(&results, "src/lib.rs"),
+        FileStatus::UpToDate
+    ));
+    // Cargo.toml may be edited, so it isn't compared
+    assert!(matches!(
+        status(&results, "Cargo.toml"),
+        FileStatus::Skipped
+    ));
+    assert_eq!(check_status(&out_dir, &[]), 0);
+}
+
+#[test]
+fn missing_file() {
+    let out_dir = generate("missing_file");
+    std::fs::remove_file(out_dir.join("src/task_list.rs")).unwrap();
+    let results = check(&out_dir);
+    assert!(matches!(
+        status(&results, "src/task_list.rs"),
+        FileStatus::Missing
+    ));
+    assert!(matches!(
+        status(&results, "src/lib.rs"),
+        FileStatus::UpToDate
+    ));
+    assert_eq!(check_status(&out_dir, &[]), 1);
+}
+
+#[test]
+fn edited_file() {
+    let out_dir = generate("edited_file");
+    let path = out_dir.join("src/lib.rs");
+    let mut lines: Vec<String> = std::fs::read_to_string(&path)
+        .unwrap()
+        .lines()
+        .map(String::from)
+        .collect();
+    lines.insert(10, "// local change".to_string());
+    std::fs::write(&path, lines.join("\n")).unwrap();
+
+    let results = check(&out_dir);
+    assert!(matches!(
+        status(&results, "src/lib.rs"),
+        FileStatus::Differs(11)
+    ));
+    assert!(results
+        .iter()
+        .find(|r| r.path == "src/lib.rs")
+        .unwrap()
+        .is_stale());
+    assert_eq!(check_status(&out_dir, &[]), 1);
+}
+
+#[test]
+fn banner_changed() {
+    // the banner contains the generation time (or schema hash), which isn't compared
+    let out_dir = generate("banner_changed");
+    let path = out_dir.join("src/task_list.rs");
+    let source = std::fs::read_to_string(&path).unwrap();
+    let banner = source.lines().find(|l| is_banner_line(l)).expect("banner");
+    let old_banner = format!(
+        "{}Generated by zk-codegen v0.1.0 from schema 0123456789abcdef",
+        &banner[..banner.find("Generated").unwrap()]
+    );
+    std::fs::write(&path, source.replacen(banner, &old_banner, 1)).unwrap();
+
+    let results = check(&out_dir);
+    assert!(matches!(
+        status(&results, "src/task_list.rs"),
+        FileStatus::UpToDate
+    ));
+    assert_eq!(check_status(&out_dir, &[]), 0);
+    assert_eq!(check_status(&out_dir, &["--reproducible"]), 0);
+}
@@ -0,0 +1,302 @@
+//! Schema diffs: each test compares the crm_for_sales fixture with an edited copy
+use serde_json::{json, Value};
+use std::path::Path;
+use zenkit_codegen::{
+    diff::{diff_schemas, Change, ChangeKind},
+    Options, WorkspaceSchema,
+};
+
+const DEALS: &str = "Deal Tracker";
+
+fn fixture() -> Value {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/crm_for_sales.json");
+    serde_json::from_slice(&std::fs::read(path).expect("read fixture")).expect("parse fixture")
+}
+
+fn schema(v: &Value) -> WorkspaceSchema {
+    WorkspaceSchema::from_slice(&serde_json::to_vec(v).unwrap()).expect("load schema")
+}
+
+/// Returns the list (from the lists with field definitions) with the name
+fn list<'v>(v: &'v mut Value, name: &str) -> &'v mut Value {
+    v["lists"]
+        .as_array_mut()
+        .unwrap()
+        .iter_mut()
+        .find(|l| l["list"]["name"] == name)
+        .expect("list in fixture")
+}
+
+fn field<'v>(v: &'v mut Value, list_name: &str, name: &str) -> &'v mut Value {
+    list(v, list_name)["fields"]
+        .as_array_mut()
+        .unwrap()
+        .iter_mut()
+        .find(|f| f["name"] == name)
+        .expect("field in fixture")
+}
+
+fn labels<'v>(v: &'v mut Value, list_name: &str, name: &str) -> &'v mut Vec<Value> {
+    field(v, list_name, name)["elementData"]["predefinedCategories"]
+        .as_array_mut()
+        .unwrap()
+}
+
+/// Sets a list property in the workspace and in the list schema
+fn set_list(v: &mut Value, name: &str, key: &str, value: &str) {
+    for l in v["workspace"]["lists"].as_array_mut().unwrap().iter_mut() {
+        if l["name"] == name {
+            l[key] = json!(value);
+        }
+    }
+    list(v, name)["list"][key] = json!(value);
+}
+
+/// Compares the fixture with a copy changed by `edit`
+fn diff_with(options: &Options, edit: impl FnOnce(&mut Value)) -> Vec<Change> {
+    let old = fixture();
+    let mut new = old.clone();
+    edit(&mut new);
+    diff_schemas(&schema(&old), &schema(&new), options).expect("diff")
+}
+
+fn diff(edit: impl FnOnce(&mut Value)) -> Vec<Change> {
+    diff_with(&Options::default(), edit)
+}
+
+fn only(mut changes: Vec<Change>) -> Change {
+    assert_eq!(changes.len(), 1, "{:#?}", changes);
+    changes.remove(0)
+}
+
+#[test]
+fn unchanged() {
+    assert!(diff(|_| {}).is_empty());
+}
+
+#[test]
+fn field_added() {
+    let change = only(diff(|v| {
+        let mut added = field(v, DEALS, "Amount").clone();
+        added["id"] = json!(99);
+        added["uuid"] = json!("00000000-0000-0000-0000-000000000099");
+        added["name"] = json!("Discount");
+        list(v, DEALS)["fields"].as_array_mut().unwrap().push(added);
+    }));
+    assert_eq!(change.kind, ChangeKind::FieldAdded);
+    assert_eq!(change.field.as_deref(), Some("Discount"));
+    assert!(!change.is_breaking());
+}
+
+#[test]
+fn field_removed() {
+    let change = only(diff(|v| {
+        list(v, DEALS)["fields"]
+            .as_array_mut()
+            .unwrap()
+            .retain(|f| f["name"] != "Amount")
+    }));
+    assert_eq!(change.kind, ChangeKind::FieldRemoved);
+    assert!(change.removed.contains(&"Deal::get_amount".to_string()));
+    assert!(change
+        .removed
+        .contains(&"UpdateDealBuilder::set_amount".to_string()));
+    assert!(change.is_breaking());
+}
+
+#[test]
+fn field_renamed() {
+    let change = only(diff(|v| field(v, DEALS, "Amount")["name"] = json!("Value")));
+    assert_eq!(
+        change.kind,
+        ChangeKind::FieldRenamed("Amount".into(), "Value".into())
+    );
+    assert!(change.removed.contains(&"Deal::get_amount".to_string()));
+    assert!(!change.removed.contains(&"Deal::get_value".to_string()));
+    assert!(change.is_breaking());
+}
+
+#[test]
+fn field_type_changed() {
+    let change = only(diff(|v| {
+        field(v, DEALS, "Homepage")["elementcategory"] = json!(1)
+    }));
+    assert_eq!(
+        change.kind,
+        ChangeKind::FieldTypeChanged("URL".into(), "Text".into())
+    );
+    // getter and setter have the same names, but different types
+    assert!(change.is_breaking());
+}
+
+#[test]
+fn single_to_multiple() {
+    let change = only(diff(|v| {
+        field(v, DEALS, "Owner")["elementData"]["multiple"] = json!(true)
+    }));
+    assert_eq!(change.kind, ChangeKind::MultipleChanged(true));
+    assert!(change.is_breaking());
+}
+
+#[test]
+fn multiple_to_single() {
+    let change = only(diff(|v| {
+        field(v, DEALS, "Tags")["elementData"]["multiple"] = json!(false)
+    }));
+    assert_eq!(change.kind, ChangeKind::MultipleChanged(false));
+    // add_ and remove_ are only generated for multi-valued fields
+    assert!(change
+        .removed
+        .contains(&"UpdateDealBuilder::add_tags".to_string()));
+    assert!(change.is_breaking());
+}
+
+#[test]
+fn label_added() {
+    let change = only(diff(|v| {
+        let labels = labels(v, DEALS, "Stage");
+        let mut label = labels[0].clone();
+        label["id"] = json!(399);
+        label["name"] = json!("On hold");
+        labels.push(label);
+    }));
+    assert_eq!(change.kind, ChangeKind::LabelAdded("On hold".into()));
+    assert!(!change.is_breaking());
+}
+
+#[test]
+fn label_removed() {
+    let change = only(diff(|v| {
+        labels(v, DEALS, "Stage").retain(|l| l["name"] != "Lost")
+    }));
+    assert_eq!(change.kind, ChangeKind::LabelRemoved("Lost".into()));
+    assert!(!change.removed.is_empty());
+    assert!(change.is_breaking());
+}
+
+#[test]
+fn label_renamed() {
+    let change = only(diff(|v| {
+        labels(v, DEALS, "Stage")[2]["name"] = json!("Closed")
+    }));
+    assert_eq!(
+        change.kind,
+        ChangeKind::LabelRenamed("Lost".into(), "Closed".into())
+    );
+    assert!(!change.removed.is_empty());
+    assert!(change.is_breaking());
+}
+
+#[test]
+fn list_renamed() {
+    let change = only(diff(|v| set_list(v, DEALS, "name", "Pipeline")));
+    assert_eq!(
+        change.kind,
+        ChangeKind::ListRenamed(DEALS.into(), "Pipeline".into())
+    );
+    for sym in ["mod deal_tracker", "DealTrackerList", "DealTrackerClient"].iter() {
+        assert!(change.removed.contains(&sym.to_string()), "{}", sym);
+    }
+    // the item name is unchanged
+    assert!(!change.removed.contains(&"Deal".to_string()));
+    assert!(change.is_breaking());
+}
+
+#[test]
+fn item_renamed() {
+    // field symbols move with the item, so the rename is reported once
+    let change = only(diff(|v| set_list(v, DEALS, "itemName", "Opportunity")));
+    assert_eq!(
+        change.kind,
+        ChangeKind::ItemRenamed("Deal".into(), "Opportunity".into())
+    );
+    for sym in [
+        "Deal",
+        "NewDealBuilder",
+        "UpdateDealBuilder",
+        "DealId",
+        "DealUuid",
+        "DealRecord",
+        "DealQuery",
+    ]
+    .iter()
+    {
+        assert!(change.removed.contains(&sym.to_string()), "{}", sym);
+    }
+    assert!(change.is_breaking());
+}
+
+#[test]
+fn category_enums() {
+    // with category enums, setters of category fields take ids with the _ids suffix
+    let options = Options {
+        category_enums: true,
+        ..Default::default()
+    };
+    let change = only(diff_with(&options, |v| {
+        field(v, DEALS, "Tags")["name"] = json!("Labels")
+    }));
+    assert!(change
+        .removed
+        .contains(&"UpdateDealBuilder::set_tags_ids".to_string()));
+
+    let change = only(diff(|v| field(v, DEALS, "Tags")["name"] = json!("Labels")));
+    assert!(!change
+        .removed
+        .contains(&"UpdateDealBuilder::set_tags_ids".to_string()));
+}
+
+#[test]
+fn resolved_names() {
+    // two fields named "Amount": the second is generated as amount_2
+    let mut old = fixture();
+    let mut second = field(&mut old, DEALS, "Amount").clone();
+    second["id"] = json!(99);
+    second["uuid"] = json!("00000000-0000-0000-0000-000000000099");
+    list(&mut old, DEALS)["fields"]
+        .as_array_mut()
+        .unwrap()
+        .push(second);
+    let mut new = old.clone();
+    for f in list(&mut new, DEALS)["fields"].as_array_mut().unwrap() {
+        if f["id"] == 99 {
+            f["name"] = json!("Net");
+        }
+    }
+
+    let change = only(diff_schemas(&schema(&old), &schema(&new), &Options::default()).unwrap());
+    assert_eq!(
+        change.kind,
+        ChangeKind::FieldRenamed("Amount".into(), "Net".into())
+    );
+    assert!(change.removed.contains(&"Deal::get_amount_2".to_string()));
+    assert!(!change.removed.contains(&"Deal::get_amount".to_string()));
+}
+
+#[test]
+fn renumbered() {
+    // two fields named "Amount": when the first is removed, amount_2 becomes amount
+    let mut old = fixture();
+    let mut second = field(&mut old, DEALS, "Amount").clone();
+    second["id"] = json!(99);
+    second["uuid"] = json!("00000000-0000-0000-0000-000000000099");
+    list(&mut old, DEALS)["fields"]
+        .as_array_mut()
+        .unwrap()
+        .push(second);
+    let mut new = old.clone();
+    list(&mut new, DEALS)["fields"]
+        .as_array_mut()
+        .unwrap()
+        .retain(|f| f["name"] != "Amount" || f["id"] == 99);
+
+    let changes = diff_schemas(&schema(&old), &schema(&new), &Options::default()).unwrap();
+    assert_eq!(changes.len(), 2, "{:#?}", changes);
+    assert_eq!(changes[0].kind, ChangeKind::FieldRemoved);
+    let change = &changes[1];
+    assert_eq!(change.kind, ChangeKind::SymbolsRenamed);
+    assert_eq!(change.field.as_deref(), Some("Amount"));
+    assert!(change.removed.contains(&"Deal::get_amount_2".to_string()));
+    assert!(!change.removed.contains(&"Deal::get_amount".to_string()));
+    assert!(change.is_breaking());
+}
@@ -0,0 +1,24 @@
+//! Escaping of workspace content in generated code
+use zenkit_codegen::escape::{doc_comment, rust_string, toml_string};
+
+#[test]
+fn string_literals() {
+    assert_eq!(rust_string(r#"Say "Hi" \ Bye"#), r#"Say \"Hi\" \\ Bye"#);
+    assert_eq!(rust_string("two\nlines"), r"two\nlines");
+    assert_eq!(rust_string("Größe 🚀"), "Größe 🚀");
+    assert_eq!(toml_string("a \"b\"\tc\u{1}"), r#"a \"b\"\tc\u0001"#);
+}
+
+#[test]
+fn doc_comments() {
+    assert_eq!(doc_comment("one line", "///"), "one line");
+    assert_eq!(
+        doc_comment("one\r\ntwo\rthree", "///"),
+        "one\n/// two\n/// three"
+    );
+    assert_eq!(
+        doc_comment("a\n\n    indented", "//!"),
+        "a\n//! \n//! indented"
+    );
+    assert_eq!(doc_comment("```\ncode", "///"), "\\`\\`\\`\n/// code");
+}
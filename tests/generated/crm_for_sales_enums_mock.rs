@@ -0,0 +1,104 @@
+//! Tests of the mock backend with category enums, copied into the crate generated from
+//! the crm_for_sales fixture with `category_enums`
+use crm_for_sales::{mock::MockBackend, *};
+use std::{
+    collections::BTreeSet,
+    future::Future,
+    sync::Arc,
+    task::{Context, Poll, Waker},
+};
+
+/// Runs a future that doesn't wait for anything, as the mock backend's futures don't
+fn block_on<F: Future>(f: F) -> F::Output {
+    let mut f = Box::pin(f);
+    match f.as_mut().poll(&mut Context::from_waker(Waker::noop())) {
+        Poll::Ready(v) => v,
+        Poll::Pending => panic!("mock future is pending"),
+    }
+}
+
+#[test]
+fn change_labels() {
+    let client = WorkspaceClient::with_backend(Arc::new(MockBackend::new()));
+    let deals = client.deal_tracker();
+    let hot: BTreeSet<DealTags> = [DealTags::Hot].iter().copied().collect();
+    let hot_renewal: BTreeSet<DealTags> =
+        [DealTags::Hot, DealTags::Renewal].iter().copied().collect();
+    let deal = block_on(deals.create().set_tags(&hot).execute()).unwrap();
+
+    // only the added label is sent, so other labels are kept
+    let deal = block_on(
+        deals
+            .update(deal.get_id())
+            .change_tags(&deal, &hot_renewal)
+            .execute(),
+    )
+    .unwrap();
+    assert_eq!(deal.get_tags(), hot_renewal);
+
+    // setting another field in the same update would send the difference as a replace
+    let all: BTreeSet<DealTags> = DealTags::ALL.iter().copied().collect();
+    let result = block_on(
+        deals
+            .update(deal.get_id())
+            .change_tags(&deal, &all)
+            .set_owner(7)
+            .execute(),
+    );
+    assert!(
+        matches!(result, Err(Error::Message(ref m)) if m.contains("Conflicting update actions"))
+    );
+    let result = block_on(
+        deals
+            .update(deal.get_id())
+            .set_owner(7)
+            .change_tags(&deal, &hot)
+            .execute(),
+    );
+    assert!(result.is_err());
+    assert_eq!(
+        block_on(deals.get(deal.get_id())).unwrap().get_tags(),
+        hot_renewal
+    );
+
+    // fields that are set can be updated together
+    let deal = block_on(
+        deals
+            .update(deal.get_id())
+            .set_tags(&hot)
+            .set_owner(7)
+            .execute(),
+    )
+    .unwrap();
+    assert_eq!(deal.get_tags(), hot);
+    assert_eq!(deal.get_owner_id(), Some(7));
+}
+
+#[test]
+fn change_keeps_unknown_labels() {
+    // a label added in Zenkit after the code was generated
+    const NEW_LABEL: u64 = 9999;
+    let client = WorkspaceClient::with_backend(Arc::new(MockBackend::new()));
+    let deals = client.deal_tracker();
+    let deal = block_on(
+        deals
+            .create()
+            .set_tags_ids(vec![DealTags::Hot.id(), NEW_LABEL])
+            .execute(),
+    )
+    .unwrap();
+    let hot: BTreeSet<DealTags> = [DealTags::Hot].iter().copied().collect();
+    assert_eq!(deal.get_tags(), hot);
+
+    // a label is added and one removed, so the value is replaced
+    let renewal: BTreeSet<DealTags> = [DealTags::Renewal].iter().copied().collect();
+    let deal = block_on(
+        deals
+            .update(deal.get_id())
+            .change_tags(&deal, &renewal)
+            .execute(),
+    )
+    .unwrap();
+    assert_eq!(deal.get_tags(), renewal);
+    assert_eq!(deal.get_tags_ids(), vec![NEW_LABEL, DealTags::Renewal.id()]);
+}
@@ -0,0 +1,370 @@
+//! Tests of the mock backend, copied into the crate generated from the crm_for_sales fixture
+use crm_for_sales::{mock::MockBackend, *};
+use serde_json::json;
+use std::{
+    future::Future,
+    sync::Arc,
+    task::{Context, Poll, Waker},
+};
+
+/// Runs a future that doesn't wait for anything, as the mock backend's futures don't
+fn block_on<F: Future>(f: F) -> F::Output {
+    let mut f = Box::pin(f);
+    match f.as_mut().poll(&mut Context::from_waker(Waker::noop())) {
+        Poll::Ready(v) => v,
+        Poll::Pending => panic!("mock future is pending"),
+    }
+}
+
+const TAGS: &str = "e5fdac3d-958a-5ff8-80b8-ff1524b50822";
+
+#[test]
+fn create_and_update() {
+    let client = WorkspaceClient::with_backend(Arc::new(MockBackend::new()));
+    let deals = client.deal_tracker();
+    let deal = block_on(
+        deals
+            .create()
+            .set_title("Big deal")
+            .set_stage_won()
+            .set_tags(vec![Deal::LABEL_TAGS_HOT_ID])
+            .execute(),
+    )
+    .unwrap();
+    assert_eq!(deal.get_title(), Some("Big deal"));
+    assert!(deal.is_stage_won());
+    assert_eq!(deal.get_stage(), Some("Won"));
+
+    let deal = block_on(
+        deals
+            .update(deal.get_id())
+            .add_tags(vec![Deal::LABEL_TAGS_RENEWAL_ID, Deal::LABEL_TAGS_HOT_ID])
+            .execute(),
+    )
+    .unwrap();
+    assert_eq!(
+        deal.get_tags_ids(),
+        vec![Deal::LABEL_TAGS_HOT_ID, Deal::LABEL_TAGS_RENEWAL_ID]
+    );
+    assert_eq!(deal.get_tags_labels(), vec!["Hot", "Renewal"]);
+
+    let deal = block_on(
+        deals
+            .update(deal.get_id())
+            .remove_tags(vec![Deal::LABEL_TAGS_HOT_ID])
+            .execute(),
+    )
+    .unwrap();
+    assert_eq!(deal.get_tags_ids(), vec![Deal::LABEL_TAGS_RENEWAL_ID]);
+    // unchanged fields are kept
+    assert_eq!(deal.get_title(), Some("Big deal"));
+
+    let found = block_on(deals.get_by_uuid(&deal.get_uuid())).unwrap();
+    assert_eq!(found.get_id(), deal.get_id());
+    assert_eq!(block_on(deals.get_items()).unwrap().len(), 1);
+}
+
+#[test]
+fn seeded_from_fixture() {
+    let fixture = json!({
+        LIST_COMPANIES_UUID: [
+            { "id": 10, "uuid": "6d3c2e55-0000-4000-8000-000000000010" },
+        ],
+        LIST_DEAL_TRACKER_ID.to_string(): [
+            { "displayString": "First", format!("{}_categories", TAGS): [Deal::LABEL_TAGS_ENTERPRISE_ID] },
+            { "displayString": "Second" },
+        ],
+    });
+    let backend = Arc::new(MockBackend::from_json(&fixture.to_string()).unwrap());
+    let client = WorkspaceClient::with_backend(backend.clone());
+
+    let deals = block_on(client.deal_tracker().get_items()).unwrap();
+    assert_eq!(deals.len(), 2);
+    assert_eq!(deals[0].get_display_string(), "First");
+    assert!(deals[0].is_tags_enterprise());
+    assert_eq!(deals[0].get_tags_labels(), vec!["Enterprise"]);
+    // new ids follow the fixture's ids
+    assert_eq!(deals[0].get_id().value(), 11);
+
+    let company = block_on(client.companies().get(CompanyId::new(10))).unwrap();
+    assert_eq!(
+        company.get_uuid().as_str(),
+        "6d3c2e55-0000-4000-8000-000000000010"
+    );
+    assert!(block_on(client.companies().get(CompanyId::new(11))).is_err());
+    assert_eq!(backend.entries(LIST_DEAL_TRACKER_ID).len(), 2);
+
+    block_on(backend.delete_entry(LIST_COMPANIES_ID, 10)).unwrap();
+    assert!(backend.entries(LIST_COMPANIES_ID).is_empty());
+}
+
+#[test]
+fn global_backend() {
+    set_global_backend(Some(Arc::new(MockBackend::new())));
+    let company = block_on(CompaniesList::create().set_name("Acme").execute()).unwrap();
+    let found = block_on(CompaniesList::get(company.get_id())).unwrap();
+    assert_eq!(found.get_name(), Some("Acme"));
+    set_global_backend(None);
+}
+
+#[test]
+fn query() {
+    let client = WorkspaceClient::with_backend(Arc::new(MockBackend::new()));
+    let deals = client.deal_tracker();
+    for (title, prob, in_progress) in [
+        ("Small", 20, true),
+        ("Likely", 80, true),
+        ("Certain", 95, true),
+        ("Gone", 90, false),
+    ] {
+        let mut builder = deals.create();
+        builder.set_title(title).set_deal_probability_in(prob);
+        if in_progress {
+            builder.set_stage_in_progress();
+        } else {
+            builder.set_stage_lost();
+        }
+        block_on(builder.execute()).unwrap();
+    }
+
+    let query = deals
+        .query()
+        .stage_is("In progress")
+        .deal_probability_in_gt(50)
+        .order_by_deal_probability_in_desc();
+    let found = block_on(query.get_items()).unwrap();
+    let titles: Vec<_> = found.iter().map(|d| d.get_title().unwrap()).collect();
+    assert_eq!(titles, vec!["Certain", "Likely"]);
+
+    // filters and sort order are in the request
+    let request = query.clone().limit(1).request();
+    assert_eq!(request.limit, 1);
+    assert_eq!(request.filter["AND"]["TERMS"].as_array().unwrap().len(), 2);
+    assert_eq!(
+        request.filter["AND"]["TERMS"][0]["elementId"],
+        json!(Deal::FIELD_STAGE_ID)
+    );
+    let found = block_on(query.limit(1).get_items()).unwrap();
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].get_title(), Some("Certain"));
+
+    let found = block_on(
+        deals
+            .query()
+            .title_starts_with("G")
+            .stage_is_not_empty()
+            .get_items(),
+    )
+    .unwrap();
+    assert_eq!(found.len(), 1);
+    assert!(found[0].is_stage_lost());
+    assert!(block_on(deals.query().tags_is_not_empty().get_items())
+        .unwrap()
+        .is_empty());
+
+    // unknown labels are reported when the query runs
+    assert!(block_on(deals.query().stage_is("Maybe").get_items()).is_err());
+}
+
+#[test]
+fn number_bounds() {
+    let client = WorkspaceClient::with_backend(Arc::new(MockBackend::new()));
+    let deals = client.deal_tracker();
+    for (title, prob, amount) in [
+        ("Min", i64::MIN, -1.5),
+        ("Zero", 0, 0.0),
+        ("Max", i64::MAX, 1.5),
+    ] {
+        block_on(
+            deals
+                .create()
+                .set_title(title)
+                .set_deal_probability_in(prob)
+                .set_amount(amount)
+                .execute(),
+        )
+        .unwrap();
+    }
+    let titles = |query: DealQuery| -> Vec<String> {
+        let mut titles: Vec<String> = block_on(query.get_items())
+            .unwrap()
+            .iter()
+            .map(|d| d.get_title().unwrap().to_string())
+            .collect();
+        titles.sort();
+        titles
+    };
+
+    // strict comparisons at the limits of i64 match nothing
+    assert!(titles(deals.query().deal_probability_in_gt(i64::MAX)).is_empty());
+    assert!(titles(deals.query().deal_probability_in_lt(i64::MIN)).is_empty());
+    assert_eq!(
+        titles(deals.query().deal_probability_in_gt(i64::MAX - 1)),
+        vec!["Max"]
+    );
+    assert_eq!(titles(deals.query().deal_probability_in_lt(0)), vec!["Min"]);
+
+    // decimal fields
+    assert_eq!(titles(deals.query().amount_gt(0.0)), vec!["Max"]);
+    assert_eq!(titles(deals.query().amount_lt(0.0)), vec!["Min"]);
+    assert_eq!(titles(deals.query().amount_gt(-1.5)), vec!["Max", "Zero"]);
+    assert_eq!(titles(deals.query().amount_lt(1.5)), vec!["Min", "Zero"]);
+    assert!(titles(deals.query().amount_gt(1.5)).is_empty());
+    assert!(titles(deals.query().amount_gt(f64::MAX)).is_empty());
+    assert!(titles(deals.query().amount_lt(f64::MIN)).is_empty());
+    assert!(titles(deals.query().amount_gt(f64::NAN)).is_empty());
+}
+
+#[test]
+fn stream() {
+    use futures::{executor::block_on as run, StreamExt, TryStreamExt};
+
+    let client = WorkspaceClient::with_backend(Arc::new(MockBackend::new()));
+    let companies = client.companies();
+    for n in 1..=5 {
+        block_on(
+            companies
+                .create()
+                .set_name(&format!("Company {}", n))
+                .set_employees(n * 10)
+                .execute(),
+        )
+        .unwrap();
+    }
+
+    let names = |items: Vec<Company>| -> Vec<String> {
+        items
+            .iter()
+            .map(|c| c.get_name().unwrap().to_string())
+            .collect()
+    };
+    let all: Vec<Company> = run(companies.stream_items().try_collect()).unwrap();
+    assert_eq!(all.len(), 5);
+
+    // pages of 2, resuming after the first 3 items
+    let query = companies
+        .query()
+        .employees_ge(20)
+        .order_by_employees_desc()
+        .page_size(2);
+    let first: Vec<Company> = run(query.clone().stream_items().take(3).try_collect()).unwrap();
+    assert_eq!(names(first), vec!["Company 5", "Company 4", "Company 3"]);
+    let rest: Vec<Company> = run(query.skip(3).stream_items().try_collect()).unwrap();
+    assert_eq!(names(rest), vec!["Company 2"]);
+
+    // invalid conditions are the stream's only item
+    let items: Vec<_> = run(client
+        .deal_tracker()
+        .query()
+        .stage_is("Maybe")
+        .stream_items()
+        .collect());
+    assert_eq!(items.len(), 1);
+    assert!(items[0].is_err());
+}
+
+#[test]
+fn create_and_update_many() {
+    let backend = Arc::new(MockBackend::new());
+    let client = WorkspaceClient::with_backend(backend.clone());
+    let deals = client.deal_tracker();
+    let builders: Vec<NewDealBuilder> = ["First", "Second", "Third"]
+        .iter()
+        .map(|title| {
+            let mut builder = deals.create();
+            builder.set_title(title);
+            if *title == "Second" {
+                builder.set_stage("No such stage");
+            }
+            builder
+        })
+        .collect();
+    let results = block_on(DealTrackerList::create_many(builders, 2));
+    // results are in the order of the builders, and a failure doesn't stop the others
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].as_ref().unwrap().get_title(), Some("First"));
+    assert!(results[1].is_err());
+    assert_eq!(results[2].as_ref().unwrap().get_title(), Some("Third"));
+    assert_eq!(backend.entries(LIST_DEAL_TRACKER_ID).len(), 2);
+
+    let builders: Vec<UpdateDealBuilder> = results
+        .iter()
+        .flatten()
+        .map(|deal| {
+            let mut builder = deals.update(deal.get_id());
+            builder.set_stage_won();
+            builder
+        })
+        .chain(std::iter::once(deals.update(DealId::new(999))))
+        .collect();
+    let results = block_on(DealTrackerList::update_many(builders, 1));
+    assert!(results[0].as_ref().unwrap().is_stage_won());
+    assert!(results[1].as_ref().unwrap().is_stage_won());
+    assert!(results[2].is_err());
+}
+
+#[test]
+fn records_with_client() {
+    let backend = Arc::new(MockBackend::new());
+    let client = WorkspaceClient::with_backend(backend.clone());
+    let deals = client.deal_tracker();
+    let deal = block_on(deals.create().set_title("Copied").set_amount(5.0).execute()).unwrap();
+
+    // builders from records use the client they are given
+    let mut record = DealRecord::from(&deal);
+    record.amount = Some(8.0);
+    let updated = block_on(record.to_update_builder_with(&deals).unwrap().execute()).unwrap();
+    assert_eq!(updated.get_id(), deal.get_id());
+    assert_eq!(updated.get_amount(), Some(8.0));
+
+    let copy = block_on(record.to_new_builder_with(&deals).execute()).unwrap();
+    assert_ne!(copy.get_id(), deal.get_id());
+    assert_eq!(copy.get_title(), Some("Copied"));
+    assert_eq!(backend.entries(LIST_DEAL_TRACKER_ID).len(), 2);
+
+    record.id = None;
+    assert!(record.to_update_builder_with(&deals).is_err());
+}
+
+#[test]
+fn archive_restore_and_delete() {
+    let backend = Arc::new(MockBackend::new());
+    let client = WorkspaceClient::with_backend(backend.clone());
+    let companies = client.companies();
+    let acme = block_on(companies.create().set_name("Acme").execute()).unwrap();
+    let globex = block_on(companies.create().set_name("Globex").execute()).unwrap();
+
+    let archived = block_on(acme.archive()).unwrap();
+    assert!(archived.get_deprecated_date().is_some());
+    // archived items are left out unless included
+    let names = |items: Vec<Company>| -> Vec<String> {
+        items
+            .iter()
+            .filter_map(|c| c.get_name().map(String::from))
+            .collect()
+    };
+    assert_eq!(
+        names(block_on(companies.get_items()).unwrap()),
+        vec!["Globex"]
+    );
+    let all = block_on(companies.query().include_archived(true).get_items()).unwrap();
+    assert_eq!(names(all), vec!["Acme", "Globex"]);
+
+    let restored = block_on(companies.restore(acme.get_id())).unwrap();
+    assert!(restored.get_deprecated_date().is_none());
+    assert_eq!(block_on(companies.get_items()).unwrap().len(), 2);
+
+    block_on(globex.delete()).unwrap();
+    assert!(block_on(companies.get(globex.get_id())).is_err());
+    assert_eq!(backend.entries(LIST_COMPANIES_ID).len(), 1);
+    assert!(block_on(companies.archive(globex.get_id())).is_err());
+
+    // deleting from a list with no entries fails like a get
+    let get_err = block_on(backend.get_entry(LIST_DEAL_TRACKER_ID, AllId::ID(1)))
+        .err()
+        .expect("no entry");
+    let delete_err = block_on(backend.delete_entry(LIST_DEAL_TRACKER_ID, 1))
+        .err()
+        .expect("no entry");
+    assert_eq!(delete_err.to_string(), get_err.to_string());
+}
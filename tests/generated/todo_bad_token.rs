@@ -0,0 +1,17 @@
+//! Test of initializing the global client after a failed attempt, copied into the crate
+//! generated from the todo fixture. The global client can only be initialized once per
+//! process, so this test has its own file. The server is started by the test in
+//! tests/golden.rs, which sets ZK_STAND_IN_ENDPOINT.
+use todo::*;
+
+#[tokio::test]
+async fn bad_token() {
+    let endpoint = std::env::var("ZK_STAND_IN_ENDPOINT").expect("ZK_STAND_IN_ENDPOINT");
+    assert!(initialize_zenkit_api(Some(""), Some(&endpoint)).is_err());
+    assert!(initialize_zenkit_api(Some("bad\ntoken"), Some(&endpoint)).is_err());
+
+    // failed attempts don't initialize the client, so it can still be initialized
+    initialize_zenkit_api(Some("test-token"), Some(&endpoint)).unwrap();
+    let task = TaskListList::get(TaskListId::new(1001)).await.unwrap();
+    assert_eq!(task.get_task(), Some("Write tests"));
+}
@@ -0,0 +1,23 @@
+//! Test of retries with the global client, copied into the crate generated from the todo
+//! fixture. The global client can only be initialized once per process, so this test has
+//! its own file. The server is started by the test in tests/golden.rs, which sets
+//! ZK_FLAKY_GLOBAL_ENDPOINT, and makes its first request fail with 429 and Retry-After: 1.
+use std::time::{Duration, Instant};
+use todo::*;
+
+#[tokio::test]
+async fn global_client_retries() {
+    let endpoint = std::env::var("ZK_FLAKY_GLOBAL_ENDPOINT").expect("ZK_FLAKY_GLOBAL_ENDPOINT");
+    initialize_zenkit_api(Some("test-token"), Some(&endpoint)).unwrap();
+    set_retry_policy(Some(RetryPolicy {
+        max_attempts: 2,
+        initial_backoff: Duration::from_millis(10),
+        max_backoff: Duration::from_secs(5),
+        jitter: false,
+    }));
+
+    let start = Instant::now();
+    let task = TaskListList::get(TaskListId::new(1001)).await.unwrap();
+    assert_eq!(task.get_task(), Some("Write tests"));
+    assert!(start.elapsed() >= Duration::from_secs(1));
+}
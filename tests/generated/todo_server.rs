@@ -0,0 +1,201 @@
+//! Tests of the generated client with the stand-in server, copied into the crate generated
+//! from the todo fixture. The servers are started by the test in tests/golden.rs, which sets
+//! ZK_STAND_IN_ENDPOINT, and ZK_FLAKY_ENDPOINT for a server whose first requests fail.
+use std::{
+    collections::BTreeSet,
+    time::{Duration, Instant},
+};
+use todo::*;
+
+fn endpoint() -> String {
+    std::env::var("ZK_STAND_IN_ENDPOINT").expect("ZK_STAND_IN_ENDPOINT")
+}
+
+#[tokio::test]
+async fn workspace_client() {
+    let client = WorkspaceClient::connect("test-token", Some(&endpoint())).unwrap();
+    let tasks = client.task_list();
+    let items = tasks.get_items().await.unwrap();
+    let names: BTreeSet<&str> = items.iter().filter_map(|t| t.get_task()).collect();
+    assert!(names.contains("Write tests"));
+    assert!(names.contains("Release"));
+
+    let task = tasks
+        .create()
+        .set_task("From the generated client")
+        .set_priority_high()
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(task.get_priority(), Some("High"));
+    let task = tasks
+        .update(task.get_id())
+        .check_done(true)
+        .execute()
+        .await
+        .unwrap();
+    assert!(task.is_checked_done());
+    assert_eq!(task.get_task(), Some("From the generated client"));
+    let found = tasks.get_by_uuid(&task.get_uuid()).await.unwrap();
+    assert_eq!(found.get_id(), task.get_id());
+}
+
+#[tokio::test]
+async fn query() {
+    let client = WorkspaceClient::connect("test-token", Some(&endpoint())).unwrap();
+    let tasks = client.task_list();
+    let low = tasks.query().priority_is("Low").get_items().await.unwrap();
+    assert_eq!(low.len(), 1);
+    assert_eq!(low[0].get_task(), Some("Release"));
+
+    let open = tasks
+        .query()
+        .task_starts_with("Write")
+        .done_is(false)
+        .order_by_task_asc()
+        .get_items()
+        .await
+        .unwrap();
+    assert_eq!(open.len(), 1);
+    assert_eq!(open[0].get_id(), TaskListId::new(1001));
+}
+
+#[tokio::test]
+async fn stream() {
+    use futures::TryStreamExt;
+
+    let client = WorkspaceClient::connect("test-token", Some(&endpoint())).unwrap();
+    // one matching item: the first page is short, so it is the last
+    let query = client.task_list().query().priority_is("Low").page_size(2);
+    let items: Vec<TaskList> = query.stream_items().try_collect().await.unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].get_task(), Some("Release"));
+    let items: Vec<TaskList> = query.skip(1).stream_items().try_collect().await.unwrap();
+    assert!(items.is_empty());
+}
+
+#[tokio::test]
+async fn global_client() {
+    initialize_zenkit_api(Some("test-token"), Some(&endpoint())).unwrap();
+    let task = TaskListList::get(TaskListId::new(1002)).await.unwrap();
+    assert_eq!(task.get_task(), Some("Release"));
+    assert!(task.is_priority_low());
+
+    assert_eq!(
+        task.get_zenkit_url(),
+        format!(
+            "{}L401/{}/",
+            DEFAULT_ITEM_URL_BASE,
+            task.get_entry().short_id
+        )
+    );
+    set_item_url_base(Some("https://zenkit.example.com/i/"));
+    assert_eq!(
+        task.get_zenkit_url(),
+        format!(
+            "https://zenkit.example.com/i/L401/{}/",
+            task.get_entry().short_id
+        )
+    );
+    set_item_url_base(None);
+
+    // archive and restore use the connection made by initialize_zenkit_api
+    let task = TaskListList::create()
+        .set_task("Archived with the global client")
+        .execute()
+        .await
+        .unwrap();
+    let archived = TaskListList::archive(task.get_id()).await.unwrap();
+    assert!(archived.get_deprecated_date().is_some());
+    let restored = TaskListList::restore(task.get_id()).await.unwrap();
+    assert!(restored.get_deprecated_date().is_none());
+    TaskListList::delete(task.get_id()).await.unwrap();
+}
+
+#[tokio::test]
+async fn retries() {
+    let endpoint = std::env::var("ZK_FLAKY_ENDPOINT").expect("ZK_FLAKY_ENDPOINT");
+    let client = WorkspaceClient::connect("test-token", Some(&endpoint))
+        .unwrap()
+        .with_retry_policy(RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_secs(5),
+            jitter: false,
+        });
+    let tasks = client.task_list();
+
+    // creates are not retried
+    let err = tasks
+        .create()
+        .set_task("Not created")
+        .execute()
+        .await
+        .err()
+        .expect("create fails");
+    assert!(err.is_transient());
+
+    // retries disabled
+    let no_retries = client.clone().with_retry_policy(RetryPolicy::none());
+    match no_retries.task_list().get(TaskListId::new(1001)).await {
+        Err(Error::Status { status, .. }) => assert_eq!(status, 502),
+        other => panic!("expected status error, got {:?}", other.map(|t| t.get_id())),
+    }
+
+    // 429 with Retry-After: 1, then 503, then success
+    let start = Instant::now();
+    let task = tasks.get(TaskListId::new(1001)).await.unwrap();
+    assert_eq!(task.get_task(), Some("Write tests"));
+    assert!(start.elapsed() >= Duration::from_secs(1));
+}
+
+#[tokio::test]
+async fn create_many() {
+    let client = WorkspaceClient::connect("test-token", Some(&endpoint())).unwrap();
+    let tasks = client.task_list();
+    let builders: Vec<NewTaskListBuilder> = (1..=5)
+        .map(|n| {
+            let mut builder = tasks.create();
+            builder.set_task(&format!("Imported {}", n));
+            builder
+        })
+        .collect();
+    let results = TaskListList::create_many(builders, 3).await;
+    let names: Vec<Option<String>> = results
+        .iter()
+        .map(|r| r.as_ref().unwrap().get_task().map(String::from))
+        .collect();
+    let expected: Vec<Option<String>> = (1..=5).map(|n| Some(format!("Imported {}", n))).collect();
+    assert_eq!(names, expected);
+}
+
+#[tokio::test]
+async fn archive_restore_and_delete() {
+    let client = WorkspaceClient::connect("test-token", Some(&endpoint())).unwrap();
+    let tasks = client.task_list();
+    let task = tasks
+        .create()
+        .set_task("To be archived")
+        .execute()
+        .await
+        .unwrap();
+
+    let archived = task.archive().await.unwrap();
+    assert!(archived.get_deprecated_date().is_some());
+    let query = tasks.query().task_is("To be archived");
+    assert!(query.clone().get_items().await.unwrap().is_empty());
+    let found = query
+        .clone()
+        .include_archived(true)
+        .get_items()
+        .await
+        .unwrap();
+    assert_eq!(found.len(), 1);
+
+    let restored = tasks.restore(task.get_id()).await.unwrap();
+    assert!(restored.get_deprecated_date().is_none());
+    assert_eq!(query.get_items().await.unwrap().len(), 1);
+
+    tasks.delete(task.get_id()).await.unwrap();
+    assert!(tasks.get(task.get_id()).await.is_err());
+}
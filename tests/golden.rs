@@ -0,0 +1,450 @@
+//! Golden-file tests for the generator.
+//!
+//! Each workspace snapshot in tests/fixtures is rendered (in reproducible mode) and compared
+//! with the expected output in tests/golden/<fixture>/. Banner lines, which contain the
+//! zk-codegen version, are ignored.
+//!
+//! After an intentional change to the generated code, regenerate the golden files with
+//!
+//!     UPDATE_GOLDEN=1 cargo test --test golden
+//!
+//! and review the diff. The `cargo check` and `cargo test` tests compile the generated crates, and need
+//! network access to fetch dependencies, so they are ignored by default:
+//!
+//!     cargo test --test golden -- --ignored
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+};
+use zenkit_codegen::{
+    check::rustfmt,
+    compile::{build_crate, BuildMode},
+    gen::{is_banner_line, SINGLE_FILE_NAME},
+    server::StandInServer,
+    GeneratedFile, Generator, Layout, Options, WorkspaceSchema,
+};
+
+const FIXTURES: &[&str] = &["crm_for_sales", "escaping", "naming", "todo"];
+
+fn fixture_path(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(format!("{}.json", name))
+}
+
+fn golden_dir(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/golden")
+        .join(name)
+}
+
+fn generator() -> Generator<'static> {
+    Generator::with_options(Options {
+        reproducible: true,
+        ..Default::default()
+    })
+    .expect("init generator")
+}
+
+/// Generator with category enums. Golden files are in tests/golden/<fixture>_enums/
+fn enum_generator() -> Generator<'static> {
+    Generator::with_options(Options {
+        reproducible: true,
+        category_enums: true,
+        ..Default::default()
+    })
+    .expect("init generator")
+}
+
+/// Generator for the single-file layout. Golden files are in tests/golden/<fixture>_single_file/
+fn single_file_generator() -> Generator<'static> {
+    Generator::with_options(Options {
+        reproducible: true,
+        layout: Layout::SingleFile,
+        ..Default::default()
+    })
+    .expect("init generator")
+}
+
+/// Renders fixture, with rust sources formatted as they would be by zk-codegen
+fn render_with(gen: &mut Generator, name: &str) -> Vec<GeneratedFile> {
+    let schema = WorkspaceSchema::load(fixture_path(name)).expect("load fixture");
+    let mut files = gen.render_workspace(&schema).expect("render");
+    for file in files.iter_mut() {
+        if file.path.ends_with(".rs") {
+            file.contents = rustfmt(&file.contents).expect("rustfmt");
+        }
+    }
+    files
+}
+
+fn without_banner(s: &str) -> Vec<&str> {
+    s.lines().filter(|l| !is_banner_line(l)).collect()
+}
+
+fn check_golden(name: &str) {
+    check_golden_with(&mut generator(), name, name);
+}
+
+/// Compares fixture rendered by the generator with the golden files in tests/golden/<golden>/
+fn check_golden_with(gen: &mut Generator, name: &str, golden: &str) {
+    let dir = golden_dir(golden);
+    let update = std::env::var_os("UPDATE_GOLDEN").is_some();
+    for file in render_with(gen, name).iter() {
+        let path = dir.join(&file.path);
+        if update {
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(&path, &file.contents).unwrap();
+            continue;
+        }
+        let expected = std::fs::read_to_string(&path).unwrap_or_else(|e| {
+            panic!(
+                "{}: {} (run with UPDATE_GOLDEN=1 to create)",
+                path.display(),
+                e
+            )
+        });
+        let expected = without_banner(&expected);
+        let actual = without_banner(&file.contents);
+        for (n, (exp, act)) in expected.iter().zip(actual.iter()).enumerate() {
+            assert_eq!(
+                exp,
+                act,
+                "{}/{}: first difference at line {} (excluding banner)",
+                golden,
+                file.path,
+                n + 1
+            );
+        }
+        assert_eq!(
+            expected.len(),
+            actual.len(),
+            "{}/{}: line count differs",
+            golden,
+            file.path
+        );
+    }
+}
+
+#[test]
+fn golden_crm_for_sales() {
+    check_golden("crm_for_sales");
+}
+
+#[test]
+fn golden_escaping() {
+    check_golden("escaping");
+}
+
+#[test]
+fn golden_naming() {
+    check_golden("naming");
+}
+
+#[test]
+fn golden_todo() {
+    check_golden("todo");
+}
+
+#[test]
+fn golden_crm_for_sales_enums() {
+    check_golden_with(
+        &mut enum_generator(),
+        "crm_for_sales",
+        "crm_for_sales_enums",
+    );
+}
+
+#[test]
+fn golden_naming_enums() {
+    check_golden_with(&mut enum_generator(), "naming", "naming_enums");
+}
+
+#[test]
+fn golden_todo_single_file() {
+    check_golden_with(&mut single_file_generator(), "todo", "todo_single_file");
+}
+
+#[test]
+fn origins_of_generated_definitions() {
+    let mut gen = generator();
+    render_with(&mut gen, "crm_for_sales");
+    let find = |file: &str, symbol: &str| {
+        gen.origins()
+            .iter()
+            .find(|o| o.file == file && o.symbol == symbol)
+            .unwrap_or_else(|| panic!("no origin for {} in {}", symbol, file))
+    };
+
+    let getter = find("src/deal_tracker.rs", "get_amount");
+    assert_eq!(getter.list.as_deref(), Some("Deal Tracker"));
+    assert_eq!(getter.field.as_deref(), Some("Amount"));
+    assert_eq!(getter.template, "get_float_field");
+
+    let label = find("src/deal_tracker.rs", "is_stage_won");
+    assert_eq!(label.field.as_deref(), Some("Stage"));
+    assert_eq!(label.label.as_deref(), Some("Won"));
+    assert_eq!(label.template, "category_getter_per_label");
+
+    let module = find("src/lib.rs", "companies");
+    assert_eq!(module.list, None);
+    assert_eq!(module.template, "lib_main");
+}
+
+#[test]
+fn warnings() {
+    let mut gen = generator();
+    render_with(&mut gen, "naming");
+    let warnings = gen.warnings();
+    assert!(
+        warnings
+            .iter()
+            .any(|w| w.starts_with("list 'tasks!' conflicts with list 'Tasks'")),
+        "{:#?}",
+        warnings
+    );
+    // each warning is reported once, though names are resolved again for each list
+    for (i, w) in warnings.iter().enumerate() {
+        assert!(!warnings[i + 1..].contains(w), "repeated: {}", w);
+    }
+
+    // warnings are cleared for the next workspace
+    render_with(&mut gen, "todo");
+    assert!(gen.warnings().is_empty(), "{:#?}", gen.warnings());
+}
+
+#[test]
+fn all_fixtures_tested() {
+    // every fixture needs a golden test (and a cargo check test) above
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        let name = path.file_stem().unwrap().to_string_lossy();
+        if name.ends_with(".entries") {
+            // entries for the stand-in server (tests/server.rs)
+            continue;
+        }
+        assert!(
+            FIXTURES.contains(&name.as_ref()),
+            "fixture {} has no tests",
+            name
+        );
+    }
+}
+
+/// Renders fixture into a new crate and runs `cargo check` or `cargo test` on it
+fn build(name: &str, mode: BuildMode) {
+    build_with(&mut generator(), name, name, mode, &[]);
+}
+
+/// Renders fixture into a new crate in <target-tmpdir>/golden/<out_name>, and builds it.
+/// `tests` are files in tests/generated/ that are copied into the crate's tests directory,
+/// and may use tokio.
+fn build_with(gen: &mut Generator, name: &str, out_name: &str, mode: BuildMode, tests: &[&str]) {
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR"))
+        .join("golden")
+        .join(out_name);
+    let _ = std::fs::remove_dir_all(&out_dir);
+    let files = render_with(gen, name);
+    for file in files.iter() {
+        let path = out_dir.join(&file.path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, &file.contents).unwrap();
+    }
+    if !tests.is_empty() {
+        let mut manifest = std::fs::OpenOptions::new()
+            .append(true)
+            .open(out_dir.join("Cargo.toml"))
+            .unwrap();
+        writeln!(
+            manifest,
+            "\n[dev-dependencies]\ntokio = {{ version=\"1.0\", features=[\"rt\", \"macros\"] }}"
+        )
+        .unwrap();
+    }
+    for test in tests.iter() {
+        let src = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/generated")
+            .join(test);
+        std::fs::create_dir_all(out_dir.join("tests")).unwrap();
+        std::fs::copy(src, out_dir.join("tests").join(test)).unwrap();
+    }
+    // share build artifacts between fixtures
+    std::env::set_var(
+        "CARGO_TARGET_DIR",
+        Path::new(env!("CARGO_TARGET_TMPDIR")).join("golden-target"),
+    );
+    let errors = build_crate(out_dir.to_str().unwrap(), mode, gen.origins()).expect("run cargo");
+    for error in errors.iter() {
+        eprintln!("{}\n", error);
+    }
+    assert!(errors.is_empty(), "build failed for fixture {}", name);
+}
+
+/// The single-file layout is included in a module of another crate, so it can't have
+/// inner attributes or doc comments, or doc comments on items that don't take them
+#[test]
+#[ignore]
+fn cargo_check_todo_single_file() {
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR"))
+        .join("golden")
+        .join("todo_single_file");
+    let _ = std::fs::remove_dir_all(&out_dir);
+    std::fs::create_dir_all(out_dir.join("src")).unwrap();
+    // the including crate has the dependencies and features of the generated crate
+    let manifest = render_with(&mut generator(), "todo")
+        .into_iter()
+        .find(|f| f.path == "Cargo.toml")
+        .unwrap();
+    std::fs::write(out_dir.join("Cargo.toml"), &manifest.contents).unwrap();
+    let mut gen = single_file_generator();
+    let files = render_with(&mut gen, "todo");
+    assert_eq!(files.len(), 1);
+    // a doc comment on `use` compiles, but documents nothing
+    let lines: Vec<&str> = files[0].contents.lines().map(|l| l.trim()).collect();
+    for pair in lines.windows(2) {
+        assert!(
+            !(pair[0].starts_with("///") && pair[1].starts_with("use ")),
+            "doc comment on {}",
+            pair[1]
+        );
+    }
+    std::fs::write(
+        out_dir.join("src").join(SINGLE_FILE_NAME),
+        &files[0].contents,
+    )
+    .unwrap();
+    std::fs::write(
+        out_dir.join("src/lib.rs"),
+        format!(
+            "#![deny(unused_doc_comments)]\npub mod todo {{\n    include!(\"{}\");\n}}\n",
+            SINGLE_FILE_NAME
+        ),
+    )
+    .unwrap();
+    std::env::set_var(
+        "CARGO_TARGET_DIR",
+        Path::new(env!("CARGO_TARGET_TMPDIR")).join("golden-target"),
+    );
+    let errors =
+        build_crate(out_dir.to_str().unwrap(), BuildMode::Check, gen.origins()).expect("run cargo");
+    for error in errors.iter() {
+        eprintln!("{}\n", error);
+    }
+    assert!(errors.is_empty(), "build failed for single-file todo");
+}
+
+/// also tests the mock backend
+#[test]
+#[ignore]
+fn cargo_test_crm_for_sales() {
+    build_with(
+        &mut generator(),
+        "crm_for_sales",
+        "crm_for_sales",
+        BuildMode::Test,
+        &["crm_for_sales_mock.rs"],
+    );
+}
+
+/// doc comments with markdown code blocks would be run as doctests
+#[test]
+#[ignore]
+fn cargo_test_escaping() {
+    build("escaping", BuildMode::Test);
+}
+
+#[test]
+#[ignore]
+fn cargo_check_naming() {
+    build("naming", BuildMode::Check);
+}
+
+/// also tests the generated client with the stand-in server
+#[test]
+#[ignore]
+fn cargo_test_todo() {
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    let server = StandInServer::from_dir(&fixtures).expect("start server");
+    std::env::set_var("ZK_STAND_IN_ENDPOINT", server.endpoint());
+    // failures for the requests of the `retries` test, in order
+    let flaky = StandInServer::from_dir(&fixtures).expect("start server");
+    flaky.fail_next(1, 503, None);
+    flaky.fail_next(1, 502, None);
+    flaky.fail_next(1, 429, Some(1));
+    flaky.fail_next(1, 503, None);
+    std::env::set_var("ZK_FLAKY_ENDPOINT", flaky.endpoint());
+    let flaky_global = StandInServer::from_dir(&fixtures).expect("start server");
+    flaky_global.fail_next(1, 429, Some(1));
+    std::env::set_var("ZK_FLAKY_GLOBAL_ENDPOINT", flaky_global.endpoint());
+    build_with(
+        &mut generator(),
+        "todo",
+        "todo",
+        BuildMode::Test,
+        &[
+            "todo_server.rs",
+            "todo_global_retry.rs",
+            "todo_bad_token.rs",
+        ],
+    );
+    // requests from the generated client
+    assert!(server
+        .requests()
+        .iter()
+        .any(|r| r.method == "POST" && r.path == "/lists/401/entries"));
+    // streams request a page at a time
+    let pages: Vec<u64> = server
+        .requests()
+        .iter()
+        .filter(|r| r.path == "/lists/401/entries/filter")
+        .filter_map(|r| r.body.as_ref())
+        .filter(|body| body["limit"] == 2)
+        .filter_map(|body| body["skip"].as_u64())
+        .collect();
+    // a short page with Release ends the stream; then an empty page after skipping Release
+    assert_eq!(pages.len(), 2);
+    assert!(pages.contains(&0) && pages.contains(&1));
+    // reads are retried, creates are sent once
+    let requests = flaky.requests();
+    let count = |method: &str, path: &str| {
+        requests
+            .iter()
+            .filter(|r| r.method == method && r.path == path)
+            .count()
+    };
+    assert_eq!(count("GET", "/lists/401/entries/1001"), 4);
+    assert_eq!(count("POST", "/lists/401/entries"), 1);
+    assert_eq!(flaky.entries(401).len(), 2);
+    // the global client honors Retry-After too
+    let requests = flaky_global.requests();
+    assert_eq!(requests.len(), 2);
+    assert!(requests
+        .iter()
+        .all(|r| r.method == "GET" && r.path == "/lists/401/entries/1001"));
+}
+
+/// also tests builders with category enums on the mock backend
+#[test]
+#[ignore]
+fn cargo_test_crm_for_sales_enums() {
+    build_with(
+        &mut enum_generator(),
+        "crm_for_sales",
+        "crm_for_sales_enums",
+        BuildMode::Test,
+        &["crm_for_sales_enums_mock.rs"],
+    );
+}
+
+#[test]
+#[ignore]
+fn cargo_check_naming_enums() {
+    build_with(
+        &mut enum_generator(),
+        "naming",
+        "naming_enums",
+        BuildMode::Check,
+        &[],
+    );
+}
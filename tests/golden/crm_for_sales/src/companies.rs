@@ -0,0 +1,1093 @@
+#![allow(dead_code, unused_imports)]
+//! CompaniesList Customer companies
+//  Generated by zk-codegen v0.5.4 from schema 2387636692c5cac1
+use super::{lookup_label, run_batch, Api, EntryQuery, Error};
+use serde_json::{self, json, Value};
+use std::{convert::AsRef, str::FromStr, sync::Arc};
+use zenkit::types::{DateTime, Entry, File, GetEntriesRequest, JsonMap, TextFormat, Utc, ID};
+
+/// CompaniesList Customer companies
+///
+pub const LIST_COMPANIES_ID: ID = 201;
+pub const LIST_COMPANIES_SHORT_ID: &str = "L201";
+pub const LIST_COMPANIES_UUID: &str = "857e17da-f7e6-5a5b-ae2e-354ac75f171b";
+pub const LIST_COMPANIES_NAME: &str = "Companies";
+
+/// ID of a Company (item of List 'Companies')
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(transparent)]
+pub struct CompanyId(ID);
+
+impl CompanyId {
+    /// Creates CompanyId from the item's numeric id
+    pub fn new(id: ID) -> Self {
+        Self(id)
+    }
+
+    /// Returns the numeric id
+    pub fn value(&self) -> ID {
+        self.0
+    }
+}
+
+impl std::fmt::Display for CompanyId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for CompanyId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<ID>()
+            .map(Self)
+            .map_err(|_| Error::Message(format!("invalid Company id '{}'", s)))
+    }
+}
+
+impl From<CompanyId> for ID {
+    fn from(id: CompanyId) -> ID {
+        id.0
+    }
+}
+
+/// UUID of a Company (item of List 'Companies').
+/// Reference setters accept this type or a `&Company`.
+#[derive(
+    Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(transparent)]
+pub struct CompanyUuid(String);
+
+impl CompanyUuid {
+    /// Creates CompanyUuid from a uuid string. The uuid is not validated;
+    /// use `parse` to check the format.
+    pub fn new<S: Into<String>>(uuid: S) -> Self {
+        Self(uuid.into())
+    }
+
+    /// Returns the uuid string
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for CompanyUuid {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for CompanyUuid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for CompanyUuid {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if super::is_uuid(s) {
+            Ok(Self(s.to_string()))
+        } else {
+            Err(Error::Message(format!("invalid Company uuid '{}'", s)))
+        }
+    }
+}
+
+impl From<&Company> for CompanyUuid {
+    fn from(item: &Company) -> Self {
+        item.get_uuid()
+    }
+}
+
+impl From<&CompanyUuid> for CompanyUuid {
+    fn from(uuid: &CompanyUuid) -> Self {
+        uuid.clone()
+    }
+}
+
+/// CompaniesList - List of Company, using the global client.
+/// For a separate connection, use `WorkspaceClient::companies()`.
+/// Customer companies
+pub struct CompaniesList {}
+
+impl CompaniesList {
+    /// fetch Company by its ID
+    pub async fn get(company_id: CompanyId) -> Result<Company, Error> {
+        CompaniesClient::new(Api::global()).get(company_id).await
+    }
+
+    /// fetch Company by its UUID
+    pub async fn get_by_uuid(company_uuid: &CompanyUuid) -> Result<Company, Error> {
+        CompaniesClient::new(Api::global())
+            .get_by_uuid(company_uuid)
+            .await
+    }
+
+    /// Returns all Company
+    pub async fn get_items() -> Result<Vec<Company>, Error> {
+        CompaniesClient::new(Api::global()).get_items().await
+    }
+
+    /// Returns stream of all Company, fetched a page at a time as the stream is read.
+    /// For other page sizes, or to resume at an offset, use `query()`.
+    pub fn stream_items() -> impl futures::Stream<Item = Result<Company, Error>> + Send {
+        CompaniesClient::new(Api::global()).stream_items()
+    }
+
+    /// Creates query for Company matching conditions, which are checked by the server
+    pub fn query() -> CompanyQuery {
+        CompanyQuery::new(Api::global())
+    }
+
+    /// Initialize builder for creating a new Company
+    pub fn create() -> NewCompanyBuilder {
+        NewCompanyBuilder::new(Api::global())
+    }
+
+    /// Creates builder for updating an existing Company
+    pub fn update(company_id: CompanyId) -> UpdateCompanyBuilder {
+        UpdateCompanyBuilder::new(Api::global(), company_id)
+    }
+
+    /// Archives Company, and returns the archived Company
+    pub async fn archive(company_id: CompanyId) -> Result<Company, Error> {
+        CompaniesClient::new(Api::global())
+            .archive(company_id)
+            .await
+    }
+
+    /// Restores archived Company, and returns the restored Company
+    pub async fn restore(company_id: CompanyId) -> Result<Company, Error> {
+        CompaniesClient::new(Api::global())
+            .restore(company_id)
+            .await
+    }
+
+    /// Deletes Company permanently
+    pub async fn delete(company_id: CompanyId) -> Result<(), Error> {
+        CompaniesClient::new(Api::global()).delete(company_id).await
+    }
+
+    /// Creates Company, sending up to `concurrency` requests at a time.
+    /// Returns the result of each builder, in the order of `builders`, so that
+    /// a failure doesn't affect the other items. Each builder uses the client
+    /// it was created with. Creates are not retried (see `RetryPolicy`).
+    pub async fn create_many(
+        builders: Vec<NewCompanyBuilder>,
+        concurrency: usize,
+    ) -> Vec<Result<Company, Error>> {
+        run_batch(
+            builders
+                .into_iter()
+                .map(|mut b| async move { b.execute().await }),
+            concurrency,
+        )
+        .await
+    }
+
+    /// Updates Company, sending up to `concurrency` requests at a time.
+    /// Returns the result of each builder, in the order of `builders`, so that
+    /// a failure doesn't affect the other items. Each builder uses the client
+    /// it was created with. Updates are not retried (see `RetryPolicy`).
+    pub async fn update_many(
+        builders: Vec<UpdateCompanyBuilder>,
+        concurrency: usize,
+    ) -> Vec<Result<Company, Error>> {
+        run_batch(
+            builders
+                .into_iter()
+                .map(|mut b| async move { b.execute().await }),
+            concurrency,
+        )
+        .await
+    }
+}
+
+/// Client for List 'Companies', from `WorkspaceClient::companies()`.
+/// Items and builders from this client use the client's connection.
+#[derive(Clone)]
+pub struct CompaniesClient {
+    api: Api,
+}
+
+impl CompaniesClient {
+    pub(crate) fn new(api: Api) -> Self {
+        Self { api }
+    }
+
+    /// fetch Company by its ID
+    pub async fn get(&self, company_id: CompanyId) -> Result<Company, Error> {
+        let obj = self
+            .api
+            .get_entry(LIST_COMPANIES_ID, company_id.value())
+            .await?;
+        Ok(Company::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// fetch Company by its UUID
+    pub async fn get_by_uuid(&self, company_uuid: &CompanyUuid) -> Result<Company, Error> {
+        let obj = self
+            .api
+            .get_entry(LIST_COMPANIES_ID, company_uuid.as_str())
+            .await?;
+        Ok(Company::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// Returns all Company
+    pub async fn get_items(&self) -> Result<Vec<Company>, Error> {
+        let entries = self.api.load_entries(LIST_COMPANIES_ID).await?;
+        let items: Vec<Company> = entries
+            .into_iter()
+            .map(|e| Company::new(Arc::new(e), self.api.clone()))
+            .collect();
+        Ok(items)
+    }
+
+    /// Returns stream of all Company, fetched a page at a time as the stream is read.
+    /// For other page sizes, or to resume at an offset, use `query()`.
+    pub fn stream_items(&self) -> impl futures::Stream<Item = Result<Company, Error>> + Send {
+        self.query().stream_items()
+    }
+
+    /// Creates query for Company matching conditions, which are checked by the server
+    pub fn query(&self) -> CompanyQuery {
+        CompanyQuery::new(self.api.clone())
+    }
+
+    /// Initialize builder for creating a new Company
+    pub fn create(&self) -> NewCompanyBuilder {
+        NewCompanyBuilder::new(self.api.clone())
+    }
+
+    /// Creates builder for updating an existing Company
+    pub fn update(&self, company_id: CompanyId) -> UpdateCompanyBuilder {
+        UpdateCompanyBuilder::new(self.api.clone(), company_id)
+    }
+
+    /// Archives Company, and returns the archived Company. Archived items are
+    /// left out of `get_items` and queries, unless a query has `include_archived(true)`.
+    pub async fn archive(&self, company_id: CompanyId) -> Result<Company, Error> {
+        let obj = self
+            .api
+            .archive_entry(LIST_COMPANIES_ID, company_id.value())
+            .await?;
+        Ok(Company::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// Restores archived Company, and returns the restored Company
+    pub async fn restore(&self, company_id: CompanyId) -> Result<Company, Error> {
+        let obj = self
+            .api
+            .restore_entry(LIST_COMPANIES_ID, company_id.value())
+            .await?;
+        Ok(Company::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// Deletes Company permanently
+    pub async fn delete(&self, company_id: CompanyId) -> Result<(), Error> {
+        self.api
+            .delete_entry(LIST_COMPANIES_ID, company_id.value())
+            .await
+    }
+}
+
+/// Company (item of List 'Companies') Customer companies
+pub struct Company {
+    obj: Arc<Entry>,
+    api: Api,
+}
+
+impl Company {
+    /// Creates Company wrapping Zenkit Entry
+    fn new(obj: Arc<Entry>, api: Api) -> Self {
+        Self { obj, api }
+    }
+
+    /// 'Name' in 'Companies'
+    pub const FIELD_NAME_ID: ID = 1;
+    pub const FIELD_NAME_UUID: &'static str = "55940c1d-4dd6-551a-8224-65a601e34527";
+    pub const FIELD_NAME_NAME: &'static str = "Name";
+
+    /// Returns 'Name' -  or None if unset
+    pub fn get_name(&self) -> Option<&str> {
+        self.obj
+            .fields
+            .get("55940c1d-4dd6-551a-8224-65a601e34527_text")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+    }
+
+    /// Returns the text format for 'Name'
+    pub fn get_name_format(&self) -> TextFormat {
+        self.obj
+            .fields
+            .get("55940c1d-4dd6-551a-8224-65a601e34527_textType")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+            // if undefined or unexpected value, return "plain"
+            .map(|f| TextFormat::from_str(f).unwrap_or_default())
+            .unwrap_or_default()
+    }
+
+    /// 'Website' in 'Companies' Company home page
+    pub const FIELD_WEBSITE_ID: ID = 2;
+    pub const FIELD_WEBSITE_UUID: &'static str = "3465be3f-51c2-54cd-b17a-8caaebada77c";
+    pub const FIELD_WEBSITE_NAME: &'static str = "Website";
+
+    /// Returns 'Website' - Company home page or None if unset
+    pub fn get_website(&self) -> Option<&str> {
+        self.obj
+            .fields
+            .get("3465be3f-51c2-54cd-b17a-8caaebada77c_link")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+    }
+
+    /// 'Employees' in 'Companies'
+    pub const FIELD_EMPLOYEES_ID: ID = 3;
+    pub const FIELD_EMPLOYEES_UUID: &'static str = "ae466722-22aa-5692-bbd8-e0c42fdedf6d";
+    pub const FIELD_EMPLOYEES_NAME: &'static str = "Employees";
+
+    /// Returns 'Employees' -  or None if unset
+    pub fn get_employees(&self) -> Option<i64> {
+        self.obj
+            .fields
+            .get("ae466722-22aa-5692-bbd8-e0c42fdedf6d_number")
+            .map(|n| n.as_i64())
+            .unwrap_or_default()
+    }
+
+    /// 'Created' in 'Companies'
+    pub const FIELD_CREATED_ID: ID = 4;
+    pub const FIELD_CREATED_UUID: &'static str = "efb454eb-0dbe-58bb-b567-8e91d74a25b1";
+    pub const FIELD_CREATED_NAME: &'static str = "Created";
+
+    /// 'Updated' in 'Companies'
+    pub const FIELD_UPDATED_ID: ID = 5;
+    pub const FIELD_UPDATED_UUID: &'static str = "846a496b-9dcb-5511-a460-0cf2647a6b0a";
+    pub const FIELD_UPDATED_NAME: &'static str = "Updated";
+
+    /// 'Deprecated' in 'Companies'
+    pub const FIELD_DEPRECATED_ID: ID = 6;
+    pub const FIELD_DEPRECATED_UUID: &'static str = "490d5ec6-9f7f-5d99-9767-39f04ae392cc";
+    pub const FIELD_DEPRECATED_NAME: &'static str = "Deprecated";
+
+    /// 'Created By' in 'Companies'
+    pub const FIELD_CREATED_BY_ID: ID = 7;
+    pub const FIELD_CREATED_BY_UUID: &'static str = "5a37cf9a-5c4c-5bf3-9b4f-cdbb77777818";
+    pub const FIELD_CREATED_BY_NAME: &'static str = "Created By";
+
+    /// 'Updated By' in 'Companies'
+    pub const FIELD_UPDATED_BY_ID: ID = 8;
+    pub const FIELD_UPDATED_BY_UUID: &'static str = "e4a5b2a8-92da-56bd-a487-bb3f0c6d19a5";
+    pub const FIELD_UPDATED_BY_NAME: &'static str = "Updated By";
+
+    /// 'Deprecated By' in 'Companies'
+    pub const FIELD_DEPRECATED_BY_ID: ID = 9;
+    pub const FIELD_DEPRECATED_BY_UUID: &'static str = "d674abe3-1547-559b-8281-d585f0fee7d5";
+    pub const FIELD_DEPRECATED_BY_NAME: &'static str = "Deprecated By";
+
+    /// Returns url to zenkit item
+    pub fn get_zenkit_url(&self) -> String {
+        format!(
+            "{}{}/{}/",
+            super::item_url_base(),
+            LIST_COMPANIES_SHORT_ID,
+            self.obj.short_id
+        )
+    }
+
+    /// Returns the item's display string (based on list's primary field)
+    pub fn get_display_string(&self) -> &str {
+        &self.obj.display_string
+    }
+
+    /// Returns user id that created the item
+    pub fn get_created_by_id(&self) -> ID {
+        self.obj.created_by
+    }
+
+    /// Returns user name that created the item
+    pub fn get_created_by_name(&self) -> Option<&str> {
+        self.obj.created_by_displayname.as_deref()
+    }
+
+    /// Returns user id that created the item
+    pub fn get_updated_by_id(&self) -> ID {
+        self.obj.updated_by
+    }
+
+    /// Returns user name that last updated the item
+    pub fn get_updated_by_name(&self) -> Option<&str> {
+        self.obj.updated_by_displayname.as_deref()
+    }
+
+    /// Returns date the item was created, in UTC
+    pub fn get_created_date(&self) -> &DateTime<Utc> {
+        &self.obj.created_at
+    }
+
+    /// Returns date the item was last updated, in UTC
+    pub fn get_updated_date(&self) -> &DateTime<Utc> {
+        &self.obj.updated_at
+    }
+
+    /// Returns date the item was deprecated, in UTC, or None if item isn't deprecated
+    pub fn get_deprecated_date(&self) -> Option<&DateTime<Utc>> {
+        self.obj.deprecated_at.as_ref()
+    }
+
+    /// Returns user id that deprecated the item, or None if item isn't deprecated
+    pub fn get_deprecated_by_id(&self) -> Option<ID> {
+        self.obj.deprecated_by
+    }
+
+    /// Returns the default sort rank within the list
+    pub fn get_sort_order(&self) -> f32 {
+        self.obj.sort_order
+    }
+
+    /// Returns the item's id within the list
+    pub fn get_id(&self) -> CompanyId {
+        CompanyId::new(self.obj.id)
+    }
+
+    /// Returns the item's uuid
+    pub fn get_uuid(&self) -> CompanyUuid {
+        CompanyUuid::new(self.obj.uuid.as_str())
+    }
+
+    /// Returns the underlying Entry object
+    pub fn get_entry(&self) -> Arc<Entry> {
+        self.obj.clone()
+    }
+
+    /// Archives the item, and returns the archived item
+    pub async fn archive(&self) -> Result<Company, Error> {
+        CompaniesClient::new(self.api.clone())
+            .archive(self.get_id())
+            .await
+    }
+
+    /// Restores the archived item, and returns the restored item
+    pub async fn restore(&self) -> Result<Company, Error> {
+        CompaniesClient::new(self.api.clone())
+            .restore(self.get_id())
+            .await
+    }
+
+    /// Deletes the item permanently
+    pub async fn delete(&self) -> Result<(), Error> {
+        CompaniesClient::new(self.api.clone())
+            .delete(self.get_id())
+            .await
+    }
+} // impl Company
+
+/// Builder for creating new Company
+pub struct NewCompanyBuilder {
+    api: Api,
+    fields: JsonMap,
+    errs: Vec<String>,
+}
+
+impl NewCompanyBuilder {
+    /// Create builder for new Company
+    fn new(api: Api) -> Self {
+        Self {
+            api,
+            fields: JsonMap::new(),
+            errs: Vec::new(),
+        }
+    }
+
+    // internal helper for setting string field
+    #[inline]
+    fn set_s(&mut self, k: &str, v: String) {
+        self.fields.insert(k.to_string(), Value::String(v));
+    }
+    // internal helper for setting json value
+    #[inline]
+    fn set_v(&mut self, k: &str, v: Value) {
+        self.fields.insert(k.to_string(), v);
+    }
+
+    /// Sets text value for Name.
+    /// Uses the field's default TextFormat. To specify format, use _with_format()
+    pub fn set_name<T: AsRef<str>>(&mut self, s: T) -> &mut NewCompanyBuilder {
+        self.set_s(
+            "55940c1d-4dd6-551a-8224-65a601e34527_text",
+            s.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sets text value for Name with format
+    ///
+    pub fn set_name_with_format<T: AsRef<str>>(
+        &mut self,
+        s: T,
+        tf: TextFormat,
+    ) -> &mut NewCompanyBuilder {
+        self.set_s(
+            "55940c1d-4dd6-551a-8224-65a601e34527_text",
+            s.as_ref().to_string(),
+        );
+        self.set_s(
+            "55940c1d-4dd6-551a-8224-65a601e34527_textType",
+            tf.to_string(),
+        );
+        self
+    }
+
+    /// Sets URL Website. Company home page
+    pub fn set_website<T: AsRef<str>>(&mut self, url: T) -> &mut NewCompanyBuilder {
+        self.set_s(
+            "3465be3f-51c2-54cd-b17a-8caaebada77c_link",
+            url.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sets Employees with integer value.
+    ///
+    pub fn set_employees(&mut self, i: i64) -> &mut NewCompanyBuilder {
+        let jn = serde_json::Number::from(i);
+        self.set_v(
+            "ae466722-22aa-5692-bbd8-e0c42fdedf6d_number",
+            Value::Number(jn),
+        );
+        self
+    }
+
+    /// Sends create request to server, returning new Company.
+    pub async fn execute(&mut self) -> Result<Company, Error> {
+        if !self.errs.is_empty() {
+            return Err(Error::Message(format!(
+                "Errors occurred in NewCompanyBuilder: {:?}",
+                &self.errs
+            )));
+        }
+        let map = self.fields.to_owned();
+        let company = self.api.create_entry(201, Value::Object(map)).await?;
+        Ok(Company::new(Arc::new(company), self.api.clone()))
+    }
+} // end impl NewCompanyBuilder
+
+/// Builder for updating existing Company
+pub struct UpdateCompanyBuilder {
+    api: Api,
+    fields: JsonMap,
+    errs: Vec<String>,
+    item_id: CompanyId,
+}
+
+impl UpdateCompanyBuilder {
+    /// Create update builder for Company with id
+    fn new(api: Api, item_id: CompanyId) -> Self {
+        Self {
+            api,
+            fields: JsonMap::new(),
+            errs: Vec::new(),
+            item_id,
+        }
+    }
+
+    // internal helper for setting the updateAction of multi-value fields.
+    // Zenkit applies one action to all fields of a request, so a request can't
+    // append or remove values of one field and replace another.
+    fn set_update_action(&mut self, action: &str) {
+        if let Some(Value::String(prev)) = self.fields.get("updateAction") {
+            if prev != action {
+                self.errs.push(format!(
+                    "Conflicting update actions '{}' and '{}': fields that add or remove values \
+                                 must be updated separately from fields that are set",
+                    prev, action
+                ));
+                return;
+            }
+        }
+        self.set_s("updateAction", action.to_string());
+    }
+
+    // internal helper for setting string field
+    #[inline]
+    fn set_s(&mut self, k: &str, v: String) {
+        self.fields.insert(k.to_string(), Value::String(v));
+    }
+    // internal helper for setting json value
+    #[inline]
+    fn set_v(&mut self, k: &str, v: Value) {
+        self.fields.insert(k.to_string(), v);
+    }
+
+    /// Sets text value for Name.
+    /// TextFormat is unchanged (to specify format, use _with_format())
+    pub fn set_name<T: AsRef<str>>(&mut self, s: T) -> &mut UpdateCompanyBuilder {
+        self.set_s(
+            "55940c1d-4dd6-551a-8224-65a601e34527_text",
+            s.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sets text value for Name with format
+    ///
+    pub fn set_name_with_format<T: AsRef<str>>(
+        &mut self,
+        s: T,
+        tf: TextFormat,
+    ) -> &mut UpdateCompanyBuilder {
+        self.set_s(
+            "55940c1d-4dd6-551a-8224-65a601e34527_text",
+            s.as_ref().to_string(),
+        );
+        self.set_s(
+            "55940c1d-4dd6-551a-8224-65a601e34527_textType",
+            tf.to_string(),
+        );
+        self
+    }
+
+    /// Sets URL Website. Company home page
+    pub fn set_website<T: AsRef<str>>(&mut self, url: T) -> &mut UpdateCompanyBuilder {
+        self.set_s(
+            "3465be3f-51c2-54cd-b17a-8caaebada77c_link",
+            url.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sets Employees with integer value.
+    ///
+    pub fn set_employees(&mut self, i: i64) -> &mut UpdateCompanyBuilder {
+        let jn = serde_json::Number::from(i);
+        self.set_v(
+            "ae466722-22aa-5692-bbd8-e0c42fdedf6d_number",
+            Value::Number(jn),
+        );
+        self
+    }
+
+    /// Sends update request to server, returning updated Company.
+    pub async fn execute(&mut self) -> Result<Company, Error> {
+        if !self.errs.is_empty() {
+            return Err(Error::Message(format!(
+                "Errors occurred in UpdateCompanyBuilder: {:?}",
+                &self.errs
+            )));
+        }
+        let map = self.fields.to_owned();
+        let company = self
+            .api
+            .update_entry(201, self.item_id.value(), Value::Object(map))
+            .await?;
+        Ok(Company::new(Arc::new(company), self.api.clone()))
+    }
+} // end impl UpdateCompanyBuilder
+
+/// Query for Company in List 'Companies'. Filters and sort order
+/// are sent with the request, so only matching items are downloaded.
+/// All conditions must match.
+#[derive(Clone)]
+pub struct CompanyQuery {
+    api: Api,
+    q: EntryQuery,
+    errs: Vec<String>,
+}
+
+impl CompanyQuery {
+    /// Creates query with no conditions
+    fn new(api: Api) -> Self {
+        Self {
+            api,
+            q: EntryQuery::default(),
+            errs: Vec::new(),
+        }
+    }
+
+    /// Returns at most n Company. The default (0) returns all matching items.
+    pub fn limit(mut self, n: usize) -> Self {
+        self.q.limit = n;
+        self
+    }
+
+    /// Skips the first n matching Company, for example, to resume
+    /// a stream after the items already read
+    pub fn skip(mut self, n: usize) -> Self {
+        self.q.skip = n;
+        self
+    }
+
+    /// Sets the number of Company requested per call. The default is 500.
+    pub fn page_size(mut self, n: usize) -> Self {
+        self.q.page_size = n;
+        self
+    }
+
+    /// Includes archived Company if true. By default, they are left out.
+    pub fn include_archived(mut self, include: bool) -> Self {
+        self.q.include_archived = include;
+        self
+    }
+
+    /// Sorts by date created, oldest first
+    pub fn order_by_created_asc(mut self) -> Self {
+        self.q.order_by("created_at", false);
+        self
+    }
+
+    /// Sorts by date created, newest first
+    pub fn order_by_created_desc(mut self) -> Self {
+        self.q.order_by("created_at", true);
+        self
+    }
+
+    /// Sorts by date last updated, oldest first
+    pub fn order_by_updated_asc(mut self) -> Self {
+        self.q.order_by("updated_at", false);
+        self
+    }
+
+    /// Sorts by date last updated, newest first
+    pub fn order_by_updated_desc(mut self) -> Self {
+        self.q.order_by("updated_at", true);
+        self
+    }
+
+    /// Returns the request parameters for all matching items. `get_items` and
+    /// `stream_items` send these in pages of up to `page_size` items.
+    pub fn request(&self) -> GetEntriesRequest {
+        self.q.request()
+    }
+
+    /// Returns the matching Company
+    pub async fn get_items(&self) -> Result<Vec<Company>, Error> {
+        self.check()?;
+        let entries = self.q.load(&self.api, LIST_COMPANIES_ID).await?;
+        Ok(entries
+            .into_iter()
+            .map(|e| Company::new(Arc::new(e), self.api.clone()))
+            .collect())
+    }
+
+    /// Returns stream of the matching Company. Pages are fetched as the stream
+    /// is read, so only one page is in memory at a time. The stream ends after the first
+    /// error; to resume, create the query again with `skip` set to the number of items read.
+    /// Items added or removed while reading can shift the offsets.
+    pub fn stream_items(&self) -> impl futures::Stream<Item = Result<Company, Error>> + Send {
+        use futures::StreamExt;
+        if let Err(e) = self.check() {
+            return futures::stream::once(futures::future::ready(Err(e))).left_stream();
+        }
+        let api = self.api.clone();
+        self.q
+            .stream(self.api.clone(), LIST_COMPANIES_ID)
+            .map(move |r| r.map(|e| Company::new(Arc::new(e), api.clone())))
+            .right_stream()
+    }
+
+    /// Returns error if a condition was invalid, e.g., an unknown label
+    fn check(&self) -> Result<(), Error> {
+        if self.errs.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Message(format!(
+                "Errors occurred in CompanyQuery: {:?}",
+                &self.errs
+            )))
+        }
+    }
+
+    /// Matches Company where 'Name' is the text
+    pub fn name_is(mut self, text: &str) -> Self {
+        self.q.filter(
+            Company::FIELD_NAME_ID,
+            json!({ "modus": "equals", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Company where 'Name' contains the text
+    pub fn name_contains(mut self, text: &str) -> Self {
+        self.q.filter(
+            Company::FIELD_NAME_ID,
+            json!({ "modus": "contains", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Company where 'Name' starts with the text
+    pub fn name_starts_with(mut self, text: &str) -> Self {
+        self.q.filter(
+            Company::FIELD_NAME_ID,
+            json!({ "modus": "startsWith", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Company where 'Name' is not set
+    pub fn name_is_empty(mut self) -> Self {
+        self.q
+            .filter(Company::FIELD_NAME_ID, json!({ "modus": "isEmpty" }));
+        self
+    }
+
+    /// Matches Company where 'Name' is set
+    pub fn name_is_not_empty(mut self) -> Self {
+        self.q
+            .filter(Company::FIELD_NAME_ID, json!({ "modus": "isNotEmpty" }));
+        self
+    }
+
+    /// Sorts by 'Name', ascending
+    pub fn order_by_name_asc(mut self) -> Self {
+        self.q
+            .order_by("55940c1d-4dd6-551a-8224-65a601e34527_text", false);
+        self
+    }
+
+    /// Sorts by 'Name', descending
+    pub fn order_by_name_desc(mut self) -> Self {
+        self.q
+            .order_by("55940c1d-4dd6-551a-8224-65a601e34527_text", true);
+        self
+    }
+
+    /// Matches Company where 'Website' is the text
+    pub fn website_is(mut self, text: &str) -> Self {
+        self.q.filter(
+            Company::FIELD_WEBSITE_ID,
+            json!({ "modus": "equals", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Company where 'Website' contains the text
+    pub fn website_contains(mut self, text: &str) -> Self {
+        self.q.filter(
+            Company::FIELD_WEBSITE_ID,
+            json!({ "modus": "contains", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Company where 'Website' starts with the text
+    pub fn website_starts_with(mut self, text: &str) -> Self {
+        self.q.filter(
+            Company::FIELD_WEBSITE_ID,
+            json!({ "modus": "startsWith", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Company where 'Website' is not set
+    pub fn website_is_empty(mut self) -> Self {
+        self.q
+            .filter(Company::FIELD_WEBSITE_ID, json!({ "modus": "isEmpty" }));
+        self
+    }
+
+    /// Matches Company where 'Website' is set
+    pub fn website_is_not_empty(mut self) -> Self {
+        self.q
+            .filter(Company::FIELD_WEBSITE_ID, json!({ "modus": "isNotEmpty" }));
+        self
+    }
+
+    /// Sorts by 'Website', ascending
+    pub fn order_by_website_asc(mut self) -> Self {
+        self.q
+            .order_by("3465be3f-51c2-54cd-b17a-8caaebada77c_link", false);
+        self
+    }
+
+    /// Sorts by 'Website', descending
+    pub fn order_by_website_desc(mut self) -> Self {
+        self.q
+            .order_by("3465be3f-51c2-54cd-b17a-8caaebada77c_link", true);
+        self
+    }
+
+    /// Matches Company where 'Employees' is the value
+    pub fn employees_eq(self, value: i64) -> Self {
+        self.employees_between(value, value)
+    }
+
+    /// Matches Company where 'Employees' is greater than the value
+    pub fn employees_gt(self, value: i64) -> Self {
+        match value.checked_add(1) {
+            Some(value) => self.employees_ge(value),
+            // nothing is greater than i64::MAX. An empty range matches nothing.
+            None => self.employees_between(1, 0),
+        }
+    }
+
+    /// Matches Company where 'Employees' is greater than or equal to the value
+    pub fn employees_ge(mut self, value: i64) -> Self {
+        self.q.filter(
+            Company::FIELD_EMPLOYEES_ID,
+            json!({ "modus": "greaterOrEqual", "numberFrom": value }),
+        );
+        self
+    }
+
+    /// Matches Company where 'Employees' is less than the value
+    pub fn employees_lt(self, value: i64) -> Self {
+        match value.checked_sub(1) {
+            Some(value) => self.employees_le(value),
+            // nothing is less than i64::MIN. An empty range matches nothing.
+            None => self.employees_between(1, 0),
+        }
+    }
+
+    /// Matches Company where 'Employees' is less than or equal to the value
+    pub fn employees_le(mut self, value: i64) -> Self {
+        self.q.filter(
+            Company::FIELD_EMPLOYEES_ID,
+            json!({ "modus": "lessOrEqual", "numberTo": value }),
+        );
+        self
+    }
+
+    /// Matches Company where 'Employees' is between the values (inclusive)
+    pub fn employees_between(mut self, from: i64, to: i64) -> Self {
+        self.q.filter(
+            Company::FIELD_EMPLOYEES_ID,
+            json!({ "modus": "inRange", "numberFrom": from, "numberTo": to }),
+        );
+        self
+    }
+
+    /// Matches Company where 'Employees' is not set
+    pub fn employees_is_empty(mut self) -> Self {
+        self.q
+            .filter(Company::FIELD_EMPLOYEES_ID, json!({ "modus": "isEmpty" }));
+        self
+    }
+
+    /// Matches Company where 'Employees' is set
+    pub fn employees_is_not_empty(mut self) -> Self {
+        self.q.filter(
+            Company::FIELD_EMPLOYEES_ID,
+            json!({ "modus": "isNotEmpty" }),
+        );
+        self
+    }
+
+    /// Sorts by 'Employees', ascending
+    pub fn order_by_employees_asc(mut self) -> Self {
+        self.q
+            .order_by("ae466722-22aa-5692-bbd8-e0c42fdedf6d_number", false);
+        self
+    }
+
+    /// Sorts by 'Employees', descending
+    pub fn order_by_employees_desc(mut self) -> Self {
+        self.q
+            .order_by("ae466722-22aa-5692-bbd8-e0c42fdedf6d_number", true);
+        self
+    }
+} // end impl CompanyQuery
+
+/// Company data, with one field per Zenkit field, that can be serialized,
+/// cached, or sent between threads. Create from an item with `From<&Company>`,
+/// and save with `to_new_builder` or `to_update_builder` (or the `*_with` versions,
+/// which take the client to save with).
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CompanyRecord {
+    /// Item id, or None if the item hasn't been created
+    pub id: Option<CompanyId>,
+    /// Item uuid, or None if the item hasn't been created
+    pub uuid: Option<CompanyUuid>,
+    /// Name
+    pub name: Option<String>,
+    /// Website
+    pub website: Option<String>,
+    /// Employees
+    pub employees: Option<i64>,
+}
+
+impl From<&Company> for CompanyRecord {
+    fn from(item: &Company) -> Self {
+        Self {
+            id: Some(item.get_id()),
+            uuid: Some(item.get_uuid()),
+            name: item.get_name().map(String::from),
+            website: item.get_website().map(String::from),
+            employees: item.get_employees(),
+        }
+    }
+}
+
+impl CompanyRecord {
+    /// Returns builder for creating a new Company with the record's values,
+    /// using the global api client. Fields that are None, and read-only fields, are not set.
+    pub fn to_new_builder(&self) -> NewCompanyBuilder {
+        self.to_new_builder_with(&CompaniesClient::new(Api::global()))
+    }
+
+    /// Returns builder for creating a new Company with the record's values,
+    /// using the client. Fields that are None, and read-only fields, are not set.
+    pub fn to_new_builder_with(&self, client: &CompaniesClient) -> NewCompanyBuilder {
+        let mut builder = client.create();
+        if let Some(ref v) = self.name {
+            builder.set_name(v);
+        }
+        if let Some(ref v) = self.website {
+            builder.set_website(v);
+        }
+        if let Some(ref v) = self.employees {
+            builder.set_employees(*v);
+        }
+        builder
+    }
+
+    /// Returns builder for updating the Company with the record's id to the record's values,
+    /// using the global api client. Fields that are None, and read-only fields, are not changed.
+    /// Multi-value fields are replaced. Returns an error if the record has no id.
+    pub fn to_update_builder(&self) -> Result<UpdateCompanyBuilder, Error> {
+        self.to_update_builder_with(&CompaniesClient::new(Api::global()))
+    }
+
+    /// Returns builder for updating the Company with the record's id to the record's values,
+    /// using the client. Fields that are None, and read-only fields, are not changed.
+    /// Multi-value fields are replaced. Returns an error if the record has no id.
+    pub fn to_update_builder_with(
+        &self,
+        client: &CompaniesClient,
+    ) -> Result<UpdateCompanyBuilder, Error> {
+        let id = self
+            .id
+            .ok_or_else(|| Error::Message("CompanyRecord has no id".to_string()))?;
+        let mut builder = client.update(id);
+        if let Some(ref v) = self.name {
+            builder.set_name(v);
+        }
+        if let Some(ref v) = self.website {
+            builder.set_website(v);
+        }
+        if let Some(ref v) = self.employees {
+            builder.set_employees(*v);
+        }
+        Ok(builder)
+    }
+}
+
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    fn assert_send<T: Send>(_: &T) {}
+    assert_send_sync::<Company>();
+    assert_send_sync::<NewCompanyBuilder>();
+    assert_send_sync::<UpdateCompanyBuilder>();
+    assert_send_sync::<CompanyRecord>();
+    assert_send(&CompaniesList::get_items());
+    assert_send(&CompaniesList::create().execute());
+    assert_send(&CompaniesList::create_many(Vec::new(), 1));
+};
@@ -0,0 +1,2692 @@
+#![allow(dead_code, unused_imports)]
+//! DealTrackerList Sales opportunities
+//  Generated by zk-codegen v0.5.4 from schema 2387636692c5cac1
+use super::{lookup_label, run_batch, Api, EntryQuery, Error};
+use serde_json::{self, json, Value};
+use std::{convert::AsRef, str::FromStr, sync::Arc};
+use zenkit::types::{DateTime, Entry, File, GetEntriesRequest, JsonMap, TextFormat, Utc, ID};
+
+/// DealTrackerList Sales opportunities
+///
+pub const LIST_DEAL_TRACKER_ID: ID = 202;
+pub const LIST_DEAL_TRACKER_SHORT_ID: &str = "L202";
+pub const LIST_DEAL_TRACKER_UUID: &str = "250746a3-de0f-53f4-b995-953f5e019793";
+pub const LIST_DEAL_TRACKER_NAME: &str = "Deal Tracker";
+
+/// ID of a Deal (item of List 'Deal Tracker')
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(transparent)]
+pub struct DealId(ID);
+
+impl DealId {
+    /// Creates DealId from the item's numeric id
+    pub fn new(id: ID) -> Self {
+        Self(id)
+    }
+
+    /// Returns the numeric id
+    pub fn value(&self) -> ID {
+        self.0
+    }
+}
+
+impl std::fmt::Display for DealId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for DealId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<ID>()
+            .map(Self)
+            .map_err(|_| Error::Message(format!("invalid Deal id '{}'", s)))
+    }
+}
+
+impl From<DealId> for ID {
+    fn from(id: DealId) -> ID {
+        id.0
+    }
+}
+
+/// UUID of a Deal (item of List 'Deal Tracker').
+/// Reference setters accept this type or a `&Deal`.
+#[derive(
+    Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(transparent)]
+pub struct DealUuid(String);
+
+impl DealUuid {
+    /// Creates DealUuid from a uuid string. The uuid is not validated;
+    /// use `parse` to check the format.
+    pub fn new<S: Into<String>>(uuid: S) -> Self {
+        Self(uuid.into())
+    }
+
+    /// Returns the uuid string
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for DealUuid {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for DealUuid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for DealUuid {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if super::is_uuid(s) {
+            Ok(Self(s.to_string()))
+        } else {
+            Err(Error::Message(format!("invalid Deal uuid '{}'", s)))
+        }
+    }
+}
+
+impl From<&Deal> for DealUuid {
+    fn from(item: &Deal) -> Self {
+        item.get_uuid()
+    }
+}
+
+impl From<&DealUuid> for DealUuid {
+    fn from(uuid: &DealUuid) -> Self {
+        uuid.clone()
+    }
+}
+
+/// DealTrackerList - List of Deal, using the global client.
+/// For a separate connection, use `WorkspaceClient::deal_tracker()`.
+/// Sales opportunities
+pub struct DealTrackerList {}
+
+impl DealTrackerList {
+    /// fetch Deal by its ID
+    pub async fn get(deal_id: DealId) -> Result<Deal, Error> {
+        DealTrackerClient::new(Api::global()).get(deal_id).await
+    }
+
+    /// fetch Deal by its UUID
+    pub async fn get_by_uuid(deal_uuid: &DealUuid) -> Result<Deal, Error> {
+        DealTrackerClient::new(Api::global())
+            .get_by_uuid(deal_uuid)
+            .await
+    }
+
+    /// Returns all Deal
+    pub async fn get_items() -> Result<Vec<Deal>, Error> {
+        DealTrackerClient::new(Api::global()).get_items().await
+    }
+
+    /// Returns stream of all Deal, fetched a page at a time as the stream is read.
+    /// For other page sizes, or to resume at an offset, use `query()`.
+    pub fn stream_items() -> impl futures::Stream<Item = Result<Deal, Error>> + Send {
+        DealTrackerClient::new(Api::global()).stream_items()
+    }
+
+    /// Creates query for Deal matching conditions, which are checked by the server
+    pub fn query() -> DealQuery {
+        DealQuery::new(Api::global())
+    }
+
+    /// Initialize builder for creating a new Deal
+    pub fn create() -> NewDealBuilder {
+        NewDealBuilder::new(Api::global())
+    }
+
+    /// Creates builder for updating an existing Deal
+    pub fn update(deal_id: DealId) -> UpdateDealBuilder {
+        UpdateDealBuilder::new(Api::global(), deal_id)
+    }
+
+    /// Archives Deal, and returns the archived Deal
+    pub async fn archive(deal_id: DealId) -> Result<Deal, Error> {
+        DealTrackerClient::new(Api::global()).archive(deal_id).await
+    }
+
+    /// Restores archived Deal, and returns the restored Deal
+    pub async fn restore(deal_id: DealId) -> Result<Deal, Error> {
+        DealTrackerClient::new(Api::global()).restore(deal_id).await
+    }
+
+    /// Deletes Deal permanently
+    pub async fn delete(deal_id: DealId) -> Result<(), Error> {
+        DealTrackerClient::new(Api::global()).delete(deal_id).await
+    }
+
+    /// Creates Deal, sending up to `concurrency` requests at a time.
+    /// Returns the result of each builder, in the order of `builders`, so that
+    /// a failure doesn't affect the other items. Each builder uses the client
+    /// it was created with. Creates are not retried (see `RetryPolicy`).
+    pub async fn create_many(
+        builders: Vec<NewDealBuilder>,
+        concurrency: usize,
+    ) -> Vec<Result<Deal, Error>> {
+        run_batch(
+            builders
+                .into_iter()
+                .map(|mut b| async move { b.execute().await }),
+            concurrency,
+        )
+        .await
+    }
+
+    /// Updates Deal, sending up to `concurrency` requests at a time.
+    /// Returns the result of each builder, in the order of `builders`, so that
+    /// a failure doesn't affect the other items. Each builder uses the client
+    /// it was created with. Updates are not retried (see `RetryPolicy`).
+    pub async fn update_many(
+        builders: Vec<UpdateDealBuilder>,
+        concurrency: usize,
+    ) -> Vec<Result<Deal, Error>> {
+        run_batch(
+            builders
+                .into_iter()
+                .map(|mut b| async move { b.execute().await }),
+            concurrency,
+        )
+        .await
+    }
+}
+
+/// Client for List 'Deal Tracker', from `WorkspaceClient::deal_tracker()`.
+/// Items and builders from this client use the client's connection.
+#[derive(Clone)]
+pub struct DealTrackerClient {
+    api: Api,
+}
+
+impl DealTrackerClient {
+    pub(crate) fn new(api: Api) -> Self {
+        Self { api }
+    }
+
+    /// fetch Deal by its ID
+    pub async fn get(&self, deal_id: DealId) -> Result<Deal, Error> {
+        let obj = self
+            .api
+            .get_entry(LIST_DEAL_TRACKER_ID, deal_id.value())
+            .await?;
+        Ok(Deal::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// fetch Deal by its UUID
+    pub async fn get_by_uuid(&self, deal_uuid: &DealUuid) -> Result<Deal, Error> {
+        let obj = self
+            .api
+            .get_entry(LIST_DEAL_TRACKER_ID, deal_uuid.as_str())
+            .await?;
+        Ok(Deal::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// Returns all Deal
+    pub async fn get_items(&self) -> Result<Vec<Deal>, Error> {
+        let entries = self.api.load_entries(LIST_DEAL_TRACKER_ID).await?;
+        let items: Vec<Deal> = entries
+            .into_iter()
+            .map(|e| Deal::new(Arc::new(e), self.api.clone()))
+            .collect();
+        Ok(items)
+    }
+
+    /// Returns stream of all Deal, fetched a page at a time as the stream is read.
+    /// For other page sizes, or to resume at an offset, use `query()`.
+    pub fn stream_items(&self) -> impl futures::Stream<Item = Result<Deal, Error>> + Send {
+        self.query().stream_items()
+    }
+
+    /// Creates query for Deal matching conditions, which are checked by the server
+    pub fn query(&self) -> DealQuery {
+        DealQuery::new(self.api.clone())
+    }
+
+    /// Initialize builder for creating a new Deal
+    pub fn create(&self) -> NewDealBuilder {
+        NewDealBuilder::new(self.api.clone())
+    }
+
+    /// Creates builder for updating an existing Deal
+    pub fn update(&self, deal_id: DealId) -> UpdateDealBuilder {
+        UpdateDealBuilder::new(self.api.clone(), deal_id)
+    }
+
+    /// Archives Deal, and returns the archived Deal. Archived items are
+    /// left out of `get_items` and queries, unless a query has `include_archived(true)`.
+    pub async fn archive(&self, deal_id: DealId) -> Result<Deal, Error> {
+        let obj = self
+            .api
+            .archive_entry(LIST_DEAL_TRACKER_ID, deal_id.value())
+            .await?;
+        Ok(Deal::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// Restores archived Deal, and returns the restored Deal
+    pub async fn restore(&self, deal_id: DealId) -> Result<Deal, Error> {
+        let obj = self
+            .api
+            .restore_entry(LIST_DEAL_TRACKER_ID, deal_id.value())
+            .await?;
+        Ok(Deal::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// Deletes Deal permanently
+    pub async fn delete(&self, deal_id: DealId) -> Result<(), Error> {
+        self.api
+            .delete_entry(LIST_DEAL_TRACKER_ID, deal_id.value())
+            .await
+    }
+}
+
+/// Deal (item of List 'Deal Tracker') Sales opportunities
+pub struct Deal {
+    obj: Arc<Entry>,
+    api: Api,
+}
+
+impl Deal {
+    /// Creates Deal wrapping Zenkit Entry
+    fn new(obj: Arc<Entry>, api: Api) -> Self {
+        Self { obj, api }
+    }
+
+    /// 'Title' in 'Deal Tracker' Name of the deal
+    pub const FIELD_TITLE_ID: ID = 20;
+    pub const FIELD_TITLE_UUID: &'static str = "bd2e279e-aba9-5d28-8cb0-29742c3744dc";
+    pub const FIELD_TITLE_NAME: &'static str = "Title";
+
+    /// Returns 'Title' - Name of the deal or None if unset
+    pub fn get_title(&self) -> Option<&str> {
+        self.obj
+            .fields
+            .get("bd2e279e-aba9-5d28-8cb0-29742c3744dc_text")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+    }
+
+    /// Returns the text format for 'Title'
+    pub fn get_title_format(&self) -> TextFormat {
+        self.obj
+            .fields
+            .get("bd2e279e-aba9-5d28-8cb0-29742c3744dc_textType")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+            // if undefined or unexpected value, return "plain"
+            .map(|f| TextFormat::from_str(f).unwrap_or_default())
+            .unwrap_or_default()
+    }
+
+    /// 'Stage' in 'Deal Tracker' Current sales stage
+    pub const FIELD_STAGE_ID: ID = 21;
+    pub const FIELD_STAGE_UUID: &'static str = "c7f61cc2-9256-5bd4-9ad6-205bf1521614";
+    pub const FIELD_STAGE_NAME: &'static str = "Stage";
+
+    pub const LABEL_STAGE_IN_PROGRESS_ID: ID = 301;
+
+    /// Returns true if 'In progress' is set on 'Stage'
+    /// Current sales stage
+    pub fn is_stage_in_progress(&self) -> bool {
+        self.obj
+            .fields
+            .get("c7f61cc2-9256-5bd4-9ad6-205bf1521614_categories")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| v.iter().any(|n| n == Deal::LABEL_STAGE_IN_PROGRESS_ID))
+            .unwrap_or_default()
+    }
+
+    pub const LABEL_STAGE_WON_ID: ID = 302;
+
+    /// Returns true if 'Won' is set on 'Stage'
+    /// Current sales stage
+    pub fn is_stage_won(&self) -> bool {
+        self.obj
+            .fields
+            .get("c7f61cc2-9256-5bd4-9ad6-205bf1521614_categories")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| v.iter().any(|n| n == Deal::LABEL_STAGE_WON_ID))
+            .unwrap_or_default()
+    }
+
+    pub const LABEL_STAGE_LOST_ID: ID = 303;
+
+    /// Returns true if 'Lost' is set on 'Stage'
+    /// Current sales stage
+    pub fn is_stage_lost(&self) -> bool {
+        self.obj
+            .fields
+            .get("c7f61cc2-9256-5bd4-9ad6-205bf1521614_categories")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| v.iter().any(|n| n == Deal::LABEL_STAGE_LOST_ID))
+            .unwrap_or_default()
+    }
+
+    /// Returns the id for a label, or None if not valid for this field
+    pub fn label_id_for_stage(label: &str) -> Option<ID> {
+        lookup_label(&Deal::LABELS_STAGE, label)
+    }
+
+    /// label index used for converting from label name to id (sorted)
+    const LABELS_STAGE: [(&'static str, u64); 3] = [
+        ("In progress", Deal::LABEL_STAGE_IN_PROGRESS_ID),
+        ("Lost", Deal::LABEL_STAGE_LOST_ID),
+        ("Won", Deal::LABEL_STAGE_WON_ID),
+    ];
+
+    /// Returns value of 'Stage', or None if field is unset
+    /// or its label is not a variant of DealStage
+    /// Current sales stage
+    pub fn get_stage(&self) -> Option<DealStage> {
+        self.get_stage_id().and_then(DealStage::from_id)
+    }
+
+    /// Returns label value of 'Stage', or None if field is unset
+    /// Current sales stage
+    pub fn get_stage_label(&self) -> Option<&str> {
+        self.obj
+            .fields
+            .get("c7f61cc2-9256-5bd4-9ad6-205bf1521614_categories_sort")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| {
+                v.iter()
+                    .take(1)
+                    .filter_map(|val| val.as_object())
+                    .filter_map(|val| val.get("name"))
+                    .filter_map(|val| val.as_str())
+                    .next()
+                    .unwrap_or_default()
+            })
+    }
+
+    /// Returns id value of 'Stage', or None if field is unset
+    /// Current sales stage
+    pub fn get_stage_id(&self) -> Option<ID> {
+        self.obj
+            .fields
+            .get("c7f61cc2-9256-5bd4-9ad6-205bf1521614_categories")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| {
+                v.iter()
+                    .take(1)
+                    .filter_map(|val| val.as_i64())
+                    .next()
+                    .map(|val| val as ID)
+            })
+            .unwrap_or_default()
+    }
+
+    /// 'Tags' in 'Deal Tracker' Deal tags
+    pub const FIELD_TAGS_ID: ID = 22;
+    pub const FIELD_TAGS_UUID: &'static str = "e5fdac3d-958a-5ff8-80b8-ff1524b50822";
+    pub const FIELD_TAGS_NAME: &'static str = "Tags";
+
+    pub const LABEL_TAGS_HOT_ID: ID = 311;
+
+    /// Returns true if 'Hot' is set on 'Tags'
+    /// Deal tags
+    pub fn is_tags_hot(&self) -> bool {
+        self.obj
+            .fields
+            .get("e5fdac3d-958a-5ff8-80b8-ff1524b50822_categories")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| v.iter().any(|n| n == Deal::LABEL_TAGS_HOT_ID))
+            .unwrap_or_default()
+    }
+
+    pub const LABEL_TAGS_ENTERPRISE_ID: ID = 312;
+
+    /// Returns true if 'Enterprise' is set on 'Tags'
+    /// Deal tags
+    pub fn is_tags_enterprise(&self) -> bool {
+        self.obj
+            .fields
+            .get("e5fdac3d-958a-5ff8-80b8-ff1524b50822_categories")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| v.iter().any(|n| n == Deal::LABEL_TAGS_ENTERPRISE_ID))
+            .unwrap_or_default()
+    }
+
+    pub const LABEL_TAGS_RENEWAL_ID: ID = 313;
+
+    /// Returns true if 'Renewal' is set on 'Tags'
+    /// Deal tags
+    pub fn is_tags_renewal(&self) -> bool {
+        self.obj
+            .fields
+            .get("e5fdac3d-958a-5ff8-80b8-ff1524b50822_categories")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| v.iter().any(|n| n == Deal::LABEL_TAGS_RENEWAL_ID))
+            .unwrap_or_default()
+    }
+
+    /// Returns the id for a label, or None if not valid for this field
+    pub fn label_id_for_tags(label: &str) -> Option<ID> {
+        lookup_label(&Deal::LABELS_TAGS, label)
+    }
+
+    /// label index used for converting from label name to id (sorted)
+    const LABELS_TAGS: [(&'static str, u64); 3] = [
+        ("Enterprise", Deal::LABEL_TAGS_ENTERPRISE_ID),
+        ("Hot", Deal::LABEL_TAGS_HOT_ID),
+        ("Renewal", Deal::LABEL_TAGS_RENEWAL_ID),
+    ];
+
+    /// Returns set of labels set on 'Tags'.
+    /// Labels that are not variants of DealTags are skipped.
+    /// Deal tags
+    pub fn get_tags(&self) -> std::collections::BTreeSet<DealTags> {
+        self.get_tags_ids()
+            .into_iter()
+            .filter_map(DealTags::from_id)
+            .collect()
+    }
+
+    /// Returns list of labels (as ids) set on 'Tags'
+    /// Deal tags
+    pub fn get_tags_ids(&self) -> Vec<ID> {
+        self.obj
+            .fields
+            .get("e5fdac3d-958a-5ff8-80b8-ff1524b50822_categories_sort")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| {
+                v.iter()
+                    .filter_map(|val| val.as_object())
+                    .filter_map(|val| val.get("id"))
+                    .filter_map(|val| val.as_u64())
+                    .collect()
+            })
+            .unwrap_or_else(Vec::new)
+    }
+    /// returns list of values set on 'Tags'
+    /// Deal tags
+    pub fn get_tags_labels(&self) -> Vec<&str> {
+        self.obj
+            .fields
+            .get("e5fdac3d-958a-5ff8-80b8-ff1524b50822_categories_sort")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| {
+                v.iter()
+                    .filter_map(|val| val.as_object())
+                    .filter_map(|val| val.get("name"))
+                    .filter_map(|val| val.as_str())
+                    .collect()
+            })
+            .unwrap_or_else(Vec::new)
+    }
+
+    /// 'Deal Probability In %' in 'Deal Tracker'
+    pub const FIELD_DEAL_PROBABILITY_IN_ID: ID = 23;
+    pub const FIELD_DEAL_PROBABILITY_IN_UUID: &'static str = "bfd5d530-913b-53b2-9d0f-78d3c8f7f226";
+    pub const FIELD_DEAL_PROBABILITY_IN_NAME: &'static str = "Deal Probability In %";
+
+    /// Returns 'Deal Probability In %' -  or None if unset
+    pub fn get_deal_probability_in(&self) -> Option<i64> {
+        self.obj
+            .fields
+            .get("bfd5d530-913b-53b2-9d0f-78d3c8f7f226_number")
+            .map(|n| n.as_i64())
+            .unwrap_or_default()
+    }
+
+    /// 'Amount' in 'Deal Tracker' Order amount
+    pub const FIELD_AMOUNT_ID: ID = 24;
+    pub const FIELD_AMOUNT_UUID: &'static str = "cf2d45d6-0fd2-51ff-83c0-4d62304c9e52";
+    pub const FIELD_AMOUNT_NAME: &'static str = "Amount";
+
+    /// Returns 'Amount' - Order amount or None if unset
+    pub fn get_amount(&self) -> Option<f64> {
+        self.obj
+            .fields
+            .get("cf2d45d6-0fd2-51ff-83c0-4d62304c9e52_number")
+            .map(|n| n.as_f64())
+            .unwrap_or_default()
+    }
+
+    /// 'Order Total' in 'Deal Tracker' Computed order total
+    pub const FIELD_ORDER_TOTAL_ID: ID = 25;
+    pub const FIELD_ORDER_TOTAL_UUID: &'static str = "7dc2c941-7938-5644-a624-52c253b9b02c";
+    pub const FIELD_ORDER_TOTAL_NAME: &'static str = "Order Total";
+
+    /// Returns value of formula field 'Order Total', or None
+    /// if the field isn't calculated, or if there was an error calculating the value.
+    /// For description of the error, use get_order_total_error()
+    pub fn get_order_total(&self) -> Option<f64> {
+        // Only return value if there's no error
+        // or if there is some other reason formula value can be null
+        if let None = self.get_order_total_error() {
+            return self
+                .obj
+                .fields
+                .get("7dc2c941-7938-5644-a624-52c253b9b02c_value")
+                .map(|n| n.as_f64())
+                .unwrap_or_default();
+        }
+        None
+    }
+
+    /// Returns error message for formula Order Total, if any
+    pub fn get_order_total_error(&self) -> Option<&String> {
+        if let Some(Value::String(s)) = self
+            .obj
+            .fields
+            .get("7dc2c941-7938-5644-a624-52c253b9b02c_valueErrorMessage")
+        {
+            if s.len() > 0 {
+                return Some(s);
+            }
+        }
+        None
+    }
+
+    /// 'Closing Date' in 'Deal Tracker'
+    pub const FIELD_CLOSING_DATE_ID: ID = 26;
+    pub const FIELD_CLOSING_DATE_UUID: &'static str = "4e88bb09-7a96-5448-8d37-e52e5707bb26";
+    pub const FIELD_CLOSING_DATE_NAME: &'static str = "Closing Date";
+
+    /// Returns 'Closing Date' in UTC , or None if unset
+    pub fn get_closing_date(&self) -> Option<DateTime<Utc>> {
+        self.obj
+            .fields
+            .get("4e88bb09-7a96-5448-8d37-e52e5707bb26_date")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+            .map(|s| s.parse::<DateTime<Utc>>().ok())
+            .unwrap_or_default()
+    }
+
+    /// 'Owner' in 'Deal Tracker' Sales rep
+    pub const FIELD_OWNER_ID: ID = 27;
+    pub const FIELD_OWNER_UUID: &'static str = "03af7f30-2542-5ccc-b57e-8633b8408efc";
+    pub const FIELD_OWNER_NAME: &'static str = "Owner";
+
+    /// Returns the person's name in Owner, or None if unset
+    /// Sales rep
+    pub fn get_owner_name(&self) -> Option<&str> {
+        self.obj
+            .fields
+            .get("03af7f30-2542-5ccc-b57e-8633b8408efc_persons_sort")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| {
+                v.iter()
+                    .take(1)
+                    .filter_map(|val| val.as_object())
+                    .filter_map(|val| val.get("displayname"))
+                    .filter_map(|val| val.as_str())
+                    .next()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the person's id in Owner, or None if unset
+    /// Sales rep
+    pub fn get_owner_id(&self) -> Option<ID> {
+        self.obj
+            .fields
+            .get("03af7f30-2542-5ccc-b57e-8633b8408efc_persons_sort")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| {
+                v.iter()
+                    .take(1)
+                    .filter_map(|val| val.as_object())
+                    .filter_map(|val| val.get("id"))
+                    .filter_map(|val| val.as_u64())
+                    .next()
+            })
+            .unwrap_or_default()
+    }
+
+    /// 'Team' in 'Deal Tracker'
+    pub const FIELD_TEAM_ID: ID = 28;
+    pub const FIELD_TEAM_UUID: &'static str = "fcc06a80-df72-52e6-bff3-f1dae4da7365";
+    pub const FIELD_TEAM_NAME: &'static str = "Team";
+
+    /// Returns the names of persons in Team
+    ///
+    pub fn get_team_names(&self) -> Vec<&str> {
+        self.obj
+            .fields
+            .get("fcc06a80-df72-52e6-bff3-f1dae4da7365_persons_sort")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| {
+                v.iter()
+                    .filter_map(|val| val.as_object())
+                    .filter_map(|val| val.get("displayname"))
+                    .filter_map(|val| val.as_str())
+                    .collect()
+            })
+            .unwrap_or_else(Vec::new)
+    }
+    /// Returns the ids of persons in Team
+    ///
+    pub fn get_team_ids(&self) -> Vec<ID> {
+        self.obj
+            .fields
+            .get("fcc06a80-df72-52e6-bff3-f1dae4da7365_persons_sort")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| {
+                v.iter()
+                    .filter_map(|val| val.as_object())
+                    .filter_map(|val| val.get("id"))
+                    .filter_map(|val| val.as_u64())
+                    .collect()
+            })
+            .unwrap_or_else(Vec::new)
+    }
+
+    /// 'Approved' in 'Deal Tracker'
+    pub const FIELD_APPROVED_ID: ID = 29;
+    pub const FIELD_APPROVED_UUID: &'static str = "dd0a353c-1e8b-5773-8022-27f5b6fd13b6";
+    pub const FIELD_APPROVED_NAME: &'static str = "Approved";
+
+    /// Returns true if 'Approved' is checked.  
+    pub fn is_checked_approved(&self) -> bool {
+        self.obj
+            .fields
+            .get("dd0a353c-1e8b-5773-8022-27f5b6fd13b6_checked")
+            .map(|n| n.as_bool())
+            .unwrap_or_default()
+            .unwrap_or_default()
+    }
+
+    /// 'Attachments' in 'Deal Tracker'
+    pub const FIELD_ATTACHMENTS_ID: ID = 30;
+    pub const FIELD_ATTACHMENTS_UUID: &'static str = "844104a4-86f0-57b4-ba64-70893bcf9b18";
+    pub const FIELD_ATTACHMENTS_NAME: &'static str = "Attachments";
+
+    /// Returns files from 'Attachments' -
+    pub fn get_attachments(&self) -> Vec<File> {
+        match self
+            .obj
+            .fields
+            .get("844104a4-86f0-57b4-ba64-70893bcf9b18_filesData")
+        {
+            Some(v) => match serde_json::from_value::<Vec<File>>(v.clone()) {
+                Ok(files) => files,
+                Err(e) => {
+                    println!("Deser files failed: {:#?}", e);
+                    println!("Original data: {:#?}", v);
+                    Vec::new()
+                }
+            },
+            None => Vec::new(),
+        }
+    }
+
+    /// 'Company' in 'Deal Tracker'
+    pub const FIELD_COMPANY_ID: ID = 31;
+    pub const FIELD_COMPANY_UUID: &'static str = "bf0c5276-920d-5b13-b079-14919038a7e2";
+    pub const FIELD_COMPANY_NAME: &'static str = "Company";
+
+    /// Returns the reference in Company (to Companies, or None if unset
+    ///
+    pub fn get_company_uuid(&self) -> Option<&str> {
+        self.obj
+            .fields
+            .get("bf0c5276-920d-5b13-b079-14919038a7e2_references_sort")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| {
+                v.iter()
+                    .take(1)
+                    .filter_map(|val| val.as_object())
+                    .filter_map(|val| val.get("uuid"))
+                    .filter_map(|val| val.as_str())
+                    .next()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Fetches the Company referenced in Company, or None if unset
+    ///
+    pub async fn get_company(&self) -> Result<Option<super::companies::Company>, Error> {
+        match self.get_company_uuid() {
+            Some(uuid) => {
+                let uuid = super::companies::CompanyUuid::new(uuid);
+                let client = super::companies::CompaniesClient::new(self.api.clone());
+                Ok(Some(client.get_by_uuid(&uuid).await?))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// 'Related Deals' in 'Deal Tracker'
+    pub const FIELD_RELATED_DEALS_ID: ID = 32;
+    pub const FIELD_RELATED_DEALS_UUID: &'static str = "6b8b96d4-a26a-5b31-a75a-0a8e05282d39";
+    pub const FIELD_RELATED_DEALS_NAME: &'static str = "Related Deals";
+
+    /// Returns the references in Related Deals to Deal Tracker
+    ///
+    pub fn get_related_deals_uuids(&self) -> Vec<&str> {
+        self.obj
+            .fields
+            .get("6b8b96d4-a26a-5b31-a75a-0a8e05282d39_references_sort")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| {
+                v.iter()
+                    .filter_map(|val| val.as_object())
+                    .filter_map(|val| val.get("uuid"))
+                    .filter_map(|val| val.as_str())
+                    .collect()
+            })
+            .unwrap_or_else(Vec::new)
+    }
+
+    /// Fetches the Deal items referenced in Related Deals
+    ///
+    pub async fn get_related_deals(&self) -> Result<Vec<super::deal_tracker::Deal>, Error> {
+        let client = super::deal_tracker::DealTrackerClient::new(self.api.clone());
+        let mut items = Vec::new();
+        for uuid in self.get_related_deals_uuids() {
+            let uuid = super::deal_tracker::DealUuid::new(uuid);
+            items.push(client.get_by_uuid(&uuid).await?);
+        }
+        Ok(items)
+    }
+
+    /// 'Subtasks' in 'Deal Tracker'
+    pub const FIELD_SUBTASKS_ID: ID = 33;
+    pub const FIELD_SUBTASKS_UUID: &'static str = "c18d1f7e-20ea-5769-9e9b-ebce76ed6b79";
+    pub const FIELD_SUBTASKS_NAME: &'static str = "Subtasks";
+
+    /// Returns subitem references in Subtasks hierarchy
+    ///
+    pub fn get_subtasks_uuids(&self) -> Vec<&str> {
+        if self.is_connected_subtasks() {
+            self.obj
+                .fields
+                .get("c18d1f7e-20ea-5769-9e9b-ebce76ed6b79_references_sort")
+                .map(|v| v.as_array())
+                .unwrap_or_default()
+                .map(|v| {
+                    v.iter()
+                        .filter_map(|val| val.as_object())
+                        .filter_map(|val| val.get("uuid"))
+                        .filter_map(|val| val.as_str())
+                        .collect()
+                })
+                .unwrap_or_else(Vec::new)
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Returns parent references in Subtasks hierarchy
+    ///
+    pub fn get_subtasks_parents(&self) -> Vec<&str> {
+        if self.is_connected_subtasks() {
+            self.obj
+                .fields
+                .get("c18d1f7e-20ea-5769-9e9b-ebce76ed6b79_parents")
+                .map(|v| v.as_array())
+                .unwrap_or_default()
+                .map(|v| v.iter().filter_map(|v| v.as_str()).collect())
+                .unwrap_or_else(Vec::new)
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Returns true if this item is connected in the Subtasks hierarchy
+    ///
+    pub fn is_connected_subtasks(&self) -> bool {
+        self.obj
+            .fields
+            .get("c18d1f7e-20ea-5769-9e9b-ebce76ed6b79_connected")
+            .map(|v| v.as_bool())
+            .unwrap_or_default()
+            .unwrap_or_default()
+    }
+
+    /// 'Homepage' in 'Deal Tracker'
+    pub const FIELD_HOMEPAGE_ID: ID = 34;
+    pub const FIELD_HOMEPAGE_UUID: &'static str = "1c064829-21cb-5155-bc07-a733f349e298";
+    pub const FIELD_HOMEPAGE_NAME: &'static str = "Homepage";
+
+    /// Returns 'Homepage' -  or None if unset
+    pub fn get_homepage(&self) -> Option<&str> {
+        self.obj
+            .fields
+            .get("1c064829-21cb-5155-bc07-a733f349e298_link")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+    }
+
+    /// Returns url to zenkit item
+    pub fn get_zenkit_url(&self) -> String {
+        format!(
+            "{}{}/{}/",
+            super::item_url_base(),
+            LIST_DEAL_TRACKER_SHORT_ID,
+            self.obj.short_id
+        )
+    }
+
+    /// Returns the item's display string (based on list's primary field)
+    pub fn get_display_string(&self) -> &str {
+        &self.obj.display_string
+    }
+
+    /// Returns user id that created the item
+    pub fn get_created_by_id(&self) -> ID {
+        self.obj.created_by
+    }
+
+    /// Returns user name that created the item
+    pub fn get_created_by_name(&self) -> Option<&str> {
+        self.obj.created_by_displayname.as_deref()
+    }
+
+    /// Returns user id that created the item
+    pub fn get_updated_by_id(&self) -> ID {
+        self.obj.updated_by
+    }
+
+    /// Returns user name that last updated the item
+    pub fn get_updated_by_name(&self) -> Option<&str> {
+        self.obj.updated_by_displayname.as_deref()
+    }
+
+    /// Returns date the item was created, in UTC
+    pub fn get_created_date(&self) -> &DateTime<Utc> {
+        &self.obj.created_at
+    }
+
+    /// Returns date the item was last updated, in UTC
+    pub fn get_updated_date(&self) -> &DateTime<Utc> {
+        &self.obj.updated_at
+    }
+
+    /// Returns date the item was deprecated, in UTC, or None if item isn't deprecated
+    pub fn get_deprecated_date(&self) -> Option<&DateTime<Utc>> {
+        self.obj.deprecated_at.as_ref()
+    }
+
+    /// Returns user id that deprecated the item, or None if item isn't deprecated
+    pub fn get_deprecated_by_id(&self) -> Option<ID> {
+        self.obj.deprecated_by
+    }
+
+    /// Returns the default sort rank within the list
+    pub fn get_sort_order(&self) -> f32 {
+        self.obj.sort_order
+    }
+
+    /// Returns the item's id within the list
+    pub fn get_id(&self) -> DealId {
+        DealId::new(self.obj.id)
+    }
+
+    /// Returns the item's uuid
+    pub fn get_uuid(&self) -> DealUuid {
+        DealUuid::new(self.obj.uuid.as_str())
+    }
+
+    /// Returns the underlying Entry object
+    pub fn get_entry(&self) -> Arc<Entry> {
+        self.obj.clone()
+    }
+
+    /// Archives the item, and returns the archived item
+    pub async fn archive(&self) -> Result<Deal, Error> {
+        DealTrackerClient::new(self.api.clone())
+            .archive(self.get_id())
+            .await
+    }
+
+    /// Restores the archived item, and returns the restored item
+    pub async fn restore(&self) -> Result<Deal, Error> {
+        DealTrackerClient::new(self.api.clone())
+            .restore(self.get_id())
+            .await
+    }
+
+    /// Deletes the item permanently
+    pub async fn delete(&self) -> Result<(), Error> {
+        DealTrackerClient::new(self.api.clone())
+            .delete(self.get_id())
+            .await
+    }
+} // impl Deal
+
+/// Builder for creating new Deal
+pub struct NewDealBuilder {
+    api: Api,
+    fields: JsonMap,
+    errs: Vec<String>,
+}
+
+impl NewDealBuilder {
+    /// Create builder for new Deal
+    fn new(api: Api) -> Self {
+        Self {
+            api,
+            fields: JsonMap::new(),
+            errs: Vec::new(),
+        }
+    }
+
+    // internal helper for setting string field
+    #[inline]
+    fn set_s(&mut self, k: &str, v: String) {
+        self.fields.insert(k.to_string(), Value::String(v));
+    }
+    // internal helper for setting json value
+    #[inline]
+    fn set_v(&mut self, k: &str, v: Value) {
+        self.fields.insert(k.to_string(), v);
+    }
+
+    /// Sets text value for Title. Name of the deal
+    /// Uses the field's default TextFormat. To specify format, use _with_format()
+    pub fn set_title<T: AsRef<str>>(&mut self, s: T) -> &mut NewDealBuilder {
+        self.set_s(
+            "bd2e279e-aba9-5d28-8cb0-29742c3744dc_text",
+            s.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sets text value for Title with format
+    /// Name of the deal
+    pub fn set_title_with_format<T: AsRef<str>>(
+        &mut self,
+        s: T,
+        tf: TextFormat,
+    ) -> &mut NewDealBuilder {
+        self.set_s(
+            "bd2e279e-aba9-5d28-8cb0-29742c3744dc_text",
+            s.as_ref().to_string(),
+        );
+        self.set_s(
+            "bd2e279e-aba9-5d28-8cb0-29742c3744dc_textType",
+            tf.to_string(),
+        );
+        self
+    }
+
+    /// Sets Stage to In progress. Current sales stage
+    pub fn set_stage_in_progress(&mut self) -> &mut NewDealBuilder {
+        self.set_v(
+            "c7f61cc2-9256-5bd4-9ad6-205bf1521614_categories",
+            json!(vec![Deal::LABEL_STAGE_IN_PROGRESS_ID]),
+        );
+        self
+    }
+
+    /// Sets Stage to Won. Current sales stage
+    pub fn set_stage_won(&mut self) -> &mut NewDealBuilder {
+        self.set_v(
+            "c7f61cc2-9256-5bd4-9ad6-205bf1521614_categories",
+            json!(vec![Deal::LABEL_STAGE_WON_ID]),
+        );
+        self
+    }
+
+    /// Sets Stage to Lost. Current sales stage
+    pub fn set_stage_lost(&mut self) -> &mut NewDealBuilder {
+        self.set_v(
+            "c7f61cc2-9256-5bd4-9ad6-205bf1521614_categories",
+            json!(vec![Deal::LABEL_STAGE_LOST_ID]),
+        );
+        self
+    }
+
+    /// Set Stage by label-id. Current sales stage
+    pub fn set_stage_id(&mut self, id: ID) -> &mut NewDealBuilder {
+        self.set_v(
+            "c7f61cc2-9256-5bd4-9ad6-205bf1521614_categories",
+            json!(vec![id]),
+        );
+        self
+    }
+    /// Set Stage. Current sales stage
+    pub fn set_stage(&mut self, value: DealStage) -> &mut NewDealBuilder {
+        self.set_stage_id(value.id())
+    }
+    /// Set Stage by label-name. Current sales stage
+    pub fn set_stage_label(&mut self, label: &str) -> &mut NewDealBuilder {
+        match lookup_label(&Deal::LABELS_STAGE, label) {
+            Some(id) => {
+                self.set_v(
+                    "c7f61cc2-9256-5bd4-9ad6-205bf1521614_categories",
+                    json!(vec![id]),
+                );
+            }
+            None => {
+                self.errs
+                    .push(format!("Label '{}' not found for set_stage_label", label));
+            }
+        }
+        self
+    }
+
+    /// Sets Tags with label ids.
+    /// Deal tags
+    pub fn set_tags_ids(&mut self, ids: Vec<ID>) -> &mut NewDealBuilder {
+        self.set_v(
+            "e5fdac3d-958a-5ff8-80b8-ff1524b50822_categories",
+            json!(ids),
+        );
+        self
+    }
+    /// Sets Tags.
+    /// Deal tags
+    pub fn set_tags(
+        &mut self,
+        values: &std::collections::BTreeSet<DealTags>,
+    ) -> &mut NewDealBuilder {
+        self.set_tags_ids(values.iter().map(DealTags::id).collect())
+    }
+
+    /// Sets Deal Probability In % with integer value.
+    ///
+    pub fn set_deal_probability_in(&mut self, i: i64) -> &mut NewDealBuilder {
+        let jn = serde_json::Number::from(i);
+        self.set_v(
+            "bfd5d530-913b-53b2-9d0f-78d3c8f7f226_number",
+            Value::Number(jn),
+        );
+        self
+    }
+
+    /// Sets Amount with float value.
+    /// Order amount
+    /// Generates error if float value is Infinite or NaN.
+    pub fn set_amount(&mut self, f: f64) -> &mut NewDealBuilder {
+        match serde_json::Number::from_f64(f) {
+            Some(n) => self.set_v(
+                "cf2d45d6-0fd2-51ff-83c0-4d62304c9e52_number",
+                Value::Number(n),
+            ),
+            None => self
+                .errs
+                .push("Float values cannot be Infinite or NaN".to_string()),
+        }
+        self
+    }
+
+    /// Sets date Closing Date.
+    pub fn set_closing_date(&mut self, date: &DateTime<Utc>) -> &mut NewDealBuilder {
+        self.set_s(
+            "4e88bb09-7a96-5448-8d37-e52e5707bb26_date",
+            date.to_string(),
+        );
+        self
+    }
+
+    /// Sets person Owner.
+    /// Sales rep
+    pub fn set_owner(&mut self, id: ID) -> &mut NewDealBuilder {
+        self.set_v(
+            "03af7f30-2542-5ccc-b57e-8633b8408efc_persons",
+            json!(vec![id]),
+        );
+        self
+    }
+
+    /// Sets person(s) Team.
+    ///
+    pub fn set_team(&mut self, ids: Vec<ID>) -> &mut NewDealBuilder {
+        self.set_v("fcc06a80-df72-52e6-bff3-f1dae4da7365_persons", json!(ids));
+        self
+    }
+
+    /// sets checkbox Approved
+    ///
+    pub fn check_approved(&mut self, b: bool) -> &mut NewDealBuilder {
+        self.set_v(
+            "dd0a353c-1e8b-5773-8022-27f5b6fd13b6_checked",
+            Value::Bool(b),
+        );
+        self
+    }
+
+    /// Sets reference Company to item in list Companies
+    ///
+    pub fn set_company_uuid(&mut self, uuid: &str) -> &mut NewDealBuilder {
+        self.set_v(
+            "bf0c5276-920d-5b13-b079-14919038a7e2_references",
+            json!(vec![uuid]),
+        );
+        self
+    }
+    /// Sets reference Company to a Company
+    ///
+    pub fn set_company<T: Into<super::companies::CompanyUuid>>(
+        &mut self,
+        value: T,
+    ) -> &mut NewDealBuilder {
+        let uuid = value.into();
+        self.set_company_uuid(uuid.as_str())
+    }
+
+    /// Sets reference Related Deals to item in list Deal Tracker.
+    ///
+    pub fn set_related_deals_uuids(&mut self, uuids: Vec<&'_ str>) -> &mut NewDealBuilder {
+        self.set_v(
+            "6b8b96d4-a26a-5b31-a75a-0a8e05282d39_references",
+            json!(uuids),
+        );
+        self
+    }
+    /// Sets reference Related Deals to Deal items.
+    ///
+    pub fn set_related_deals<I, T>(&mut self, values: I) -> &mut NewDealBuilder
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<super::deal_tracker::DealUuid>,
+    {
+        let uuids: Vec<super::deal_tracker::DealUuid> =
+            values.into_iter().map(Into::into).collect();
+        self.set_related_deals_uuids(uuids.iter().map(|u| u.as_str()).collect())
+    }
+
+    /// Sets subitems in Subtasks hierarchy.
+    ///
+    pub fn set_subtasks(&mut self, uuids: Vec<&'_ str>) -> &mut NewDealBuilder {
+        self.set_v(
+            "c18d1f7e-20ea-5769-9e9b-ebce76ed6b79_references",
+            json!(uuids),
+        );
+        self
+    }
+
+    /// Sets URL Homepage.
+    pub fn set_homepage<T: AsRef<str>>(&mut self, url: T) -> &mut NewDealBuilder {
+        self.set_s(
+            "1c064829-21cb-5155-bc07-a733f349e298_link",
+            url.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sends create request to server, returning new Deal.
+    pub async fn execute(&mut self) -> Result<Deal, Error> {
+        if !self.errs.is_empty() {
+            return Err(Error::Message(format!(
+                "Errors occurred in NewDealBuilder: {:?}",
+                &self.errs
+            )));
+        }
+        let map = self.fields.to_owned();
+        let deal = self.api.create_entry(202, Value::Object(map)).await?;
+        Ok(Deal::new(Arc::new(deal), self.api.clone()))
+    }
+} // end impl NewDealBuilder
+
+/// Builder for updating existing Deal
+pub struct UpdateDealBuilder {
+    api: Api,
+    fields: JsonMap,
+    errs: Vec<String>,
+    item_id: DealId,
+}
+
+impl UpdateDealBuilder {
+    /// Create update builder for Deal with id
+    fn new(api: Api, item_id: DealId) -> Self {
+        Self {
+            api,
+            fields: JsonMap::new(),
+            errs: Vec::new(),
+            item_id,
+        }
+    }
+
+    // internal helper for setting the updateAction of multi-value fields.
+    // Zenkit applies one action to all fields of a request, so a request can't
+    // append or remove values of one field and replace another.
+    fn set_update_action(&mut self, action: &str) {
+        if let Some(Value::String(prev)) = self.fields.get("updateAction") {
+            if prev != action {
+                self.errs.push(format!(
+                    "Conflicting update actions '{}' and '{}': fields that add or remove values \
+                                 must be updated separately from fields that are set",
+                    prev, action
+                ));
+                return;
+            }
+        }
+        self.set_s("updateAction", action.to_string());
+    }
+
+    // internal helper for setting string field
+    #[inline]
+    fn set_s(&mut self, k: &str, v: String) {
+        self.fields.insert(k.to_string(), Value::String(v));
+    }
+    // internal helper for setting json value
+    #[inline]
+    fn set_v(&mut self, k: &str, v: Value) {
+        self.fields.insert(k.to_string(), v);
+    }
+
+    /// Sets text value for Title. Name of the deal
+    /// TextFormat is unchanged (to specify format, use _with_format())
+    pub fn set_title<T: AsRef<str>>(&mut self, s: T) -> &mut UpdateDealBuilder {
+        self.set_s(
+            "bd2e279e-aba9-5d28-8cb0-29742c3744dc_text",
+            s.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sets text value for Title with format
+    /// Name of the deal
+    pub fn set_title_with_format<T: AsRef<str>>(
+        &mut self,
+        s: T,
+        tf: TextFormat,
+    ) -> &mut UpdateDealBuilder {
+        self.set_s(
+            "bd2e279e-aba9-5d28-8cb0-29742c3744dc_text",
+            s.as_ref().to_string(),
+        );
+        self.set_s(
+            "bd2e279e-aba9-5d28-8cb0-29742c3744dc_textType",
+            tf.to_string(),
+        );
+        self
+    }
+
+    /// Sets Stage to In progress. Current sales stage
+    pub fn set_stage_in_progress(&mut self) -> &mut UpdateDealBuilder {
+        self.set_v(
+            "c7f61cc2-9256-5bd4-9ad6-205bf1521614_categories",
+            json!(vec![Deal::LABEL_STAGE_IN_PROGRESS_ID]),
+        );
+        self.set_update_action("replace");
+        self
+    }
+
+    /// Sets Stage to Won. Current sales stage
+    pub fn set_stage_won(&mut self) -> &mut UpdateDealBuilder {
+        self.set_v(
+            "c7f61cc2-9256-5bd4-9ad6-205bf1521614_categories",
+            json!(vec![Deal::LABEL_STAGE_WON_ID]),
+        );
+        self.set_update_action("replace");
+        self
+    }
+
+    /// Sets Stage to Lost. Current sales stage
+    pub fn set_stage_lost(&mut self) -> &mut UpdateDealBuilder {
+        self.set_v(
+            "c7f61cc2-9256-5bd4-9ad6-205bf1521614_categories",
+            json!(vec![Deal::LABEL_STAGE_LOST_ID]),
+        );
+        self.set_update_action("replace");
+        self
+    }
+
+    /// Set Stage by label-id. Current sales stage
+    pub fn set_stage_id(&mut self, id: ID) -> &mut UpdateDealBuilder {
+        self.set_v(
+            "c7f61cc2-9256-5bd4-9ad6-205bf1521614_categories",
+            json!(vec![id]),
+        );
+        self.set_update_action("replace");
+        self
+    }
+    /// Set Stage. Current sales stage
+    pub fn set_stage(&mut self, value: DealStage) -> &mut UpdateDealBuilder {
+        self.set_stage_id(value.id())
+    }
+    /// Set Stage by label-name. Current sales stage
+    pub fn set_stage_label(&mut self, label: &str) -> &mut UpdateDealBuilder {
+        match lookup_label(&Deal::LABELS_STAGE, label) {
+            Some(id) => {
+                self.set_v(
+                    "c7f61cc2-9256-5bd4-9ad6-205bf1521614_categories",
+                    json!(vec![id]),
+                );
+                self.set_update_action("replace");
+            }
+            None => {
+                self.errs
+                    .push(format!("Label '{}' not found for set_stage_label", label));
+            }
+        }
+        self
+    }
+    /// Clears (unsets) Stage Current sales stage
+    pub fn unset_stage(&mut self) -> &mut UpdateDealBuilder {
+        self.set_v(
+            "c7f61cc2-9256-5bd4-9ad6-205bf1521614_categories",
+            json!(Vec::<String>::new()),
+        );
+        self.set_update_action("replace");
+        self
+    }
+
+    /// Sets Tags with label ids. Replaces any previous values
+    /// Deal tags
+    pub fn set_tags_ids(&mut self, ids: Vec<ID>) -> &mut UpdateDealBuilder {
+        self.set_v(
+            "e5fdac3d-958a-5ff8-80b8-ff1524b50822_categories",
+            json!(ids),
+        );
+        self.set_update_action("replace");
+        self
+    }
+    /// Sets Tags. Replaces any previous values
+    /// Deal tags
+    pub fn set_tags(
+        &mut self,
+        values: &std::collections::BTreeSet<DealTags>,
+    ) -> &mut UpdateDealBuilder {
+        self.set_tags_ids(values.iter().map(DealTags::id).collect())
+    }
+    /// Adds label ids to Tags. Appends any previous values
+    /// Deal tags
+    pub fn add_tags_ids(&mut self, ids: Vec<ID>) -> &mut UpdateDealBuilder {
+        self.set_v(
+            "e5fdac3d-958a-5ff8-80b8-ff1524b50822_categories",
+            json!(ids),
+        );
+        self.set_update_action("append");
+        self
+    }
+    /// Removes label ids from Tags.
+    /// Deal tags
+    pub fn remove_tags_ids(&mut self, ids: Vec<ID>) -> &mut UpdateDealBuilder {
+        self.set_v(
+            "e5fdac3d-958a-5ff8-80b8-ff1524b50822_categories",
+            json!(ids),
+        );
+        self.set_update_action("remove");
+        self
+    }
+    /// Adds labels to Tags. Appends any previous values
+    /// Deal tags
+    pub fn add_tags(
+        &mut self,
+        values: &std::collections::BTreeSet<DealTags>,
+    ) -> &mut UpdateDealBuilder {
+        self.add_tags_ids(values.iter().map(DealTags::id).collect())
+    }
+    /// Removes labels from Tags.
+    /// Deal tags
+    pub fn remove_tags(
+        &mut self,
+        values: &std::collections::BTreeSet<DealTags>,
+    ) -> &mut UpdateDealBuilder {
+        self.remove_tags_ids(values.iter().map(DealTags::id).collect())
+    }
+    /// Changes Tags from the labels of `item` to `new`.
+    /// If labels are only added or only removed, just the difference is sent,
+    /// so that concurrent changes to other labels are kept. Otherwise the value is replaced,
+    /// keeping the item's labels that are not variants of DealTags.
+    /// Zenkit applies one update action to the whole request, so if only the difference
+    /// is sent, setting other fields in the same update is reported as an error.
+    /// Deal tags
+    pub fn change_tags(
+        &mut self,
+        item: &Deal,
+        new: &std::collections::BTreeSet<DealTags>,
+    ) -> &mut UpdateDealBuilder {
+        let old = item.get_tags();
+        if &old == new {
+            self
+        } else if new.is_superset(&old) {
+            self.add_tags(&new.difference(&old).copied().collect())
+        } else if new.is_subset(&old) {
+            self.remove_tags(&old.difference(new).copied().collect())
+        } else {
+            let removed: Vec<ID> = old.difference(new).map(DealTags::id).collect();
+            let mut ids = item.get_tags_ids();
+            ids.retain(|id| !removed.contains(id));
+            ids.extend(new.difference(&old).map(DealTags::id));
+            self.set_tags_ids(ids)
+        }
+    }
+    /// Clears (unsets) Tags Deal tags
+    pub fn unset_tags(&mut self) -> &mut UpdateDealBuilder {
+        self.set_v(
+            "e5fdac3d-958a-5ff8-80b8-ff1524b50822_categories",
+            json!(Vec::<String>::new()),
+        );
+        self.set_update_action("replace");
+        self
+    }
+
+    /// Sets Deal Probability In % with integer value.
+    ///
+    pub fn set_deal_probability_in(&mut self, i: i64) -> &mut UpdateDealBuilder {
+        let jn = serde_json::Number::from(i);
+        self.set_v(
+            "bfd5d530-913b-53b2-9d0f-78d3c8f7f226_number",
+            Value::Number(jn),
+        );
+        self
+    }
+
+    /// Sets Amount with float value.
+    /// Order amount
+    /// Generates error if float value is Infinite or NaN.
+    pub fn set_amount(&mut self, f: f64) -> &mut UpdateDealBuilder {
+        match serde_json::Number::from_f64(f) {
+            Some(n) => self.set_v(
+                "cf2d45d6-0fd2-51ff-83c0-4d62304c9e52_number",
+                Value::Number(n),
+            ),
+            None => self
+                .errs
+                .push("Float values cannot be Infinite or NaN".to_string()),
+        }
+        self
+    }
+
+    /// Sets date Closing Date.
+    pub fn set_closing_date(&mut self, date: &DateTime<Utc>) -> &mut UpdateDealBuilder {
+        self.set_s(
+            "4e88bb09-7a96-5448-8d37-e52e5707bb26_date",
+            date.to_string(),
+        );
+        self
+    }
+
+    /// Sets person Owner. Replaces previous value
+    /// Sales rep
+    pub fn set_owner(&mut self, id: ID) -> &mut UpdateDealBuilder {
+        self.set_v(
+            "03af7f30-2542-5ccc-b57e-8633b8408efc_persons",
+            json!(vec![id]),
+        );
+        self.set_update_action("replace");
+        self
+    }
+    /// Remove person Owner
+    /// Sales rep
+    pub fn unset_owner(&mut self) -> &mut UpdateDealBuilder {
+        self.set_v(
+            "03af7f30-2542-5ccc-b57e-8633b8408efc_persons",
+            json!(Vec::<String>::new()),
+        );
+        self.set_update_action("replace");
+        self
+    }
+
+    /// Sets person(s) Team. Replaces previous value(s)
+    ///
+    pub fn set_team(&mut self, ids: Vec<ID>) -> &mut UpdateDealBuilder {
+        self.set_v("fcc06a80-df72-52e6-bff3-f1dae4da7365_persons", json!(ids));
+        self.set_update_action("replace");
+        self
+    }
+    /// Adds person(s) Team
+    ///
+    pub fn add_team(&mut self, ids: Vec<ID>) -> &mut UpdateDealBuilder {
+        self.set_v("fcc06a80-df72-52e6-bff3-f1dae4da7365_persons", json!(ids));
+        self.set_update_action("append");
+        self
+    }
+    /// Remove person(s) Team
+    ///
+    pub fn remove_team(&mut self, ids: Vec<ID>) -> &mut UpdateDealBuilder {
+        self.set_v("fcc06a80-df72-52e6-bff3-f1dae4da7365_persons", json!(ids));
+        self.set_update_action("remove");
+        self
+    }
+    /// Remove person Team
+    ///
+    pub fn unset_team(&mut self) -> &mut UpdateDealBuilder {
+        self.set_v(
+            "fcc06a80-df72-52e6-bff3-f1dae4da7365_persons",
+            json!(Vec::<String>::new()),
+        );
+        self.set_update_action("replace");
+        self
+    }
+
+    /// sets checkbox Approved
+    ///
+    pub fn check_approved(&mut self, b: bool) -> &mut UpdateDealBuilder {
+        self.set_v(
+            "dd0a353c-1e8b-5773-8022-27f5b6fd13b6_checked",
+            Value::Bool(b),
+        );
+        self
+    }
+
+    /// Sets reference Company to item in list Companies
+    ///
+    pub fn set_company_uuid(&mut self, uuid: &str) -> &mut UpdateDealBuilder {
+        self.set_v(
+            "bf0c5276-920d-5b13-b079-14919038a7e2_references",
+            json!(vec![uuid]),
+        );
+        self.set_update_action("replace");
+        self
+    }
+    /// Sets reference Company to a Company
+    ///
+    pub fn set_company<T: Into<super::companies::CompanyUuid>>(
+        &mut self,
+        value: T,
+    ) -> &mut UpdateDealBuilder {
+        let uuid = value.into();
+        self.set_company_uuid(uuid.as_str())
+    }
+    /// Removes references to Company
+    ///
+    pub fn unset_company(&mut self) -> &mut UpdateDealBuilder {
+        self.set_v(
+            "bf0c5276-920d-5b13-b079-14919038a7e2_references",
+            json!(Vec::<String>::new()),
+        );
+        self.set_update_action("replace");
+        self
+    }
+
+    /// Sets reference Related Deals to item in list Deal Tracker.
+    ///
+    pub fn set_related_deals_uuids(&mut self, uuids: Vec<&'_ str>) -> &mut UpdateDealBuilder {
+        self.set_v(
+            "6b8b96d4-a26a-5b31-a75a-0a8e05282d39_references",
+            json!(uuids),
+        );
+        self.set_update_action("replace");
+        self
+    }
+    /// Sets reference Related Deals to Deal items.
+    ///
+    pub fn set_related_deals<I, T>(&mut self, values: I) -> &mut UpdateDealBuilder
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<super::deal_tracker::DealUuid>,
+    {
+        let uuids: Vec<super::deal_tracker::DealUuid> =
+            values.into_iter().map(Into::into).collect();
+        self.set_related_deals_uuids(uuids.iter().map(|u| u.as_str()).collect())
+    }
+    /// Adds references to Related Deals to item(s) in list Deal Tracker
+    ///
+    pub fn add_related_deals_uuids(&mut self, uuids: Vec<&'_ str>) -> &mut UpdateDealBuilder {
+        self.set_v(
+            "6b8b96d4-a26a-5b31-a75a-0a8e05282d39_references",
+            json!(uuids),
+        );
+        self.set_update_action("append");
+        self
+    }
+    /// Adds references to Deal items to Related Deals
+    ///
+    pub fn add_related_deals<I, T>(&mut self, values: I) -> &mut UpdateDealBuilder
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<super::deal_tracker::DealUuid>,
+    {
+        let uuids: Vec<super::deal_tracker::DealUuid> =
+            values.into_iter().map(Into::into).collect();
+        self.add_related_deals_uuids(uuids.iter().map(|u| u.as_str()).collect())
+    }
+    /// Removes references to Related Deals
+    ///
+    pub fn remove_related_deals_uuids(&mut self, uuids: Vec<&'_ str>) -> &mut UpdateDealBuilder {
+        self.set_v(
+            "6b8b96d4-a26a-5b31-a75a-0a8e05282d39_references",
+            json!(uuids),
+        );
+        self.set_update_action("remove");
+        self
+    }
+    /// Removes references to Deal items from Related Deals
+    ///
+    pub fn remove_related_deals<I, T>(&mut self, values: I) -> &mut UpdateDealBuilder
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<super::deal_tracker::DealUuid>,
+    {
+        let uuids: Vec<super::deal_tracker::DealUuid> =
+            values.into_iter().map(Into::into).collect();
+        self.remove_related_deals_uuids(uuids.iter().map(|u| u.as_str()).collect())
+    }
+    /// Removes references to Related Deals
+    ///
+    pub fn unset_related_deals(&mut self) -> &mut UpdateDealBuilder {
+        self.set_v(
+            "6b8b96d4-a26a-5b31-a75a-0a8e05282d39_references",
+            json!(Vec::<String>::new()),
+        );
+        self.set_update_action("replace");
+        self
+    }
+
+    /// Sets subitems in Subtasks hierarchy.
+    ///
+    pub fn set_subtasks(&mut self, uuids: Vec<&'_ str>) -> &mut UpdateDealBuilder {
+        self.set_v(
+            "c18d1f7e-20ea-5769-9e9b-ebce76ed6b79_references",
+            json!(uuids),
+        );
+        self.set_update_action("replace");
+        self
+    }
+
+    /// Adds subitems to Subtasks hierarchy
+    ///
+    pub fn add_subtasks(&mut self, uuids: Vec<&'_ str>) -> &mut UpdateDealBuilder {
+        self.set_v(
+            "c18d1f7e-20ea-5769-9e9b-ebce76ed6b79_references",
+            json!(uuids),
+        );
+        self.set_update_action("append");
+        self
+    }
+
+    /// Removes subitems in Subtasks hierarchy
+    ///
+    pub fn remove_subtasks(&mut self, uuids: Vec<&'_ str>) -> &mut UpdateDealBuilder {
+        self.set_v(
+            "c18d1f7e-20ea-5769-9e9b-ebce76ed6b79_references",
+            json!(uuids),
+        );
+        self.set_update_action("remove");
+        self
+    }
+
+    /// Removes all subitems in Subtasks hierarchy
+    ///
+    pub fn unset_subtasks(&mut self) -> &mut UpdateDealBuilder {
+        self.set_v(
+            "c18d1f7e-20ea-5769-9e9b-ebce76ed6b79_references",
+            json!(Vec::<String>::new()),
+        );
+        self.set_update_action("replace");
+        self
+    }
+
+    /// Sets URL Homepage.
+    pub fn set_homepage<T: AsRef<str>>(&mut self, url: T) -> &mut UpdateDealBuilder {
+        self.set_s(
+            "1c064829-21cb-5155-bc07-a733f349e298_link",
+            url.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sends update request to server, returning updated Deal.
+    pub async fn execute(&mut self) -> Result<Deal, Error> {
+        if !self.errs.is_empty() {
+            return Err(Error::Message(format!(
+                "Errors occurred in UpdateDealBuilder: {:?}",
+                &self.errs
+            )));
+        }
+        let map = self.fields.to_owned();
+        let deal = self
+            .api
+            .update_entry(202, self.item_id.value(), Value::Object(map))
+            .await?;
+        Ok(Deal::new(Arc::new(deal), self.api.clone()))
+    }
+} // end impl UpdateDealBuilder
+
+/// Query for Deal in List 'Deal Tracker'. Filters and sort order
+/// are sent with the request, so only matching items are downloaded.
+/// All conditions must match.
+#[derive(Clone)]
+pub struct DealQuery {
+    api: Api,
+    q: EntryQuery,
+    errs: Vec<String>,
+}
+
+impl DealQuery {
+    /// Creates query with no conditions
+    fn new(api: Api) -> Self {
+        Self {
+            api,
+            q: EntryQuery::default(),
+            errs: Vec::new(),
+        }
+    }
+
+    /// Returns at most n Deal. The default (0) returns all matching items.
+    pub fn limit(mut self, n: usize) -> Self {
+        self.q.limit = n;
+        self
+    }
+
+    /// Skips the first n matching Deal, for example, to resume
+    /// a stream after the items already read
+    pub fn skip(mut self, n: usize) -> Self {
+        self.q.skip = n;
+        self
+    }
+
+    /// Sets the number of Deal requested per call. The default is 500.
+    pub fn page_size(mut self, n: usize) -> Self {
+        self.q.page_size = n;
+        self
+    }
+
+    /// Includes archived Deal if true. By default, they are left out.
+    pub fn include_archived(mut self, include: bool) -> Self {
+        self.q.include_archived = include;
+        self
+    }
+
+    /// Sorts by date created, oldest first
+    pub fn order_by_created_asc(mut self) -> Self {
+        self.q.order_by("created_at", false);
+        self
+    }
+
+    /// Sorts by date created, newest first
+    pub fn order_by_created_desc(mut self) -> Self {
+        self.q.order_by("created_at", true);
+        self
+    }
+
+    /// Sorts by date last updated, oldest first
+    pub fn order_by_updated_asc(mut self) -> Self {
+        self.q.order_by("updated_at", false);
+        self
+    }
+
+    /// Sorts by date last updated, newest first
+    pub fn order_by_updated_desc(mut self) -> Self {
+        self.q.order_by("updated_at", true);
+        self
+    }
+
+    /// Returns the request parameters for all matching items. `get_items` and
+    /// `stream_items` send these in pages of up to `page_size` items.
+    pub fn request(&self) -> GetEntriesRequest {
+        self.q.request()
+    }
+
+    /// Returns the matching Deal
+    pub async fn get_items(&self) -> Result<Vec<Deal>, Error> {
+        self.check()?;
+        let entries = self.q.load(&self.api, LIST_DEAL_TRACKER_ID).await?;
+        Ok(entries
+            .into_iter()
+            .map(|e| Deal::new(Arc::new(e), self.api.clone()))
+            .collect())
+    }
+
+    /// Returns stream of the matching Deal. Pages are fetched as the stream
+    /// is read, so only one page is in memory at a time. The stream ends after the first
+    /// error; to resume, create the query again with `skip` set to the number of items read.
+    /// Items added or removed while reading can shift the offsets.
+    pub fn stream_items(&self) -> impl futures::Stream<Item = Result<Deal, Error>> + Send {
+        use futures::StreamExt;
+        if let Err(e) = self.check() {
+            return futures::stream::once(futures::future::ready(Err(e))).left_stream();
+        }
+        let api = self.api.clone();
+        self.q
+            .stream(self.api.clone(), LIST_DEAL_TRACKER_ID)
+            .map(move |r| r.map(|e| Deal::new(Arc::new(e), api.clone())))
+            .right_stream()
+    }
+
+    /// Returns error if a condition was invalid, e.g., an unknown label
+    fn check(&self) -> Result<(), Error> {
+        if self.errs.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Message(format!(
+                "Errors occurred in DealQuery: {:?}",
+                &self.errs
+            )))
+        }
+    }
+
+    /// Matches Deal where 'Title' is the text
+    pub fn title_is(mut self, text: &str) -> Self {
+        self.q.filter(
+            Deal::FIELD_TITLE_ID,
+            json!({ "modus": "equals", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Deal where 'Title' contains the text
+    pub fn title_contains(mut self, text: &str) -> Self {
+        self.q.filter(
+            Deal::FIELD_TITLE_ID,
+            json!({ "modus": "contains", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Deal where 'Title' starts with the text
+    pub fn title_starts_with(mut self, text: &str) -> Self {
+        self.q.filter(
+            Deal::FIELD_TITLE_ID,
+            json!({ "modus": "startsWith", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Deal where 'Title' is not set
+    pub fn title_is_empty(mut self) -> Self {
+        self.q
+            .filter(Deal::FIELD_TITLE_ID, json!({ "modus": "isEmpty" }));
+        self
+    }
+
+    /// Matches Deal where 'Title' is set
+    pub fn title_is_not_empty(mut self) -> Self {
+        self.q
+            .filter(Deal::FIELD_TITLE_ID, json!({ "modus": "isNotEmpty" }));
+        self
+    }
+
+    /// Sorts by 'Title', ascending
+    pub fn order_by_title_asc(mut self) -> Self {
+        self.q
+            .order_by("bd2e279e-aba9-5d28-8cb0-29742c3744dc_text", false);
+        self
+    }
+
+    /// Sorts by 'Title', descending
+    pub fn order_by_title_desc(mut self) -> Self {
+        self.q
+            .order_by("bd2e279e-aba9-5d28-8cb0-29742c3744dc_text", true);
+        self
+    }
+
+    /// Matches Deal where 'Stage' has the label id
+    pub fn stage_is_id(mut self, id: ID) -> Self {
+        self.q
+            .filter(Deal::FIELD_STAGE_ID, json!({ "filterCategories": [id] }));
+        self
+    }
+
+    /// Matches Deal where 'Stage' has the label
+    pub fn stage_is(self, value: DealStage) -> Self {
+        self.stage_is_id(value.id())
+    }
+
+    /// Matches Deal where 'Stage' has the label name
+    pub fn stage_is_label(mut self, label: &str) -> Self {
+        match Deal::label_id_for_stage(label) {
+            Some(id) => self.stage_is_id(id),
+            None => {
+                self.errs
+                    .push(format!("Label '{}' not found for stage", label));
+                self
+            }
+        }
+    }
+
+    /// Matches Deal where 'Stage' is not set
+    pub fn stage_is_empty(mut self) -> Self {
+        self.q
+            .filter(Deal::FIELD_STAGE_ID, json!({ "modus": "isEmpty" }));
+        self
+    }
+
+    /// Matches Deal where 'Stage' is set
+    pub fn stage_is_not_empty(mut self) -> Self {
+        self.q
+            .filter(Deal::FIELD_STAGE_ID, json!({ "modus": "isNotEmpty" }));
+        self
+    }
+
+    /// Matches Deal where 'Tags' has the label id
+    pub fn tags_is_id(mut self, id: ID) -> Self {
+        self.q
+            .filter(Deal::FIELD_TAGS_ID, json!({ "filterCategories": [id] }));
+        self
+    }
+
+    /// Matches Deal where 'Tags' has the label
+    pub fn tags_is(self, value: DealTags) -> Self {
+        self.tags_is_id(value.id())
+    }
+
+    /// Matches Deal where 'Tags' has the label name
+    pub fn tags_is_label(mut self, label: &str) -> Self {
+        match Deal::label_id_for_tags(label) {
+            Some(id) => self.tags_is_id(id),
+            None => {
+                self.errs
+                    .push(format!("Label '{}' not found for tags", label));
+                self
+            }
+        }
+    }
+
+    /// Matches Deal where 'Tags' is not set
+    pub fn tags_is_empty(mut self) -> Self {
+        self.q
+            .filter(Deal::FIELD_TAGS_ID, json!({ "modus": "isEmpty" }));
+        self
+    }
+
+    /// Matches Deal where 'Tags' is set
+    pub fn tags_is_not_empty(mut self) -> Self {
+        self.q
+            .filter(Deal::FIELD_TAGS_ID, json!({ "modus": "isNotEmpty" }));
+        self
+    }
+
+    /// Matches Deal where 'Deal Probability In %' is the value
+    pub fn deal_probability_in_eq(self, value: i64) -> Self {
+        self.deal_probability_in_between(value, value)
+    }
+
+    /// Matches Deal where 'Deal Probability In %' is greater than the value
+    pub fn deal_probability_in_gt(self, value: i64) -> Self {
+        match value.checked_add(1) {
+            Some(value) => self.deal_probability_in_ge(value),
+            // nothing is greater than i64::MAX. An empty range matches nothing.
+            None => self.deal_probability_in_between(1, 0),
+        }
+    }
+
+    /// Matches Deal where 'Deal Probability In %' is greater than or equal to the value
+    pub fn deal_probability_in_ge(mut self, value: i64) -> Self {
+        self.q.filter(
+            Deal::FIELD_DEAL_PROBABILITY_IN_ID,
+            json!({ "modus": "greaterOrEqual", "numberFrom": value }),
+        );
+        self
+    }
+
+    /// Matches Deal where 'Deal Probability In %' is less than the value
+    pub fn deal_probability_in_lt(self, value: i64) -> Self {
+        match value.checked_sub(1) {
+            Some(value) => self.deal_probability_in_le(value),
+            // nothing is less than i64::MIN. An empty range matches nothing.
+            None => self.deal_probability_in_between(1, 0),
+        }
+    }
+
+    /// Matches Deal where 'Deal Probability In %' is less than or equal to the value
+    pub fn deal_probability_in_le(mut self, value: i64) -> Self {
+        self.q.filter(
+            Deal::FIELD_DEAL_PROBABILITY_IN_ID,
+            json!({ "modus": "lessOrEqual", "numberTo": value }),
+        );
+        self
+    }
+
+    /// Matches Deal where 'Deal Probability In %' is between the values (inclusive)
+    pub fn deal_probability_in_between(mut self, from: i64, to: i64) -> Self {
+        self.q.filter(
+            Deal::FIELD_DEAL_PROBABILITY_IN_ID,
+            json!({ "modus": "inRange", "numberFrom": from, "numberTo": to }),
+        );
+        self
+    }
+
+    /// Matches Deal where 'Deal Probability In %' is not set
+    pub fn deal_probability_in_is_empty(mut self) -> Self {
+        self.q.filter(
+            Deal::FIELD_DEAL_PROBABILITY_IN_ID,
+            json!({ "modus": "isEmpty" }),
+        );
+        self
+    }
+
+    /// Matches Deal where 'Deal Probability In %' is set
+    pub fn deal_probability_in_is_not_empty(mut self) -> Self {
+        self.q.filter(
+            Deal::FIELD_DEAL_PROBABILITY_IN_ID,
+            json!({ "modus": "isNotEmpty" }),
+        );
+        self
+    }
+
+    /// Sorts by 'Deal Probability In %', ascending
+    pub fn order_by_deal_probability_in_asc(mut self) -> Self {
+        self.q
+            .order_by("bfd5d530-913b-53b2-9d0f-78d3c8f7f226_number", false);
+        self
+    }
+
+    /// Sorts by 'Deal Probability In %', descending
+    pub fn order_by_deal_probability_in_desc(mut self) -> Self {
+        self.q
+            .order_by("bfd5d530-913b-53b2-9d0f-78d3c8f7f226_number", true);
+        self
+    }
+
+    /// Matches Deal where 'Amount' is greater than the value
+    pub fn amount_gt(self, value: f64) -> Self {
+        match super::next_f64(value, true) {
+            next if next.is_finite() => self.amount_ge(next),
+            // nothing is greater than f64::MAX, or NaN. An empty range matches nothing.
+            _ => self.amount_between(1.0, 0.0),
+        }
+    }
+
+    /// Matches Deal where 'Amount' is greater than or equal to the value
+    pub fn amount_ge(mut self, value: f64) -> Self {
+        self.q.filter(
+            Deal::FIELD_AMOUNT_ID,
+            json!({ "modus": "greaterOrEqual", "numberFrom": value }),
+        );
+        self
+    }
+
+    /// Matches Deal where 'Amount' is less than the value
+    pub fn amount_lt(self, value: f64) -> Self {
+        match super::next_f64(value, false) {
+            next if next.is_finite() => self.amount_le(next),
+            // nothing is less than f64::MIN, or NaN. An empty range matches nothing.
+            _ => self.amount_between(1.0, 0.0),
+        }
+    }
+
+    /// Matches Deal where 'Amount' is less than or equal to the value
+    pub fn amount_le(mut self, value: f64) -> Self {
+        self.q.filter(
+            Deal::FIELD_AMOUNT_ID,
+            json!({ "modus": "lessOrEqual", "numberTo": value }),
+        );
+        self
+    }
+
+    /// Matches Deal where 'Amount' is between the values (inclusive)
+    pub fn amount_between(mut self, from: f64, to: f64) -> Self {
+        self.q.filter(
+            Deal::FIELD_AMOUNT_ID,
+            json!({ "modus": "inRange", "numberFrom": from, "numberTo": to }),
+        );
+        self
+    }
+
+    /// Matches Deal where 'Amount' is not set
+    pub fn amount_is_empty(mut self) -> Self {
+        self.q
+            .filter(Deal::FIELD_AMOUNT_ID, json!({ "modus": "isEmpty" }));
+        self
+    }
+
+    /// Matches Deal where 'Amount' is set
+    pub fn amount_is_not_empty(mut self) -> Self {
+        self.q
+            .filter(Deal::FIELD_AMOUNT_ID, json!({ "modus": "isNotEmpty" }));
+        self
+    }
+
+    /// Sorts by 'Amount', ascending
+    pub fn order_by_amount_asc(mut self) -> Self {
+        self.q
+            .order_by("cf2d45d6-0fd2-51ff-83c0-4d62304c9e52_number", false);
+        self
+    }
+
+    /// Sorts by 'Amount', descending
+    pub fn order_by_amount_desc(mut self) -> Self {
+        self.q
+            .order_by("cf2d45d6-0fd2-51ff-83c0-4d62304c9e52_number", true);
+        self
+    }
+
+    /// Matches Deal where 'Closing Date' is at or after the time
+    pub fn closing_date_since(mut self, from: DateTime<Utc>) -> Self {
+        // dateType 10: custom range
+        self.q.filter(
+            Deal::FIELD_CLOSING_DATE_ID,
+            json!({ "modus": "inRange", "dateType": 10, "dateFrom": from }),
+        );
+        self
+    }
+
+    /// Matches Deal where 'Closing Date' is at or before the time
+    pub fn closing_date_until(mut self, to: DateTime<Utc>) -> Self {
+        self.q.filter(
+            Deal::FIELD_CLOSING_DATE_ID,
+            json!({ "modus": "inRange", "dateType": 10, "dateTo": to }),
+        );
+        self
+    }
+
+    /// Matches Deal where 'Closing Date' is in the time range (inclusive)
+    pub fn closing_date_between(mut self, from: DateTime<Utc>, to: DateTime<Utc>) -> Self {
+        self.q.filter(
+            Deal::FIELD_CLOSING_DATE_ID,
+            json!({ "modus": "inRange", "dateType": 10, "dateFrom": from, "dateTo": to }),
+        );
+        self
+    }
+
+    /// Matches Deal where 'Closing Date' is not set
+    pub fn closing_date_is_empty(mut self) -> Self {
+        self.q
+            .filter(Deal::FIELD_CLOSING_DATE_ID, json!({ "modus": "isEmpty" }));
+        self
+    }
+
+    /// Matches Deal where 'Closing Date' is set
+    pub fn closing_date_is_not_empty(mut self) -> Self {
+        self.q.filter(
+            Deal::FIELD_CLOSING_DATE_ID,
+            json!({ "modus": "isNotEmpty" }),
+        );
+        self
+    }
+
+    /// Sorts by 'Closing Date', ascending
+    pub fn order_by_closing_date_asc(mut self) -> Self {
+        self.q
+            .order_by("4e88bb09-7a96-5448-8d37-e52e5707bb26_date", false);
+        self
+    }
+
+    /// Sorts by 'Closing Date', descending
+    pub fn order_by_closing_date_desc(mut self) -> Self {
+        self.q
+            .order_by("4e88bb09-7a96-5448-8d37-e52e5707bb26_date", true);
+        self
+    }
+
+    /// Matches Deal where 'Owner' includes the user id
+    pub fn owner_is(mut self, person_id: ID) -> Self {
+        self.q.filter(
+            Deal::FIELD_OWNER_ID,
+            json!({ "filterPersons": [person_id] }),
+        );
+        self
+    }
+
+    /// Matches Deal where 'Owner' is not set
+    pub fn owner_is_empty(mut self) -> Self {
+        self.q
+            .filter(Deal::FIELD_OWNER_ID, json!({ "modus": "isEmpty" }));
+        self
+    }
+
+    /// Matches Deal where 'Owner' is set
+    pub fn owner_is_not_empty(mut self) -> Self {
+        self.q
+            .filter(Deal::FIELD_OWNER_ID, json!({ "modus": "isNotEmpty" }));
+        self
+    }
+
+    /// Matches Deal where 'Team' includes the user id
+    pub fn team_is(mut self, person_id: ID) -> Self {
+        self.q
+            .filter(Deal::FIELD_TEAM_ID, json!({ "filterPersons": [person_id] }));
+        self
+    }
+
+    /// Matches Deal where 'Team' is not set
+    pub fn team_is_empty(mut self) -> Self {
+        self.q
+            .filter(Deal::FIELD_TEAM_ID, json!({ "modus": "isEmpty" }));
+        self
+    }
+
+    /// Matches Deal where 'Team' is set
+    pub fn team_is_not_empty(mut self) -> Self {
+        self.q
+            .filter(Deal::FIELD_TEAM_ID, json!({ "modus": "isNotEmpty" }));
+        self
+    }
+
+    /// Matches Deal where 'Approved' is checked (true) or unchecked (false)
+    pub fn approved_is(mut self, checked: bool) -> Self {
+        self.q
+            .filter(Deal::FIELD_APPROVED_ID, json!({ "checked": checked }));
+        self
+    }
+
+    /// Sorts by 'Approved', ascending
+    pub fn order_by_approved_asc(mut self) -> Self {
+        self.q
+            .order_by("dd0a353c-1e8b-5773-8022-27f5b6fd13b6_checked", false);
+        self
+    }
+
+    /// Sorts by 'Approved', descending
+    pub fn order_by_approved_desc(mut self) -> Self {
+        self.q
+            .order_by("dd0a353c-1e8b-5773-8022-27f5b6fd13b6_checked", true);
+        self
+    }
+
+    /// Matches Deal where 'Homepage' is the text
+    pub fn homepage_is(mut self, text: &str) -> Self {
+        self.q.filter(
+            Deal::FIELD_HOMEPAGE_ID,
+            json!({ "modus": "equals", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Deal where 'Homepage' contains the text
+    pub fn homepage_contains(mut self, text: &str) -> Self {
+        self.q.filter(
+            Deal::FIELD_HOMEPAGE_ID,
+            json!({ "modus": "contains", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Deal where 'Homepage' starts with the text
+    pub fn homepage_starts_with(mut self, text: &str) -> Self {
+        self.q.filter(
+            Deal::FIELD_HOMEPAGE_ID,
+            json!({ "modus": "startsWith", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Deal where 'Homepage' is not set
+    pub fn homepage_is_empty(mut self) -> Self {
+        self.q
+            .filter(Deal::FIELD_HOMEPAGE_ID, json!({ "modus": "isEmpty" }));
+        self
+    }
+
+    /// Matches Deal where 'Homepage' is set
+    pub fn homepage_is_not_empty(mut self) -> Self {
+        self.q
+            .filter(Deal::FIELD_HOMEPAGE_ID, json!({ "modus": "isNotEmpty" }));
+        self
+    }
+
+    /// Sorts by 'Homepage', ascending
+    pub fn order_by_homepage_asc(mut self) -> Self {
+        self.q
+            .order_by("1c064829-21cb-5155-bc07-a733f349e298_link", false);
+        self
+    }
+
+    /// Sorts by 'Homepage', descending
+    pub fn order_by_homepage_desc(mut self) -> Self {
+        self.q
+            .order_by("1c064829-21cb-5155-bc07-a733f349e298_link", true);
+        self
+    }
+} // end impl DealQuery
+
+/// Labels of 'Stage' in List 'Deal Tracker'
+/// Current sales stage
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+pub enum DealStage {
+    /// In progress
+    InProgress,
+    /// Won
+    Won,
+    /// Lost
+    Lost,
+}
+
+impl DealStage {
+    /// All labels, in Zenkit order
+    pub const ALL: [DealStage; 3] = [DealStage::InProgress, DealStage::Won, DealStage::Lost];
+
+    /// Returns the label id
+    pub fn id(&self) -> ID {
+        match *self {
+            DealStage::InProgress => Deal::LABEL_STAGE_IN_PROGRESS_ID,
+            DealStage::Won => Deal::LABEL_STAGE_WON_ID,
+            DealStage::Lost => Deal::LABEL_STAGE_LOST_ID,
+        }
+    }
+
+    /// Returns the label name
+    pub fn name(&self) -> &'static str {
+        match *self {
+            DealStage::InProgress => "In progress",
+            DealStage::Won => "Won",
+            DealStage::Lost => "Lost",
+        }
+    }
+
+    /// Returns the label with the id, or None if the id is not a label of this field
+    pub fn from_id(id: ID) -> Option<Self> {
+        Self::ALL.iter().find(|v| v.id() == id).copied()
+    }
+}
+
+impl std::fmt::Display for DealStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for DealStage {
+    type Err = Error;
+
+    /// Parses label name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .find(|v| v.name() == s)
+            .copied()
+            .ok_or_else(|| Error::Message(format!("'{}' is not a label of '{}'", s, "Stage")))
+    }
+}
+
+impl std::convert::TryFrom<ID> for DealStage {
+    type Error = Error;
+
+    fn try_from(id: ID) -> Result<Self, Self::Error> {
+        Self::from_id(id)
+            .ok_or_else(|| Error::Message(format!("{} is not a label id of '{}'", id, "Stage")))
+    }
+}
+
+impl From<DealStage> for ID {
+    fn from(value: DealStage) -> ID {
+        value.id()
+    }
+}
+
+/// Labels of 'Tags' in List 'Deal Tracker'
+/// Deal tags
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+pub enum DealTags {
+    /// Hot
+    Hot,
+    /// Enterprise
+    Enterprise,
+    /// Renewal
+    Renewal,
+}
+
+impl DealTags {
+    /// All labels, in Zenkit order
+    pub const ALL: [DealTags; 3] = [DealTags::Hot, DealTags::Enterprise, DealTags::Renewal];
+
+    /// Returns the label id
+    pub fn id(&self) -> ID {
+        match *self {
+            DealTags::Hot => Deal::LABEL_TAGS_HOT_ID,
+            DealTags::Enterprise => Deal::LABEL_TAGS_ENTERPRISE_ID,
+            DealTags::Renewal => Deal::LABEL_TAGS_RENEWAL_ID,
+        }
+    }
+
+    /// Returns the label name
+    pub fn name(&self) -> &'static str {
+        match *self {
+            DealTags::Hot => "Hot",
+            DealTags::Enterprise => "Enterprise",
+            DealTags::Renewal => "Renewal",
+        }
+    }
+
+    /// Returns the label with the id, or None if the id is not a label of this field
+    pub fn from_id(id: ID) -> Option<Self> {
+        Self::ALL.iter().find(|v| v.id() == id).copied()
+    }
+}
+
+impl std::fmt::Display for DealTags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for DealTags {
+    type Err = Error;
+
+    /// Parses label name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .find(|v| v.name() == s)
+            .copied()
+            .ok_or_else(|| Error::Message(format!("'{}' is not a label of '{}'", s, "Tags")))
+    }
+}
+
+impl std::convert::TryFrom<ID> for DealTags {
+    type Error = Error;
+
+    fn try_from(id: ID) -> Result<Self, Self::Error> {
+        Self::from_id(id)
+            .ok_or_else(|| Error::Message(format!("{} is not a label id of '{}'", id, "Tags")))
+    }
+}
+
+impl From<DealTags> for ID {
+    fn from(value: DealTags) -> ID {
+        value.id()
+    }
+}
+
+/// Deal data, with one field per Zenkit field, that can be serialized,
+/// cached, or sent between threads. Create from an item with `From<&Deal>`,
+/// and save with `to_new_builder` or `to_update_builder` (or the `*_with` versions,
+/// which take the client to save with).
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DealRecord {
+    /// Item id, or None if the item hasn't been created
+    pub id: Option<DealId>,
+    /// Item uuid, or None if the item hasn't been created
+    pub uuid: Option<DealUuid>,
+    /// Title
+    pub title: Option<String>,
+    /// Stage
+    pub stage: Option<DealStage>,
+    /// Tags
+    pub tags: std::collections::BTreeSet<DealTags>,
+    /// Deal Probability In %
+    pub deal_probability_in: Option<i64>,
+    /// Amount
+    pub amount: Option<f64>,
+    /// Order Total (read-only)
+    pub order_total: Option<f64>,
+    /// Closing Date
+    pub closing_date: Option<DateTime<Utc>>,
+    /// Owner
+    pub owner: Option<ID>,
+    /// Team
+    pub team: Vec<ID>,
+    /// Approved
+    pub approved: bool,
+    /// Company
+    pub company: Option<super::companies::CompanyUuid>,
+    /// Related Deals
+    pub related_deals: Vec<super::deal_tracker::DealUuid>,
+    /// Subtasks
+    pub subtasks: Vec<String>,
+    /// Homepage
+    pub homepage: Option<String>,
+}
+
+impl From<&Deal> for DealRecord {
+    fn from(item: &Deal) -> Self {
+        Self {
+            id: Some(item.get_id()),
+            uuid: Some(item.get_uuid()),
+            title: item.get_title().map(String::from),
+            stage: item.get_stage(),
+            tags: item.get_tags(),
+            deal_probability_in: item.get_deal_probability_in(),
+            amount: item.get_amount(),
+            order_total: item.get_order_total(),
+            closing_date: item.get_closing_date(),
+            owner: item.get_owner_id(),
+            team: item.get_team_ids(),
+            approved: item.is_checked_approved(),
+            company: item
+                .get_company_uuid()
+                .map(super::companies::CompanyUuid::new),
+            related_deals: item
+                .get_related_deals_uuids()
+                .into_iter()
+                .map(super::deal_tracker::DealUuid::new)
+                .collect(),
+            subtasks: item
+                .get_subtasks_uuids()
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            homepage: item.get_homepage().map(String::from),
+        }
+    }
+}
+
+impl DealRecord {
+    /// Returns builder for creating a new Deal with the record's values,
+    /// using the global api client. Fields that are None, and read-only fields, are not set.
+    pub fn to_new_builder(&self) -> NewDealBuilder {
+        self.to_new_builder_with(&DealTrackerClient::new(Api::global()))
+    }
+
+    /// Returns builder for creating a new Deal with the record's values,
+    /// using the client. Fields that are None, and read-only fields, are not set.
+    pub fn to_new_builder_with(&self, client: &DealTrackerClient) -> NewDealBuilder {
+        let mut builder = client.create();
+        if let Some(ref v) = self.title {
+            builder.set_title(v);
+        }
+        if let Some(ref v) = self.stage {
+            builder.set_stage(*v);
+        }
+        {
+            let v = &self.tags;
+            builder.set_tags(v);
+        }
+        if let Some(ref v) = self.deal_probability_in {
+            builder.set_deal_probability_in(*v);
+        }
+        if let Some(ref v) = self.amount {
+            builder.set_amount(*v);
+        }
+        if let Some(ref v) = self.closing_date {
+            builder.set_closing_date(v);
+        }
+        if let Some(ref v) = self.owner {
+            builder.set_owner(*v);
+        }
+        {
+            let v = &self.team;
+            builder.set_team(v.clone());
+        }
+        {
+            let v = &self.approved;
+            builder.check_approved(*v);
+        }
+        if let Some(ref v) = self.company {
+            builder.set_company(v);
+        }
+        {
+            let v = &self.related_deals;
+            builder.set_related_deals(v);
+        }
+        {
+            let v = &self.subtasks;
+            builder.set_subtasks(v.iter().map(|s| s.as_str()).collect());
+        }
+        if let Some(ref v) = self.homepage {
+            builder.set_homepage(v);
+        }
+        builder
+    }
+
+    /// Returns builder for updating the Deal with the record's id to the record's values,
+    /// using the global api client. Fields that are None, and read-only fields, are not changed.
+    /// Multi-value fields are replaced. Returns an error if the record has no id.
+    pub fn to_update_builder(&self) -> Result<UpdateDealBuilder, Error> {
+        self.to_update_builder_with(&DealTrackerClient::new(Api::global()))
+    }
+
+    /// Returns builder for updating the Deal with the record's id to the record's values,
+    /// using the client. Fields that are None, and read-only fields, are not changed.
+    /// Multi-value fields are replaced. Returns an error if the record has no id.
+    pub fn to_update_builder_with(
+        &self,
+        client: &DealTrackerClient,
+    ) -> Result<UpdateDealBuilder, Error> {
+        let id = self
+            .id
+            .ok_or_else(|| Error::Message("DealRecord has no id".to_string()))?;
+        let mut builder = client.update(id);
+        if let Some(ref v) = self.title {
+            builder.set_title(v);
+        }
+        if let Some(ref v) = self.stage {
+            builder.set_stage(*v);
+        }
+        {
+            let v = &self.tags;
+            builder.set_tags(v);
+        }
+        if let Some(ref v) = self.deal_probability_in {
+            builder.set_deal_probability_in(*v);
+        }
+        if let Some(ref v) = self.amount {
+            builder.set_amount(*v);
+        }
+        if let Some(ref v) = self.closing_date {
+            builder.set_closing_date(v);
+        }
+        if let Some(ref v) = self.owner {
+            builder.set_owner(*v);
+        }
+        {
+            let v = &self.team;
+            builder.set_team(v.clone());
+        }
+        {
+            let v = &self.approved;
+            builder.check_approved(*v);
+        }
+        if let Some(ref v) = self.company {
+            builder.set_company(v);
+        }
+        {
+            let v = &self.related_deals;
+            builder.set_related_deals(v);
+        }
+        {
+            let v = &self.subtasks;
+            builder.set_subtasks(v.iter().map(|s| s.as_str()).collect());
+        }
+        if let Some(ref v) = self.homepage {
+            builder.set_homepage(v);
+        }
+        Ok(builder)
+    }
+}
+
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    fn assert_send<T: Send>(_: &T) {}
+    assert_send_sync::<Deal>();
+    assert_send_sync::<NewDealBuilder>();
+    assert_send_sync::<UpdateDealBuilder>();
+    assert_send_sync::<DealRecord>();
+    assert_send(&DealTrackerList::get_items());
+    assert_send(&DealTrackerList::create().execute());
+    assert_send(&DealTrackerList::create_many(Vec::new(), 1));
+};
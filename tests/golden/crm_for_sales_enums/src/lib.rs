@@ -0,0 +1,1447 @@
+#![allow(dead_code, unused_imports)]
+//! Zenkit Workspace CRM For Sales
+//! Sales CRM
+//  Generated by zk-codegen v0.5.4 from schema 2387636692c5cac1
+
+use std::{
+    fmt,
+    future::Future,
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+// use and re-export
+pub use zenkit::{
+    get_api, init_api,
+    types::{AllId, DateTime, Entry, GetEntriesRequest, JsonMap, TextFormat, Utc, ID},
+    ApiClient, ApiConfig,
+};
+
+mod companies;
+pub use companies::*;
+mod deal_tracker;
+pub use deal_tracker::*;
+
+const ZENKIT_API_TOKEN_VAR: &str = "ZENKIT_API_TOKEN";
+const ZENKIT_ITEM_URL_BASE_VAR: &str = "ZENKIT_ITEM_URL_BASE";
+
+/// Default base of the item urls returned by `get_zenkit_url`
+pub const DEFAULT_ITEM_URL_BASE: &str = "https://base.zenkit.com/i/";
+
+/// Base of item urls, if set with `set_item_url_base`
+static ITEM_URL_BASE: RwLock<Option<String>> = RwLock::new(None);
+
+/// Sets the base of the item urls returned by `get_zenkit_url`, for on-premises
+/// or proxied deployments, e.g., "https://zenkit.example.com/i/".
+/// `None` restores the default.
+pub fn set_item_url_base(base: Option<&str>) {
+    let mut item_url_base = ITEM_URL_BASE.write().unwrap_or_else(|e| e.into_inner());
+    *item_url_base = base.map(|s| s.to_string());
+}
+
+/// Returns the base of item urls: the value set with `set_item_url_base`,
+/// or the environment variable ZENKIT_ITEM_URL_BASE, or DEFAULT_ITEM_URL_BASE
+pub fn item_url_base() -> String {
+    if let Some(ref base) = *ITEM_URL_BASE.read().unwrap_or_else(|e| e.into_inner()) {
+        return base.clone();
+    }
+    std::env::var(ZENKIT_ITEM_URL_BASE_VAR)
+        .ok()
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| DEFAULT_ITEM_URL_BASE.to_string())
+}
+
+/// Errors returned by this crate
+#[derive(Debug)]
+pub enum Error {
+    Message(String),
+    Zenkit(String),
+    NoApi,
+    /// Error status returned by Zenkit, with the delay requested by the server's
+    /// Retry-After header, if any
+    Status {
+        status: u16,
+        message: String,
+        retry_after: Option<Duration>,
+    },
+    /// The request could not be sent, or the response was not received
+    Network(String),
+}
+
+impl Error {
+    /// Returns true if the call may succeed when repeated: rate limits (429),
+    /// timeouts (408), server errors (500, 502, 503, 504), and network errors
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Error::Status { status, .. } => matches!(status, 408 | 429 | 500 | 502 | 503 | 504),
+            Error::Network(_) => true,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "{:?}",
+            match self {
+                Error::Message(s) => s,
+                Error::Zenkit(s) => s,
+                Error::Status { message, .. } => message,
+                Error::Network(s) => s,
+                Error::NoApi => "Api must be initialized with 'initialize_zenkit_api()' before use",
+            }
+        )
+    }
+}
+
+impl From<zenkit::Error> for Error {
+    fn from(e: zenkit::Error) -> Error {
+        match e {
+            zenkit::Error::ApiError(status, _) => Error::Status {
+                status,
+                message: e.to_string(),
+                retry_after: None,
+            },
+            zenkit::Error::Reqwest(ref re)
+                if re.is_connect() || re.is_timeout() || re.is_request() =>
+            {
+                Error::Network(e.to_string())
+            }
+            _ => Error::Zenkit(e.to_string()),
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Error {
+        Error::Message(e.to_string())
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Initialize zenkit using api token.
+/// If token is None, the value will be taken from the environment variable ZENKIT_API_TOKEN.
+/// Returns error if token is undefined
+pub fn initialize_zenkit_api(
+    token: Option<&'_ str>,
+    endpoint: Option<&'_ str>,
+) -> Result<&'static ApiClient, Error> {
+    let defaults = ApiConfig::default();
+    let token = token.unwrap_or(&defaults.token).to_string();
+    let endpoint = endpoint.unwrap_or(&defaults.endpoint).to_string();
+    // connect first: once init_api has set the zenkit client it can't be set again
+    let http = HttpApi::connect(&token, Some(&endpoint))?;
+    let zk = init_api(ApiConfig { token, endpoint }).map_err(|e| Error::Message(e.to_string()))?;
+    let mut global_http = GLOBAL_HTTP.write().unwrap_or_else(|e| e.into_inner());
+    *global_http = Some(Arc::new(http));
+    Ok(zk)
+}
+
+/// Find id for label name using binary search
+pub(crate) fn lookup_label(lookup_list: &[(&str, ID)], label: &str) -> Option<ID> {
+    match lookup_list.binary_search_by(|a| a.0.partial_cmp(label).unwrap()) {
+        Ok(index) => Some(lookup_list[index].1),
+        Err(_) => None,
+    }
+}
+
+/// Returns true if the string is formatted as a uuid (8-4-4-4-12 hex digits)
+pub(crate) fn is_uuid(s: &str) -> bool {
+    s.len() == 36
+        && s.char_indices().all(|(i, c)| match i {
+            8 | 13 | 18 | 23 => c == '-',
+            _ => c.is_ascii_hexdigit(),
+        })
+}
+
+///
+/// Workspace "CRM For Sales"
+/// Sales CRM
+///
+pub const WORKSPACE_CRM_FOR_SALES_ID: ID = 100;
+pub const WORKSPACE_CRM_FOR_SALES_UUID: &str = "01b1ed52-04d5-54fa-b355-37f1da0a38ce";
+pub const WORKSPACE_CRM_FOR_SALES_NAME: &str = "CRM For Sales";
+
+/// Client for the lists in workspace "CRM For Sales", with its own
+/// connection to Zenkit. A program can have clients for several Zenkit accounts
+/// or endpoints. The functions of the `*List` types use the global client
+/// (see `initialize_zenkit_api`), which is also available as `WorkspaceClient::global()`.
+#[derive(Clone)]
+pub struct WorkspaceClient {
+    api: Api,
+}
+
+impl WorkspaceClient {
+    /// Creates client with a new connection. If endpoint is None, the default
+    /// Zenkit endpoint is used.
+    pub fn connect(token: &str, endpoint: Option<&str>) -> Result<Self, Error> {
+        Ok(Self::with_backend(Arc::new(HttpApi::connect(
+            token, endpoint,
+        )?)))
+    }
+
+    /// Creates client that sends requests with the http client, for example,
+    /// one configured with a proxy or for a test server. The http client must
+    /// add the `Zenkit-API-Key` header. If endpoint is None, the default Zenkit
+    /// endpoint is used.
+    pub fn with_http_client(client: reqwest::Client, endpoint: Option<&str>) -> Self {
+        Self::with_backend(Arc::new(HttpApi::new(client, endpoint)))
+    }
+
+    /// Creates client that sends requests to the backend, for example,
+    /// a `mock::MockBackend` in tests
+    pub fn with_backend(backend: Arc<dyn Backend>) -> Self {
+        Self {
+            api: Api::with_backend(backend),
+        }
+    }
+
+    /// Returns client that uses the global api client, which must be initialized
+    /// with `initialize_zenkit_api` before use
+    pub fn global() -> Self {
+        Self { api: Api::global() }
+    }
+
+    /// Sets the retry policy for calls made with this client, instead of the global
+    /// policy (see `set_retry_policy`). Use `RetryPolicy::none()` to disable retries.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.api.retry_policy = Some(policy);
+        self
+    }
+
+    /// Returns client for List 'Companies'
+    pub fn companies(&self) -> CompaniesClient {
+        CompaniesClient::new(self.api.clone())
+    }
+
+    /// Returns client for List 'Deal Tracker'
+    pub fn deal_tracker(&self) -> DealTrackerClient {
+        DealTrackerClient::new(self.api.clone())
+    }
+}
+
+/// Operations on list entries used by the generated clients. Implemented by the Zenkit
+/// api client, by the connection of `WorkspaceClient::connect`, and, with the "mock"
+/// feature, by the in-memory `mock::MockBackend`.
+#[async_trait::async_trait]
+pub trait Backend: Send + Sync {
+    /// Fetches an entry by id, short id, or uuid
+    async fn get_entry(&self, list_id: ID, entry: AllId) -> Result<Entry, Error>;
+
+    /// Fetches a page of entries
+    async fn get_list_entries(
+        &self,
+        list_id: ID,
+        params: &GetEntriesRequest,
+    ) -> Result<Vec<Entry>, Error>;
+
+    /// Creates an entry from the fields set by a builder
+    async fn create_entry(&self, list_id: ID, val: serde_json::Value) -> Result<Entry, Error>;
+
+    /// Updates an entry with the fields set by a builder
+    async fn update_entry(
+        &self,
+        list_id: ID,
+        entry_id: ID,
+        val: serde_json::Value,
+    ) -> Result<Entry, Error>;
+
+    /// Deletes an entry permanently
+    async fn delete_entry(&self, list_id: ID, entry_id: ID) -> Result<(), Error>;
+
+    /// Archives (deprecates) an entry, and returns the archived entry.
+    /// The default implementation returns an error, for backends that can't archive.
+    async fn archive_entry(&self, list_id: ID, entry_id: ID) -> Result<Entry, Error> {
+        let _ = (list_id, entry_id);
+        Err(Error::Message(
+            "archive is not supported by this backend".to_string(),
+        ))
+    }
+
+    /// Restores an archived entry, and returns the restored entry.
+    /// The default implementation returns an error, for backends that can't restore.
+    async fn restore_entry(&self, list_id: ID, entry_id: ID) -> Result<Entry, Error> {
+        let _ = (list_id, entry_id);
+        Err(Error::Message(
+            "restore is not supported by this backend".to_string(),
+        ))
+    }
+}
+
+/// The zenkit api client has no calls to archive or restore entries, so `archive_entry`
+/// and `restore_entry` return an error. The global client (after `initialize_zenkit_api`)
+/// and clients from `WorkspaceClient::connect` or `with_http_client` support them.
+#[async_trait::async_trait]
+impl Backend for ApiClient {
+    async fn get_entry(&self, list_id: ID, entry: AllId) -> Result<Entry, Error> {
+        Ok(ApiClient::get_entry(self, list_id, entry).await?)
+    }
+
+    async fn get_list_entries(
+        &self,
+        list_id: ID,
+        params: &GetEntriesRequest,
+    ) -> Result<Vec<Entry>, Error> {
+        Ok(ApiClient::get_list_entries(self, list_id, params).await?)
+    }
+
+    async fn create_entry(&self, list_id: ID, val: serde_json::Value) -> Result<Entry, Error> {
+        Ok(ApiClient::create_entry(self, list_id, val).await?)
+    }
+
+    async fn update_entry(
+        &self,
+        list_id: ID,
+        entry_id: ID,
+        val: serde_json::Value,
+    ) -> Result<Entry, Error> {
+        Ok(ApiClient::update_entry(self, list_id, entry_id, val).await?)
+    }
+
+    async fn delete_entry(&self, list_id: ID, entry_id: ID) -> Result<(), Error> {
+        ApiClient::delete_entry(self, list_id, entry_id).await?;
+        Ok(())
+    }
+}
+
+/// Backend used by the global client, if set with `set_global_backend`
+static GLOBAL_BACKEND: RwLock<Option<Arc<dyn Backend>>> = RwLock::new(None);
+
+/// Sets the backend used by the `*List` functions and `WorkspaceClient::global()`
+/// instead of the api client from `initialize_zenkit_api`, for example, to run
+/// tests with a `mock::MockBackend`. `None` restores the api client.
+/// Tests that run in parallel share the global backend; to give each test its own
+/// backend, use `WorkspaceClient::with_backend`.
+pub fn set_global_backend(backend: Option<Arc<dyn Backend>>) {
+    let mut global = GLOBAL_BACKEND.write().unwrap_or_else(|e| e.into_inner());
+    *global = backend;
+}
+
+/// Retry policy for api calls that fail with a transient error (see `Error::is_transient`).
+/// Calls are repeated after an exponential backoff, or after the delay requested
+/// by the server's Retry-After header. Only calls that are safe to repeat are retried
+/// automatically: fetching entries by id, and entry queries. Creates, updates, and
+/// deletes are sent once, since a failed response doesn't mean the change wasn't made;
+/// to retry them anyway, wrap the call in `RetryPolicy::retry`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first call. 1 disables retries.
+    pub max_attempts: u32,
+    /// Delay before the first retry. The delay doubles for each later retry.
+    pub initial_backoff: Duration,
+    /// Maximum delay before a retry, including delays requested with Retry-After
+    pub max_backoff: Duration,
+    /// If true, each backoff is randomized between half and all of its length,
+    /// so that clients that failed at the same time don't retry at the same time
+    pub jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    /// Up to 4 attempts, with backoff starting at 500ms, and at most 30s between attempts
+    fn default() -> Self {
+        Self {
+            max_attempts: 4,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Returns policy that never retries
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Calls `f` until it succeeds, returns an error that is not transient,
+    /// or the attempts are used up. Returns the result of the last call.
+    pub async fn retry<T, F, Fut>(&self, mut f: F) -> Result<T, Error>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let mut attempt = 1;
+        loop {
+            match f().await {
+                Err(e) if e.is_transient() && attempt < self.max_attempts => {
+                    tokio::time::sleep(self.delay(attempt, &e)).await;
+                    attempt += 1;
+                }
+                res => return res,
+            }
+        }
+    }
+
+    /// Returns the delay before the next call, after `attempt` calls have failed
+    fn delay(&self, attempt: u32, err: &Error) -> Duration {
+        if let Error::Status {
+            retry_after: Some(retry_after),
+            ..
+        } = err
+        {
+            return (*retry_after).min(self.max_backoff);
+        }
+        let backoff = self
+            .initial_backoff
+            .checked_mul(1 << (attempt - 1).min(31))
+            .unwrap_or(self.max_backoff)
+            .min(self.max_backoff);
+        if self.jitter {
+            backoff / 2 + backoff.mul_f64(random_fraction() / 2.0)
+        } else {
+            backoff
+        }
+    }
+}
+
+/// Returns a pseudo-random number in [0, 1), for backoff jitter
+fn random_fraction() -> f64 {
+    use std::hash::{BuildHasher, Hasher};
+    let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+    hasher.write_u128(
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos(),
+    );
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Retry policy of the global client and of clients without their own policy,
+/// if set with `set_retry_policy`
+static RETRY_POLICY: RwLock<Option<RetryPolicy>> = RwLock::new(None);
+
+/// Sets the retry policy used by the `*List` functions and by clients that don't have
+/// their own (see `WorkspaceClient::with_retry_policy`). `None` restores the default.
+pub fn set_retry_policy(policy: Option<RetryPolicy>) {
+    let mut global = RETRY_POLICY.write().unwrap_or_else(|e| e.into_inner());
+    *global = policy;
+}
+
+/// Global api client, from `get_api()`
+struct GlobalApi;
+
+#[async_trait::async_trait]
+impl Backend for GlobalApi {
+    // reads use the connection made by `initialize_zenkit_api`, which reports the
+    // Retry-After header of errors; the zenkit api client is used if the api
+    // was initialized with `init_api`
+    async fn get_entry(&self, list_id: ID, entry: AllId) -> Result<Entry, Error> {
+        match global_http() {
+            Ok(http) => http.get_entry(list_id, entry).await,
+            Err(_) => Backend::get_entry(get_api()?, list_id, entry).await,
+        }
+    }
+
+    async fn get_list_entries(
+        &self,
+        list_id: ID,
+        params: &GetEntriesRequest,
+    ) -> Result<Vec<Entry>, Error> {
+        match global_http() {
+            Ok(http) => http.get_list_entries(list_id, params).await,
+            Err(_) => Backend::get_list_entries(get_api()?, list_id, params).await,
+        }
+    }
+
+    async fn create_entry(&self, list_id: ID, val: serde_json::Value) -> Result<Entry, Error> {
+        Backend::create_entry(get_api()?, list_id, val).await
+    }
+
+    async fn update_entry(
+        &self,
+        list_id: ID,
+        entry_id: ID,
+        val: serde_json::Value,
+    ) -> Result<Entry, Error> {
+        Backend::update_entry(get_api()?, list_id, entry_id, val).await
+    }
+
+    async fn delete_entry(&self, list_id: ID, entry_id: ID) -> Result<(), Error> {
+        Backend::delete_entry(get_api()?, list_id, entry_id).await
+    }
+
+    // the zenkit api client can't archive or restore entries,
+    // so these use the connection made by `initialize_zenkit_api`
+    async fn archive_entry(&self, list_id: ID, entry_id: ID) -> Result<Entry, Error> {
+        global_http()?.archive_entry(list_id, entry_id).await
+    }
+
+    async fn restore_entry(&self, list_id: ID, entry_id: ID) -> Result<Entry, Error> {
+        global_http()?.restore_entry(list_id, entry_id).await
+    }
+}
+
+/// Http connection with the token and endpoint of `initialize_zenkit_api`
+static GLOBAL_HTTP: RwLock<Option<Arc<HttpApi>>> = RwLock::new(None);
+
+/// Returns the connection made by `initialize_zenkit_api`
+fn global_http() -> Result<Arc<HttpApi>, Error> {
+    GLOBAL_HTTP
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+        .ok_or(Error::NoApi)
+}
+
+/// Backend and retry policy used for api calls
+#[derive(Clone)]
+pub(crate) struct Api {
+    /// backend owned by a WorkspaceClient, or None for the global backend
+    /// (see `set_global_backend`) or the api client from `get_api()`
+    backend: Option<Arc<dyn Backend>>,
+    /// retry policy of a WorkspaceClient, or None for the global policy
+    retry_policy: Option<RetryPolicy>,
+}
+
+impl Api {
+    /// Returns api that uses the global backend
+    pub(crate) fn global() -> Self {
+        Self {
+            backend: None,
+            retry_policy: None,
+        }
+    }
+
+    /// Returns api that uses a separate backend
+    pub(crate) fn with_backend(backend: Arc<dyn Backend>) -> Self {
+        Self {
+            backend: Some(backend),
+            retry_policy: None,
+        }
+    }
+
+    /// Returns the backend for the next call
+    fn backend(&self) -> Arc<dyn Backend> {
+        match &self.backend {
+            None => GLOBAL_BACKEND
+                .read()
+                .unwrap_or_else(|e| e.into_inner())
+                .clone()
+                .unwrap_or_else(|| Arc::new(GlobalApi)),
+            Some(backend) => backend.clone(),
+        }
+    }
+
+    /// Returns the retry policy for the next call
+    fn retry_policy(&self) -> RetryPolicy {
+        match &self.retry_policy {
+            None => RETRY_POLICY
+                .read()
+                .unwrap_or_else(|e| e.into_inner())
+                .clone()
+                .unwrap_or_default(),
+            Some(policy) => policy.clone(),
+        }
+    }
+
+    /// Fetches an entry by id or uuid, with retries
+    pub(crate) async fn get_entry<E: Into<AllId>>(
+        &self,
+        list_id: ID,
+        entry: E,
+    ) -> Result<Entry, Error> {
+        let backend = self.backend();
+        let entry = entry.into();
+        self.retry_policy()
+            .retry(|| backend.get_entry(list_id, copy_id(&entry)))
+            .await
+    }
+
+    /// Fetches a page of entries, with retries
+    pub(crate) async fn get_list_entries(
+        &self,
+        list_id: ID,
+        params: &GetEntriesRequest,
+    ) -> Result<Vec<Entry>, Error> {
+        let backend = self.backend();
+        self.retry_policy()
+            .retry(|| backend.get_list_entries(list_id, params))
+            .await
+    }
+
+    /// Creates an entry
+    pub(crate) async fn create_entry(
+        &self,
+        list_id: ID,
+        val: serde_json::Value,
+    ) -> Result<Entry, Error> {
+        self.backend().create_entry(list_id, val).await
+    }
+
+    /// Updates an entry
+    pub(crate) async fn update_entry(
+        &self,
+        list_id: ID,
+        entry_id: ID,
+        val: serde_json::Value,
+    ) -> Result<Entry, Error> {
+        self.backend().update_entry(list_id, entry_id, val).await
+    }
+
+    /// Deletes an entry permanently
+    pub(crate) async fn delete_entry(&self, list_id: ID, entry_id: ID) -> Result<(), Error> {
+        self.backend().delete_entry(list_id, entry_id).await
+    }
+
+    /// Archives an entry
+    pub(crate) async fn archive_entry(&self, list_id: ID, entry_id: ID) -> Result<Entry, Error> {
+        self.backend().archive_entry(list_id, entry_id).await
+    }
+
+    /// Restores an archived entry
+    pub(crate) async fn restore_entry(&self, list_id: ID, entry_id: ID) -> Result<Entry, Error> {
+        self.backend().restore_entry(list_id, entry_id).await
+    }
+
+    /// Loads all entries for a list into memory
+    pub(crate) async fn load_entries(&self, list_id: ID) -> Result<Vec<Entry>, Error> {
+        EntryQuery::default().load(self, list_id).await
+    }
+}
+
+/// Returns a copy of the id (AllId isn't Clone)
+fn copy_id(id: &AllId) -> AllId {
+    match id {
+        AllId::ID(id) => AllId::ID(*id),
+        AllId::ShortId(s) => AllId::ShortId(s.clone()),
+        AllId::UUID(s) => AllId::UUID(s.clone()),
+        AllId::Any(s) => AllId::Any(s.clone()),
+    }
+}
+
+/// Runs the calls with at most `concurrency` in progress at a time (at least 1),
+/// and returns their results in the order of the calls
+pub(crate) async fn run_batch<T, I>(calls: I, concurrency: usize) -> Vec<Result<T, Error>>
+where
+    I: IntoIterator,
+    I::Item: Future<Output = Result<T, Error>>,
+{
+    use futures::StreamExt;
+    futures::stream::iter(calls)
+        .buffered(concurrency.max(1))
+        .collect()
+        .await
+}
+
+/// Returns the next f64 after the value, toward positive infinity if `up`, otherwise
+/// toward negative infinity. Filters only have inclusive bounds, so query conditions
+/// like `_gt` use the next value. Returns NaN for NaN.
+pub(crate) fn next_f64(value: f64, up: bool) -> f64 {
+    if value.is_nan() || (value.is_infinite() && (value > 0.0) == up) {
+        value
+    } else if value == 0.0 {
+        let tiny = f64::from_bits(1);
+        if up {
+            tiny
+        } else {
+            -tiny
+        }
+    } else if (value > 0.0) == up {
+        // away from zero
+        f64::from_bits(value.to_bits() + 1)
+    } else {
+        f64::from_bits(value.to_bits() - 1)
+    }
+}
+
+/// Filters, sort order, and range of a list query, used by the generated `*Query` types.
+/// Filters are sent as `{"AND": {"TERMS": [...]}}`. Each term has the field's `elementId`,
+/// an optional `modus` (e.g., "contains" or "isEmpty"), and values for the field's
+/// filter keys (e.g., "text", "numberFrom" and "numberTo", or "filterCategories").
+#[derive(Clone, Debug, Default)]
+pub(crate) struct EntryQuery {
+    terms: Vec<serde_json::Value>,
+    /// (column, descending)
+    order_by: Vec<(&'static str, bool)>,
+    /// maximum number of entries, or 0 for all
+    pub(crate) limit: usize,
+    /// number of matching entries to skip
+    pub(crate) skip: usize,
+    /// number of entries requested per call, or 0 for PAGE_SIZE
+    pub(crate) page_size: usize,
+    /// if true, archived (deprecated) entries are included
+    pub(crate) include_archived: bool,
+}
+
+impl EntryQuery {
+    /// Default number of entries requested per call
+    pub(crate) const PAGE_SIZE: usize = 500;
+
+    /// Adds a filter term for a field
+    pub(crate) fn filter(&mut self, element_id: ID, mut term: serde_json::Value) {
+        term["elementId"] = serde_json::json!(element_id);
+        self.terms.push(term);
+    }
+
+    /// Adds a sort column, e.g., "created_at" or "<field uuid>_number".
+    /// Earlier columns take precedence.
+    pub(crate) fn order_by(&mut self, column: &'static str, descending: bool) {
+        self.order_by.push((column, descending));
+    }
+
+    /// Returns the request parameters, with the query's limit and skip
+    pub(crate) fn request(&self) -> GetEntriesRequest {
+        self.page(self.skip, self.limit)
+    }
+
+    /// Returns the request parameters for a page of results
+    fn page(&self, skip: usize, limit: usize) -> GetEntriesRequest {
+        use zenkit::types::{OrderBy, SortDirection};
+        let filter = if self.terms.is_empty() {
+            serde_json::json!({})
+        } else {
+            serde_json::json!({ "AND": { "TERMS": self.terms } })
+        };
+        let order_by = self
+            .order_by
+            .iter()
+            .map(|(column, descending)| OrderBy {
+                column: Some(column.to_string()),
+                direction: if *descending {
+                    SortDirection::Desc
+                } else {
+                    SortDirection::Asc
+                },
+            })
+            .collect();
+        GetEntriesRequest {
+            filter,
+            limit,
+            skip,
+            order_by,
+            allow_deprecated: self.include_archived,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the number of entries to request after `fetched` entries,
+    /// or 0 if the limit has been reached
+    fn next_page_size(&self, fetched: usize) -> usize {
+        let page_size = match self.page_size {
+            0 => Self::PAGE_SIZE,
+            n => n,
+        };
+        match self.limit {
+            0 => page_size,
+            limit => page_size.min(limit.saturating_sub(fetched)),
+        }
+    }
+
+    /// Loads all matching entries into memory
+    pub(crate) async fn load(&self, api: &Api, list_id: ID) -> Result<Vec<Entry>, Error> {
+        use futures::TryStreamExt;
+        self.stream(api.clone(), list_id).try_collect().await
+    }
+
+    /// Returns stream of the matching entries. Pages are requested as the stream
+    /// is read, so only one page is in memory at a time. The stream ends after
+    /// the first error.
+    pub(crate) fn stream(
+        &self,
+        api: Api,
+        list_id: ID,
+    ) -> impl futures::Stream<Item = Result<Entry, Error>> + Send {
+        let pages = EntryPages {
+            api,
+            query: self.clone(),
+            list_id,
+            fetched: 0,
+            page: std::collections::VecDeque::new(),
+            done: false,
+        };
+        futures::stream::unfold(pages, |mut pages| async move {
+            loop {
+                if let Some(entry) = pages.page.pop_front() {
+                    return Some((Ok(entry), pages));
+                }
+                let page_size = pages.query.next_page_size(pages.fetched);
+                if pages.done || page_size == 0 {
+                    return None;
+                }
+                let params = pages
+                    .query
+                    .page(pages.query.skip + pages.fetched, page_size);
+                match pages.api.get_list_entries(pages.list_id, &params).await {
+                    Ok(mut entries) => {
+                        // a page with fewer entries than requested is the last
+                        pages.done = entries.len() < page_size;
+                        entries.truncate(page_size);
+                        pages.fetched += entries.len();
+                        pages.page.extend(entries);
+                    }
+                    Err(e) => {
+                        pages.done = true;
+                        return Some((Err(e), pages));
+                    }
+                }
+            }
+        })
+    }
+}
+
+/// State of an entry stream
+struct EntryPages {
+    api: Api,
+    query: EntryQuery,
+    list_id: ID,
+    /// number of entries received
+    fetched: usize,
+    /// entries received but not yet returned
+    page: std::collections::VecDeque<Entry>,
+    /// true after the last page (one with fewer entries than requested) or an error
+    done: bool,
+}
+
+/// Http connection to the Zenkit api
+pub(crate) struct HttpApi {
+    client: reqwest::Client,
+    endpoint: String,
+}
+
+impl HttpApi {
+    /// Creates connection that sends the token with each request.
+    /// If endpoint is None, the default Zenkit endpoint is used.
+    fn connect(token: &str, endpoint: Option<&str>) -> Result<Self, Error> {
+        use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+        if token.is_empty() {
+            return Err(Error::Message("Missing API token".to_string()));
+        }
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        headers.insert(
+            "Zenkit-API-Key",
+            HeaderValue::from_str(token)
+                .map_err(|_| Error::Message("token has non-ascii chars".to_string()))?,
+        );
+        let client = reqwest::Client::builder()
+            .default_headers(headers)
+            .build()
+            .map_err(zenkit::Error::from)?;
+        Ok(Self::new(client, endpoint))
+    }
+
+    /// Creates connection that sends requests with the http client
+    fn new(client: reqwest::Client, endpoint: Option<&str>) -> Self {
+        let endpoint = match endpoint {
+            Some(endpoint) => endpoint.trim_end_matches('/').to_string(),
+            None => ApiConfig::default().endpoint,
+        };
+        Self { client, endpoint }
+    }
+}
+
+#[async_trait::async_trait]
+impl Backend for HttpApi {
+    async fn get_entry(&self, list_id: ID, entry: AllId) -> Result<Entry, Error> {
+        let url = format!("{}/lists/{}/entries/{}", self.endpoint, list_id, entry);
+        HttpApi::json(self.client.get(&url).send().await).await
+    }
+
+    async fn get_list_entries(
+        &self,
+        list_id: ID,
+        params: &GetEntriesRequest,
+    ) -> Result<Vec<Entry>, Error> {
+        let url = format!("{}/lists/{}/entries/filter", self.endpoint, list_id);
+        HttpApi::json(self.client.post(&url).json(params).send().await).await
+    }
+
+    async fn create_entry(&self, list_id: ID, val: serde_json::Value) -> Result<Entry, Error> {
+        let url = format!("{}/lists/{}/entries", self.endpoint, list_id);
+        HttpApi::json(self.client.post(&url).json(&val).send().await).await
+    }
+
+    async fn update_entry(
+        &self,
+        list_id: ID,
+        entry_id: ID,
+        val: serde_json::Value,
+    ) -> Result<Entry, Error> {
+        let url = format!("{}/lists/{}/entries/{}", self.endpoint, list_id, entry_id);
+        HttpApi::json(self.client.put(&url).json(&val).send().await).await
+    }
+
+    async fn delete_entry(&self, list_id: ID, entry_id: ID) -> Result<(), Error> {
+        let url = format!(
+            "{}/lists/{}/deprecated-entries/{}",
+            self.endpoint, list_id, entry_id
+        );
+        let _: serde_json::Value = HttpApi::json(self.client.delete(&url).send().await).await?;
+        Ok(())
+    }
+
+    async fn archive_entry(&self, list_id: ID, entry_id: ID) -> Result<Entry, Error> {
+        let url = format!("{}/lists/{}/entries/{}", self.endpoint, list_id, entry_id);
+        let _: serde_json::Value = HttpApi::json(self.client.delete(&url).send().await).await?;
+        self.get_entry(list_id, AllId::ID(entry_id)).await
+    }
+
+    async fn restore_entry(&self, list_id: ID, entry_id: ID) -> Result<Entry, Error> {
+        let url = format!(
+            "{}/lists/{}/deprecated-entries/{}/restore",
+            self.endpoint, list_id, entry_id
+        );
+        let _: serde_json::Value = HttpApi::json(self.client.post(&url).send().await).await?;
+        self.get_entry(list_id, AllId::ID(entry_id)).await
+    }
+}
+
+impl HttpApi {
+    /// Checks response for http errors, and deserializes the response body
+    async fn json<T: serde::de::DeserializeOwned>(
+        resp: Result<reqwest::Response, reqwest::Error>,
+    ) -> Result<T, Error> {
+        let resp = resp.map_err(zenkit::Error::from)?;
+        let status = resp.status();
+        // Retry-After in seconds (the http-date form is ignored)
+        let retry_after = resp
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.trim().parse().ok())
+            .map(Duration::from_secs);
+        let bytes = resp.bytes().await.map_err(zenkit::Error::from)?;
+        if !status.is_success() {
+            let message = match serde_json::from_slice::<zenkit::types::ErrorResult>(&bytes) {
+                Ok(res) => zenkit::Error::ApiError(status.as_u16(), Some(res.error)).to_string(),
+                Err(_) => format!(
+                    "Server returned status {}:{}",
+                    status.as_u16(),
+                    String::from_utf8_lossy(&bytes)
+                ),
+            };
+            return Err(Error::Status {
+                status: status.as_u16(),
+                message,
+                retry_after,
+            });
+        }
+        serde_json::from_slice(&bytes)
+            .map_err(|e| Error::Zenkit(format!("invalid response: {}", e)))
+    }
+}
+
+/// In-memory backend for tests, enabled with the "mock" feature
+#[cfg(feature = "mock")]
+pub mod mock {
+    use super::{AllId, Backend, DateTime, Entry, Error, GetEntriesRequest, JsonMap, Utc, ID};
+    use serde_json::{json, Value};
+    use std::{
+        cmp::Ordering,
+        collections::BTreeMap,
+        sync::{Mutex, MutexGuard},
+    };
+
+    /// Lists in the workspace, as (id, uuid)
+    const LISTS: &[(ID, &str)] = &[
+        (201, "857e17da-f7e6-5a5b-ae2e-354ac75f171b"),
+        (202, "250746a3-de0f-53f4-b995-953f5e019793"),
+    ];
+
+    /// Fields of the lists, as (id, uuid), sorted by id
+    const FIELDS: &[(ID, &str)] = &[
+        (1, "55940c1d-4dd6-551a-8224-65a601e34527"),
+        (2, "3465be3f-51c2-54cd-b17a-8caaebada77c"),
+        (3, "ae466722-22aa-5692-bbd8-e0c42fdedf6d"),
+        (4, "efb454eb-0dbe-58bb-b567-8e91d74a25b1"),
+        (5, "846a496b-9dcb-5511-a460-0cf2647a6b0a"),
+        (6, "490d5ec6-9f7f-5d99-9767-39f04ae392cc"),
+        (7, "5a37cf9a-5c4c-5bf3-9b4f-cdbb77777818"),
+        (8, "e4a5b2a8-92da-56bd-a487-bb3f0c6d19a5"),
+        (9, "d674abe3-1547-559b-8281-d585f0fee7d5"),
+        (20, "bd2e279e-aba9-5d28-8cb0-29742c3744dc"),
+        (21, "c7f61cc2-9256-5bd4-9ad6-205bf1521614"),
+        (22, "e5fdac3d-958a-5ff8-80b8-ff1524b50822"),
+        (23, "bfd5d530-913b-53b2-9d0f-78d3c8f7f226"),
+        (24, "cf2d45d6-0fd2-51ff-83c0-4d62304c9e52"),
+        (25, "7dc2c941-7938-5644-a624-52c253b9b02c"),
+        (26, "4e88bb09-7a96-5448-8d37-e52e5707bb26"),
+        (27, "03af7f30-2542-5ccc-b57e-8633b8408efc"),
+        (28, "fcc06a80-df72-52e6-bff3-f1dae4da7365"),
+        (29, "dd0a353c-1e8b-5773-8022-27f5b6fd13b6"),
+        (30, "844104a4-86f0-57b4-ba64-70893bcf9b18"),
+        (31, "bf0c5276-920d-5b13-b079-14919038a7e2"),
+        (32, "6b8b96d4-a26a-5b31-a75a-0a8e05282d39"),
+        (33, "c18d1f7e-20ea-5769-9e9b-ebce76ed6b79"),
+        (34, "1c064829-21cb-5155-bc07-a733f349e298"),
+        (35, "93be2121-b674-505c-8a46-f6a57a8a428e"),
+        (36, "35d834bc-358a-5b10-ac83-fd411b2cc0c1"),
+        (37, "c290737d-4ddf-5e86-a29d-65ec431eb93c"),
+    ];
+
+    /// Names of category labels, sorted by id
+    const LABELS: &[(ID, &str)] = &[
+        (301, "In progress"),
+        (302, "Won"),
+        (303, "Lost"),
+        (311, "Hot"),
+        (312, "Enterprise"),
+        (313, "Renewal"),
+    ];
+
+    /// Backend that keeps entries in memory, so that code using this crate can be tested
+    /// without network access. Creates and updates apply the fields set by builders,
+    /// including the `updateAction` of multi-value fields: "append" adds values,
+    /// "remove" removes them, and "replace" sets the field.
+    /// Entry queries apply the filters and sort order of the generated `*Query` types.
+    /// Archived entries are kept, and only returned by queries that include them.
+    #[derive(Default)]
+    pub struct MockBackend {
+        state: Mutex<MockState>,
+    }
+
+    #[derive(Default)]
+    struct MockState {
+        /// entries of each list, in the order added
+        lists: BTreeMap<ID, Vec<JsonMap>>,
+        /// highest entry id in use
+        last_id: ID,
+    }
+
+    impl MockBackend {
+        /// Creates backend with no entries
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Creates backend with entries from a json fixture: an object whose keys are
+        /// list ids or uuids, and whose values are arrays of entries (see `insert`)
+        pub fn from_json(json: &str) -> Result<Self, Error> {
+            let fixture: BTreeMap<String, Vec<Value>> = serde_json::from_str(json)
+                .map_err(|e| Error::Message(format!("invalid mock fixture: {}", e)))?;
+            let backend = Self::new();
+            // entries without an id are numbered after the highest id in the fixture
+            backend.lock().last_id = fixture
+                .values()
+                .flatten()
+                .filter_map(|entry| entry.get("id").and_then(|v| v.as_u64()))
+                .max()
+                .unwrap_or_default();
+            for (key, entries) in fixture.into_iter() {
+                let list_id = list_id(&key).ok_or_else(|| {
+                    Error::Message(format!("invalid mock fixture: unknown list '{}'", key))
+                })?;
+                for entry in entries.into_iter() {
+                    backend.insert(list_id, entry)?;
+                }
+            }
+            Ok(backend)
+        }
+
+        /// Adds an entry to a list. The entry is json in the format returned by Zenkit.
+        /// Missing properties, such as id, uuid, and timestamps, are filled in,
+        /// and multi-value fields may be given as ids only
+        /// (e.g., `"<field uuid>_categories": [12]`).
+        pub fn insert(&self, list_id: ID, entry: Value) -> Result<Entry, Error> {
+            let fields = as_object(entry)?;
+            let mut state = self.lock();
+            let id = match fields.get("id").and_then(|v| v.as_u64()) {
+                Some(id) => id,
+                None => state.last_id + 1,
+            };
+            state.last_id = state.last_id.max(id);
+            let mut obj = new_entry(list_id, id);
+            apply_fields(&mut obj, &fields, "replace");
+            state.lists.entry(list_id).or_default().push(obj.clone());
+            to_entry(obj)
+        }
+
+        /// Returns the json of the entries in a list, for checking the results of a test
+        pub fn entries(&self, list_id: ID) -> Vec<Value> {
+            self.lock()
+                .lists
+                .get(&list_id)
+                .map(|entries| entries.iter().cloned().map(Value::Object).collect())
+                .unwrap_or_default()
+        }
+
+        fn lock(&self) -> MutexGuard<'_, MockState> {
+            self.state.lock().unwrap_or_else(|e| e.into_inner())
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl Backend for MockBackend {
+        async fn get_entry(&self, list_id: ID, entry: AllId) -> Result<Entry, Error> {
+            let key = entry.to_string();
+            let obj = self
+                .lock()
+                .lists
+                .get(&list_id)
+                .and_then(|entries| entries.iter().find(|obj| has_key(obj, &key)))
+                .cloned()
+                .ok_or_else(|| not_found(list_id, &key))?;
+            to_entry(obj)
+        }
+
+        async fn get_list_entries(
+            &self,
+            list_id: ID,
+            params: &GetEntriesRequest,
+        ) -> Result<Vec<Entry>, Error> {
+            let limit = if params.limit == 0 {
+                usize::MAX
+            } else {
+                params.limit
+            };
+            let state = self.lock();
+            let mut entries: Vec<&JsonMap> = state
+                .lists
+                .get(&list_id)
+                .map(|entries| entries.as_slice())
+                .unwrap_or_default()
+                .iter()
+                .filter(|obj| {
+                    params.allow_deprecated || obj.get("deprecated_at").map_or(true, Value::is_null)
+                })
+                .filter(|obj| matches_filter(obj, &params.filter))
+                .collect();
+            for order in params.order_by.iter().rev() {
+                let column = order.column.as_deref().unwrap_or("sortOrder");
+                let descending = matches!(order.direction, zenkit::types::SortDirection::Desc);
+                entries.sort_by(|a, b| compare_values(a.get(column), b.get(column), descending));
+            }
+            entries
+                .into_iter()
+                .skip(params.skip)
+                .take(limit)
+                .cloned()
+                .map(to_entry)
+                .collect()
+        }
+
+        async fn create_entry(&self, list_id: ID, val: Value) -> Result<Entry, Error> {
+            let fields = as_object(val)?;
+            let mut state = self.lock();
+            state.last_id += 1;
+            let mut obj = new_entry(list_id, state.last_id);
+            apply_fields(&mut obj, &fields, update_action(&fields));
+            state.lists.entry(list_id).or_default().push(obj.clone());
+            to_entry(obj)
+        }
+
+        async fn update_entry(
+            &self,
+            list_id: ID,
+            entry_id: ID,
+            val: Value,
+        ) -> Result<Entry, Error> {
+            let fields = as_object(val)?;
+            let mut state = self.lock();
+            let obj = state
+                .lists
+                .get_mut(&list_id)
+                .and_then(|entries| {
+                    entries
+                        .iter_mut()
+                        .find(|obj| has_key(obj, &entry_id.to_string()))
+                })
+                .ok_or_else(|| not_found(list_id, &entry_id.to_string()))?;
+            apply_fields(obj, &fields, update_action(&fields));
+            obj.insert("updated_at".to_string(), json!(Utc::now()));
+            to_entry(obj.clone())
+        }
+
+        async fn delete_entry(&self, list_id: ID, entry_id: ID) -> Result<(), Error> {
+            let mut state = self.lock();
+            let key = entry_id.to_string();
+            let entries = state
+                .lists
+                .get_mut(&list_id)
+                .ok_or_else(|| not_found(list_id, &key))?;
+            let index = entries
+                .iter()
+                .position(|obj| has_key(obj, &key))
+                .ok_or_else(|| not_found(list_id, &key))?;
+            entries.remove(index);
+            Ok(())
+        }
+
+        async fn archive_entry(&self, list_id: ID, entry_id: ID) -> Result<Entry, Error> {
+            self.set_deprecated(list_id, entry_id, json!(Utc::now()))
+        }
+
+        async fn restore_entry(&self, list_id: ID, entry_id: ID) -> Result<Entry, Error> {
+            self.set_deprecated(list_id, entry_id, Value::Null)
+        }
+    }
+
+    impl MockBackend {
+        /// Sets the date an entry was archived, or null to restore it
+        fn set_deprecated(
+            &self,
+            list_id: ID,
+            entry_id: ID,
+            deprecated_at: Value,
+        ) -> Result<Entry, Error> {
+            let mut state = self.lock();
+            let obj = state
+                .lists
+                .get_mut(&list_id)
+                .and_then(|entries| {
+                    entries
+                        .iter_mut()
+                        .find(|obj| has_key(obj, &entry_id.to_string()))
+                })
+                .ok_or_else(|| not_found(list_id, &entry_id.to_string()))?;
+            obj.insert("deprecated_at".to_string(), deprecated_at);
+            to_entry(obj.clone())
+        }
+    }
+
+    /// Returns id of a list in the workspace, from its id or uuid
+    fn list_id(key: &str) -> Option<ID> {
+        LISTS
+            .iter()
+            .find(|(id, uuid)| id.to_string() == key || *uuid == key)
+            .map(|(id, _)| *id)
+    }
+
+    /// Returns uuid of a field
+    fn field_uuid(id: ID) -> Option<&'static str> {
+        FIELDS
+            .binary_search_by_key(&id, |(id, _)| *id)
+            .ok()
+            .map(|index| FIELDS[index].1)
+    }
+
+    /// Returns true if the entry matches all terms of a filter (see `EntryQuery`)
+    fn matches_filter(obj: &JsonMap, filter: &Value) -> bool {
+        filter["AND"]["TERMS"]
+            .as_array()
+            .map(|terms| terms.iter().all(|term| matches_term(obj, term)))
+            .unwrap_or(true)
+    }
+
+    /// Returns true if the entry matches a filter term
+    fn matches_term(obj: &JsonMap, term: &Value) -> bool {
+        let uuid = match term["elementId"].as_u64().and_then(field_uuid) {
+            Some(uuid) => uuid,
+            None => return false,
+        };
+        let value = |kind: &str| {
+            obj.get(&format!("{}_{}", uuid, kind))
+                .filter(|v| !is_empty(v))
+        };
+        let modus = term["modus"].as_str().unwrap_or_default();
+        let is_set = [
+            "text",
+            "link",
+            "number",
+            "date",
+            "checked",
+            "categories",
+            "persons",
+            "references",
+        ]
+        .iter()
+        .any(|kind| value(kind).is_some());
+        let any_of = |kind: &str, key: &str| match (value(kind), term[key].as_array()) {
+            (Some(Value::Array(values)), Some(wanted)) => values.iter().any(|v| wanted.contains(v)),
+            _ => false,
+        };
+        if modus == "isEmpty" {
+            !is_set
+        } else if modus == "isNotEmpty" {
+            is_set
+        } else if let Some(wanted) = term["text"].as_str() {
+            let text = value("text")
+                .or_else(|| value("link"))
+                .and_then(|v| v.as_str())
+                .unwrap_or_default();
+            match modus {
+                "contains" => text.contains(wanted),
+                "notContains" => !text.contains(wanted),
+                "startsWith" => text.starts_with(wanted),
+                "endsWith" => text.ends_with(wanted),
+                "notEquals" => text != wanted,
+                _ => text == wanted,
+            }
+        } else if term.get("numberFrom").is_some() || term.get("numberTo").is_some() {
+            let in_range = match value("number").and_then(|v| v.as_f64()) {
+                Some(n) => {
+                    term["numberFrom"].as_f64().map_or(true, |from| n >= from)
+                        && term["numberTo"].as_f64().map_or(true, |to| n <= to)
+                }
+                None => false,
+            };
+            in_range != (modus == "notInRange")
+        } else if term.get("dateFrom").is_some() || term.get("dateTo").is_some() {
+            let date = |v: &Value| {
+                v.as_str()
+                    .and_then(|s| s.parse::<DateTime<Utc>>().ok())
+                    .map(|d| d.timestamp_millis())
+            };
+            match value("date").and_then(date) {
+                Some(d) => {
+                    date(&term["dateFrom"]).map_or(true, |from| d >= from)
+                        && date(&term["dateTo"]).map_or(true, |to| d <= to)
+                }
+                None => false,
+            }
+        } else if let Some(checked) = term["checked"].as_bool() {
+            value("checked")
+                .and_then(|v| v.as_bool())
+                .unwrap_or_default()
+                == checked
+        } else if term.get("filterCategories").is_some() {
+            any_of("categories", "filterCategories")
+        } else if term.get("filterPersons").is_some() {
+            any_of("persons", "filterPersons")
+        } else {
+            true
+        }
+    }
+
+    /// Returns true for null, empty strings, and empty arrays
+    fn is_empty(val: &Value) -> bool {
+        match val {
+            Value::Null => true,
+            Value::String(s) => s.is_empty(),
+            Value::Array(a) => a.is_empty(),
+            _ => false,
+        }
+    }
+
+    /// Compares values of a sort column. Missing values are last in either direction.
+    fn compare_values(a: Option<&Value>, b: Option<&Value>, descending: bool) -> Ordering {
+        let ord = match (a.filter(|v| !is_empty(v)), b.filter(|v| !is_empty(v))) {
+            (Some(Value::Number(a)), Some(Value::Number(b))) => a
+                .as_f64()
+                .partial_cmp(&b.as_f64())
+                .unwrap_or(Ordering::Equal),
+            (Some(Value::String(a)), Some(Value::String(b))) => a.cmp(b),
+            (Some(Value::Bool(a)), Some(Value::Bool(b))) => a.cmp(b),
+            (Some(_), None) => return Ordering::Less,
+            (None, Some(_)) => return Ordering::Greater,
+            _ => Ordering::Equal,
+        };
+        if descending {
+            ord.reverse()
+        } else {
+            ord
+        }
+    }
+
+    /// Returns name of a category label
+    fn label_name(id: ID) -> Option<&'static str> {
+        LABELS
+            .binary_search_by_key(&id, |(id, _)| *id)
+            .ok()
+            .map(|index| LABELS[index].1)
+    }
+
+    /// Returns true if the entry's id, uuid, or short id is the key
+    fn has_key(obj: &JsonMap, key: &str) -> bool {
+        ["id", "uuid", "shortId"]
+            .iter()
+            .any(|prop| match obj.get(*prop) {
+                Some(Value::String(s)) => s == key,
+                Some(Value::Number(n)) => n.to_string() == key,
+                _ => false,
+            })
+    }
+
+    /// Returns the updateAction of a request: "append", "remove", or "replace"
+    fn update_action(fields: &JsonMap) -> &str {
+        fields
+            .get("updateAction")
+            .and_then(|v| v.as_str())
+            .unwrap_or("replace")
+    }
+
+    /// Creates entry with no field values
+    fn new_entry(list_id: ID, id: ID) -> JsonMap {
+        let now = json!(Utc::now());
+        let mut obj = JsonMap::new();
+        obj.insert("id".to_string(), json!(id));
+        obj.insert("shortId".to_string(), json!(format!("mock-{}", id)));
+        obj.insert(
+            "uuid".to_string(),
+            json!(format!("00000000-0000-4000-8000-{:012x}", id)),
+        );
+        obj.insert("listId".to_string(), json!(list_id));
+        obj.insert("created_at".to_string(), now.clone());
+        obj.insert("updated_at".to_string(), now);
+        obj.insert("deprecated_at".to_string(), Value::Null);
+        obj.insert("created_by".to_string(), json!(0));
+        obj.insert("updated_by".to_string(), json!(0));
+        obj.insert("displayString".to_string(), json!(""));
+        obj.insert("sortOrder".to_string(), json!(id));
+        obj.insert("comment_count".to_string(), json!(0));
+        obj.insert("checklists".to_string(), json!([]));
+        obj
+    }
+
+    /// Applies the fields set by a builder (or from a fixture) to an entry
+    fn apply_fields(obj: &mut JsonMap, fields: &JsonMap, action: &str) {
+        for (key, value) in fields.iter() {
+            if key == "updateAction" {
+                continue;
+            }
+            let value = match (action, obj.get(key), value) {
+                ("append", Some(Value::Array(old)), Value::Array(added)) => {
+                    let mut values = old.clone();
+                    values.extend(added.iter().filter(|v| !old.contains(v)).cloned());
+                    Value::Array(values)
+                }
+                ("remove", Some(Value::Array(old)), Value::Array(removed)) => Value::Array(
+                    old.iter()
+                        .filter(|v| !removed.contains(v))
+                        .cloned()
+                        .collect(),
+                ),
+                ("remove", None, Value::Array(_)) => Value::Array(Vec::new()),
+                _ => value.clone(),
+            };
+            if let Some(details) = sort_details(key, &value) {
+                obj.insert(format!("{}_sort", key), details);
+            }
+            obj.insert(key.clone(), value);
+        }
+    }
+
+    /// Returns the "_sort" property of a multi-value field, which has the details
+    /// of each value, or None for other fields
+    fn sort_details(key: &str, value: &Value) -> Option<Value> {
+        let values = value.as_array()?;
+        let details = if key.ends_with("_categories") {
+            values
+                .iter()
+                .map(|id| json!({ "id": id, "name": id.as_u64().and_then(label_name) }))
+                .collect()
+        } else if key.ends_with("_persons") {
+            values.iter().map(|id| json!({ "id": id })).collect()
+        } else if key.ends_with("_references") {
+            values.iter().map(|uuid| json!({ "uuid": uuid })).collect()
+        } else {
+            return None;
+        };
+        Some(Value::Array(details))
+    }
+
+    fn as_object(val: Value) -> Result<JsonMap, Error> {
+        match val {
+            Value::Object(map) => Ok(map),
+            _ => Err(Error::Message(
+                "mock entry must be a json object".to_string(),
+            )),
+        }
+    }
+
+    fn to_entry(obj: JsonMap) -> Result<Entry, Error> {
+        serde_json::from_value(Value::Object(obj))
+            .map_err(|e| Error::Message(format!("invalid mock entry: {}", e)))
+    }
+
+    fn not_found(list_id: ID, key: &str) -> Error {
+        Error::Zenkit(format!("entry {} not found in list {}", key, list_id))
+    }
+}
@@ -0,0 +1,1493 @@
+#![allow(dead_code, unused_imports)]
+//! SayHiByeList Line one with "quotes" and a \ backslash
+//! Indented:
+//!
+//! let x: i32 = "not code";
+//! \`\`\`
+//! fn broken( {
+//! \`\`\`
+//! CRLF
+//! line
+//! bare CR */ end
+//  Generated by zk-codegen v0.5.4 from schema 106e265f968c2049
+use super::{lookup_label, run_batch, Api, EntryQuery, Error};
+use serde_json::{self, json, Value};
+use std::{convert::AsRef, str::FromStr, sync::Arc};
+use zenkit::types::{DateTime, Entry, File, GetEntriesRequest, JsonMap, TextFormat, Utc, ID};
+
+/// SayHiByeList Line one with "quotes" and a \ backslash
+/// Indented:
+///
+/// let x: i32 = "not code";
+/// \`\`\`
+/// fn broken( {
+/// \`\`\`
+/// CRLF
+/// line
+/// bare CR */ end
+///
+pub const LIST_SAY_HI_BYE_ID: ID = 701;
+pub const LIST_SAY_HI_BYE_SHORT_ID: &str = "L701";
+pub const LIST_SAY_HI_BYE_UUID: &str = "cb91ddb6-6488-5a3b-9d7f-43ef60bd3a0c";
+pub const LIST_SAY_HI_BYE_NAME: &str = "Say \"Hi\" \\ Bye";
+
+/// ID of a Greeting (item of List 'Say "Hi" \ Bye')
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(transparent)]
+pub struct GreetingId(ID);
+
+impl GreetingId {
+    /// Creates GreetingId from the item's numeric id
+    pub fn new(id: ID) -> Self {
+        Self(id)
+    }
+
+    /// Returns the numeric id
+    pub fn value(&self) -> ID {
+        self.0
+    }
+}
+
+impl std::fmt::Display for GreetingId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for GreetingId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<ID>()
+            .map(Self)
+            .map_err(|_| Error::Message(format!("invalid Greeting id '{}'", s)))
+    }
+}
+
+impl From<GreetingId> for ID {
+    fn from(id: GreetingId) -> ID {
+        id.0
+    }
+}
+
+/// UUID of a Greeting (item of List 'Say "Hi" \ Bye').
+/// Reference setters accept this type or a `&Greeting`.
+#[derive(
+    Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(transparent)]
+pub struct GreetingUuid(String);
+
+impl GreetingUuid {
+    /// Creates GreetingUuid from a uuid string. The uuid is not validated;
+    /// use `parse` to check the format.
+    pub fn new<S: Into<String>>(uuid: S) -> Self {
+        Self(uuid.into())
+    }
+
+    /// Returns the uuid string
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for GreetingUuid {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for GreetingUuid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for GreetingUuid {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if super::is_uuid(s) {
+            Ok(Self(s.to_string()))
+        } else {
+            Err(Error::Message(format!("invalid Greeting uuid '{}'", s)))
+        }
+    }
+}
+
+impl From<&Greeting> for GreetingUuid {
+    fn from(item: &Greeting) -> Self {
+        item.get_uuid()
+    }
+}
+
+impl From<&GreetingUuid> for GreetingUuid {
+    fn from(uuid: &GreetingUuid) -> Self {
+        uuid.clone()
+    }
+}
+
+/// SayHiByeList - List of Greeting, using the global client.
+/// For a separate connection, use `WorkspaceClient::say_hi_bye()`.
+/// Line one with "quotes" and a \ backslash
+/// Indented:
+///
+/// let x: i32 = "not code";
+/// \`\`\`
+/// fn broken( {
+/// \`\`\`
+/// CRLF
+/// line
+/// bare CR */ end
+pub struct SayHiByeList {}
+
+impl SayHiByeList {
+    /// fetch Greeting by its ID
+    pub async fn get(greeting_id: GreetingId) -> Result<Greeting, Error> {
+        SayHiByeClient::new(Api::global()).get(greeting_id).await
+    }
+
+    /// fetch Greeting by its UUID
+    pub async fn get_by_uuid(greeting_uuid: &GreetingUuid) -> Result<Greeting, Error> {
+        SayHiByeClient::new(Api::global())
+            .get_by_uuid(greeting_uuid)
+            .await
+    }
+
+    /// Returns all Greeting
+    pub async fn get_items() -> Result<Vec<Greeting>, Error> {
+        SayHiByeClient::new(Api::global()).get_items().await
+    }
+
+    /// Returns stream of all Greeting, fetched a page at a time as the stream is read.
+    /// For other page sizes, or to resume at an offset, use `query()`.
+    pub fn stream_items() -> impl futures::Stream<Item = Result<Greeting, Error>> + Send {
+        SayHiByeClient::new(Api::global()).stream_items()
+    }
+
+    /// Creates query for Greeting matching conditions, which are checked by the server
+    pub fn query() -> GreetingQuery {
+        GreetingQuery::new(Api::global())
+    }
+
+    /// Initialize builder for creating a new Greeting
+    pub fn create() -> NewGreetingBuilder {
+        NewGreetingBuilder::new(Api::global())
+    }
+
+    /// Creates builder for updating an existing Greeting
+    pub fn update(greeting_id: GreetingId) -> UpdateGreetingBuilder {
+        UpdateGreetingBuilder::new(Api::global(), greeting_id)
+    }
+
+    /// Archives Greeting, and returns the archived Greeting
+    pub async fn archive(greeting_id: GreetingId) -> Result<Greeting, Error> {
+        SayHiByeClient::new(Api::global())
+            .archive(greeting_id)
+            .await
+    }
+
+    /// Restores archived Greeting, and returns the restored Greeting
+    pub async fn restore(greeting_id: GreetingId) -> Result<Greeting, Error> {
+        SayHiByeClient::new(Api::global())
+            .restore(greeting_id)
+            .await
+    }
+
+    /// Deletes Greeting permanently
+    pub async fn delete(greeting_id: GreetingId) -> Result<(), Error> {
+        SayHiByeClient::new(Api::global()).delete(greeting_id).await
+    }
+
+    /// Creates Greeting, sending up to `concurrency` requests at a time.
+    /// Returns the result of each builder, in the order of `builders`, so that
+    /// a failure doesn't affect the other items. Each builder uses the client
+    /// it was created with. Creates are not retried (see `RetryPolicy`).
+    pub async fn create_many(
+        builders: Vec<NewGreetingBuilder>,
+        concurrency: usize,
+    ) -> Vec<Result<Greeting, Error>> {
+        run_batch(
+            builders
+                .into_iter()
+                .map(|mut b| async move { b.execute().await }),
+            concurrency,
+        )
+        .await
+    }
+
+    /// Updates Greeting, sending up to `concurrency` requests at a time.
+    /// Returns the result of each builder, in the order of `builders`, so that
+    /// a failure doesn't affect the other items. Each builder uses the client
+    /// it was created with. Updates are not retried (see `RetryPolicy`).
+    pub async fn update_many(
+        builders: Vec<UpdateGreetingBuilder>,
+        concurrency: usize,
+    ) -> Vec<Result<Greeting, Error>> {
+        run_batch(
+            builders
+                .into_iter()
+                .map(|mut b| async move { b.execute().await }),
+            concurrency,
+        )
+        .await
+    }
+}
+
+/// Client for List 'Say "Hi" \ Bye', from `WorkspaceClient::say_hi_bye()`.
+/// Items and builders from this client use the client's connection.
+#[derive(Clone)]
+pub struct SayHiByeClient {
+    api: Api,
+}
+
+impl SayHiByeClient {
+    pub(crate) fn new(api: Api) -> Self {
+        Self { api }
+    }
+
+    /// fetch Greeting by its ID
+    pub async fn get(&self, greeting_id: GreetingId) -> Result<Greeting, Error> {
+        let obj = self
+            .api
+            .get_entry(LIST_SAY_HI_BYE_ID, greeting_id.value())
+            .await?;
+        Ok(Greeting::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// fetch Greeting by its UUID
+    pub async fn get_by_uuid(&self, greeting_uuid: &GreetingUuid) -> Result<Greeting, Error> {
+        let obj = self
+            .api
+            .get_entry(LIST_SAY_HI_BYE_ID, greeting_uuid.as_str())
+            .await?;
+        Ok(Greeting::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// Returns all Greeting
+    pub async fn get_items(&self) -> Result<Vec<Greeting>, Error> {
+        let entries = self.api.load_entries(LIST_SAY_HI_BYE_ID).await?;
+        let items: Vec<Greeting> = entries
+            .into_iter()
+            .map(|e| Greeting::new(Arc::new(e), self.api.clone()))
+            .collect();
+        Ok(items)
+    }
+
+    /// Returns stream of all Greeting, fetched a page at a time as the stream is read.
+    /// For other page sizes, or to resume at an offset, use `query()`.
+    pub fn stream_items(&self) -> impl futures::Stream<Item = Result<Greeting, Error>> + Send {
+        self.query().stream_items()
+    }
+
+    /// Creates query for Greeting matching conditions, which are checked by the server
+    pub fn query(&self) -> GreetingQuery {
+        GreetingQuery::new(self.api.clone())
+    }
+
+    /// Initialize builder for creating a new Greeting
+    pub fn create(&self) -> NewGreetingBuilder {
+        NewGreetingBuilder::new(self.api.clone())
+    }
+
+    /// Creates builder for updating an existing Greeting
+    pub fn update(&self, greeting_id: GreetingId) -> UpdateGreetingBuilder {
+        UpdateGreetingBuilder::new(self.api.clone(), greeting_id)
+    }
+
+    /// Archives Greeting, and returns the archived Greeting. Archived items are
+    /// left out of `get_items` and queries, unless a query has `include_archived(true)`.
+    pub async fn archive(&self, greeting_id: GreetingId) -> Result<Greeting, Error> {
+        let obj = self
+            .api
+            .archive_entry(LIST_SAY_HI_BYE_ID, greeting_id.value())
+            .await?;
+        Ok(Greeting::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// Restores archived Greeting, and returns the restored Greeting
+    pub async fn restore(&self, greeting_id: GreetingId) -> Result<Greeting, Error> {
+        let obj = self
+            .api
+            .restore_entry(LIST_SAY_HI_BYE_ID, greeting_id.value())
+            .await?;
+        Ok(Greeting::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// Deletes Greeting permanently
+    pub async fn delete(&self, greeting_id: GreetingId) -> Result<(), Error> {
+        self.api
+            .delete_entry(LIST_SAY_HI_BYE_ID, greeting_id.value())
+            .await
+    }
+}
+
+/// Greeting (item of List 'Say "Hi" \ Bye') Line one with "quotes" and a \ backslash
+/// Indented:
+///
+/// let x: i32 = "not code";
+/// \`\`\`
+/// fn broken( {
+/// \`\`\`
+/// CRLF
+/// line
+/// bare CR */ end
+pub struct Greeting {
+    obj: Arc<Entry>,
+    api: Api,
+}
+
+impl Greeting {
+    /// Creates Greeting wrapping Zenkit Entry
+    fn new(obj: Arc<Entry>, api: Api) -> Self {
+        Self { obj, api }
+    }
+
+    /// 'Title "main"' in 'Say "Hi" \ Bye' Line one with "quotes" and a \ backslash
+    /// Indented:
+    ///
+    /// let x: i32 = "not code";
+    /// \`\`\`
+    /// fn broken( {
+    /// \`\`\`
+    /// CRLF
+    /// line
+    /// bare CR */ end
+    pub const FIELD_TITLE_MAIN_ID: ID = 71;
+    pub const FIELD_TITLE_MAIN_UUID: &'static str = "f888c136-2aa2-5db4-8b3d-f53d3739bc82";
+    pub const FIELD_TITLE_MAIN_NAME: &'static str = "Title \"main\"";
+
+    /// Returns 'Title "main"' - Line one with "quotes" and a \ backslash
+    /// Indented:
+    ///
+    /// let x: i32 = "not code";
+    /// \`\`\`
+    /// fn broken( {
+    /// \`\`\`
+    /// CRLF
+    /// line
+    /// bare CR */ end or None if unset
+    pub fn get_title_main(&self) -> Option<&str> {
+        self.obj
+            .fields
+            .get("f888c136-2aa2-5db4-8b3d-f53d3739bc82_text")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+    }
+
+    /// Returns the text format for 'Title "main"'
+    pub fn get_title_main_format(&self) -> TextFormat {
+        self.obj
+            .fields
+            .get("f888c136-2aa2-5db4-8b3d-f53d3739bc82_textType")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+            // if undefined or unexpected value, return "plain"
+            .map(|f| TextFormat::from_str(f).unwrap_or_default())
+            .unwrap_or_default()
+    }
+
+    /// 'Path\To' in 'Say "Hi" \ Bye' multi
+    /// line
+    pub const FIELD_PATH_TO_ID: ID = 72;
+    pub const FIELD_PATH_TO_UUID: &'static str = "cbd7a483-c465-5579-97fd-5133e8fdbf82";
+    pub const FIELD_PATH_TO_NAME: &'static str = "Path\\To";
+
+    /// Returns 'Path\To' - multi
+    /// line or None if unset
+    pub fn get_path_to(&self) -> Option<&str> {
+        self.obj
+            .fields
+            .get("cbd7a483-c465-5579-97fd-5133e8fdbf82_link")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+    }
+
+    /// 'Mood' in 'Say "Hi" \ Bye' pick
+    /// one
+    pub const FIELD_MOOD_ID: ID = 73;
+    pub const FIELD_MOOD_UUID: &'static str = "e15cdc82-c9a4-553f-b8ce-c4ab65bb6118";
+    pub const FIELD_MOOD_NAME: &'static str = "Mood";
+
+    pub const LABEL_MOOD_SAY_YES_ID: ID = 751;
+
+    /// Returns true if 'Say "yes"' is set on 'Mood'
+    /// pick
+    /// one
+    pub fn is_mood_say_yes(&self) -> bool {
+        self.obj
+            .fields
+            .get("e15cdc82-c9a4-553f-b8ce-c4ab65bb6118_categories")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| v.iter().any(|n| n == Greeting::LABEL_MOOD_SAY_YES_ID))
+            .unwrap_or_default()
+    }
+
+    pub const LABEL_MOOD_BACK_SLASH_ID: ID = 752;
+
+    /// Returns true if 'back\slash' is set on 'Mood'
+    /// pick
+    /// one
+    pub fn is_mood_back_slash(&self) -> bool {
+        self.obj
+            .fields
+            .get("e15cdc82-c9a4-553f-b8ce-c4ab65bb6118_categories")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| v.iter().any(|n| n == Greeting::LABEL_MOOD_BACK_SLASH_ID))
+            .unwrap_or_default()
+    }
+
+    pub const LABEL_MOOD_TWO_LINES_ID: ID = 753;
+
+    /// Returns true if 'two
+    /// lines' is set on 'Mood'
+    /// pick
+    /// one
+    pub fn is_mood_two_lines(&self) -> bool {
+        self.obj
+            .fields
+            .get("e15cdc82-c9a4-553f-b8ce-c4ab65bb6118_categories")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| v.iter().any(|n| n == Greeting::LABEL_MOOD_TWO_LINES_ID))
+            .unwrap_or_default()
+    }
+
+    pub const LABEL_MOOD_LABEL_754_ID: ID = 754;
+
+    /// Returns true if '{}' is set on 'Mood'
+    /// pick
+    /// one
+    pub fn is_mood_label_754(&self) -> bool {
+        self.obj
+            .fields
+            .get("e15cdc82-c9a4-553f-b8ce-c4ab65bb6118_categories")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| v.iter().any(|n| n == Greeting::LABEL_MOOD_LABEL_754_ID))
+            .unwrap_or_default()
+    }
+
+    /// Returns the id for a label, or None if not valid for this field
+    pub fn label_id_for_mood(label: &str) -> Option<ID> {
+        lookup_label(&Greeting::LABELS_MOOD, label)
+    }
+
+    /// label index used for converting from label name to id (sorted)
+    const LABELS_MOOD: [(&'static str, u64); 4] = [
+        ("Say \"yes\"", Greeting::LABEL_MOOD_SAY_YES_ID),
+        ("back\\slash", Greeting::LABEL_MOOD_BACK_SLASH_ID),
+        ("two\nlines", Greeting::LABEL_MOOD_TWO_LINES_ID),
+        ("{}", Greeting::LABEL_MOOD_LABEL_754_ID),
+    ];
+
+    /// Returns label value of 'Mood', or None if field is unset
+    /// pick
+    /// one
+    pub fn get_mood(&self) -> Option<&str> {
+        self.obj
+            .fields
+            .get("e15cdc82-c9a4-553f-b8ce-c4ab65bb6118_categories_sort")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| {
+                v.iter()
+                    .take(1)
+                    .filter_map(|val| val.as_object())
+                    .filter_map(|val| val.get("name"))
+                    .filter_map(|val| val.as_str())
+                    .next()
+                    .unwrap_or_default()
+            })
+    }
+
+    /// Returns id value of 'Mood', or None if field is unset
+    /// pick
+    /// one
+    pub fn get_mood_id(&self) -> Option<ID> {
+        self.obj
+            .fields
+            .get("e15cdc82-c9a4-553f-b8ce-c4ab65bb6118_categories")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| {
+                v.iter()
+                    .take(1)
+                    .filter_map(|val| val.as_i64())
+                    .next()
+                    .map(|val| val as ID)
+            })
+            .unwrap_or_default()
+    }
+
+    /// 'Notes {0}' in 'Say "Hi" \ Bye' format! braces {} {0}
+    pub const FIELD_NOTES_0_ID: ID = 74;
+    pub const FIELD_NOTES_0_UUID: &'static str = "3486ea99-76c3-5fd3-86c3-a18da0ee63c5";
+    pub const FIELD_NOTES_0_NAME: &'static str = "Notes {0}";
+
+    /// Returns 'Notes {0}' - format! braces {} {0} or None if unset
+    pub fn get_notes_0(&self) -> Option<&str> {
+        self.obj
+            .fields
+            .get("3486ea99-76c3-5fd3-86c3-a18da0ee63c5_text")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+    }
+
+    /// Returns the text format for 'Notes {0}'
+    pub fn get_notes_0_format(&self) -> TextFormat {
+        self.obj
+            .fields
+            .get("3486ea99-76c3-5fd3-86c3-a18da0ee63c5_textType")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+            // if undefined or unexpected value, return "plain"
+            .map(|f| TextFormat::from_str(f).unwrap_or_default())
+            .unwrap_or_default()
+    }
+
+    /// Returns url to zenkit item
+    pub fn get_zenkit_url(&self) -> String {
+        format!(
+            "{}{}/{}/",
+            super::item_url_base(),
+            LIST_SAY_HI_BYE_SHORT_ID,
+            self.obj.short_id
+        )
+    }
+
+    /// Returns the item's display string (based on list's primary field)
+    pub fn get_display_string(&self) -> &str {
+        &self.obj.display_string
+    }
+
+    /// Returns user id that created the item
+    pub fn get_created_by_id(&self) -> ID {
+        self.obj.created_by
+    }
+
+    /// Returns user name that created the item
+    pub fn get_created_by_name(&self) -> Option<&str> {
+        self.obj.created_by_displayname.as_deref()
+    }
+
+    /// Returns user id that created the item
+    pub fn get_updated_by_id(&self) -> ID {
+        self.obj.updated_by
+    }
+
+    /// Returns user name that last updated the item
+    pub fn get_updated_by_name(&self) -> Option<&str> {
+        self.obj.updated_by_displayname.as_deref()
+    }
+
+    /// Returns date the item was created, in UTC
+    pub fn get_created_date(&self) -> &DateTime<Utc> {
+        &self.obj.created_at
+    }
+
+    /// Returns date the item was last updated, in UTC
+    pub fn get_updated_date(&self) -> &DateTime<Utc> {
+        &self.obj.updated_at
+    }
+
+    /// Returns date the item was deprecated, in UTC, or None if item isn't deprecated
+    pub fn get_deprecated_date(&self) -> Option<&DateTime<Utc>> {
+        self.obj.deprecated_at.as_ref()
+    }
+
+    /// Returns user id that deprecated the item, or None if item isn't deprecated
+    pub fn get_deprecated_by_id(&self) -> Option<ID> {
+        self.obj.deprecated_by
+    }
+
+    /// Returns the default sort rank within the list
+    pub fn get_sort_order(&self) -> f32 {
+        self.obj.sort_order
+    }
+
+    /// Returns the item's id within the list
+    pub fn get_id(&self) -> GreetingId {
+        GreetingId::new(self.obj.id)
+    }
+
+    /// Returns the item's uuid
+    pub fn get_uuid(&self) -> GreetingUuid {
+        GreetingUuid::new(self.obj.uuid.as_str())
+    }
+
+    /// Returns the underlying Entry object
+    pub fn get_entry(&self) -> Arc<Entry> {
+        self.obj.clone()
+    }
+
+    /// Archives the item, and returns the archived item
+    pub async fn archive(&self) -> Result<Greeting, Error> {
+        SayHiByeClient::new(self.api.clone())
+            .archive(self.get_id())
+            .await
+    }
+
+    /// Restores the archived item, and returns the restored item
+    pub async fn restore(&self) -> Result<Greeting, Error> {
+        SayHiByeClient::new(self.api.clone())
+            .restore(self.get_id())
+            .await
+    }
+
+    /// Deletes the item permanently
+    pub async fn delete(&self) -> Result<(), Error> {
+        SayHiByeClient::new(self.api.clone())
+            .delete(self.get_id())
+            .await
+    }
+} // impl Greeting
+
+/// Builder for creating new Greeting
+pub struct NewGreetingBuilder {
+    api: Api,
+    fields: JsonMap,
+    errs: Vec<String>,
+}
+
+impl NewGreetingBuilder {
+    /// Create builder for new Greeting
+    fn new(api: Api) -> Self {
+        Self {
+            api,
+            fields: JsonMap::new(),
+            errs: Vec::new(),
+        }
+    }
+
+    // internal helper for setting string field
+    #[inline]
+    fn set_s(&mut self, k: &str, v: String) {
+        self.fields.insert(k.to_string(), Value::String(v));
+    }
+    // internal helper for setting json value
+    #[inline]
+    fn set_v(&mut self, k: &str, v: Value) {
+        self.fields.insert(k.to_string(), v);
+    }
+
+    /// Sets text value for Title "main". Line one with "quotes" and a \ backslash
+    /// Indented:
+    ///
+    /// let x: i32 = "not code";
+    /// \`\`\`
+    /// fn broken( {
+    /// \`\`\`
+    /// CRLF
+    /// line
+    /// bare CR */ end
+    /// Uses the field's default TextFormat. To specify format, use _with_format()
+    pub fn set_title_main<T: AsRef<str>>(&mut self, s: T) -> &mut NewGreetingBuilder {
+        self.set_s(
+            "f888c136-2aa2-5db4-8b3d-f53d3739bc82_text",
+            s.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sets text value for Title "main" with format
+    /// Line one with "quotes" and a \ backslash
+    /// Indented:
+    ///
+    /// let x: i32 = "not code";
+    /// \`\`\`
+    /// fn broken( {
+    /// \`\`\`
+    /// CRLF
+    /// line
+    /// bare CR */ end
+    pub fn set_title_main_with_format<T: AsRef<str>>(
+        &mut self,
+        s: T,
+        tf: TextFormat,
+    ) -> &mut NewGreetingBuilder {
+        self.set_s(
+            "f888c136-2aa2-5db4-8b3d-f53d3739bc82_text",
+            s.as_ref().to_string(),
+        );
+        self.set_s(
+            "f888c136-2aa2-5db4-8b3d-f53d3739bc82_textType",
+            tf.to_string(),
+        );
+        self
+    }
+
+    /// Sets URL Path\To. multi
+    /// line
+    pub fn set_path_to<T: AsRef<str>>(&mut self, url: T) -> &mut NewGreetingBuilder {
+        self.set_s(
+            "cbd7a483-c465-5579-97fd-5133e8fdbf82_link",
+            url.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sets Mood to Say "yes". pick
+    /// one
+    pub fn set_mood_say_yes(&mut self) -> &mut NewGreetingBuilder {
+        self.set_v(
+            "e15cdc82-c9a4-553f-b8ce-c4ab65bb6118_categories",
+            json!(vec![Greeting::LABEL_MOOD_SAY_YES_ID]),
+        );
+        self
+    }
+
+    /// Sets Mood to back\slash. pick
+    /// one
+    pub fn set_mood_back_slash(&mut self) -> &mut NewGreetingBuilder {
+        self.set_v(
+            "e15cdc82-c9a4-553f-b8ce-c4ab65bb6118_categories",
+            json!(vec![Greeting::LABEL_MOOD_BACK_SLASH_ID]),
+        );
+        self
+    }
+
+    /// Sets Mood to two
+    /// lines. pick
+    /// one
+    pub fn set_mood_two_lines(&mut self) -> &mut NewGreetingBuilder {
+        self.set_v(
+            "e15cdc82-c9a4-553f-b8ce-c4ab65bb6118_categories",
+            json!(vec![Greeting::LABEL_MOOD_TWO_LINES_ID]),
+        );
+        self
+    }
+
+    /// Sets Mood to {}. pick
+    /// one
+    pub fn set_mood_label_754(&mut self) -> &mut NewGreetingBuilder {
+        self.set_v(
+            "e15cdc82-c9a4-553f-b8ce-c4ab65bb6118_categories",
+            json!(vec![Greeting::LABEL_MOOD_LABEL_754_ID]),
+        );
+        self
+    }
+
+    /// Set Mood by label-id. pick
+    /// one
+    pub fn set_mood_id(&mut self, id: ID) -> &mut NewGreetingBuilder {
+        self.set_v(
+            "e15cdc82-c9a4-553f-b8ce-c4ab65bb6118_categories",
+            json!(vec![id]),
+        );
+        self
+    }
+    /// Set Mood by label-name. pick
+    /// one
+    pub fn set_mood(&mut self, label: &str) -> &mut NewGreetingBuilder {
+        match lookup_label(&Greeting::LABELS_MOOD, label) {
+            Some(id) => {
+                self.set_v(
+                    "e15cdc82-c9a4-553f-b8ce-c4ab65bb6118_categories",
+                    json!(vec![id]),
+                );
+            }
+            None => {
+                self.errs
+                    .push(format!("Label '{}' not found for set_mood_label", label));
+            }
+        }
+        self
+    }
+
+    /// Sets text value for Notes {0}. format! braces {} {0}
+    /// Uses the field's default TextFormat. To specify format, use _with_format()
+    pub fn set_notes_0<T: AsRef<str>>(&mut self, s: T) -> &mut NewGreetingBuilder {
+        self.set_s(
+            "3486ea99-76c3-5fd3-86c3-a18da0ee63c5_text",
+            s.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sets text value for Notes {0} with format
+    /// format! braces {} {0}
+    pub fn set_notes_0_with_format<T: AsRef<str>>(
+        &mut self,
+        s: T,
+        tf: TextFormat,
+    ) -> &mut NewGreetingBuilder {
+        self.set_s(
+            "3486ea99-76c3-5fd3-86c3-a18da0ee63c5_text",
+            s.as_ref().to_string(),
+        );
+        self.set_s(
+            "3486ea99-76c3-5fd3-86c3-a18da0ee63c5_textType",
+            tf.to_string(),
+        );
+        self
+    }
+
+    /// Sends create request to server, returning new Greeting.
+    pub async fn execute(&mut self) -> Result<Greeting, Error> {
+        if !self.errs.is_empty() {
+            return Err(Error::Message(format!(
+                "Errors occurred in NewGreetingBuilder: {:?}",
+                &self.errs
+            )));
+        }
+        let map = self.fields.to_owned();
+        let greeting = self.api.create_entry(701, Value::Object(map)).await?;
+        Ok(Greeting::new(Arc::new(greeting), self.api.clone()))
+    }
+} // end impl NewGreetingBuilder
+
+/// Builder for updating existing Greeting
+pub struct UpdateGreetingBuilder {
+    api: Api,
+    fields: JsonMap,
+    errs: Vec<String>,
+    item_id: GreetingId,
+}
+
+impl UpdateGreetingBuilder {
+    /// Create update builder for Greeting with id
+    fn new(api: Api, item_id: GreetingId) -> Self {
+        Self {
+            api,
+            fields: JsonMap::new(),
+            errs: Vec::new(),
+            item_id,
+        }
+    }
+
+    // internal helper for setting the updateAction of multi-value fields.
+    // Zenkit applies one action to all fields of a request, so a request can't
+    // append or remove values of one field and replace another.
+    fn set_update_action(&mut self, action: &str) {
+        if let Some(Value::String(prev)) = self.fields.get("updateAction") {
+            if prev != action {
+                self.errs.push(format!(
+                    "Conflicting update actions '{}' and '{}': fields that add or remove values \
+                                 must be updated separately from fields that are set",
+                    prev, action
+                ));
+                return;
+            }
+        }
+        self.set_s("updateAction", action.to_string());
+    }
+
+    // internal helper for setting string field
+    #[inline]
+    fn set_s(&mut self, k: &str, v: String) {
+        self.fields.insert(k.to_string(), Value::String(v));
+    }
+    // internal helper for setting json value
+    #[inline]
+    fn set_v(&mut self, k: &str, v: Value) {
+        self.fields.insert(k.to_string(), v);
+    }
+
+    /// Sets text value for Title "main". Line one with "quotes" and a \ backslash
+    /// Indented:
+    ///
+    /// let x: i32 = "not code";
+    /// \`\`\`
+    /// fn broken( {
+    /// \`\`\`
+    /// CRLF
+    /// line
+    /// bare CR */ end
+    /// TextFormat is unchanged (to specify format, use _with_format())
+    pub fn set_title_main<T: AsRef<str>>(&mut self, s: T) -> &mut UpdateGreetingBuilder {
+        self.set_s(
+            "f888c136-2aa2-5db4-8b3d-f53d3739bc82_text",
+            s.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sets text value for Title "main" with format
+    /// Line one with "quotes" and a \ backslash
+    /// Indented:
+    ///
+    /// let x: i32 = "not code";
+    /// \`\`\`
+    /// fn broken( {
+    /// \`\`\`
+    /// CRLF
+    /// line
+    /// bare CR */ end
+    pub fn set_title_main_with_format<T: AsRef<str>>(
+        &mut self,
+        s: T,
+        tf: TextFormat,
+    ) -> &mut UpdateGreetingBuilder {
+        self.set_s(
+            "f888c136-2aa2-5db4-8b3d-f53d3739bc82_text",
+            s.as_ref().to_string(),
+        );
+        self.set_s(
+            "f888c136-2aa2-5db4-8b3d-f53d3739bc82_textType",
+            tf.to_string(),
+        );
+        self
+    }
+
+    /// Sets URL Path\To. multi
+    /// line
+    pub fn set_path_to<T: AsRef<str>>(&mut self, url: T) -> &mut UpdateGreetingBuilder {
+        self.set_s(
+            "cbd7a483-c465-5579-97fd-5133e8fdbf82_link",
+            url.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sets Mood to Say "yes". pick
+    /// one
+    pub fn set_mood_say_yes(&mut self) -> &mut UpdateGreetingBuilder {
+        self.set_v(
+            "e15cdc82-c9a4-553f-b8ce-c4ab65bb6118_categories",
+            json!(vec![Greeting::LABEL_MOOD_SAY_YES_ID]),
+        );
+        self.set_update_action("replace");
+        self
+    }
+
+    /// Sets Mood to back\slash. pick
+    /// one
+    pub fn set_mood_back_slash(&mut self) -> &mut UpdateGreetingBuilder {
+        self.set_v(
+            "e15cdc82-c9a4-553f-b8ce-c4ab65bb6118_categories",
+            json!(vec![Greeting::LABEL_MOOD_BACK_SLASH_ID]),
+        );
+        self.set_update_action("replace");
+        self
+    }
+
+    /// Sets Mood to two
+    /// lines. pick
+    /// one
+    pub fn set_mood_two_lines(&mut self) -> &mut UpdateGreetingBuilder {
+        self.set_v(
+            "e15cdc82-c9a4-553f-b8ce-c4ab65bb6118_categories",
+            json!(vec![Greeting::LABEL_MOOD_TWO_LINES_ID]),
+        );
+        self.set_update_action("replace");
+        self
+    }
+
+    /// Sets Mood to {}. pick
+    /// one
+    pub fn set_mood_label_754(&mut self) -> &mut UpdateGreetingBuilder {
+        self.set_v(
+            "e15cdc82-c9a4-553f-b8ce-c4ab65bb6118_categories",
+            json!(vec![Greeting::LABEL_MOOD_LABEL_754_ID]),
+        );
+        self.set_update_action("replace");
+        self
+    }
+
+    /// Set Mood by label-id. pick
+    /// one
+    pub fn set_mood_id(&mut self, id: ID) -> &mut UpdateGreetingBuilder {
+        self.set_v(
+            "e15cdc82-c9a4-553f-b8ce-c4ab65bb6118_categories",
+            json!(vec![id]),
+        );
+        self.set_update_action("replace");
+        self
+    }
+    /// Set Mood by label-name. pick
+    /// one
+    pub fn set_mood(&mut self, label: &str) -> &mut UpdateGreetingBuilder {
+        match lookup_label(&Greeting::LABELS_MOOD, label) {
+            Some(id) => {
+                self.set_v(
+                    "e15cdc82-c9a4-553f-b8ce-c4ab65bb6118_categories",
+                    json!(vec![id]),
+                );
+                self.set_update_action("replace");
+            }
+            None => {
+                self.errs
+                    .push(format!("Label '{}' not found for set_mood_label", label));
+            }
+        }
+        self
+    }
+    /// Clears (unsets) Mood pick
+    /// one
+    pub fn unset_mood(&mut self) -> &mut UpdateGreetingBuilder {
+        self.set_v(
+            "e15cdc82-c9a4-553f-b8ce-c4ab65bb6118_categories",
+            json!(Vec::<String>::new()),
+        );
+        self.set_update_action("replace");
+        self
+    }
+
+    /// Sets text value for Notes {0}. format! braces {} {0}
+    /// TextFormat is unchanged (to specify format, use _with_format())
+    pub fn set_notes_0<T: AsRef<str>>(&mut self, s: T) -> &mut UpdateGreetingBuilder {
+        self.set_s(
+            "3486ea99-76c3-5fd3-86c3-a18da0ee63c5_text",
+            s.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sets text value for Notes {0} with format
+    /// format! braces {} {0}
+    pub fn set_notes_0_with_format<T: AsRef<str>>(
+        &mut self,
+        s: T,
+        tf: TextFormat,
+    ) -> &mut UpdateGreetingBuilder {
+        self.set_s(
+            "3486ea99-76c3-5fd3-86c3-a18da0ee63c5_text",
+            s.as_ref().to_string(),
+        );
+        self.set_s(
+            "3486ea99-76c3-5fd3-86c3-a18da0ee63c5_textType",
+            tf.to_string(),
+        );
+        self
+    }
+
+    /// Sends update request to server, returning updated Greeting.
+    pub async fn execute(&mut self) -> Result<Greeting, Error> {
+        if !self.errs.is_empty() {
+            return Err(Error::Message(format!(
+                "Errors occurred in UpdateGreetingBuilder: {:?}",
+                &self.errs
+            )));
+        }
+        let map = self.fields.to_owned();
+        let greeting = self
+            .api
+            .update_entry(701, self.item_id.value(), Value::Object(map))
+            .await?;
+        Ok(Greeting::new(Arc::new(greeting), self.api.clone()))
+    }
+} // end impl UpdateGreetingBuilder
+
+/// Query for Greeting in List 'Say "Hi" \ Bye'. Filters and sort order
+/// are sent with the request, so only matching items are downloaded.
+/// All conditions must match.
+#[derive(Clone)]
+pub struct GreetingQuery {
+    api: Api,
+    q: EntryQuery,
+    errs: Vec<String>,
+}
+
+impl GreetingQuery {
+    /// Creates query with no conditions
+    fn new(api: Api) -> Self {
+        Self {
+            api,
+            q: EntryQuery::default(),
+            errs: Vec::new(),
+        }
+    }
+
+    /// Returns at most n Greeting. The default (0) returns all matching items.
+    pub fn limit(mut self, n: usize) -> Self {
+        self.q.limit = n;
+        self
+    }
+
+    /// Skips the first n matching Greeting, for example, to resume
+    /// a stream after the items already read
+    pub fn skip(mut self, n: usize) -> Self {
+        self.q.skip = n;
+        self
+    }
+
+    /// Sets the number of Greeting requested per call. The default is 500.
+    pub fn page_size(mut self, n: usize) -> Self {
+        self.q.page_size = n;
+        self
+    }
+
+    /// Includes archived Greeting if true. By default, they are left out.
+    pub fn include_archived(mut self, include: bool) -> Self {
+        self.q.include_archived = include;
+        self
+    }
+
+    /// Sorts by date created, oldest first
+    pub fn order_by_created_asc(mut self) -> Self {
+        self.q.order_by("created_at", false);
+        self
+    }
+
+    /// Sorts by date created, newest first
+    pub fn order_by_created_desc(mut self) -> Self {
+        self.q.order_by("created_at", true);
+        self
+    }
+
+    /// Sorts by date last updated, oldest first
+    pub fn order_by_updated_asc(mut self) -> Self {
+        self.q.order_by("updated_at", false);
+        self
+    }
+
+    /// Sorts by date last updated, newest first
+    pub fn order_by_updated_desc(mut self) -> Self {
+        self.q.order_by("updated_at", true);
+        self
+    }
+
+    /// Returns the request parameters for all matching items. `get_items` and
+    /// `stream_items` send these in pages of up to `page_size` items.
+    pub fn request(&self) -> GetEntriesRequest {
+        self.q.request()
+    }
+
+    /// Returns the matching Greeting
+    pub async fn get_items(&self) -> Result<Vec<Greeting>, Error> {
+        self.check()?;
+        let entries = self.q.load(&self.api, LIST_SAY_HI_BYE_ID).await?;
+        Ok(entries
+            .into_iter()
+            .map(|e| Greeting::new(Arc::new(e), self.api.clone()))
+            .collect())
+    }
+
+    /// Returns stream of the matching Greeting. Pages are fetched as the stream
+    /// is read, so only one page is in memory at a time. The stream ends after the first
+    /// error; to resume, create the query again with `skip` set to the number of items read.
+    /// Items added or removed while reading can shift the offsets.
+    pub fn stream_items(&self) -> impl futures::Stream<Item = Result<Greeting, Error>> + Send {
+        use futures::StreamExt;
+        if let Err(e) = self.check() {
+            return futures::stream::once(futures::future::ready(Err(e))).left_stream();
+        }
+        let api = self.api.clone();
+        self.q
+            .stream(self.api.clone(), LIST_SAY_HI_BYE_ID)
+            .map(move |r| r.map(|e| Greeting::new(Arc::new(e), api.clone())))
+            .right_stream()
+    }
+
+    /// Returns error if a condition was invalid, e.g., an unknown label
+    fn check(&self) -> Result<(), Error> {
+        if self.errs.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Message(format!(
+                "Errors occurred in GreetingQuery: {:?}",
+                &self.errs
+            )))
+        }
+    }
+
+    /// Matches Greeting where 'Title "main"' is the text
+    pub fn title_main_is(mut self, text: &str) -> Self {
+        self.q.filter(
+            Greeting::FIELD_TITLE_MAIN_ID,
+            json!({ "modus": "equals", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Greeting where 'Title "main"' contains the text
+    pub fn title_main_contains(mut self, text: &str) -> Self {
+        self.q.filter(
+            Greeting::FIELD_TITLE_MAIN_ID,
+            json!({ "modus": "contains", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Greeting where 'Title "main"' starts with the text
+    pub fn title_main_starts_with(mut self, text: &str) -> Self {
+        self.q.filter(
+            Greeting::FIELD_TITLE_MAIN_ID,
+            json!({ "modus": "startsWith", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Greeting where 'Title "main"' is not set
+    pub fn title_main_is_empty(mut self) -> Self {
+        self.q
+            .filter(Greeting::FIELD_TITLE_MAIN_ID, json!({ "modus": "isEmpty" }));
+        self
+    }
+
+    /// Matches Greeting where 'Title "main"' is set
+    pub fn title_main_is_not_empty(mut self) -> Self {
+        self.q.filter(
+            Greeting::FIELD_TITLE_MAIN_ID,
+            json!({ "modus": "isNotEmpty" }),
+        );
+        self
+    }
+
+    /// Sorts by 'Title "main"', ascending
+    pub fn order_by_title_main_asc(mut self) -> Self {
+        self.q
+            .order_by("f888c136-2aa2-5db4-8b3d-f53d3739bc82_text", false);
+        self
+    }
+
+    /// Sorts by 'Title "main"', descending
+    pub fn order_by_title_main_desc(mut self) -> Self {
+        self.q
+            .order_by("f888c136-2aa2-5db4-8b3d-f53d3739bc82_text", true);
+        self
+    }
+
+    /// Matches Greeting where 'Path\To' is the text
+    pub fn path_to_is(mut self, text: &str) -> Self {
+        self.q.filter(
+            Greeting::FIELD_PATH_TO_ID,
+            json!({ "modus": "equals", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Greeting where 'Path\To' contains the text
+    pub fn path_to_contains(mut self, text: &str) -> Self {
+        self.q.filter(
+            Greeting::FIELD_PATH_TO_ID,
+            json!({ "modus": "contains", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Greeting where 'Path\To' starts with the text
+    pub fn path_to_starts_with(mut self, text: &str) -> Self {
+        self.q.filter(
+            Greeting::FIELD_PATH_TO_ID,
+            json!({ "modus": "startsWith", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Greeting where 'Path\To' is not set
+    pub fn path_to_is_empty(mut self) -> Self {
+        self.q
+            .filter(Greeting::FIELD_PATH_TO_ID, json!({ "modus": "isEmpty" }));
+        self
+    }
+
+    /// Matches Greeting where 'Path\To' is set
+    pub fn path_to_is_not_empty(mut self) -> Self {
+        self.q
+            .filter(Greeting::FIELD_PATH_TO_ID, json!({ "modus": "isNotEmpty" }));
+        self
+    }
+
+    /// Sorts by 'Path\To', ascending
+    pub fn order_by_path_to_asc(mut self) -> Self {
+        self.q
+            .order_by("cbd7a483-c465-5579-97fd-5133e8fdbf82_link", false);
+        self
+    }
+
+    /// Sorts by 'Path\To', descending
+    pub fn order_by_path_to_desc(mut self) -> Self {
+        self.q
+            .order_by("cbd7a483-c465-5579-97fd-5133e8fdbf82_link", true);
+        self
+    }
+
+    /// Matches Greeting where 'Mood' has the label id
+    pub fn mood_is_id(mut self, id: ID) -> Self {
+        self.q
+            .filter(Greeting::FIELD_MOOD_ID, json!({ "filterCategories": [id] }));
+        self
+    }
+
+    /// Matches Greeting where 'Mood' has the label name
+    pub fn mood_is(mut self, label: &str) -> Self {
+        match Greeting::label_id_for_mood(label) {
+            Some(id) => self.mood_is_id(id),
+            None => {
+                self.errs
+                    .push(format!("Label '{}' not found for mood", label));
+                self
+            }
+        }
+    }
+
+    /// Matches Greeting where 'Mood' is not set
+    pub fn mood_is_empty(mut self) -> Self {
+        self.q
+            .filter(Greeting::FIELD_MOOD_ID, json!({ "modus": "isEmpty" }));
+        self
+    }
+
+    /// Matches Greeting where 'Mood' is set
+    pub fn mood_is_not_empty(mut self) -> Self {
+        self.q
+            .filter(Greeting::FIELD_MOOD_ID, json!({ "modus": "isNotEmpty" }));
+        self
+    }
+
+    /// Matches Greeting where 'Notes {0}' is the text
+    pub fn notes_0_is(mut self, text: &str) -> Self {
+        self.q.filter(
+            Greeting::FIELD_NOTES_0_ID,
+            json!({ "modus": "equals", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Greeting where 'Notes {0}' contains the text
+    pub fn notes_0_contains(mut self, text: &str) -> Self {
+        self.q.filter(
+            Greeting::FIELD_NOTES_0_ID,
+            json!({ "modus": "contains", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Greeting where 'Notes {0}' starts with the text
+    pub fn notes_0_starts_with(mut self, text: &str) -> Self {
+        self.q.filter(
+            Greeting::FIELD_NOTES_0_ID,
+            json!({ "modus": "startsWith", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Greeting where 'Notes {0}' is not set
+    pub fn notes_0_is_empty(mut self) -> Self {
+        self.q
+            .filter(Greeting::FIELD_NOTES_0_ID, json!({ "modus": "isEmpty" }));
+        self
+    }
+
+    /// Matches Greeting where 'Notes {0}' is set
+    pub fn notes_0_is_not_empty(mut self) -> Self {
+        self.q
+            .filter(Greeting::FIELD_NOTES_0_ID, json!({ "modus": "isNotEmpty" }));
+        self
+    }
+
+    /// Sorts by 'Notes {0}', ascending
+    pub fn order_by_notes_0_asc(mut self) -> Self {
+        self.q
+            .order_by("3486ea99-76c3-5fd3-86c3-a18da0ee63c5_text", false);
+        self
+    }
+
+    /// Sorts by 'Notes {0}', descending
+    pub fn order_by_notes_0_desc(mut self) -> Self {
+        self.q
+            .order_by("3486ea99-76c3-5fd3-86c3-a18da0ee63c5_text", true);
+        self
+    }
+} // end impl GreetingQuery
+
+/// Greeting data, with one field per Zenkit field, that can be serialized,
+/// cached, or sent between threads. Create from an item with `From<&Greeting>`,
+/// and save with `to_new_builder` or `to_update_builder` (or the `*_with` versions,
+/// which take the client to save with).
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct GreetingRecord {
+    /// Item id, or None if the item hasn't been created
+    pub id: Option<GreetingId>,
+    /// Item uuid, or None if the item hasn't been created
+    pub uuid: Option<GreetingUuid>,
+    /// Title "main"
+    pub title_main: Option<String>,
+    /// Path\To
+    pub path_to: Option<String>,
+    /// Mood
+    pub mood: Option<ID>,
+    /// Notes {0}
+    pub notes_0: Option<String>,
+}
+
+impl From<&Greeting> for GreetingRecord {
+    fn from(item: &Greeting) -> Self {
+        Self {
+            id: Some(item.get_id()),
+            uuid: Some(item.get_uuid()),
+            title_main: item.get_title_main().map(String::from),
+            path_to: item.get_path_to().map(String::from),
+            mood: item.get_mood_id(),
+            notes_0: item.get_notes_0().map(String::from),
+        }
+    }
+}
+
+impl GreetingRecord {
+    /// Returns builder for creating a new Greeting with the record's values,
+    /// using the global api client. Fields that are None, and read-only fields, are not set.
+    pub fn to_new_builder(&self) -> NewGreetingBuilder {
+        self.to_new_builder_with(&SayHiByeClient::new(Api::global()))
+    }
+
+    /// Returns builder for creating a new Greeting with the record's values,
+    /// using the client. Fields that are None, and read-only fields, are not set.
+    pub fn to_new_builder_with(&self, client: &SayHiByeClient) -> NewGreetingBuilder {
+        let mut builder = client.create();
+        if let Some(ref v) = self.title_main {
+            builder.set_title_main(v);
+        }
+        if let Some(ref v) = self.path_to {
+            builder.set_path_to(v);
+        }
+        if let Some(ref v) = self.mood {
+            builder.set_mood_id(*v);
+        }
+        if let Some(ref v) = self.notes_0 {
+            builder.set_notes_0(v);
+        }
+        builder
+    }
+
+    /// Returns builder for updating the Greeting with the record's id to the record's values,
+    /// using the global api client. Fields that are None, and read-only fields, are not changed.
+    /// Multi-value fields are replaced. Returns an error if the record has no id.
+    pub fn to_update_builder(&self) -> Result<UpdateGreetingBuilder, Error> {
+        self.to_update_builder_with(&SayHiByeClient::new(Api::global()))
+    }
+
+    /// Returns builder for updating the Greeting with the record's id to the record's values,
+    /// using the client. Fields that are None, and read-only fields, are not changed.
+    /// Multi-value fields are replaced. Returns an error if the record has no id.
+    pub fn to_update_builder_with(
+        &self,
+        client: &SayHiByeClient,
+    ) -> Result<UpdateGreetingBuilder, Error> {
+        let id = self
+            .id
+            .ok_or_else(|| Error::Message("GreetingRecord has no id".to_string()))?;
+        let mut builder = client.update(id);
+        if let Some(ref v) = self.title_main {
+            builder.set_title_main(v);
+        }
+        if let Some(ref v) = self.path_to {
+            builder.set_path_to(v);
+        }
+        if let Some(ref v) = self.mood {
+            builder.set_mood_id(*v);
+        }
+        if let Some(ref v) = self.notes_0 {
+            builder.set_notes_0(v);
+        }
+        Ok(builder)
+    }
+}
+
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    fn assert_send<T: Send>(_: &T) {}
+    assert_send_sync::<Greeting>();
+    assert_send_sync::<NewGreetingBuilder>();
+    assert_send_sync::<UpdateGreetingBuilder>();
+    assert_send_sync::<GreetingRecord>();
+    assert_send(&SayHiByeList::get_items());
+    assert_send(&SayHiByeList::create().execute());
+    assert_send(&SayHiByeList::create_many(Vec::new(), 1));
+};
@@ -0,0 +1,838 @@
+#![allow(dead_code, unused_imports)]
+//! Errors2List
+//  Generated by zk-codegen v0.5.4 from schema d0db8d0e29fb7e6b
+use super::{lookup_label, run_batch, Api, EntryQuery, Error};
+use serde_json::{self, json, Value};
+use std::{convert::AsRef, str::FromStr, sync::Arc};
+use zenkit::types::{DateTime, Entry, File, GetEntriesRequest, JsonMap, TextFormat, Utc, ID};
+
+/// Errors2List
+///
+pub const LIST_ERRORS_ID: ID = 506;
+pub const LIST_ERRORS_SHORT_ID: &str = "L506";
+pub const LIST_ERRORS_UUID: &str = "de9fdd9e-58ac-555e-9586-b7a72d9abf09";
+pub const LIST_ERRORS_NAME: &str = "Errors";
+
+/// ID of a Error2 (item of List 'Errors')
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(transparent)]
+pub struct Error2Id(ID);
+
+impl Error2Id {
+    /// Creates Error2Id from the item's numeric id
+    pub fn new(id: ID) -> Self {
+        Self(id)
+    }
+
+    /// Returns the numeric id
+    pub fn value(&self) -> ID {
+        self.0
+    }
+}
+
+impl std::fmt::Display for Error2Id {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Error2Id {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<ID>()
+            .map(Self)
+            .map_err(|_| Error::Message(format!("invalid Error2 id '{}'", s)))
+    }
+}
+
+impl From<Error2Id> for ID {
+    fn from(id: Error2Id) -> ID {
+        id.0
+    }
+}
+
+/// UUID of a Error2 (item of List 'Errors').
+/// Reference setters accept this type or a `&Error2`.
+#[derive(
+    Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(transparent)]
+pub struct Error2Uuid(String);
+
+impl Error2Uuid {
+    /// Creates Error2Uuid from a uuid string. The uuid is not validated;
+    /// use `parse` to check the format.
+    pub fn new<S: Into<String>>(uuid: S) -> Self {
+        Self(uuid.into())
+    }
+
+    /// Returns the uuid string
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Error2Uuid {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for Error2Uuid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for Error2Uuid {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if super::is_uuid(s) {
+            Ok(Self(s.to_string()))
+        } else {
+            Err(Error::Message(format!("invalid Error2 uuid '{}'", s)))
+        }
+    }
+}
+
+impl From<&Error2> for Error2Uuid {
+    fn from(item: &Error2) -> Self {
+        item.get_uuid()
+    }
+}
+
+impl From<&Error2Uuid> for Error2Uuid {
+    fn from(uuid: &Error2Uuid) -> Self {
+        uuid.clone()
+    }
+}
+
+/// Errors2List - List of Error2, using the global client.
+/// For a separate connection, use `WorkspaceClient::errors()`.
+///
+pub struct Errors2List {}
+
+impl Errors2List {
+    /// fetch Error2 by its ID
+    pub async fn get(error_2_id: Error2Id) -> Result<Error2, Error> {
+        Errors2Client::new(Api::global()).get(error_2_id).await
+    }
+
+    /// fetch Error2 by its UUID
+    pub async fn get_by_uuid(error_2_uuid: &Error2Uuid) -> Result<Error2, Error> {
+        Errors2Client::new(Api::global())
+            .get_by_uuid(error_2_uuid)
+            .await
+    }
+
+    /// Returns all Error2
+    pub async fn get_items() -> Result<Vec<Error2>, Error> {
+        Errors2Client::new(Api::global()).get_items().await
+    }
+
+    /// Returns stream of all Error2, fetched a page at a time as the stream is read.
+    /// For other page sizes, or to resume at an offset, use `query()`.
+    pub fn stream_items() -> impl futures::Stream<Item = Result<Error2, Error>> + Send {
+        Errors2Client::new(Api::global()).stream_items()
+    }
+
+    /// Creates query for Error2 matching conditions, which are checked by the server
+    pub fn query() -> Error2Query {
+        Error2Query::new(Api::global())
+    }
+
+    /// Initialize builder for creating a new Error2
+    pub fn create() -> NewError2Builder {
+        NewError2Builder::new(Api::global())
+    }
+
+    /// Creates builder for updating an existing Error2
+    pub fn update(error_2_id: Error2Id) -> UpdateError2Builder {
+        UpdateError2Builder::new(Api::global(), error_2_id)
+    }
+
+    /// Archives Error2, and returns the archived Error2
+    pub async fn archive(error_2_id: Error2Id) -> Result<Error2, Error> {
+        Errors2Client::new(Api::global()).archive(error_2_id).await
+    }
+
+    /// Restores archived Error2, and returns the restored Error2
+    pub async fn restore(error_2_id: Error2Id) -> Result<Error2, Error> {
+        Errors2Client::new(Api::global()).restore(error_2_id).await
+    }
+
+    /// Deletes Error2 permanently
+    pub async fn delete(error_2_id: Error2Id) -> Result<(), Error> {
+        Errors2Client::new(Api::global()).delete(error_2_id).await
+    }
+
+    /// Creates Error2, sending up to `concurrency` requests at a time.
+    /// Returns the result of each builder, in the order of `builders`, so that
+    /// a failure doesn't affect the other items. Each builder uses the client
+    /// it was created with. Creates are not retried (see `RetryPolicy`).
+    pub async fn create_many(
+        builders: Vec<NewError2Builder>,
+        concurrency: usize,
+    ) -> Vec<Result<Error2, Error>> {
+        run_batch(
+            builders
+                .into_iter()
+                .map(|mut b| async move { b.execute().await }),
+            concurrency,
+        )
+        .await
+    }
+
+    /// Updates Error2, sending up to `concurrency` requests at a time.
+    /// Returns the result of each builder, in the order of `builders`, so that
+    /// a failure doesn't affect the other items. Each builder uses the client
+    /// it was created with. Updates are not retried (see `RetryPolicy`).
+    pub async fn update_many(
+        builders: Vec<UpdateError2Builder>,
+        concurrency: usize,
+    ) -> Vec<Result<Error2, Error>> {
+        run_batch(
+            builders
+                .into_iter()
+                .map(|mut b| async move { b.execute().await }),
+            concurrency,
+        )
+        .await
+    }
+}
+
+/// Client for List 'Errors', from `WorkspaceClient::errors()`.
+/// Items and builders from this client use the client's connection.
+#[derive(Clone)]
+pub struct Errors2Client {
+    api: Api,
+}
+
+impl Errors2Client {
+    pub(crate) fn new(api: Api) -> Self {
+        Self { api }
+    }
+
+    /// fetch Error2 by its ID
+    pub async fn get(&self, error_2_id: Error2Id) -> Result<Error2, Error> {
+        let obj = self
+            .api
+            .get_entry(LIST_ERRORS_ID, error_2_id.value())
+            .await?;
+        Ok(Error2::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// fetch Error2 by its UUID
+    pub async fn get_by_uuid(&self, error_2_uuid: &Error2Uuid) -> Result<Error2, Error> {
+        let obj = self
+            .api
+            .get_entry(LIST_ERRORS_ID, error_2_uuid.as_str())
+            .await?;
+        Ok(Error2::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// Returns all Error2
+    pub async fn get_items(&self) -> Result<Vec<Error2>, Error> {
+        let entries = self.api.load_entries(LIST_ERRORS_ID).await?;
+        let items: Vec<Error2> = entries
+            .into_iter()
+            .map(|e| Error2::new(Arc::new(e), self.api.clone()))
+            .collect();
+        Ok(items)
+    }
+
+    /// Returns stream of all Error2, fetched a page at a time as the stream is read.
+    /// For other page sizes, or to resume at an offset, use `query()`.
+    pub fn stream_items(&self) -> impl futures::Stream<Item = Result<Error2, Error>> + Send {
+        self.query().stream_items()
+    }
+
+    /// Creates query for Error2 matching conditions, which are checked by the server
+    pub fn query(&self) -> Error2Query {
+        Error2Query::new(self.api.clone())
+    }
+
+    /// Initialize builder for creating a new Error2
+    pub fn create(&self) -> NewError2Builder {
+        NewError2Builder::new(self.api.clone())
+    }
+
+    /// Creates builder for updating an existing Error2
+    pub fn update(&self, error_2_id: Error2Id) -> UpdateError2Builder {
+        UpdateError2Builder::new(self.api.clone(), error_2_id)
+    }
+
+    /// Archives Error2, and returns the archived Error2. Archived items are
+    /// left out of `get_items` and queries, unless a query has `include_archived(true)`.
+    pub async fn archive(&self, error_2_id: Error2Id) -> Result<Error2, Error> {
+        let obj = self
+            .api
+            .archive_entry(LIST_ERRORS_ID, error_2_id.value())
+            .await?;
+        Ok(Error2::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// Restores archived Error2, and returns the restored Error2
+    pub async fn restore(&self, error_2_id: Error2Id) -> Result<Error2, Error> {
+        let obj = self
+            .api
+            .restore_entry(LIST_ERRORS_ID, error_2_id.value())
+            .await?;
+        Ok(Error2::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// Deletes Error2 permanently
+    pub async fn delete(&self, error_2_id: Error2Id) -> Result<(), Error> {
+        self.api
+            .delete_entry(LIST_ERRORS_ID, error_2_id.value())
+            .await
+    }
+}
+
+/// Error2 (item of List 'Errors')
+pub struct Error2 {
+    obj: Arc<Entry>,
+    api: Api,
+}
+
+impl Error2 {
+    /// Creates Error2 wrapping Zenkit Entry
+    fn new(obj: Arc<Entry>, api: Api) -> Self {
+        Self { obj, api }
+    }
+
+    /// 'Name' in 'Errors'
+    pub const FIELD_NAME_ID: ID = 76;
+    pub const FIELD_NAME_UUID: &'static str = "9bd523ab-9514-5854-837e-132804ec3a64";
+    pub const FIELD_NAME_NAME: &'static str = "Name";
+
+    /// Returns 'Name' -  or None if unset
+    pub fn get_name(&self) -> Option<&str> {
+        self.obj
+            .fields
+            .get("9bd523ab-9514-5854-837e-132804ec3a64_text")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+    }
+
+    /// Returns the text format for 'Name'
+    pub fn get_name_format(&self) -> TextFormat {
+        self.obj
+            .fields
+            .get("9bd523ab-9514-5854-837e-132804ec3a64_textType")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+            // if undefined or unexpected value, return "plain"
+            .map(|f| TextFormat::from_str(f).unwrap_or_default())
+            .unwrap_or_default()
+    }
+
+    /// Returns url to zenkit item
+    pub fn get_zenkit_url(&self) -> String {
+        format!(
+            "{}{}/{}/",
+            super::item_url_base(),
+            LIST_ERRORS_SHORT_ID,
+            self.obj.short_id
+        )
+    }
+
+    /// Returns the item's display string (based on list's primary field)
+    pub fn get_display_string(&self) -> &str {
+        &self.obj.display_string
+    }
+
+    /// Returns user id that created the item
+    pub fn get_created_by_id(&self) -> ID {
+        self.obj.created_by
+    }
+
+    /// Returns user name that created the item
+    pub fn get_created_by_name(&self) -> Option<&str> {
+        self.obj.created_by_displayname.as_deref()
+    }
+
+    /// Returns user id that created the item
+    pub fn get_updated_by_id(&self) -> ID {
+        self.obj.updated_by
+    }
+
+    /// Returns user name that last updated the item
+    pub fn get_updated_by_name(&self) -> Option<&str> {
+        self.obj.updated_by_displayname.as_deref()
+    }
+
+    /// Returns date the item was created, in UTC
+    pub fn get_created_date(&self) -> &DateTime<Utc> {
+        &self.obj.created_at
+    }
+
+    /// Returns date the item was last updated, in UTC
+    pub fn get_updated_date(&self) -> &DateTime<Utc> {
+        &self.obj.updated_at
+    }
+
+    /// Returns date the item was deprecated, in UTC, or None if item isn't deprecated
+    pub fn get_deprecated_date(&self) -> Option<&DateTime<Utc>> {
+        self.obj.deprecated_at.as_ref()
+    }
+
+    /// Returns user id that deprecated the item, or None if item isn't deprecated
+    pub fn get_deprecated_by_id(&self) -> Option<ID> {
+        self.obj.deprecated_by
+    }
+
+    /// Returns the default sort rank within the list
+    pub fn get_sort_order(&self) -> f32 {
+        self.obj.sort_order
+    }
+
+    /// Returns the item's id within the list
+    pub fn get_id(&self) -> Error2Id {
+        Error2Id::new(self.obj.id)
+    }
+
+    /// Returns the item's uuid
+    pub fn get_uuid(&self) -> Error2Uuid {
+        Error2Uuid::new(self.obj.uuid.as_str())
+    }
+
+    /// Returns the underlying Entry object
+    pub fn get_entry(&self) -> Arc<Entry> {
+        self.obj.clone()
+    }
+
+    /// Archives the item, and returns the archived item
+    pub async fn archive(&self) -> Result<Error2, Error> {
+        Errors2Client::new(self.api.clone())
+            .archive(self.get_id())
+            .await
+    }
+
+    /// Restores the archived item, and returns the restored item
+    pub async fn restore(&self) -> Result<Error2, Error> {
+        Errors2Client::new(self.api.clone())
+            .restore(self.get_id())
+            .await
+    }
+
+    /// Deletes the item permanently
+    pub async fn delete(&self) -> Result<(), Error> {
+        Errors2Client::new(self.api.clone())
+            .delete(self.get_id())
+            .await
+    }
+} // impl Error2
+
+/// Builder for creating new Error2
+pub struct NewError2Builder {
+    api: Api,
+    fields: JsonMap,
+    errs: Vec<String>,
+}
+
+impl NewError2Builder {
+    /// Create builder for new Error2
+    fn new(api: Api) -> Self {
+        Self {
+            api,
+            fields: JsonMap::new(),
+            errs: Vec::new(),
+        }
+    }
+
+    // internal helper for setting string field
+    #[inline]
+    fn set_s(&mut self, k: &str, v: String) {
+        self.fields.insert(k.to_string(), Value::String(v));
+    }
+    // internal helper for setting json value
+    #[inline]
+    fn set_v(&mut self, k: &str, v: Value) {
+        self.fields.insert(k.to_string(), v);
+    }
+
+    /// Sets text value for Name.
+    /// Uses the field's default TextFormat. To specify format, use _with_format()
+    pub fn set_name<T: AsRef<str>>(&mut self, s: T) -> &mut NewError2Builder {
+        self.set_s(
+            "9bd523ab-9514-5854-837e-132804ec3a64_text",
+            s.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sets text value for Name with format
+    ///
+    pub fn set_name_with_format<T: AsRef<str>>(
+        &mut self,
+        s: T,
+        tf: TextFormat,
+    ) -> &mut NewError2Builder {
+        self.set_s(
+            "9bd523ab-9514-5854-837e-132804ec3a64_text",
+            s.as_ref().to_string(),
+        );
+        self.set_s(
+            "9bd523ab-9514-5854-837e-132804ec3a64_textType",
+            tf.to_string(),
+        );
+        self
+    }
+
+    /// Sends create request to server, returning new Error2.
+    pub async fn execute(&mut self) -> Result<Error2, Error> {
+        if !self.errs.is_empty() {
+            return Err(Error::Message(format!(
+                "Errors occurred in NewError2Builder: {:?}",
+                &self.errs
+            )));
+        }
+        let map = self.fields.to_owned();
+        let error_2 = self.api.create_entry(506, Value::Object(map)).await?;
+        Ok(Error2::new(Arc::new(error_2), self.api.clone()))
+    }
+} // end impl NewError2Builder
+
+/// Builder for updating existing Error2
+pub struct UpdateError2Builder {
+    api: Api,
+    fields: JsonMap,
+    errs: Vec<String>,
+    item_id: Error2Id,
+}
+
+impl UpdateError2Builder {
+    /// Create update builder for Error2 with id
+    fn new(api: Api, item_id: Error2Id) -> Self {
+        Self {
+            api,
+            fields: JsonMap::new(),
+            errs: Vec::new(),
+            item_id,
+        }
+    }
+
+    // internal helper for setting the updateAction of multi-value fields.
+    // Zenkit applies one action to all fields of a request, so a request can't
+    // append or remove values of one field and replace another.
+    fn set_update_action(&mut self, action: &str) {
+        if let Some(Value::String(prev)) = self.fields.get("updateAction") {
+            if prev != action {
+                self.errs.push(format!(
+                    "Conflicting update actions '{}' and '{}': fields that add or remove values \
+                                 must be updated separately from fields that are set",
+                    prev, action
+                ));
+                return;
+            }
+        }
+        self.set_s("updateAction", action.to_string());
+    }
+
+    // internal helper for setting string field
+    #[inline]
+    fn set_s(&mut self, k: &str, v: String) {
+        self.fields.insert(k.to_string(), Value::String(v));
+    }
+    // internal helper for setting json value
+    #[inline]
+    fn set_v(&mut self, k: &str, v: Value) {
+        self.fields.insert(k.to_string(), v);
+    }
+
+    /// Sets text value for Name.
+    /// TextFormat is unchanged (to specify format, use _with_format())
+    pub fn set_name<T: AsRef<str>>(&mut self, s: T) -> &mut UpdateError2Builder {
+        self.set_s(
+            "9bd523ab-9514-5854-837e-132804ec3a64_text",
+            s.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sets text value for Name with format
+    ///
+    pub fn set_name_with_format<T: AsRef<str>>(
+        &mut self,
+        s: T,
+        tf: TextFormat,
+    ) -> &mut UpdateError2Builder {
+        self.set_s(
+            "9bd523ab-9514-5854-837e-132804ec3a64_text",
+            s.as_ref().to_string(),
+        );
+        self.set_s(
+            "9bd523ab-9514-5854-837e-132804ec3a64_textType",
+            tf.to_string(),
+        );
+        self
+    }
+
+    /// Sends update request to server, returning updated Error2.
+    pub async fn execute(&mut self) -> Result<Error2, Error> {
+        if !self.errs.is_empty() {
+            return Err(Error::Message(format!(
+                "Errors occurred in UpdateError2Builder: {:?}",
+                &self.errs
+            )));
+        }
+        let map = self.fields.to_owned();
+        let error_2 = self
+            .api
+            .update_entry(506, self.item_id.value(), Value::Object(map))
+            .await?;
+        Ok(Error2::new(Arc::new(error_2), self.api.clone()))
+    }
+} // end impl UpdateError2Builder
+
+/// Query for Error2 in List 'Errors'. Filters and sort order
+/// are sent with the request, so only matching items are downloaded.
+/// All conditions must match.
+#[derive(Clone)]
+pub struct Error2Query {
+    api: Api,
+    q: EntryQuery,
+    errs: Vec<String>,
+}
+
+impl Error2Query {
+    /// Creates query with no conditions
+    fn new(api: Api) -> Self {
+        Self {
+            api,
+            q: EntryQuery::default(),
+            errs: Vec::new(),
+        }
+    }
+
+    /// Returns at most n Error2. The default (0) returns all matching items.
+    pub fn limit(mut self, n: usize) -> Self {
+        self.q.limit = n;
+        self
+    }
+
+    /// Skips the first n matching Error2, for example, to resume
+    /// a stream after the items already read
+    pub fn skip(mut self, n: usize) -> Self {
+        self.q.skip = n;
+        self
+    }
+
+    /// Sets the number of Error2 requested per call. The default is 500.
+    pub fn page_size(mut self, n: usize) -> Self {
+        self.q.page_size = n;
+        self
+    }
+
+    /// Includes archived Error2 if true. By default, they are left out.
+    pub fn include_archived(mut self, include: bool) -> Self {
+        self.q.include_archived = include;
+        self
+    }
+
+    /// Sorts by date created, oldest first
+    pub fn order_by_created_asc(mut self) -> Self {
+        self.q.order_by("created_at", false);
+        self
+    }
+
+    /// Sorts by date created, newest first
+    pub fn order_by_created_desc(mut self) -> Self {
+        self.q.order_by("created_at", true);
+        self
+    }
+
+    /// Sorts by date last updated, oldest first
+    pub fn order_by_updated_asc(mut self) -> Self {
+        self.q.order_by("updated_at", false);
+        self
+    }
+
+    /// Sorts by date last updated, newest first
+    pub fn order_by_updated_desc(mut self) -> Self {
+        self.q.order_by("updated_at", true);
+        self
+    }
+
+    /// Returns the request parameters for all matching items. `get_items` and
+    /// `stream_items` send these in pages of up to `page_size` items.
+    pub fn request(&self) -> GetEntriesRequest {
+        self.q.request()
+    }
+
+    /// Returns the matching Error2
+    pub async fn get_items(&self) -> Result<Vec<Error2>, Error> {
+        self.check()?;
+        let entries = self.q.load(&self.api, LIST_ERRORS_ID).await?;
+        Ok(entries
+            .into_iter()
+            .map(|e| Error2::new(Arc::new(e), self.api.clone()))
+            .collect())
+    }
+
+    /// Returns stream of the matching Error2. Pages are fetched as the stream
+    /// is read, so only one page is in memory at a time. The stream ends after the first
+    /// error; to resume, create the query again with `skip` set to the number of items read.
+    /// Items added or removed while reading can shift the offsets.
+    pub fn stream_items(&self) -> impl futures::Stream<Item = Result<Error2, Error>> + Send {
+        use futures::StreamExt;
+        if let Err(e) = self.check() {
+            return futures::stream::once(futures::future::ready(Err(e))).left_stream();
+        }
+        let api = self.api.clone();
+        self.q
+            .stream(self.api.clone(), LIST_ERRORS_ID)
+            .map(move |r| r.map(|e| Error2::new(Arc::new(e), api.clone())))
+            .right_stream()
+    }
+
+    /// Returns error if a condition was invalid, e.g., an unknown label
+    fn check(&self) -> Result<(), Error> {
+        if self.errs.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Message(format!(
+                "Errors occurred in Error2Query: {:?}",
+                &self.errs
+            )))
+        }
+    }
+
+    /// Matches Error2 where 'Name' is the text
+    pub fn name_is(mut self, text: &str) -> Self {
+        self.q.filter(
+            Error2::FIELD_NAME_ID,
+            json!({ "modus": "equals", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Error2 where 'Name' contains the text
+    pub fn name_contains(mut self, text: &str) -> Self {
+        self.q.filter(
+            Error2::FIELD_NAME_ID,
+            json!({ "modus": "contains", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Error2 where 'Name' starts with the text
+    pub fn name_starts_with(mut self, text: &str) -> Self {
+        self.q.filter(
+            Error2::FIELD_NAME_ID,
+            json!({ "modus": "startsWith", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Error2 where 'Name' is not set
+    pub fn name_is_empty(mut self) -> Self {
+        self.q
+            .filter(Error2::FIELD_NAME_ID, json!({ "modus": "isEmpty" }));
+        self
+    }
+
+    /// Matches Error2 where 'Name' is set
+    pub fn name_is_not_empty(mut self) -> Self {
+        self.q
+            .filter(Error2::FIELD_NAME_ID, json!({ "modus": "isNotEmpty" }));
+        self
+    }
+
+    /// Sorts by 'Name', ascending
+    pub fn order_by_name_asc(mut self) -> Self {
+        self.q
+            .order_by("9bd523ab-9514-5854-837e-132804ec3a64_text", false);
+        self
+    }
+
+    /// Sorts by 'Name', descending
+    pub fn order_by_name_desc(mut self) -> Self {
+        self.q
+            .order_by("9bd523ab-9514-5854-837e-132804ec3a64_text", true);
+        self
+    }
+} // end impl Error2Query
+
+/// Error2 data, with one field per Zenkit field, that can be serialized,
+/// cached, or sent between threads. Create from an item with `From<&Error2>`,
+/// and save with `to_new_builder` or `to_update_builder` (or the `*_with` versions,
+/// which take the client to save with).
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Error2Record {
+    /// Item id, or None if the item hasn't been created
+    pub id: Option<Error2Id>,
+    /// Item uuid, or None if the item hasn't been created
+    pub uuid: Option<Error2Uuid>,
+    /// Name
+    pub name: Option<String>,
+}
+
+impl From<&Error2> for Error2Record {
+    fn from(item: &Error2) -> Self {
+        Self {
+            id: Some(item.get_id()),
+            uuid: Some(item.get_uuid()),
+            name: item.get_name().map(String::from),
+        }
+    }
+}
+
+impl Error2Record {
+    /// Returns builder for creating a new Error2 with the record's values,
+    /// using the global api client. Fields that are None, and read-only fields, are not set.
+    pub fn to_new_builder(&self) -> NewError2Builder {
+        self.to_new_builder_with(&Errors2Client::new(Api::global()))
+    }
+
+    /// Returns builder for creating a new Error2 with the record's values,
+    /// using the client. Fields that are None, and read-only fields, are not set.
+    pub fn to_new_builder_with(&self, client: &Errors2Client) -> NewError2Builder {
+        let mut builder = client.create();
+        if let Some(ref v) = self.name {
+            builder.set_name(v);
+        }
+        builder
+    }
+
+    /// Returns builder for updating the Error2 with the record's id to the record's values,
+    /// using the global api client. Fields that are None, and read-only fields, are not changed.
+    /// Multi-value fields are replaced. Returns an error if the record has no id.
+    pub fn to_update_builder(&self) -> Result<UpdateError2Builder, Error> {
+        self.to_update_builder_with(&Errors2Client::new(Api::global()))
+    }
+
+    /// Returns builder for updating the Error2 with the record's id to the record's values,
+    /// using the client. Fields that are None, and read-only fields, are not changed.
+    /// Multi-value fields are replaced. Returns an error if the record has no id.
+    pub fn to_update_builder_with(
+        &self,
+        client: &Errors2Client,
+    ) -> Result<UpdateError2Builder, Error> {
+        let id = self
+            .id
+            .ok_or_else(|| Error::Message("Error2Record has no id".to_string()))?;
+        let mut builder = client.update(id);
+        if let Some(ref v) = self.name {
+            builder.set_name(v);
+        }
+        Ok(builder)
+    }
+}
+
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    fn assert_send<T: Send>(_: &T) {}
+    assert_send_sync::<Error2>();
+    assert_send_sync::<NewError2Builder>();
+    assert_send_sync::<UpdateError2Builder>();
+    assert_send_sync::<Error2Record>();
+    assert_send(&Errors2List::get_items());
+    assert_send(&Errors2List::create().execute());
+    assert_send(&Errors2List::create_many(Vec::new(), 1));
+};
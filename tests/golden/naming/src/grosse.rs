@@ -0,0 +1,838 @@
+#![allow(dead_code, unused_imports)]
+//! GrosseList
+//  Generated by zk-codegen v0.5.4 from schema d0db8d0e29fb7e6b
+use super::{lookup_label, run_batch, Api, EntryQuery, Error};
+use serde_json::{self, json, Value};
+use std::{convert::AsRef, str::FromStr, sync::Arc};
+use zenkit::types::{DateTime, Entry, File, GetEntriesRequest, JsonMap, TextFormat, Utc, ID};
+
+/// GrosseList
+///
+pub const LIST_GROSSE_ID: ID = 503;
+pub const LIST_GROSSE_SHORT_ID: &str = "L503";
+pub const LIST_GROSSE_UUID: &str = "04ba89ab-57fc-5953-98e3-e43a13c0f6ff";
+pub const LIST_GROSSE_NAME: &str = "Größe";
+
+/// ID of a Grosse (item of List 'Größe')
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(transparent)]
+pub struct GrosseId(ID);
+
+impl GrosseId {
+    /// Creates GrosseId from the item's numeric id
+    pub fn new(id: ID) -> Self {
+        Self(id)
+    }
+
+    /// Returns the numeric id
+    pub fn value(&self) -> ID {
+        self.0
+    }
+}
+
+impl std::fmt::Display for GrosseId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for GrosseId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<ID>()
+            .map(Self)
+            .map_err(|_| Error::Message(format!("invalid Grosse id '{}'", s)))
+    }
+}
+
+impl From<GrosseId> for ID {
+    fn from(id: GrosseId) -> ID {
+        id.0
+    }
+}
+
+/// UUID of a Grosse (item of List 'Größe').
+/// Reference setters accept this type or a `&Grosse`.
+#[derive(
+    Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(transparent)]
+pub struct GrosseUuid(String);
+
+impl GrosseUuid {
+    /// Creates GrosseUuid from a uuid string. The uuid is not validated;
+    /// use `parse` to check the format.
+    pub fn new<S: Into<String>>(uuid: S) -> Self {
+        Self(uuid.into())
+    }
+
+    /// Returns the uuid string
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for GrosseUuid {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for GrosseUuid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for GrosseUuid {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if super::is_uuid(s) {
+            Ok(Self(s.to_string()))
+        } else {
+            Err(Error::Message(format!("invalid Grosse uuid '{}'", s)))
+        }
+    }
+}
+
+impl From<&Grosse> for GrosseUuid {
+    fn from(item: &Grosse) -> Self {
+        item.get_uuid()
+    }
+}
+
+impl From<&GrosseUuid> for GrosseUuid {
+    fn from(uuid: &GrosseUuid) -> Self {
+        uuid.clone()
+    }
+}
+
+/// GrosseList - List of Grosses, using the global client.
+/// For a separate connection, use `WorkspaceClient::grosse()`.
+///
+pub struct GrosseList {}
+
+impl GrosseList {
+    /// fetch Grosse by its ID
+    pub async fn get(grosse_id: GrosseId) -> Result<Grosse, Error> {
+        GrosseClient::new(Api::global()).get(grosse_id).await
+    }
+
+    /// fetch Grosse by its UUID
+    pub async fn get_by_uuid(grosse_uuid: &GrosseUuid) -> Result<Grosse, Error> {
+        GrosseClient::new(Api::global())
+            .get_by_uuid(grosse_uuid)
+            .await
+    }
+
+    /// Returns all Grosses
+    pub async fn get_items() -> Result<Vec<Grosse>, Error> {
+        GrosseClient::new(Api::global()).get_items().await
+    }
+
+    /// Returns stream of all Grosses, fetched a page at a time as the stream is read.
+    /// For other page sizes, or to resume at an offset, use `query()`.
+    pub fn stream_items() -> impl futures::Stream<Item = Result<Grosse, Error>> + Send {
+        GrosseClient::new(Api::global()).stream_items()
+    }
+
+    /// Creates query for Grosses matching conditions, which are checked by the server
+    pub fn query() -> GrosseQuery {
+        GrosseQuery::new(Api::global())
+    }
+
+    /// Initialize builder for creating a new Grosse
+    pub fn create() -> NewGrosseBuilder {
+        NewGrosseBuilder::new(Api::global())
+    }
+
+    /// Creates builder for updating an existing Grosse
+    pub fn update(grosse_id: GrosseId) -> UpdateGrosseBuilder {
+        UpdateGrosseBuilder::new(Api::global(), grosse_id)
+    }
+
+    /// Archives Grosse, and returns the archived Grosse
+    pub async fn archive(grosse_id: GrosseId) -> Result<Grosse, Error> {
+        GrosseClient::new(Api::global()).archive(grosse_id).await
+    }
+
+    /// Restores archived Grosse, and returns the restored Grosse
+    pub async fn restore(grosse_id: GrosseId) -> Result<Grosse, Error> {
+        GrosseClient::new(Api::global()).restore(grosse_id).await
+    }
+
+    /// Deletes Grosse permanently
+    pub async fn delete(grosse_id: GrosseId) -> Result<(), Error> {
+        GrosseClient::new(Api::global()).delete(grosse_id).await
+    }
+
+    /// Creates Grosses, sending up to `concurrency` requests at a time.
+    /// Returns the result of each builder, in the order of `builders`, so that
+    /// a failure doesn't affect the other items. Each builder uses the client
+    /// it was created with. Creates are not retried (see `RetryPolicy`).
+    pub async fn create_many(
+        builders: Vec<NewGrosseBuilder>,
+        concurrency: usize,
+    ) -> Vec<Result<Grosse, Error>> {
+        run_batch(
+            builders
+                .into_iter()
+                .map(|mut b| async move { b.execute().await }),
+            concurrency,
+        )
+        .await
+    }
+
+    /// Updates Grosses, sending up to `concurrency` requests at a time.
+    /// Returns the result of each builder, in the order of `builders`, so that
+    /// a failure doesn't affect the other items. Each builder uses the client
+    /// it was created with. Updates are not retried (see `RetryPolicy`).
+    pub async fn update_many(
+        builders: Vec<UpdateGrosseBuilder>,
+        concurrency: usize,
+    ) -> Vec<Result<Grosse, Error>> {
+        run_batch(
+            builders
+                .into_iter()
+                .map(|mut b| async move { b.execute().await }),
+            concurrency,
+        )
+        .await
+    }
+}
+
+/// Client for List 'Größe', from `WorkspaceClient::grosse()`.
+/// Items and builders from this client use the client's connection.
+#[derive(Clone)]
+pub struct GrosseClient {
+    api: Api,
+}
+
+impl GrosseClient {
+    pub(crate) fn new(api: Api) -> Self {
+        Self { api }
+    }
+
+    /// fetch Grosse by its ID
+    pub async fn get(&self, grosse_id: GrosseId) -> Result<Grosse, Error> {
+        let obj = self
+            .api
+            .get_entry(LIST_GROSSE_ID, grosse_id.value())
+            .await?;
+        Ok(Grosse::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// fetch Grosse by its UUID
+    pub async fn get_by_uuid(&self, grosse_uuid: &GrosseUuid) -> Result<Grosse, Error> {
+        let obj = self
+            .api
+            .get_entry(LIST_GROSSE_ID, grosse_uuid.as_str())
+            .await?;
+        Ok(Grosse::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// Returns all Grosses
+    pub async fn get_items(&self) -> Result<Vec<Grosse>, Error> {
+        let entries = self.api.load_entries(LIST_GROSSE_ID).await?;
+        let items: Vec<Grosse> = entries
+            .into_iter()
+            .map(|e| Grosse::new(Arc::new(e), self.api.clone()))
+            .collect();
+        Ok(items)
+    }
+
+    /// Returns stream of all Grosses, fetched a page at a time as the stream is read.
+    /// For other page sizes, or to resume at an offset, use `query()`.
+    pub fn stream_items(&self) -> impl futures::Stream<Item = Result<Grosse, Error>> + Send {
+        self.query().stream_items()
+    }
+
+    /// Creates query for Grosses matching conditions, which are checked by the server
+    pub fn query(&self) -> GrosseQuery {
+        GrosseQuery::new(self.api.clone())
+    }
+
+    /// Initialize builder for creating a new Grosse
+    pub fn create(&self) -> NewGrosseBuilder {
+        NewGrosseBuilder::new(self.api.clone())
+    }
+
+    /// Creates builder for updating an existing Grosse
+    pub fn update(&self, grosse_id: GrosseId) -> UpdateGrosseBuilder {
+        UpdateGrosseBuilder::new(self.api.clone(), grosse_id)
+    }
+
+    /// Archives Grosse, and returns the archived Grosse. Archived items are
+    /// left out of `get_items` and queries, unless a query has `include_archived(true)`.
+    pub async fn archive(&self, grosse_id: GrosseId) -> Result<Grosse, Error> {
+        let obj = self
+            .api
+            .archive_entry(LIST_GROSSE_ID, grosse_id.value())
+            .await?;
+        Ok(Grosse::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// Restores archived Grosse, and returns the restored Grosse
+    pub async fn restore(&self, grosse_id: GrosseId) -> Result<Grosse, Error> {
+        let obj = self
+            .api
+            .restore_entry(LIST_GROSSE_ID, grosse_id.value())
+            .await?;
+        Ok(Grosse::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// Deletes Grosse permanently
+    pub async fn delete(&self, grosse_id: GrosseId) -> Result<(), Error> {
+        self.api
+            .delete_entry(LIST_GROSSE_ID, grosse_id.value())
+            .await
+    }
+}
+
+/// Grosse (item of List 'Größe')
+pub struct Grosse {
+    obj: Arc<Entry>,
+    api: Api,
+}
+
+impl Grosse {
+    /// Creates Grosse wrapping Zenkit Entry
+    fn new(obj: Arc<Entry>, api: Api) -> Self {
+        Self { obj, api }
+    }
+
+    /// 'Name' in 'Größe'
+    pub const FIELD_NAME_ID: ID = 73;
+    pub const FIELD_NAME_UUID: &'static str = "e15cdc82-c9a4-553f-b8ce-c4ab65bb6118";
+    pub const FIELD_NAME_NAME: &'static str = "Name";
+
+    /// Returns 'Name' -  or None if unset
+    pub fn get_name(&self) -> Option<&str> {
+        self.obj
+            .fields
+            .get("e15cdc82-c9a4-553f-b8ce-c4ab65bb6118_text")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+    }
+
+    /// Returns the text format for 'Name'
+    pub fn get_name_format(&self) -> TextFormat {
+        self.obj
+            .fields
+            .get("e15cdc82-c9a4-553f-b8ce-c4ab65bb6118_textType")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+            // if undefined or unexpected value, return "plain"
+            .map(|f| TextFormat::from_str(f).unwrap_or_default())
+            .unwrap_or_default()
+    }
+
+    /// Returns url to zenkit item
+    pub fn get_zenkit_url(&self) -> String {
+        format!(
+            "{}{}/{}/",
+            super::item_url_base(),
+            LIST_GROSSE_SHORT_ID,
+            self.obj.short_id
+        )
+    }
+
+    /// Returns the item's display string (based on list's primary field)
+    pub fn get_display_string(&self) -> &str {
+        &self.obj.display_string
+    }
+
+    /// Returns user id that created the item
+    pub fn get_created_by_id(&self) -> ID {
+        self.obj.created_by
+    }
+
+    /// Returns user name that created the item
+    pub fn get_created_by_name(&self) -> Option<&str> {
+        self.obj.created_by_displayname.as_deref()
+    }
+
+    /// Returns user id that created the item
+    pub fn get_updated_by_id(&self) -> ID {
+        self.obj.updated_by
+    }
+
+    /// Returns user name that last updated the item
+    pub fn get_updated_by_name(&self) -> Option<&str> {
+        self.obj.updated_by_displayname.as_deref()
+    }
+
+    /// Returns date the item was created, in UTC
+    pub fn get_created_date(&self) -> &DateTime<Utc> {
+        &self.obj.created_at
+    }
+
+    /// Returns date the item was last updated, in UTC
+    pub fn get_updated_date(&self) -> &DateTime<Utc> {
+        &self.obj.updated_at
+    }
+
+    /// Returns date the item was deprecated, in UTC, or None if item isn't deprecated
+    pub fn get_deprecated_date(&self) -> Option<&DateTime<Utc>> {
+        self.obj.deprecated_at.as_ref()
+    }
+
+    /// Returns user id that deprecated the item, or None if item isn't deprecated
+    pub fn get_deprecated_by_id(&self) -> Option<ID> {
+        self.obj.deprecated_by
+    }
+
+    /// Returns the default sort rank within the list
+    pub fn get_sort_order(&self) -> f32 {
+        self.obj.sort_order
+    }
+
+    /// Returns the item's id within the list
+    pub fn get_id(&self) -> GrosseId {
+        GrosseId::new(self.obj.id)
+    }
+
+    /// Returns the item's uuid
+    pub fn get_uuid(&self) -> GrosseUuid {
+        GrosseUuid::new(self.obj.uuid.as_str())
+    }
+
+    /// Returns the underlying Entry object
+    pub fn get_entry(&self) -> Arc<Entry> {
+        self.obj.clone()
+    }
+
+    /// Archives the item, and returns the archived item
+    pub async fn archive(&self) -> Result<Grosse, Error> {
+        GrosseClient::new(self.api.clone())
+            .archive(self.get_id())
+            .await
+    }
+
+    /// Restores the archived item, and returns the restored item
+    pub async fn restore(&self) -> Result<Grosse, Error> {
+        GrosseClient::new(self.api.clone())
+            .restore(self.get_id())
+            .await
+    }
+
+    /// Deletes the item permanently
+    pub async fn delete(&self) -> Result<(), Error> {
+        GrosseClient::new(self.api.clone())
+            .delete(self.get_id())
+            .await
+    }
+} // impl Grosse
+
+/// Builder for creating new Grosse
+pub struct NewGrosseBuilder {
+    api: Api,
+    fields: JsonMap,
+    errs: Vec<String>,
+}
+
+impl NewGrosseBuilder {
+    /// Create builder for new Grosse
+    fn new(api: Api) -> Self {
+        Self {
+            api,
+            fields: JsonMap::new(),
+            errs: Vec::new(),
+        }
+    }
+
+    // internal helper for setting string field
+    #[inline]
+    fn set_s(&mut self, k: &str, v: String) {
+        self.fields.insert(k.to_string(), Value::String(v));
+    }
+    // internal helper for setting json value
+    #[inline]
+    fn set_v(&mut self, k: &str, v: Value) {
+        self.fields.insert(k.to_string(), v);
+    }
+
+    /// Sets text value for Name.
+    /// Uses the field's default TextFormat. To specify format, use _with_format()
+    pub fn set_name<T: AsRef<str>>(&mut self, s: T) -> &mut NewGrosseBuilder {
+        self.set_s(
+            "e15cdc82-c9a4-553f-b8ce-c4ab65bb6118_text",
+            s.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sets text value for Name with format
+    ///
+    pub fn set_name_with_format<T: AsRef<str>>(
+        &mut self,
+        s: T,
+        tf: TextFormat,
+    ) -> &mut NewGrosseBuilder {
+        self.set_s(
+            "e15cdc82-c9a4-553f-b8ce-c4ab65bb6118_text",
+            s.as_ref().to_string(),
+        );
+        self.set_s(
+            "e15cdc82-c9a4-553f-b8ce-c4ab65bb6118_textType",
+            tf.to_string(),
+        );
+        self
+    }
+
+    /// Sends create request to server, returning new Grosse.
+    pub async fn execute(&mut self) -> Result<Grosse, Error> {
+        if !self.errs.is_empty() {
+            return Err(Error::Message(format!(
+                "Errors occurred in NewGrosseBuilder: {:?}",
+                &self.errs
+            )));
+        }
+        let map = self.fields.to_owned();
+        let grosse = self.api.create_entry(503, Value::Object(map)).await?;
+        Ok(Grosse::new(Arc::new(grosse), self.api.clone()))
+    }
+} // end impl NewGrosseBuilder
+
+/// Builder for updating existing Grosse
+pub struct UpdateGrosseBuilder {
+    api: Api,
+    fields: JsonMap,
+    errs: Vec<String>,
+    item_id: GrosseId,
+}
+
+impl UpdateGrosseBuilder {
+    /// Create update builder for Grosse with id
+    fn new(api: Api, item_id: GrosseId) -> Self {
+        Self {
+            api,
+            fields: JsonMap::new(),
+            errs: Vec::new(),
+            item_id,
+        }
+    }
+
+    // internal helper for setting the updateAction of multi-value fields.
+    // Zenkit applies one action to all fields of a request, so a request can't
+    // append or remove values of one field and replace another.
+    fn set_update_action(&mut self, action: &str) {
+        if let Some(Value::String(prev)) = self.fields.get("updateAction") {
+            if prev != action {
+                self.errs.push(format!(
+                    "Conflicting update actions '{}' and '{}': fields that add or remove values \
+                                 must be updated separately from fields that are set",
+                    prev, action
+                ));
+                return;
+            }
+        }
+        self.set_s("updateAction", action.to_string());
+    }
+
+    // internal helper for setting string field
+    #[inline]
+    fn set_s(&mut self, k: &str, v: String) {
+        self.fields.insert(k.to_string(), Value::String(v));
+    }
+    // internal helper for setting json value
+    #[inline]
+    fn set_v(&mut self, k: &str, v: Value) {
+        self.fields.insert(k.to_string(), v);
+    }
+
+    /// Sets text value for Name.
+    /// TextFormat is unchanged (to specify format, use _with_format())
+    pub fn set_name<T: AsRef<str>>(&mut self, s: T) -> &mut UpdateGrosseBuilder {
+        self.set_s(
+            "e15cdc82-c9a4-553f-b8ce-c4ab65bb6118_text",
+            s.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sets text value for Name with format
+    ///
+    pub fn set_name_with_format<T: AsRef<str>>(
+        &mut self,
+        s: T,
+        tf: TextFormat,
+    ) -> &mut UpdateGrosseBuilder {
+        self.set_s(
+            "e15cdc82-c9a4-553f-b8ce-c4ab65bb6118_text",
+            s.as_ref().to_string(),
+        );
+        self.set_s(
+            "e15cdc82-c9a4-553f-b8ce-c4ab65bb6118_textType",
+            tf.to_string(),
+        );
+        self
+    }
+
+    /// Sends update request to server, returning updated Grosse.
+    pub async fn execute(&mut self) -> Result<Grosse, Error> {
+        if !self.errs.is_empty() {
+            return Err(Error::Message(format!(
+                "Errors occurred in UpdateGrosseBuilder: {:?}",
+                &self.errs
+            )));
+        }
+        let map = self.fields.to_owned();
+        let grosse = self
+            .api
+            .update_entry(503, self.item_id.value(), Value::Object(map))
+            .await?;
+        Ok(Grosse::new(Arc::new(grosse), self.api.clone()))
+    }
+} // end impl UpdateGrosseBuilder
+
+/// Query for Grosses in List 'Größe'. Filters and sort order
+/// are sent with the request, so only matching items are downloaded.
+/// All conditions must match.
+#[derive(Clone)]
+pub struct GrosseQuery {
+    api: Api,
+    q: EntryQuery,
+    errs: Vec<String>,
+}
+
+impl GrosseQuery {
+    /// Creates query with no conditions
+    fn new(api: Api) -> Self {
+        Self {
+            api,
+            q: EntryQuery::default(),
+            errs: Vec::new(),
+        }
+    }
+
+    /// Returns at most n Grosses. The default (0) returns all matching items.
+    pub fn limit(mut self, n: usize) -> Self {
+        self.q.limit = n;
+        self
+    }
+
+    /// Skips the first n matching Grosses, for example, to resume
+    /// a stream after the items already read
+    pub fn skip(mut self, n: usize) -> Self {
+        self.q.skip = n;
+        self
+    }
+
+    /// Sets the number of Grosses requested per call. The default is 500.
+    pub fn page_size(mut self, n: usize) -> Self {
+        self.q.page_size = n;
+        self
+    }
+
+    /// Includes archived Grosses if true. By default, they are left out.
+    pub fn include_archived(mut self, include: bool) -> Self {
+        self.q.include_archived = include;
+        self
+    }
+
+    /// Sorts by date created, oldest first
+    pub fn order_by_created_asc(mut self) -> Self {
+        self.q.order_by("created_at", false);
+        self
+    }
+
+    /// Sorts by date created, newest first
+    pub fn order_by_created_desc(mut self) -> Self {
+        self.q.order_by("created_at", true);
+        self
+    }
+
+    /// Sorts by date last updated, oldest first
+    pub fn order_by_updated_asc(mut self) -> Self {
+        self.q.order_by("updated_at", false);
+        self
+    }
+
+    /// Sorts by date last updated, newest first
+    pub fn order_by_updated_desc(mut self) -> Self {
+        self.q.order_by("updated_at", true);
+        self
+    }
+
+    /// Returns the request parameters for all matching items. `get_items` and
+    /// `stream_items` send these in pages of up to `page_size` items.
+    pub fn request(&self) -> GetEntriesRequest {
+        self.q.request()
+    }
+
+    /// Returns the matching Grosses
+    pub async fn get_items(&self) -> Result<Vec<Grosse>, Error> {
+        self.check()?;
+        let entries = self.q.load(&self.api, LIST_GROSSE_ID).await?;
+        Ok(entries
+            .into_iter()
+            .map(|e| Grosse::new(Arc::new(e), self.api.clone()))
+            .collect())
+    }
+
+    /// Returns stream of the matching Grosses. Pages are fetched as the stream
+    /// is read, so only one page is in memory at a time. The stream ends after the first
+    /// error; to resume, create the query again with `skip` set to the number of items read.
+    /// Items added or removed while reading can shift the offsets.
+    pub fn stream_items(&self) -> impl futures::Stream<Item = Result<Grosse, Error>> + Send {
+        use futures::StreamExt;
+        if let Err(e) = self.check() {
+            return futures::stream::once(futures::future::ready(Err(e))).left_stream();
+        }
+        let api = self.api.clone();
+        self.q
+            .stream(self.api.clone(), LIST_GROSSE_ID)
+            .map(move |r| r.map(|e| Grosse::new(Arc::new(e), api.clone())))
+            .right_stream()
+    }
+
+    /// Returns error if a condition was invalid, e.g., an unknown label
+    fn check(&self) -> Result<(), Error> {
+        if self.errs.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Message(format!(
+                "Errors occurred in GrosseQuery: {:?}",
+                &self.errs
+            )))
+        }
+    }
+
+    /// Matches Grosses where 'Name' is the text
+    pub fn name_is(mut self, text: &str) -> Self {
+        self.q.filter(
+            Grosse::FIELD_NAME_ID,
+            json!({ "modus": "equals", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Grosses where 'Name' contains the text
+    pub fn name_contains(mut self, text: &str) -> Self {
+        self.q.filter(
+            Grosse::FIELD_NAME_ID,
+            json!({ "modus": "contains", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Grosses where 'Name' starts with the text
+    pub fn name_starts_with(mut self, text: &str) -> Self {
+        self.q.filter(
+            Grosse::FIELD_NAME_ID,
+            json!({ "modus": "startsWith", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Grosses where 'Name' is not set
+    pub fn name_is_empty(mut self) -> Self {
+        self.q
+            .filter(Grosse::FIELD_NAME_ID, json!({ "modus": "isEmpty" }));
+        self
+    }
+
+    /// Matches Grosses where 'Name' is set
+    pub fn name_is_not_empty(mut self) -> Self {
+        self.q
+            .filter(Grosse::FIELD_NAME_ID, json!({ "modus": "isNotEmpty" }));
+        self
+    }
+
+    /// Sorts by 'Name', ascending
+    pub fn order_by_name_asc(mut self) -> Self {
+        self.q
+            .order_by("e15cdc82-c9a4-553f-b8ce-c4ab65bb6118_text", false);
+        self
+    }
+
+    /// Sorts by 'Name', descending
+    pub fn order_by_name_desc(mut self) -> Self {
+        self.q
+            .order_by("e15cdc82-c9a4-553f-b8ce-c4ab65bb6118_text", true);
+        self
+    }
+} // end impl GrosseQuery
+
+/// Grosse data, with one field per Zenkit field, that can be serialized,
+/// cached, or sent between threads. Create from an item with `From<&Grosse>`,
+/// and save with `to_new_builder` or `to_update_builder` (or the `*_with` versions,
+/// which take the client to save with).
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct GrosseRecord {
+    /// Item id, or None if the item hasn't been created
+    pub id: Option<GrosseId>,
+    /// Item uuid, or None if the item hasn't been created
+    pub uuid: Option<GrosseUuid>,
+    /// Name
+    pub name: Option<String>,
+}
+
+impl From<&Grosse> for GrosseRecord {
+    fn from(item: &Grosse) -> Self {
+        Self {
+            id: Some(item.get_id()),
+            uuid: Some(item.get_uuid()),
+            name: item.get_name().map(String::from),
+        }
+    }
+}
+
+impl GrosseRecord {
+    /// Returns builder for creating a new Grosse with the record's values,
+    /// using the global api client. Fields that are None, and read-only fields, are not set.
+    pub fn to_new_builder(&self) -> NewGrosseBuilder {
+        self.to_new_builder_with(&GrosseClient::new(Api::global()))
+    }
+
+    /// Returns builder for creating a new Grosse with the record's values,
+    /// using the client. Fields that are None, and read-only fields, are not set.
+    pub fn to_new_builder_with(&self, client: &GrosseClient) -> NewGrosseBuilder {
+        let mut builder = client.create();
+        if let Some(ref v) = self.name {
+            builder.set_name(v);
+        }
+        builder
+    }
+
+    /// Returns builder for updating the Grosse with the record's id to the record's values,
+    /// using the global api client. Fields that are None, and read-only fields, are not changed.
+    /// Multi-value fields are replaced. Returns an error if the record has no id.
+    pub fn to_update_builder(&self) -> Result<UpdateGrosseBuilder, Error> {
+        self.to_update_builder_with(&GrosseClient::new(Api::global()))
+    }
+
+    /// Returns builder for updating the Grosse with the record's id to the record's values,
+    /// using the client. Fields that are None, and read-only fields, are not changed.
+    /// Multi-value fields are replaced. Returns an error if the record has no id.
+    pub fn to_update_builder_with(
+        &self,
+        client: &GrosseClient,
+    ) -> Result<UpdateGrosseBuilder, Error> {
+        let id = self
+            .id
+            .ok_or_else(|| Error::Message("GrosseRecord has no id".to_string()))?;
+        let mut builder = client.update(id);
+        if let Some(ref v) = self.name {
+            builder.set_name(v);
+        }
+        Ok(builder)
+    }
+}
+
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    fn assert_send<T: Send>(_: &T) {}
+    assert_send_sync::<Grosse>();
+    assert_send_sync::<NewGrosseBuilder>();
+    assert_send_sync::<UpdateGrosseBuilder>();
+    assert_send_sync::<GrosseRecord>();
+    assert_send(&GrosseList::get_items());
+    assert_send(&GrosseList::create().execute());
+    assert_send(&GrosseList::create_many(Vec::new(), 1));
+};
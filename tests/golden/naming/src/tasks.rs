@@ -0,0 +1,828 @@
+#![allow(dead_code, unused_imports)]
+//! TasksList
+//  Generated by zk-codegen v0.5.4 from schema d0db8d0e29fb7e6b
+use super::{lookup_label, run_batch, Api, EntryQuery, Error};
+use serde_json::{self, json, Value};
+use std::{convert::AsRef, str::FromStr, sync::Arc};
+use zenkit::types::{DateTime, Entry, File, GetEntriesRequest, JsonMap, TextFormat, Utc, ID};
+
+/// TasksList
+///
+pub const LIST_TASKS_ID: ID = 504;
+pub const LIST_TASKS_SHORT_ID: &str = "L504";
+pub const LIST_TASKS_UUID: &str = "519631f2-5c7e-520d-8060-34bb5245f314";
+pub const LIST_TASKS_NAME: &str = "Tasks";
+
+/// ID of a Task (item of List 'Tasks')
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(transparent)]
+pub struct TaskId(ID);
+
+impl TaskId {
+    /// Creates TaskId from the item's numeric id
+    pub fn new(id: ID) -> Self {
+        Self(id)
+    }
+
+    /// Returns the numeric id
+    pub fn value(&self) -> ID {
+        self.0
+    }
+}
+
+impl std::fmt::Display for TaskId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for TaskId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<ID>()
+            .map(Self)
+            .map_err(|_| Error::Message(format!("invalid Task id '{}'", s)))
+    }
+}
+
+impl From<TaskId> for ID {
+    fn from(id: TaskId) -> ID {
+        id.0
+    }
+}
+
+/// UUID of a Task (item of List 'Tasks').
+/// Reference setters accept this type or a `&Task`.
+#[derive(
+    Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(transparent)]
+pub struct TaskUuid(String);
+
+impl TaskUuid {
+    /// Creates TaskUuid from a uuid string. The uuid is not validated;
+    /// use `parse` to check the format.
+    pub fn new<S: Into<String>>(uuid: S) -> Self {
+        Self(uuid.into())
+    }
+
+    /// Returns the uuid string
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for TaskUuid {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for TaskUuid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for TaskUuid {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if super::is_uuid(s) {
+            Ok(Self(s.to_string()))
+        } else {
+            Err(Error::Message(format!("invalid Task uuid '{}'", s)))
+        }
+    }
+}
+
+impl From<&Task> for TaskUuid {
+    fn from(item: &Task) -> Self {
+        item.get_uuid()
+    }
+}
+
+impl From<&TaskUuid> for TaskUuid {
+    fn from(uuid: &TaskUuid) -> Self {
+        uuid.clone()
+    }
+}
+
+/// TasksList - List of Tasks, using the global client.
+/// For a separate connection, use `WorkspaceClient::tasks()`.
+///
+pub struct TasksList {}
+
+impl TasksList {
+    /// fetch Task by its ID
+    pub async fn get(task_id: TaskId) -> Result<Task, Error> {
+        TasksClient::new(Api::global()).get(task_id).await
+    }
+
+    /// fetch Task by its UUID
+    pub async fn get_by_uuid(task_uuid: &TaskUuid) -> Result<Task, Error> {
+        TasksClient::new(Api::global()).get_by_uuid(task_uuid).await
+    }
+
+    /// Returns all Tasks
+    pub async fn get_items() -> Result<Vec<Task>, Error> {
+        TasksClient::new(Api::global()).get_items().await
+    }
+
+    /// Returns stream of all Tasks, fetched a page at a time as the stream is read.
+    /// For other page sizes, or to resume at an offset, use `query()`.
+    pub fn stream_items() -> impl futures::Stream<Item = Result<Task, Error>> + Send {
+        TasksClient::new(Api::global()).stream_items()
+    }
+
+    /// Creates query for Tasks matching conditions, which are checked by the server
+    pub fn query() -> TaskQuery {
+        TaskQuery::new(Api::global())
+    }
+
+    /// Initialize builder for creating a new Task
+    pub fn create() -> NewTaskBuilder {
+        NewTaskBuilder::new(Api::global())
+    }
+
+    /// Creates builder for updating an existing Task
+    pub fn update(task_id: TaskId) -> UpdateTaskBuilder {
+        UpdateTaskBuilder::new(Api::global(), task_id)
+    }
+
+    /// Archives Task, and returns the archived Task
+    pub async fn archive(task_id: TaskId) -> Result<Task, Error> {
+        TasksClient::new(Api::global()).archive(task_id).await
+    }
+
+    /// Restores archived Task, and returns the restored Task
+    pub async fn restore(task_id: TaskId) -> Result<Task, Error> {
+        TasksClient::new(Api::global()).restore(task_id).await
+    }
+
+    /// Deletes Task permanently
+    pub async fn delete(task_id: TaskId) -> Result<(), Error> {
+        TasksClient::new(Api::global()).delete(task_id).await
+    }
+
+    /// Creates Tasks, sending up to `concurrency` requests at a time.
+    /// Returns the result of each builder, in the order of `builders`, so that
+    /// a failure doesn't affect the other items. Each builder uses the client
+    /// it was created with. Creates are not retried (see `RetryPolicy`).
+    pub async fn create_many(
+        builders: Vec<NewTaskBuilder>,
+        concurrency: usize,
+    ) -> Vec<Result<Task, Error>> {
+        run_batch(
+            builders
+                .into_iter()
+                .map(|mut b| async move { b.execute().await }),
+            concurrency,
+        )
+        .await
+    }
+
+    /// Updates Tasks, sending up to `concurrency` requests at a time.
+    /// Returns the result of each builder, in the order of `builders`, so that
+    /// a failure doesn't affect the other items. Each builder uses the client
+    /// it was created with. Updates are not retried (see `RetryPolicy`).
+    pub async fn update_many(
+        builders: Vec<UpdateTaskBuilder>,
+        concurrency: usize,
+    ) -> Vec<Result<Task, Error>> {
+        run_batch(
+            builders
+                .into_iter()
+                .map(|mut b| async move { b.execute().await }),
+            concurrency,
+        )
+        .await
+    }
+}
+
+/// Client for List 'Tasks', from `WorkspaceClient::tasks()`.
+/// Items and builders from this client use the client's connection.
+#[derive(Clone)]
+pub struct TasksClient {
+    api: Api,
+}
+
+impl TasksClient {
+    pub(crate) fn new(api: Api) -> Self {
+        Self { api }
+    }
+
+    /// fetch Task by its ID
+    pub async fn get(&self, task_id: TaskId) -> Result<Task, Error> {
+        let obj = self.api.get_entry(LIST_TASKS_ID, task_id.value()).await?;
+        Ok(Task::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// fetch Task by its UUID
+    pub async fn get_by_uuid(&self, task_uuid: &TaskUuid) -> Result<Task, Error> {
+        let obj = self
+            .api
+            .get_entry(LIST_TASKS_ID, task_uuid.as_str())
+            .await?;
+        Ok(Task::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// Returns all Tasks
+    pub async fn get_items(&self) -> Result<Vec<Task>, Error> {
+        let entries = self.api.load_entries(LIST_TASKS_ID).await?;
+        let items: Vec<Task> = entries
+            .into_iter()
+            .map(|e| Task::new(Arc::new(e), self.api.clone()))
+            .collect();
+        Ok(items)
+    }
+
+    /// Returns stream of all Tasks, fetched a page at a time as the stream is read.
+    /// For other page sizes, or to resume at an offset, use `query()`.
+    pub fn stream_items(&self) -> impl futures::Stream<Item = Result<Task, Error>> + Send {
+        self.query().stream_items()
+    }
+
+    /// Creates query for Tasks matching conditions, which are checked by the server
+    pub fn query(&self) -> TaskQuery {
+        TaskQuery::new(self.api.clone())
+    }
+
+    /// Initialize builder for creating a new Task
+    pub fn create(&self) -> NewTaskBuilder {
+        NewTaskBuilder::new(self.api.clone())
+    }
+
+    /// Creates builder for updating an existing Task
+    pub fn update(&self, task_id: TaskId) -> UpdateTaskBuilder {
+        UpdateTaskBuilder::new(self.api.clone(), task_id)
+    }
+
+    /// Archives Task, and returns the archived Task. Archived items are
+    /// left out of `get_items` and queries, unless a query has `include_archived(true)`.
+    pub async fn archive(&self, task_id: TaskId) -> Result<Task, Error> {
+        let obj = self
+            .api
+            .archive_entry(LIST_TASKS_ID, task_id.value())
+            .await?;
+        Ok(Task::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// Restores archived Task, and returns the restored Task
+    pub async fn restore(&self, task_id: TaskId) -> Result<Task, Error> {
+        let obj = self
+            .api
+            .restore_entry(LIST_TASKS_ID, task_id.value())
+            .await?;
+        Ok(Task::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// Deletes Task permanently
+    pub async fn delete(&self, task_id: TaskId) -> Result<(), Error> {
+        self.api.delete_entry(LIST_TASKS_ID, task_id.value()).await
+    }
+}
+
+/// Task (item of List 'Tasks')
+pub struct Task {
+    obj: Arc<Entry>,
+    api: Api,
+}
+
+impl Task {
+    /// Creates Task wrapping Zenkit Entry
+    fn new(obj: Arc<Entry>, api: Api) -> Self {
+        Self { obj, api }
+    }
+
+    /// 'Name' in 'Tasks'
+    pub const FIELD_NAME_ID: ID = 74;
+    pub const FIELD_NAME_UUID: &'static str = "3486ea99-76c3-5fd3-86c3-a18da0ee63c5";
+    pub const FIELD_NAME_NAME: &'static str = "Name";
+
+    /// Returns 'Name' -  or None if unset
+    pub fn get_name(&self) -> Option<&str> {
+        self.obj
+            .fields
+            .get("3486ea99-76c3-5fd3-86c3-a18da0ee63c5_text")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+    }
+
+    /// Returns the text format for 'Name'
+    pub fn get_name_format(&self) -> TextFormat {
+        self.obj
+            .fields
+            .get("3486ea99-76c3-5fd3-86c3-a18da0ee63c5_textType")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+            // if undefined or unexpected value, return "plain"
+            .map(|f| TextFormat::from_str(f).unwrap_or_default())
+            .unwrap_or_default()
+    }
+
+    /// Returns url to zenkit item
+    pub fn get_zenkit_url(&self) -> String {
+        format!(
+            "{}{}/{}/",
+            super::item_url_base(),
+            LIST_TASKS_SHORT_ID,
+            self.obj.short_id
+        )
+    }
+
+    /// Returns the item's display string (based on list's primary field)
+    pub fn get_display_string(&self) -> &str {
+        &self.obj.display_string
+    }
+
+    /// Returns user id that created the item
+    pub fn get_created_by_id(&self) -> ID {
+        self.obj.created_by
+    }
+
+    /// Returns user name that created the item
+    pub fn get_created_by_name(&self) -> Option<&str> {
+        self.obj.created_by_displayname.as_deref()
+    }
+
+    /// Returns user id that created the item
+    pub fn get_updated_by_id(&self) -> ID {
+        self.obj.updated_by
+    }
+
+    /// Returns user name that last updated the item
+    pub fn get_updated_by_name(&self) -> Option<&str> {
+        self.obj.updated_by_displayname.as_deref()
+    }
+
+    /// Returns date the item was created, in UTC
+    pub fn get_created_date(&self) -> &DateTime<Utc> {
+        &self.obj.created_at
+    }
+
+    /// Returns date the item was last updated, in UTC
+    pub fn get_updated_date(&self) -> &DateTime<Utc> {
+        &self.obj.updated_at
+    }
+
+    /// Returns date the item was deprecated, in UTC, or None if item isn't deprecated
+    pub fn get_deprecated_date(&self) -> Option<&DateTime<Utc>> {
+        self.obj.deprecated_at.as_ref()
+    }
+
+    /// Returns user id that deprecated the item, or None if item isn't deprecated
+    pub fn get_deprecated_by_id(&self) -> Option<ID> {
+        self.obj.deprecated_by
+    }
+
+    /// Returns the default sort rank within the list
+    pub fn get_sort_order(&self) -> f32 {
+        self.obj.sort_order
+    }
+
+    /// Returns the item's id within the list
+    pub fn get_id(&self) -> TaskId {
+        TaskId::new(self.obj.id)
+    }
+
+    /// Returns the item's uuid
+    pub fn get_uuid(&self) -> TaskUuid {
+        TaskUuid::new(self.obj.uuid.as_str())
+    }
+
+    /// Returns the underlying Entry object
+    pub fn get_entry(&self) -> Arc<Entry> {
+        self.obj.clone()
+    }
+
+    /// Archives the item, and returns the archived item
+    pub async fn archive(&self) -> Result<Task, Error> {
+        TasksClient::new(self.api.clone())
+            .archive(self.get_id())
+            .await
+    }
+
+    /// Restores the archived item, and returns the restored item
+    pub async fn restore(&self) -> Result<Task, Error> {
+        TasksClient::new(self.api.clone())
+            .restore(self.get_id())
+            .await
+    }
+
+    /// Deletes the item permanently
+    pub async fn delete(&self) -> Result<(), Error> {
+        TasksClient::new(self.api.clone())
+            .delete(self.get_id())
+            .await
+    }
+} // impl Task
+
+/// Builder for creating new Task
+pub struct NewTaskBuilder {
+    api: Api,
+    fields: JsonMap,
+    errs: Vec<String>,
+}
+
+impl NewTaskBuilder {
+    /// Create builder for new Task
+    fn new(api: Api) -> Self {
+        Self {
+            api,
+            fields: JsonMap::new(),
+            errs: Vec::new(),
+        }
+    }
+
+    // internal helper for setting string field
+    #[inline]
+    fn set_s(&mut self, k: &str, v: String) {
+        self.fields.insert(k.to_string(), Value::String(v));
+    }
+    // internal helper for setting json value
+    #[inline]
+    fn set_v(&mut self, k: &str, v: Value) {
+        self.fields.insert(k.to_string(), v);
+    }
+
+    /// Sets text value for Name.
+    /// Uses the field's default TextFormat. To specify format, use _with_format()
+    pub fn set_name<T: AsRef<str>>(&mut self, s: T) -> &mut NewTaskBuilder {
+        self.set_s(
+            "3486ea99-76c3-5fd3-86c3-a18da0ee63c5_text",
+            s.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sets text value for Name with format
+    ///
+    pub fn set_name_with_format<T: AsRef<str>>(
+        &mut self,
+        s: T,
+        tf: TextFormat,
+    ) -> &mut NewTaskBuilder {
+        self.set_s(
+            "3486ea99-76c3-5fd3-86c3-a18da0ee63c5_text",
+            s.as_ref().to_string(),
+        );
+        self.set_s(
+            "3486ea99-76c3-5fd3-86c3-a18da0ee63c5_textType",
+            tf.to_string(),
+        );
+        self
+    }
+
+    /// Sends create request to server, returning new Task.
+    pub async fn execute(&mut self) -> Result<Task, Error> {
+        if !self.errs.is_empty() {
+            return Err(Error::Message(format!(
+                "Errors occurred in NewTaskBuilder: {:?}",
+                &self.errs
+            )));
+        }
+        let map = self.fields.to_owned();
+        let task = self.api.create_entry(504, Value::Object(map)).await?;
+        Ok(Task::new(Arc::new(task), self.api.clone()))
+    }
+} // end impl NewTaskBuilder
+
+/// Builder for updating existing Task
+pub struct UpdateTaskBuilder {
+    api: Api,
+    fields: JsonMap,
+    errs: Vec<String>,
+    item_id: TaskId,
+}
+
+impl UpdateTaskBuilder {
+    /// Create update builder for Task with id
+    fn new(api: Api, item_id: TaskId) -> Self {
+        Self {
+            api,
+            fields: JsonMap::new(),
+            errs: Vec::new(),
+            item_id,
+        }
+    }
+
+    // internal helper for setting the updateAction of multi-value fields.
+    // Zenkit applies one action to all fields of a request, so a request can't
+    // append or remove values of one field and replace another.
+    fn set_update_action(&mut self, action: &str) {
+        if let Some(Value::String(prev)) = self.fields.get("updateAction") {
+            if prev != action {
+                self.errs.push(format!(
+                    "Conflicting update actions '{}' and '{}': fields that add or remove values \
+                                 must be updated separately from fields that are set",
+                    prev, action
+                ));
+                return;
+            }
+        }
+        self.set_s("updateAction", action.to_string());
+    }
+
+    // internal helper for setting string field
+    #[inline]
+    fn set_s(&mut self, k: &str, v: String) {
+        self.fields.insert(k.to_string(), Value::String(v));
+    }
+    // internal helper for setting json value
+    #[inline]
+    fn set_v(&mut self, k: &str, v: Value) {
+        self.fields.insert(k.to_string(), v);
+    }
+
+    /// Sets text value for Name.
+    /// TextFormat is unchanged (to specify format, use _with_format())
+    pub fn set_name<T: AsRef<str>>(&mut self, s: T) -> &mut UpdateTaskBuilder {
+        self.set_s(
+            "3486ea99-76c3-5fd3-86c3-a18da0ee63c5_text",
+            s.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sets text value for Name with format
+    ///
+    pub fn set_name_with_format<T: AsRef<str>>(
+        &mut self,
+        s: T,
+        tf: TextFormat,
+    ) -> &mut UpdateTaskBuilder {
+        self.set_s(
+            "3486ea99-76c3-5fd3-86c3-a18da0ee63c5_text",
+            s.as_ref().to_string(),
+        );
+        self.set_s(
+            "3486ea99-76c3-5fd3-86c3-a18da0ee63c5_textType",
+            tf.to_string(),
+        );
+        self
+    }
+
+    /// Sends update request to server, returning updated Task.
+    pub async fn execute(&mut self) -> Result<Task, Error> {
+        if !self.errs.is_empty() {
+            return Err(Error::Message(format!(
+                "Errors occurred in UpdateTaskBuilder: {:?}",
+                &self.errs
+            )));
+        }
+        let map = self.fields.to_owned();
+        let task = self
+            .api
+            .update_entry(504, self.item_id.value(), Value::Object(map))
+            .await?;
+        Ok(Task::new(Arc::new(task), self.api.clone()))
+    }
+} // end impl UpdateTaskBuilder
+
+/// Query for Tasks in List 'Tasks'. Filters and sort order
+/// are sent with the request, so only matching items are downloaded.
+/// All conditions must match.
+#[derive(Clone)]
+pub struct TaskQuery {
+    api: Api,
+    q: EntryQuery,
+    errs: Vec<String>,
+}
+
+impl TaskQuery {
+    /// Creates query with no conditions
+    fn new(api: Api) -> Self {
+        Self {
+            api,
+            q: EntryQuery::default(),
+            errs: Vec::new(),
+        }
+    }
+
+    /// Returns at most n Tasks. The default (0) returns all matching items.
+    pub fn limit(mut self, n: usize) -> Self {
+        self.q.limit = n;
+        self
+    }
+
+    /// Skips the first n matching Tasks, for example, to resume
+    /// a stream after the items already read
+    pub fn skip(mut self, n: usize) -> Self {
+        self.q.skip = n;
+        self
+    }
+
+    /// Sets the number of Tasks requested per call. The default is 500.
+    pub fn page_size(mut self, n: usize) -> Self {
+        self.q.page_size = n;
+        self
+    }
+
+    /// Includes archived Tasks if true. By default, they are left out.
+    pub fn include_archived(mut self, include: bool) -> Self {
+        self.q.include_archived = include;
+        self
+    }
+
+    /// Sorts by date created, oldest first
+    pub fn order_by_created_asc(mut self) -> Self {
+        self.q.order_by("created_at", false);
+        self
+    }
+
+    /// Sorts by date created, newest first
+    pub fn order_by_created_desc(mut self) -> Self {
+        self.q.order_by("created_at", true);
+        self
+    }
+
+    /// Sorts by date last updated, oldest first
+    pub fn order_by_updated_asc(mut self) -> Self {
+        self.q.order_by("updated_at", false);
+        self
+    }
+
+    /// Sorts by date last updated, newest first
+    pub fn order_by_updated_desc(mut self) -> Self {
+        self.q.order_by("updated_at", true);
+        self
+    }
+
+    /// Returns the request parameters for all matching items. `get_items` and
+    /// `stream_items` send these in pages of up to `page_size` items.
+    pub fn request(&self) -> GetEntriesRequest {
+        self.q.request()
+    }
+
+    /// Returns the matching Tasks
+    pub async fn get_items(&self) -> Result<Vec<Task>, Error> {
+        self.check()?;
+        let entries = self.q.load(&self.api, LIST_TASKS_ID).await?;
+        Ok(entries
+            .into_iter()
+            .map(|e| Task::new(Arc::new(e), self.api.clone()))
+            .collect())
+    }
+
+    /// Returns stream of the matching Tasks. Pages are fetched as the stream
+    /// is read, so only one page is in memory at a time. The stream ends after the first
+    /// error; to resume, create the query again with `skip` set to the number of items read.
+    /// Items added or removed while reading can shift the offsets.
+    pub fn stream_items(&self) -> impl futures::Stream<Item = Result<Task, Error>> + Send {
+        use futures::StreamExt;
+        if let Err(e) = self.check() {
+            return futures::stream::once(futures::future::ready(Err(e))).left_stream();
+        }
+        let api = self.api.clone();
+        self.q
+            .stream(self.api.clone(), LIST_TASKS_ID)
+            .map(move |r| r.map(|e| Task::new(Arc::new(e), api.clone())))
+            .right_stream()
+    }
+
+    /// Returns error if a condition was invalid, e.g., an unknown label
+    fn check(&self) -> Result<(), Error> {
+        if self.errs.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Message(format!(
+                "Errors occurred in TaskQuery: {:?}",
+                &self.errs
+            )))
+        }
+    }
+
+    /// Matches Tasks where 'Name' is the text
+    pub fn name_is(mut self, text: &str) -> Self {
+        self.q.filter(
+            Task::FIELD_NAME_ID,
+            json!({ "modus": "equals", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Tasks where 'Name' contains the text
+    pub fn name_contains(mut self, text: &str) -> Self {
+        self.q.filter(
+            Task::FIELD_NAME_ID,
+            json!({ "modus": "contains", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Tasks where 'Name' starts with the text
+    pub fn name_starts_with(mut self, text: &str) -> Self {
+        self.q.filter(
+            Task::FIELD_NAME_ID,
+            json!({ "modus": "startsWith", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Tasks where 'Name' is not set
+    pub fn name_is_empty(mut self) -> Self {
+        self.q
+            .filter(Task::FIELD_NAME_ID, json!({ "modus": "isEmpty" }));
+        self
+    }
+
+    /// Matches Tasks where 'Name' is set
+    pub fn name_is_not_empty(mut self) -> Self {
+        self.q
+            .filter(Task::FIELD_NAME_ID, json!({ "modus": "isNotEmpty" }));
+        self
+    }
+
+    /// Sorts by 'Name', ascending
+    pub fn order_by_name_asc(mut self) -> Self {
+        self.q
+            .order_by("3486ea99-76c3-5fd3-86c3-a18da0ee63c5_text", false);
+        self
+    }
+
+    /// Sorts by 'Name', descending
+    pub fn order_by_name_desc(mut self) -> Self {
+        self.q
+            .order_by("3486ea99-76c3-5fd3-86c3-a18da0ee63c5_text", true);
+        self
+    }
+} // end impl TaskQuery
+
+/// Task data, with one field per Zenkit field, that can be serialized,
+/// cached, or sent between threads. Create from an item with `From<&Task>`,
+/// and save with `to_new_builder` or `to_update_builder` (or the `*_with` versions,
+/// which take the client to save with).
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct TaskRecord {
+    /// Item id, or None if the item hasn't been created
+    pub id: Option<TaskId>,
+    /// Item uuid, or None if the item hasn't been created
+    pub uuid: Option<TaskUuid>,
+    /// Name
+    pub name: Option<String>,
+}
+
+impl From<&Task> for TaskRecord {
+    fn from(item: &Task) -> Self {
+        Self {
+            id: Some(item.get_id()),
+            uuid: Some(item.get_uuid()),
+            name: item.get_name().map(String::from),
+        }
+    }
+}
+
+impl TaskRecord {
+    /// Returns builder for creating a new Task with the record's values,
+    /// using the global api client. Fields that are None, and read-only fields, are not set.
+    pub fn to_new_builder(&self) -> NewTaskBuilder {
+        self.to_new_builder_with(&TasksClient::new(Api::global()))
+    }
+
+    /// Returns builder for creating a new Task with the record's values,
+    /// using the client. Fields that are None, and read-only fields, are not set.
+    pub fn to_new_builder_with(&self, client: &TasksClient) -> NewTaskBuilder {
+        let mut builder = client.create();
+        if let Some(ref v) = self.name {
+            builder.set_name(v);
+        }
+        builder
+    }
+
+    /// Returns builder for updating the Task with the record's id to the record's values,
+    /// using the global api client. Fields that are None, and read-only fields, are not changed.
+    /// Multi-value fields are replaced. Returns an error if the record has no id.
+    pub fn to_update_builder(&self) -> Result<UpdateTaskBuilder, Error> {
+        self.to_update_builder_with(&TasksClient::new(Api::global()))
+    }
+
+    /// Returns builder for updating the Task with the record's id to the record's values,
+    /// using the client. Fields that are None, and read-only fields, are not changed.
+    /// Multi-value fields are replaced. Returns an error if the record has no id.
+    pub fn to_update_builder_with(&self, client: &TasksClient) -> Result<UpdateTaskBuilder, Error> {
+        let id = self
+            .id
+            .ok_or_else(|| Error::Message("TaskRecord has no id".to_string()))?;
+        let mut builder = client.update(id);
+        if let Some(ref v) = self.name {
+            builder.set_name(v);
+        }
+        Ok(builder)
+    }
+}
+
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    fn assert_send<T: Send>(_: &T) {}
+    assert_send_sync::<Task>();
+    assert_send_sync::<NewTaskBuilder>();
+    assert_send_sync::<UpdateTaskBuilder>();
+    assert_send_sync::<TaskRecord>();
+    assert_send(&TasksList::get_items());
+    assert_send(&TasksList::create().execute());
+    assert_send(&TasksList::create_many(Vec::new(), 1));
+};
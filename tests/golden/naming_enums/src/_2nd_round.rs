@@ -0,0 +1,846 @@
+#![allow(dead_code, unused_imports)]
+//! _2NdRoundList
+//  Generated by zk-codegen v0.5.4 from schema d0db8d0e29fb7e6b
+use super::{lookup_label, run_batch, Api, EntryQuery, Error};
+use serde_json::{self, json, Value};
+use std::{convert::AsRef, str::FromStr, sync::Arc};
+use zenkit::types::{DateTime, Entry, File, GetEntriesRequest, JsonMap, TextFormat, Utc, ID};
+
+/// _2NdRoundList
+///
+pub const LIST_2ND_ROUND_ID: ID = 502;
+pub const LIST_2ND_ROUND_SHORT_ID: &str = "L502";
+pub const LIST_2ND_ROUND_UUID: &str = "9826c760-1eea-534f-b88c-5187644bcd84";
+pub const LIST_2ND_ROUND_NAME: &str = "2nd Round";
+
+/// ID of a _2NdRound (item of List '2nd Round')
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(transparent)]
+pub struct _2NdRoundId(ID);
+
+impl _2NdRoundId {
+    /// Creates _2NdRoundId from the item's numeric id
+    pub fn new(id: ID) -> Self {
+        Self(id)
+    }
+
+    /// Returns the numeric id
+    pub fn value(&self) -> ID {
+        self.0
+    }
+}
+
+impl std::fmt::Display for _2NdRoundId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for _2NdRoundId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<ID>()
+            .map(Self)
+            .map_err(|_| Error::Message(format!("invalid _2NdRound id '{}'", s)))
+    }
+}
+
+impl From<_2NdRoundId> for ID {
+    fn from(id: _2NdRoundId) -> ID {
+        id.0
+    }
+}
+
+/// UUID of a _2NdRound (item of List '2nd Round').
+/// Reference setters accept this type or a `&_2NdRound`.
+#[derive(
+    Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(transparent)]
+pub struct _2NdRoundUuid(String);
+
+impl _2NdRoundUuid {
+    /// Creates _2NdRoundUuid from a uuid string. The uuid is not validated;
+    /// use `parse` to check the format.
+    pub fn new<S: Into<String>>(uuid: S) -> Self {
+        Self(uuid.into())
+    }
+
+    /// Returns the uuid string
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for _2NdRoundUuid {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for _2NdRoundUuid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for _2NdRoundUuid {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if super::is_uuid(s) {
+            Ok(Self(s.to_string()))
+        } else {
+            Err(Error::Message(format!("invalid _2NdRound uuid '{}'", s)))
+        }
+    }
+}
+
+impl From<&_2NdRound> for _2NdRoundUuid {
+    fn from(item: &_2NdRound) -> Self {
+        item.get_uuid()
+    }
+}
+
+impl From<&_2NdRoundUuid> for _2NdRoundUuid {
+    fn from(uuid: &_2NdRoundUuid) -> Self {
+        uuid.clone()
+    }
+}
+
+/// _2NdRoundList - List of _2NdRounds, using the global client.
+/// For a separate connection, use `WorkspaceClient::_2nd_round()`.
+///
+pub struct _2NdRoundList {}
+
+impl _2NdRoundList {
+    /// fetch _2NdRound by its ID
+    pub async fn get(_2_nd_round_id: _2NdRoundId) -> Result<_2NdRound, Error> {
+        _2NdRoundClient::new(Api::global())
+            .get(_2_nd_round_id)
+            .await
+    }
+
+    /// fetch _2NdRound by its UUID
+    pub async fn get_by_uuid(_2_nd_round_uuid: &_2NdRoundUuid) -> Result<_2NdRound, Error> {
+        _2NdRoundClient::new(Api::global())
+            .get_by_uuid(_2_nd_round_uuid)
+            .await
+    }
+
+    /// Returns all _2NdRounds
+    pub async fn get_items() -> Result<Vec<_2NdRound>, Error> {
+        _2NdRoundClient::new(Api::global()).get_items().await
+    }
+
+    /// Returns stream of all _2NdRounds, fetched a page at a time as the stream is read.
+    /// For other page sizes, or to resume at an offset, use `query()`.
+    pub fn stream_items() -> impl futures::Stream<Item = Result<_2NdRound, Error>> + Send {
+        _2NdRoundClient::new(Api::global()).stream_items()
+    }
+
+    /// Creates query for _2NdRounds matching conditions, which are checked by the server
+    pub fn query() -> _2NdRoundQuery {
+        _2NdRoundQuery::new(Api::global())
+    }
+
+    /// Initialize builder for creating a new _2NdRound
+    pub fn create() -> New_2NdRoundBuilder {
+        New_2NdRoundBuilder::new(Api::global())
+    }
+
+    /// Creates builder for updating an existing _2NdRound
+    pub fn update(_2_nd_round_id: _2NdRoundId) -> Update_2NdRoundBuilder {
+        Update_2NdRoundBuilder::new(Api::global(), _2_nd_round_id)
+    }
+
+    /// Archives _2NdRound, and returns the archived _2NdRound
+    pub async fn archive(_2_nd_round_id: _2NdRoundId) -> Result<_2NdRound, Error> {
+        _2NdRoundClient::new(Api::global())
+            .archive(_2_nd_round_id)
+            .await
+    }
+
+    /// Restores archived _2NdRound, and returns the restored _2NdRound
+    pub async fn restore(_2_nd_round_id: _2NdRoundId) -> Result<_2NdRound, Error> {
+        _2NdRoundClient::new(Api::global())
+            .restore(_2_nd_round_id)
+            .await
+    }
+
+    /// Deletes _2NdRound permanently
+    pub async fn delete(_2_nd_round_id: _2NdRoundId) -> Result<(), Error> {
+        _2NdRoundClient::new(Api::global())
+            .delete(_2_nd_round_id)
+            .await
+    }
+
+    /// Creates _2NdRounds, sending up to `concurrency` requests at a time.
+    /// Returns the result of each builder, in the order of `builders`, so that
+    /// a failure doesn't affect the other items. Each builder uses the client
+    /// it was created with. Creates are not retried (see `RetryPolicy`).
+    pub async fn create_many(
+        builders: Vec<New_2NdRoundBuilder>,
+        concurrency: usize,
+    ) -> Vec<Result<_2NdRound, Error>> {
+        run_batch(
+            builders
+                .into_iter()
+                .map(|mut b| async move { b.execute().await }),
+            concurrency,
+        )
+        .await
+    }
+
+    /// Updates _2NdRounds, sending up to `concurrency` requests at a time.
+    /// Returns the result of each builder, in the order of `builders`, so that
+    /// a failure doesn't affect the other items. Each builder uses the client
+    /// it was created with. Updates are not retried (see `RetryPolicy`).
+    pub async fn update_many(
+        builders: Vec<Update_2NdRoundBuilder>,
+        concurrency: usize,
+    ) -> Vec<Result<_2NdRound, Error>> {
+        run_batch(
+            builders
+                .into_iter()
+                .map(|mut b| async move { b.execute().await }),
+            concurrency,
+        )
+        .await
+    }
+}
+
+/// Client for List '2nd Round', from `WorkspaceClient::_2nd_round()`.
+/// Items and builders from this client use the client's connection.
+#[derive(Clone)]
+pub struct _2NdRoundClient {
+    api: Api,
+}
+
+impl _2NdRoundClient {
+    pub(crate) fn new(api: Api) -> Self {
+        Self { api }
+    }
+
+    /// fetch _2NdRound by its ID
+    pub async fn get(&self, _2_nd_round_id: _2NdRoundId) -> Result<_2NdRound, Error> {
+        let obj = self
+            .api
+            .get_entry(LIST_2ND_ROUND_ID, _2_nd_round_id.value())
+            .await?;
+        Ok(_2NdRound::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// fetch _2NdRound by its UUID
+    pub async fn get_by_uuid(&self, _2_nd_round_uuid: &_2NdRoundUuid) -> Result<_2NdRound, Error> {
+        let obj = self
+            .api
+            .get_entry(LIST_2ND_ROUND_ID, _2_nd_round_uuid.as_str())
+            .await?;
+        Ok(_2NdRound::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// Returns all _2NdRounds
+    pub async fn get_items(&self) -> Result<Vec<_2NdRound>, Error> {
+        let entries = self.api.load_entries(LIST_2ND_ROUND_ID).await?;
+        let items: Vec<_2NdRound> = entries
+            .into_iter()
+            .map(|e| _2NdRound::new(Arc::new(e), self.api.clone()))
+            .collect();
+        Ok(items)
+    }
+
+    /// Returns stream of all _2NdRounds, fetched a page at a time as the stream is read.
+    /// For other page sizes, or to resume at an offset, use `query()`.
+    pub fn stream_items(&self) -> impl futures::Stream<Item = Result<_2NdRound, Error>> + Send {
+        self.query().stream_items()
+    }
+
+    /// Creates query for _2NdRounds matching conditions, which are checked by the server
+    pub fn query(&self) -> _2NdRoundQuery {
+        _2NdRoundQuery::new(self.api.clone())
+    }
+
+    /// Initialize builder for creating a new _2NdRound
+    pub fn create(&self) -> New_2NdRoundBuilder {
+        New_2NdRoundBuilder::new(self.api.clone())
+    }
+
+    /// Creates builder for updating an existing _2NdRound
+    pub fn update(&self, _2_nd_round_id: _2NdRoundId) -> Update_2NdRoundBuilder {
+        Update_2NdRoundBuilder::new(self.api.clone(), _2_nd_round_id)
+    }
+
+    /// Archives _2NdRound, and returns the archived _2NdRound. Archived items are
+    /// left out of `get_items` and queries, unless a query has `include_archived(true)`.
+    pub async fn archive(&self, _2_nd_round_id: _2NdRoundId) -> Result<_2NdRound, Error> {
+        let obj = self
+            .api
+            .archive_entry(LIST_2ND_ROUND_ID, _2_nd_round_id.value())
+            .await?;
+        Ok(_2NdRound::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// Restores archived _2NdRound, and returns the restored _2NdRound
+    pub async fn restore(&self, _2_nd_round_id: _2NdRoundId) -> Result<_2NdRound, Error> {
+        let obj = self
+            .api
+            .restore_entry(LIST_2ND_ROUND_ID, _2_nd_round_id.value())
+            .await?;
+        Ok(_2NdRound::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// Deletes _2NdRound permanently
+    pub async fn delete(&self, _2_nd_round_id: _2NdRoundId) -> Result<(), Error> {
+        self.api
+            .delete_entry(LIST_2ND_ROUND_ID, _2_nd_round_id.value())
+            .await
+    }
+}
+
+/// _2NdRound (item of List '2nd Round')
+pub struct _2NdRound {
+    obj: Arc<Entry>,
+    api: Api,
+}
+
+impl _2NdRound {
+    /// Creates _2NdRound wrapping Zenkit Entry
+    fn new(obj: Arc<Entry>, api: Api) -> Self {
+        Self { obj, api }
+    }
+
+    /// 'Name' in '2nd Round'
+    pub const FIELD_NAME_ID: ID = 72;
+    pub const FIELD_NAME_UUID: &'static str = "cbd7a483-c465-5579-97fd-5133e8fdbf82";
+    pub const FIELD_NAME_NAME: &'static str = "Name";
+
+    /// Returns 'Name' -  or None if unset
+    pub fn get_name(&self) -> Option<&str> {
+        self.obj
+            .fields
+            .get("cbd7a483-c465-5579-97fd-5133e8fdbf82_text")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+    }
+
+    /// Returns the text format for 'Name'
+    pub fn get_name_format(&self) -> TextFormat {
+        self.obj
+            .fields
+            .get("cbd7a483-c465-5579-97fd-5133e8fdbf82_textType")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+            // if undefined or unexpected value, return "plain"
+            .map(|f| TextFormat::from_str(f).unwrap_or_default())
+            .unwrap_or_default()
+    }
+
+    /// Returns url to zenkit item
+    pub fn get_zenkit_url(&self) -> String {
+        format!(
+            "{}{}/{}/",
+            super::item_url_base(),
+            LIST_2ND_ROUND_SHORT_ID,
+            self.obj.short_id
+        )
+    }
+
+    /// Returns the item's display string (based on list's primary field)
+    pub fn get_display_string(&self) -> &str {
+        &self.obj.display_string
+    }
+
+    /// Returns user id that created the item
+    pub fn get_created_by_id(&self) -> ID {
+        self.obj.created_by
+    }
+
+    /// Returns user name that created the item
+    pub fn get_created_by_name(&self) -> Option<&str> {
+        self.obj.created_by_displayname.as_deref()
+    }
+
+    /// Returns user id that created the item
+    pub fn get_updated_by_id(&self) -> ID {
+        self.obj.updated_by
+    }
+
+    /// Returns user name that last updated the item
+    pub fn get_updated_by_name(&self) -> Option<&str> {
+        self.obj.updated_by_displayname.as_deref()
+    }
+
+    /// Returns date the item was created, in UTC
+    pub fn get_created_date(&self) -> &DateTime<Utc> {
+        &self.obj.created_at
+    }
+
+    /// Returns date the item was last updated, in UTC
+    pub fn get_updated_date(&self) -> &DateTime<Utc> {
+        &self.obj.updated_at
+    }
+
+    /// Returns date the item was deprecated, in UTC, or None if item isn't deprecated
+    pub fn get_deprecated_date(&self) -> Option<&DateTime<Utc>> {
+        self.obj.deprecated_at.as_ref()
+    }
+
+    /// Returns user id that deprecated the item, or None if item isn't deprecated
+    pub fn get_deprecated_by_id(&self) -> Option<ID> {
+        self.obj.deprecated_by
+    }
+
+    /// Returns the default sort rank within the list
+    pub fn get_sort_order(&self) -> f32 {
+        self.obj.sort_order
+    }
+
+    /// Returns the item's id within the list
+    pub fn get_id(&self) -> _2NdRoundId {
+        _2NdRoundId::new(self.obj.id)
+    }
+
+    /// Returns the item's uuid
+    pub fn get_uuid(&self) -> _2NdRoundUuid {
+        _2NdRoundUuid::new(self.obj.uuid.as_str())
+    }
+
+    /// Returns the underlying Entry object
+    pub fn get_entry(&self) -> Arc<Entry> {
+        self.obj.clone()
+    }
+
+    /// Archives the item, and returns the archived item
+    pub async fn archive(&self) -> Result<_2NdRound, Error> {
+        _2NdRoundClient::new(self.api.clone())
+            .archive(self.get_id())
+            .await
+    }
+
+    /// Restores the archived item, and returns the restored item
+    pub async fn restore(&self) -> Result<_2NdRound, Error> {
+        _2NdRoundClient::new(self.api.clone())
+            .restore(self.get_id())
+            .await
+    }
+
+    /// Deletes the item permanently
+    pub async fn delete(&self) -> Result<(), Error> {
+        _2NdRoundClient::new(self.api.clone())
+            .delete(self.get_id())
+            .await
+    }
+} // impl _2NdRound
+
+/// Builder for creating new _2NdRound
+pub struct New_2NdRoundBuilder {
+    api: Api,
+    fields: JsonMap,
+    errs: Vec<String>,
+}
+
+impl New_2NdRoundBuilder {
+    /// Create builder for new _2NdRound
+    fn new(api: Api) -> Self {
+        Self {
+            api,
+            fields: JsonMap::new(),
+            errs: Vec::new(),
+        }
+    }
+
+    // internal helper for setting string field
+    #[inline]
+    fn set_s(&mut self, k: &str, v: String) {
+        self.fields.insert(k.to_string(), Value::String(v));
+    }
+    // internal helper for setting json value
+    #[inline]
+    fn set_v(&mut self, k: &str, v: Value) {
+        self.fields.insert(k.to_string(), v);
+    }
+
+    /// Sets text value for Name.
+    /// Uses the field's default TextFormat. To specify format, use _with_format()
+    pub fn set_name<T: AsRef<str>>(&mut self, s: T) -> &mut New_2NdRoundBuilder {
+        self.set_s(
+            "cbd7a483-c465-5579-97fd-5133e8fdbf82_text",
+            s.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sets text value for Name with format
+    ///
+    pub fn set_name_with_format<T: AsRef<str>>(
+        &mut self,
+        s: T,
+        tf: TextFormat,
+    ) -> &mut New_2NdRoundBuilder {
+        self.set_s(
+            "cbd7a483-c465-5579-97fd-5133e8fdbf82_text",
+            s.as_ref().to_string(),
+        );
+        self.set_s(
+            "cbd7a483-c465-5579-97fd-5133e8fdbf82_textType",
+            tf.to_string(),
+        );
+        self
+    }
+
+    /// Sends create request to server, returning new _2NdRound.
+    pub async fn execute(&mut self) -> Result<_2NdRound, Error> {
+        if !self.errs.is_empty() {
+            return Err(Error::Message(format!(
+                "Errors occurred in New_2NdRoundBuilder: {:?}",
+                &self.errs
+            )));
+        }
+        let map = self.fields.to_owned();
+        let _2_nd_round = self.api.create_entry(502, Value::Object(map)).await?;
+        Ok(_2NdRound::new(Arc::new(_2_nd_round), self.api.clone()))
+    }
+} // end impl New_2NdRoundBuilder
+
+/// Builder for updating existing _2NdRound
+pub struct Update_2NdRoundBuilder {
+    api: Api,
+    fields: JsonMap,
+    errs: Vec<String>,
+    item_id: _2NdRoundId,
+}
+
+impl Update_2NdRoundBuilder {
+    /// Create update builder for _2NdRound with id
+    fn new(api: Api, item_id: _2NdRoundId) -> Self {
+        Self {
+            api,
+            fields: JsonMap::new(),
+            errs: Vec::new(),
+            item_id,
+        }
+    }
+
+    // internal helper for setting the updateAction of multi-value fields.
+    // Zenkit applies one action to all fields of a request, so a request can't
+    // append or remove values of one field and replace another.
+    fn set_update_action(&mut self, action: &str) {
+        if let Some(Value::String(prev)) = self.fields.get("updateAction") {
+            if prev != action {
+                self.errs.push(format!(
+                    "Conflicting update actions '{}' and '{}': fields that add or remove values \
+                                 must be updated separately from fields that are set",
+                    prev, action
+                ));
+                return;
+            }
+        }
+        self.set_s("updateAction", action.to_string());
+    }
+
+    // internal helper for setting string field
+    #[inline]
+    fn set_s(&mut self, k: &str, v: String) {
+        self.fields.insert(k.to_string(), Value::String(v));
+    }
+    // internal helper for setting json value
+    #[inline]
+    fn set_v(&mut self, k: &str, v: Value) {
+        self.fields.insert(k.to_string(), v);
+    }
+
+    /// Sets text value for Name.
+    /// TextFormat is unchanged (to specify format, use _with_format())
+    pub fn set_name<T: AsRef<str>>(&mut self, s: T) -> &mut Update_2NdRoundBuilder {
+        self.set_s(
+            "cbd7a483-c465-5579-97fd-5133e8fdbf82_text",
+            s.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sets text value for Name with format
+    ///
+    pub fn set_name_with_format<T: AsRef<str>>(
+        &mut self,
+        s: T,
+        tf: TextFormat,
+    ) -> &mut Update_2NdRoundBuilder {
+        self.set_s(
+            "cbd7a483-c465-5579-97fd-5133e8fdbf82_text",
+            s.as_ref().to_string(),
+        );
+        self.set_s(
+            "cbd7a483-c465-5579-97fd-5133e8fdbf82_textType",
+            tf.to_string(),
+        );
+        self
+    }
+
+    /// Sends update request to server, returning updated _2NdRound.
+    pub async fn execute(&mut self) -> Result<_2NdRound, Error> {
+        if !self.errs.is_empty() {
+            return Err(Error::Message(format!(
+                "Errors occurred in Update_2NdRoundBuilder: {:?}",
+                &self.errs
+            )));
+        }
+        let map = self.fields.to_owned();
+        let _2_nd_round = self
+            .api
+            .update_entry(502, self.item_id.value(), Value::Object(map))
+            .await?;
+        Ok(_2NdRound::new(Arc::new(_2_nd_round), self.api.clone()))
+    }
+} // end impl Update_2NdRoundBuilder
+
+/// Query for _2NdRounds in List '2nd Round'. Filters and sort order
+/// are sent with the request, so only matching items are downloaded.
+/// All conditions must match.
+#[derive(Clone)]
+pub struct _2NdRoundQuery {
+    api: Api,
+    q: EntryQuery,
+    errs: Vec<String>,
+}
+
+impl _2NdRoundQuery {
+    /// Creates query with no conditions
+    fn new(api: Api) -> Self {
+        Self {
+            api,
+            q: EntryQuery::default(),
+            errs: Vec::new(),
+        }
+    }
+
+    /// Returns at most n _2NdRounds. The default (0) returns all matching items.
+    pub fn limit(mut self, n: usize) -> Self {
+        self.q.limit = n;
+        self
+    }
+
+    /// Skips the first n matching _2NdRounds, for example, to resume
+    /// a stream after the items already read
+    pub fn skip(mut self, n: usize) -> Self {
+        self.q.skip = n;
+        self
+    }
+
+    /// Sets the number of _2NdRounds requested per call. The default is 500.
+    pub fn page_size(mut self, n: usize) -> Self {
+        self.q.page_size = n;
+        self
+    }
+
+    /// Includes archived _2NdRounds if true. By default, they are left out.
+    pub fn include_archived(mut self, include: bool) -> Self {
+        self.q.include_archived = include;
+        self
+    }
+
+    /// Sorts by date created, oldest first
+    pub fn order_by_created_asc(mut self) -> Self {
+        self.q.order_by("created_at", false);
+        self
+    }
+
+    /// Sorts by date created, newest first
+    pub fn order_by_created_desc(mut self) -> Self {
+        self.q.order_by("created_at", true);
+        self
+    }
+
+    /// Sorts by date last updated, oldest first
+    pub fn order_by_updated_asc(mut self) -> Self {
+        self.q.order_by("updated_at", false);
+        self
+    }
+
+    /// Sorts by date last updated, newest first
+    pub fn order_by_updated_desc(mut self) -> Self {
+        self.q.order_by("updated_at", true);
+        self
+    }
+
+    /// Returns the request parameters for all matching items. `get_items` and
+    /// `stream_items` send these in pages of up to `page_size` items.
+    pub fn request(&self) -> GetEntriesRequest {
+        self.q.request()
+    }
+
+    /// Returns the matching _2NdRounds
+    pub async fn get_items(&self) -> Result<Vec<_2NdRound>, Error> {
+        self.check()?;
+        let entries = self.q.load(&self.api, LIST_2ND_ROUND_ID).await?;
+        Ok(entries
+            .into_iter()
+            .map(|e| _2NdRound::new(Arc::new(e), self.api.clone()))
+            .collect())
+    }
+
+    /// Returns stream of the matching _2NdRounds. Pages are fetched as the stream
+    /// is read, so only one page is in memory at a time. The stream ends after the first
+    /// error; to resume, create the query again with `skip` set to the number of items read.
+    /// Items added or removed while reading can shift the offsets.
+    pub fn stream_items(&self) -> impl futures::Stream<Item = Result<_2NdRound, Error>> + Send {
+        use futures::StreamExt;
+        if let Err(e) = self.check() {
+            return futures::stream::once(futures::future::ready(Err(e))).left_stream();
+        }
+        let api = self.api.clone();
+        self.q
+            .stream(self.api.clone(), LIST_2ND_ROUND_ID)
+            .map(move |r| r.map(|e| _2NdRound::new(Arc::new(e), api.clone())))
+            .right_stream()
+    }
+
+    /// Returns error if a condition was invalid, e.g., an unknown label
+    fn check(&self) -> Result<(), Error> {
+        if self.errs.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Message(format!(
+                "Errors occurred in _2NdRoundQuery: {:?}",
+                &self.errs
+            )))
+        }
+    }
+
+    /// Matches _2NdRounds where 'Name' is the text
+    pub fn name_is(mut self, text: &str) -> Self {
+        self.q.filter(
+            _2NdRound::FIELD_NAME_ID,
+            json!({ "modus": "equals", "text": text }),
+        );
+        self
+    }
+
+    /// Matches _2NdRounds where 'Name' contains the text
+    pub fn name_contains(mut self, text: &str) -> Self {
+        self.q.filter(
+            _2NdRound::FIELD_NAME_ID,
+            json!({ "modus": "contains", "text": text }),
+        );
+        self
+    }
+
+    /// Matches _2NdRounds where 'Name' starts with the text
+    pub fn name_starts_with(mut self, text: &str) -> Self {
+        self.q.filter(
+            _2NdRound::FIELD_NAME_ID,
+            json!({ "modus": "startsWith", "text": text }),
+        );
+        self
+    }
+
+    /// Matches _2NdRounds where 'Name' is not set
+    pub fn name_is_empty(mut self) -> Self {
+        self.q
+            .filter(_2NdRound::FIELD_NAME_ID, json!({ "modus": "isEmpty" }));
+        self
+    }
+
+    /// Matches _2NdRounds where 'Name' is set
+    pub fn name_is_not_empty(mut self) -> Self {
+        self.q
+            .filter(_2NdRound::FIELD_NAME_ID, json!({ "modus": "isNotEmpty" }));
+        self
+    }
+
+    /// Sorts by 'Name', ascending
+    pub fn order_by_name_asc(mut self) -> Self {
+        self.q
+            .order_by("cbd7a483-c465-5579-97fd-5133e8fdbf82_text", false);
+        self
+    }
+
+    /// Sorts by 'Name', descending
+    pub fn order_by_name_desc(mut self) -> Self {
+        self.q
+            .order_by("cbd7a483-c465-5579-97fd-5133e8fdbf82_text", true);
+        self
+    }
+} // end impl _2NdRoundQuery
+
+/// _2NdRound data, with one field per Zenkit field, that can be serialized,
+/// cached, or sent between threads. Create from an item with `From<&_2NdRound>`,
+/// and save with `to_new_builder` or `to_update_builder` (or the `*_with` versions,
+/// which take the client to save with).
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct _2NdRoundRecord {
+    /// Item id, or None if the item hasn't been created
+    pub id: Option<_2NdRoundId>,
+    /// Item uuid, or None if the item hasn't been created
+    pub uuid: Option<_2NdRoundUuid>,
+    /// Name
+    pub name: Option<String>,
+}
+
+impl From<&_2NdRound> for _2NdRoundRecord {
+    fn from(item: &_2NdRound) -> Self {
+        Self {
+            id: Some(item.get_id()),
+            uuid: Some(item.get_uuid()),
+            name: item.get_name().map(String::from),
+        }
+    }
+}
+
+impl _2NdRoundRecord {
+    /// Returns builder for creating a new _2NdRound with the record's values,
+    /// using the global api client. Fields that are None, and read-only fields, are not set.
+    pub fn to_new_builder(&self) -> New_2NdRoundBuilder {
+        self.to_new_builder_with(&_2NdRoundClient::new(Api::global()))
+    }
+
+    /// Returns builder for creating a new _2NdRound with the record's values,
+    /// using the client. Fields that are None, and read-only fields, are not set.
+    pub fn to_new_builder_with(&self, client: &_2NdRoundClient) -> New_2NdRoundBuilder {
+        let mut builder = client.create();
+        if let Some(ref v) = self.name {
+            builder.set_name(v);
+        }
+        builder
+    }
+
+    /// Returns builder for updating the _2NdRound with the record's id to the record's values,
+    /// using the global api client. Fields that are None, and read-only fields, are not changed.
+    /// Multi-value fields are replaced. Returns an error if the record has no id.
+    pub fn to_update_builder(&self) -> Result<Update_2NdRoundBuilder, Error> {
+        self.to_update_builder_with(&_2NdRoundClient::new(Api::global()))
+    }
+
+    /// Returns builder for updating the _2NdRound with the record's id to the record's values,
+    /// using the client. Fields that are None, and read-only fields, are not changed.
+    /// Multi-value fields are replaced. Returns an error if the record has no id.
+    pub fn to_update_builder_with(
+        &self,
+        client: &_2NdRoundClient,
+    ) -> Result<Update_2NdRoundBuilder, Error> {
+        let id = self
+            .id
+            .ok_or_else(|| Error::Message("_2NdRoundRecord has no id".to_string()))?;
+        let mut builder = client.update(id);
+        if let Some(ref v) = self.name {
+            builder.set_name(v);
+        }
+        Ok(builder)
+    }
+}
+
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    fn assert_send<T: Send>(_: &T) {}
+    assert_send_sync::<_2NdRound>();
+    assert_send_sync::<New_2NdRoundBuilder>();
+    assert_send_sync::<Update_2NdRoundBuilder>();
+    assert_send_sync::<_2NdRoundRecord>();
+    assert_send(&_2NdRoundList::get_items());
+    assert_send(&_2NdRoundList::create().execute());
+    assert_send(&_2NdRoundList::create_many(Vec::new(), 1));
+};
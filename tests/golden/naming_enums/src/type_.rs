@@ -0,0 +1,2783 @@
+#![allow(dead_code, unused_imports)]
+//! TypeList
+//  Generated by zk-codegen v0.5.4 from schema d0db8d0e29fb7e6b
+use super::{lookup_label, run_batch, Api, EntryQuery, Error};
+use serde_json::{self, json, Value};
+use std::{convert::AsRef, str::FromStr, sync::Arc};
+use zenkit::types::{DateTime, Entry, File, GetEntriesRequest, JsonMap, TextFormat, Utc, ID};
+
+/// TypeList
+///
+pub const LIST_TYPE_ID: ID = 501;
+pub const LIST_TYPE_SHORT_ID: &str = "L501";
+pub const LIST_TYPE_UUID: &str = "910b3653-1098-5bf5-9c36-23c2aaba31fa";
+pub const LIST_TYPE_NAME: &str = "Type";
+
+/// ID of a Match (item of List 'Type')
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(transparent)]
+pub struct MatchId(ID);
+
+impl MatchId {
+    /// Creates MatchId from the item's numeric id
+    pub fn new(id: ID) -> Self {
+        Self(id)
+    }
+
+    /// Returns the numeric id
+    pub fn value(&self) -> ID {
+        self.0
+    }
+}
+
+impl std::fmt::Display for MatchId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for MatchId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<ID>()
+            .map(Self)
+            .map_err(|_| Error::Message(format!("invalid Match id '{}'", s)))
+    }
+}
+
+impl From<MatchId> for ID {
+    fn from(id: MatchId) -> ID {
+        id.0
+    }
+}
+
+/// UUID of a Match (item of List 'Type').
+/// Reference setters accept this type or a `&Match`.
+#[derive(
+    Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(transparent)]
+pub struct MatchUuid(String);
+
+impl MatchUuid {
+    /// Creates MatchUuid from a uuid string. The uuid is not validated;
+    /// use `parse` to check the format.
+    pub fn new<S: Into<String>>(uuid: S) -> Self {
+        Self(uuid.into())
+    }
+
+    /// Returns the uuid string
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for MatchUuid {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for MatchUuid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for MatchUuid {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if super::is_uuid(s) {
+            Ok(Self(s.to_string()))
+        } else {
+            Err(Error::Message(format!("invalid Match uuid '{}'", s)))
+        }
+    }
+}
+
+impl From<&Match> for MatchUuid {
+    fn from(item: &Match) -> Self {
+        item.get_uuid()
+    }
+}
+
+impl From<&MatchUuid> for MatchUuid {
+    fn from(uuid: &MatchUuid) -> Self {
+        uuid.clone()
+    }
+}
+
+/// TypeList - List of Match, using the global client.
+/// For a separate connection, use `WorkspaceClient::type_()`.
+///
+pub struct TypeList {}
+
+impl TypeList {
+    /// fetch Match by its ID
+    pub async fn get(match__id: MatchId) -> Result<Match, Error> {
+        TypeClient::new(Api::global()).get(match__id).await
+    }
+
+    /// fetch Match by its UUID
+    pub async fn get_by_uuid(match__uuid: &MatchUuid) -> Result<Match, Error> {
+        TypeClient::new(Api::global())
+            .get_by_uuid(match__uuid)
+            .await
+    }
+
+    /// Returns all Match
+    pub async fn get_items() -> Result<Vec<Match>, Error> {
+        TypeClient::new(Api::global()).get_items().await
+    }
+
+    /// Returns stream of all Match, fetched a page at a time as the stream is read.
+    /// For other page sizes, or to resume at an offset, use `query()`.
+    pub fn stream_items() -> impl futures::Stream<Item = Result<Match, Error>> + Send {
+        TypeClient::new(Api::global()).stream_items()
+    }
+
+    /// Creates query for Match matching conditions, which are checked by the server
+    pub fn query() -> MatchQuery {
+        MatchQuery::new(Api::global())
+    }
+
+    /// Initialize builder for creating a new Match
+    pub fn create() -> NewMatchBuilder {
+        NewMatchBuilder::new(Api::global())
+    }
+
+    /// Creates builder for updating an existing Match
+    pub fn update(match__id: MatchId) -> UpdateMatchBuilder {
+        UpdateMatchBuilder::new(Api::global(), match__id)
+    }
+
+    /// Archives Match, and returns the archived Match
+    pub async fn archive(match__id: MatchId) -> Result<Match, Error> {
+        TypeClient::new(Api::global()).archive(match__id).await
+    }
+
+    /// Restores archived Match, and returns the restored Match
+    pub async fn restore(match__id: MatchId) -> Result<Match, Error> {
+        TypeClient::new(Api::global()).restore(match__id).await
+    }
+
+    /// Deletes Match permanently
+    pub async fn delete(match__id: MatchId) -> Result<(), Error> {
+        TypeClient::new(Api::global()).delete(match__id).await
+    }
+
+    /// Creates Match, sending up to `concurrency` requests at a time.
+    /// Returns the result of each builder, in the order of `builders`, so that
+    /// a failure doesn't affect the other items. Each builder uses the client
+    /// it was created with. Creates are not retried (see `RetryPolicy`).
+    pub async fn create_many(
+        builders: Vec<NewMatchBuilder>,
+        concurrency: usize,
+    ) -> Vec<Result<Match, Error>> {
+        run_batch(
+            builders
+                .into_iter()
+                .map(|mut b| async move { b.execute().await }),
+            concurrency,
+        )
+        .await
+    }
+
+    /// Updates Match, sending up to `concurrency` requests at a time.
+    /// Returns the result of each builder, in the order of `builders`, so that
+    /// a failure doesn't affect the other items. Each builder uses the client
+    /// it was created with. Updates are not retried (see `RetryPolicy`).
+    pub async fn update_many(
+        builders: Vec<UpdateMatchBuilder>,
+        concurrency: usize,
+    ) -> Vec<Result<Match, Error>> {
+        run_batch(
+            builders
+                .into_iter()
+                .map(|mut b| async move { b.execute().await }),
+            concurrency,
+        )
+        .await
+    }
+}
+
+/// Client for List 'Type', from `WorkspaceClient::type_()`.
+/// Items and builders from this client use the client's connection.
+#[derive(Clone)]
+pub struct TypeClient {
+    api: Api,
+}
+
+impl TypeClient {
+    pub(crate) fn new(api: Api) -> Self {
+        Self { api }
+    }
+
+    /// fetch Match by its ID
+    pub async fn get(&self, match__id: MatchId) -> Result<Match, Error> {
+        let obj = self.api.get_entry(LIST_TYPE_ID, match__id.value()).await?;
+        Ok(Match::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// fetch Match by its UUID
+    pub async fn get_by_uuid(&self, match__uuid: &MatchUuid) -> Result<Match, Error> {
+        let obj = self
+            .api
+            .get_entry(LIST_TYPE_ID, match__uuid.as_str())
+            .await?;
+        Ok(Match::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// Returns all Match
+    pub async fn get_items(&self) -> Result<Vec<Match>, Error> {
+        let entries = self.api.load_entries(LIST_TYPE_ID).await?;
+        let items: Vec<Match> = entries
+            .into_iter()
+            .map(|e| Match::new(Arc::new(e), self.api.clone()))
+            .collect();
+        Ok(items)
+    }
+
+    /// Returns stream of all Match, fetched a page at a time as the stream is read.
+    /// For other page sizes, or to resume at an offset, use `query()`.
+    pub fn stream_items(&self) -> impl futures::Stream<Item = Result<Match, Error>> + Send {
+        self.query().stream_items()
+    }
+
+    /// Creates query for Match matching conditions, which are checked by the server
+    pub fn query(&self) -> MatchQuery {
+        MatchQuery::new(self.api.clone())
+    }
+
+    /// Initialize builder for creating a new Match
+    pub fn create(&self) -> NewMatchBuilder {
+        NewMatchBuilder::new(self.api.clone())
+    }
+
+    /// Creates builder for updating an existing Match
+    pub fn update(&self, match__id: MatchId) -> UpdateMatchBuilder {
+        UpdateMatchBuilder::new(self.api.clone(), match__id)
+    }
+
+    /// Archives Match, and returns the archived Match. Archived items are
+    /// left out of `get_items` and queries, unless a query has `include_archived(true)`.
+    pub async fn archive(&self, match__id: MatchId) -> Result<Match, Error> {
+        let obj = self
+            .api
+            .archive_entry(LIST_TYPE_ID, match__id.value())
+            .await?;
+        Ok(Match::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// Restores archived Match, and returns the restored Match
+    pub async fn restore(&self, match__id: MatchId) -> Result<Match, Error> {
+        let obj = self
+            .api
+            .restore_entry(LIST_TYPE_ID, match__id.value())
+            .await?;
+        Ok(Match::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// Deletes Match permanently
+    pub async fn delete(&self, match__id: MatchId) -> Result<(), Error> {
+        self.api.delete_entry(LIST_TYPE_ID, match__id.value()).await
+    }
+}
+
+/// Match (item of List 'Type')
+pub struct Match {
+    obj: Arc<Entry>,
+    api: Api,
+}
+
+impl Match {
+    /// Creates Match wrapping Zenkit Entry
+    fn new(obj: Arc<Entry>, api: Api) -> Self {
+        Self { obj, api }
+    }
+
+    /// 'Type' in 'Type'
+    pub const FIELD_TYPE_ID: ID = 51;
+    pub const FIELD_TYPE_UUID: &'static str = "1f36a43e-0cab-5964-ba28-ebf271b3f8f0";
+    pub const FIELD_TYPE_NAME: &'static str = "Type";
+
+    /// Returns 'Type' -  or None if unset
+    pub fn get_type(&self) -> Option<&str> {
+        self.obj
+            .fields
+            .get("1f36a43e-0cab-5964-ba28-ebf271b3f8f0_text")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+    }
+
+    /// Returns the text format for 'Type'
+    pub fn get_type_format(&self) -> TextFormat {
+        self.obj
+            .fields
+            .get("1f36a43e-0cab-5964-ba28-ebf271b3f8f0_textType")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+            // if undefined or unexpected value, return "plain"
+            .map(|f| TextFormat::from_str(f).unwrap_or_default())
+            .unwrap_or_default()
+    }
+
+    /// 'Match' in 'Type'
+    pub const FIELD_MATCH_ID: ID = 52;
+    pub const FIELD_MATCH_UUID: &'static str = "a86e0820-aab2-5a23-a834-3fc59e7622ea";
+    pub const FIELD_MATCH_NAME: &'static str = "Match";
+
+    /// Returns 'Match' -  or None if unset
+    pub fn get_match(&self) -> Option<i64> {
+        self.obj
+            .fields
+            .get("a86e0820-aab2-5a23-a834-3fc59e7622ea_number")
+            .map(|n| n.as_i64())
+            .unwrap_or_default()
+    }
+
+    /// 'Due Date' in 'Type'
+    pub const FIELD_DUE_DATE_ID: ID = 53;
+    pub const FIELD_DUE_DATE_UUID: &'static str = "5f9b7c04-85c1-538d-a688-a64e1448ba2d";
+    pub const FIELD_DUE_DATE_NAME: &'static str = "Due Date";
+
+    /// Returns 'Due Date' in UTC , or None if unset
+    pub fn get_due_date(&self) -> Option<DateTime<Utc>> {
+        self.obj
+            .fields
+            .get("5f9b7c04-85c1-538d-a688-a64e1448ba2d_date")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+            .map(|s| s.parse::<DateTime<Utc>>().ok())
+            .unwrap_or_default()
+    }
+
+    /// 'Due-Date' in 'Type'
+    pub const FIELD_DUE_DATE_2_ID: ID = 54;
+    pub const FIELD_DUE_DATE_2_UUID: &'static str = "8bc033ec-438d-5c25-ba7f-7f0488703a6f";
+    pub const FIELD_DUE_DATE_2_NAME: &'static str = "Due-Date";
+
+    /// Returns 'Due-Date' in UTC , or None if unset
+    pub fn get_due_date_2(&self) -> Option<DateTime<Utc>> {
+        self.obj
+            .fields
+            .get("8bc033ec-438d-5c25-ba7f-7f0488703a6f_date")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+            .map(|s| s.parse::<DateTime<Utc>>().ok())
+            .unwrap_or_default()
+    }
+
+    /// 'due_date' in 'Type'
+    pub const FIELD_DUE_DATE_3_ID: ID = 55;
+    pub const FIELD_DUE_DATE_3_UUID: &'static str = "5eb2956c-6260-5fb8-b7a6-15e94d43d726";
+    pub const FIELD_DUE_DATE_3_NAME: &'static str = "due_date";
+
+    /// Returns 'due_date' -  or None if unset
+    pub fn get_due_date_3(&self) -> Option<&str> {
+        self.obj
+            .fields
+            .get("5eb2956c-6260-5fb8-b7a6-15e94d43d726_text")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+    }
+
+    /// Returns the text format for 'due_date'
+    pub fn get_due_date_3_format(&self) -> TextFormat {
+        self.obj
+            .fields
+            .get("5eb2956c-6260-5fb8-b7a6-15e94d43d726_textType")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+            // if undefined or unexpected value, return "plain"
+            .map(|f| TextFormat::from_str(f).unwrap_or_default())
+            .unwrap_or_default()
+    }
+
+    /// 'ID' in 'Type'
+    pub const FIELD_ID_2_ID: ID = 56;
+    pub const FIELD_ID_2_UUID: &'static str = "7788560d-d97e-5a71-8750-8666c9451333";
+    pub const FIELD_ID_2_NAME: &'static str = "ID";
+
+    /// Returns 'ID' -  or None if unset
+    pub fn get_id_2(&self) -> Option<&str> {
+        self.obj
+            .fields
+            .get("7788560d-d97e-5a71-8750-8666c9451333_text")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+    }
+
+    /// Returns the text format for 'ID'
+    pub fn get_id_2_format(&self) -> TextFormat {
+        self.obj
+            .fields
+            .get("7788560d-d97e-5a71-8750-8666c9451333_textType")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+            // if undefined or unexpected value, return "plain"
+            .map(|f| TextFormat::from_str(f).unwrap_or_default())
+            .unwrap_or_default()
+    }
+
+    /// '???' in 'Type'
+    pub const FIELD_FIELD_57_ID: ID = 57;
+    pub const FIELD_FIELD_57_UUID: &'static str = "5af88775-3950-5973-88db-1ceee7a2668b";
+    pub const FIELD_FIELD_57_NAME: &'static str = "???";
+
+    /// Returns '???' -  or None if unset
+    pub fn get_field_57(&self) -> Option<&str> {
+        self.obj
+            .fields
+            .get("5af88775-3950-5973-88db-1ceee7a2668b_text")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+    }
+
+    /// Returns the text format for '???'
+    pub fn get_field_57_format(&self) -> TextFormat {
+        self.obj
+            .fields
+            .get("5af88775-3950-5973-88db-1ceee7a2668b_textType")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+            // if undefined or unexpected value, return "plain"
+            .map(|f| TextFormat::from_str(f).unwrap_or_default())
+            .unwrap_or_default()
+    }
+
+    /// '🎉' in 'Type'
+    pub const FIELD_TADA_ID: ID = 58;
+    pub const FIELD_TADA_UUID: &'static str = "b03ce1b3-5707-5987-a3e6-fc86fbcfbe86";
+    pub const FIELD_TADA_NAME: &'static str = "🎉";
+
+    /// Returns true if '🎉' is checked.  
+    pub fn is_checked_tada(&self) -> bool {
+        self.obj
+            .fields
+            .get("b03ce1b3-5707-5987-a3e6-fc86fbcfbe86_checked")
+            .map(|n| n.as_bool())
+            .unwrap_or_default()
+            .unwrap_or_default()
+    }
+
+    /// '2nd Round' in 'Type'
+    pub const FIELD_2ND_ROUND_ID: ID = 59;
+    pub const FIELD_2ND_ROUND_UUID: &'static str = "e508af18-e376-531b-b9ad-05f2694f7564";
+    pub const FIELD_2ND_ROUND_NAME: &'static str = "2nd Round";
+
+    /// Returns '2nd Round' -  or None if unset
+    pub fn get_2nd_round(&self) -> Option<&str> {
+        self.obj
+            .fields
+            .get("e508af18-e376-531b-b9ad-05f2694f7564_link")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+    }
+
+    /// 'Größe' in 'Type'
+    pub const FIELD_GROSSE_ID: ID = 60;
+    pub const FIELD_GROSSE_UUID: &'static str = "bf1683b2-8710-5699-a85a-56c78aece0bd";
+    pub const FIELD_GROSSE_NAME: &'static str = "Größe";
+
+    /// Returns 'Größe' -  or None if unset
+    pub fn get_grosse(&self) -> Option<f64> {
+        self.obj
+            .fields
+            .get("bf1683b2-8710-5699-a85a-56c78aece0bd_number")
+            .map(|n| n.as_f64())
+            .unwrap_or_default()
+    }
+
+    /// 'Stage' in 'Type'
+    pub const FIELD_STAGE_ID: ID = 61;
+    pub const FIELD_STAGE_UUID: &'static str = "0c65f3d2-bbf7-599a-9b77-5ff3ea6dc8a4";
+    pub const FIELD_STAGE_NAME: &'static str = "Stage";
+
+    pub const LABEL_STAGE_2ND_ROUND_ID: ID = 601;
+
+    /// Returns true if '2nd Round' is set on 'Stage'
+    ///
+    pub fn is_stage_2nd_round(&self) -> bool {
+        self.obj
+            .fields
+            .get("0c65f3d2-bbf7-599a-9b77-5ff3ea6dc8a4_categories")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| v.iter().any(|n| n == Match::LABEL_STAGE_2ND_ROUND_ID))
+            .unwrap_or_default()
+    }
+
+    pub const LABEL_STAGE_IN_PROGRESS_ID: ID = 602;
+
+    /// Returns true if 'In progress' is set on 'Stage'
+    ///
+    pub fn is_stage_in_progress(&self) -> bool {
+        self.obj
+            .fields
+            .get("0c65f3d2-bbf7-599a-9b77-5ff3ea6dc8a4_categories")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| v.iter().any(|n| n == Match::LABEL_STAGE_IN_PROGRESS_ID))
+            .unwrap_or_default()
+    }
+
+    pub const LABEL_STAGE_IN_PROGRESS_2_ID: ID = 603;
+
+    /// Returns true if 'In-Progress' is set on 'Stage'
+    ///
+    pub fn is_stage_in_progress_2(&self) -> bool {
+        self.obj
+            .fields
+            .get("0c65f3d2-bbf7-599a-9b77-5ff3ea6dc8a4_categories")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| v.iter().any(|n| n == Match::LABEL_STAGE_IN_PROGRESS_2_ID))
+            .unwrap_or_default()
+    }
+
+    pub const LABEL_STAGE_ID_2_ID: ID = 604;
+
+    /// Returns true if 'Id' is set on 'Stage'
+    ///
+    pub fn is_stage_id_2(&self) -> bool {
+        self.obj
+            .fields
+            .get("0c65f3d2-bbf7-599a-9b77-5ff3ea6dc8a4_categories")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| v.iter().any(|n| n == Match::LABEL_STAGE_ID_2_ID))
+            .unwrap_or_default()
+    }
+
+    pub const LABEL_STAGE_LABEL_605_ID: ID = 605;
+
+    /// Returns true if '???' is set on 'Stage'
+    ///
+    pub fn is_stage_label_605(&self) -> bool {
+        self.obj
+            .fields
+            .get("0c65f3d2-bbf7-599a-9b77-5ff3ea6dc8a4_categories")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| v.iter().any(|n| n == Match::LABEL_STAGE_LABEL_605_ID))
+            .unwrap_or_default()
+    }
+
+    /// Returns the id for a label, or None if not valid for this field
+    pub fn label_id_for_stage(label: &str) -> Option<ID> {
+        lookup_label(&Match::LABELS_STAGE, label)
+    }
+
+    /// label index used for converting from label name to id (sorted)
+    const LABELS_STAGE: [(&'static str, u64); 5] = [
+        ("2nd Round", Match::LABEL_STAGE_2ND_ROUND_ID),
+        ("???", Match::LABEL_STAGE_LABEL_605_ID),
+        ("Id", Match::LABEL_STAGE_ID_2_ID),
+        ("In progress", Match::LABEL_STAGE_IN_PROGRESS_ID),
+        ("In-Progress", Match::LABEL_STAGE_IN_PROGRESS_2_ID),
+    ];
+
+    /// Returns value of 'Stage', or None if field is unset
+    /// or its label is not a variant of MatchStage
+    ///
+    pub fn get_stage(&self) -> Option<MatchStage> {
+        self.get_stage_id().and_then(MatchStage::from_id)
+    }
+
+    /// Returns label value of 'Stage', or None if field is unset
+    ///
+    pub fn get_stage_label(&self) -> Option<&str> {
+        self.obj
+            .fields
+            .get("0c65f3d2-bbf7-599a-9b77-5ff3ea6dc8a4_categories_sort")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| {
+                v.iter()
+                    .take(1)
+                    .filter_map(|val| val.as_object())
+                    .filter_map(|val| val.get("name"))
+                    .filter_map(|val| val.as_str())
+                    .next()
+                    .unwrap_or_default()
+            })
+    }
+
+    /// Returns id value of 'Stage', or None if field is unset
+    ///
+    pub fn get_stage_id(&self) -> Option<ID> {
+        self.obj
+            .fields
+            .get("0c65f3d2-bbf7-599a-9b77-5ff3ea6dc8a4_categories")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| {
+                v.iter()
+                    .take(1)
+                    .filter_map(|val| val.as_i64())
+                    .next()
+                    .map(|val| val as ID)
+            })
+            .unwrap_or_default()
+    }
+
+    /// 'Created Date' in 'Type'
+    pub const FIELD_CREATED_DATE_2_ID: ID = 62;
+    pub const FIELD_CREATED_DATE_2_UUID: &'static str = "485234c5-6ceb-59c7-b786-1fabaa305f29";
+    pub const FIELD_CREATED_DATE_2_NAME: &'static str = "Created Date";
+
+    /// Returns 'Created Date' -  or None if unset
+    pub fn get_created_date_2(&self) -> Option<&str> {
+        self.obj
+            .fields
+            .get("485234c5-6ceb-59c7-b786-1fabaa305f29_text")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+    }
+
+    /// Returns the text format for 'Created Date'
+    pub fn get_created_date_2_format(&self) -> TextFormat {
+        self.obj
+            .fields
+            .get("485234c5-6ceb-59c7-b786-1fabaa305f29_textType")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+            // if undefined or unexpected value, return "plain"
+            .map(|f| TextFormat::from_str(f).unwrap_or_default())
+            .unwrap_or_default()
+    }
+
+    /// 'Tags' in 'Type'
+    pub const FIELD_TAGS_ID: ID = 63;
+    pub const FIELD_TAGS_UUID: &'static str = "e00203da-37d5-5260-aa45-877409f8a7f7";
+    pub const FIELD_TAGS_NAME: &'static str = "Tags";
+
+    pub const LABEL_TAGS_SELF_ID: ID = 611;
+
+    /// Returns true if 'self' is set on 'Tags'
+    ///
+    pub fn is_tags_self(&self) -> bool {
+        self.obj
+            .fields
+            .get("e00203da-37d5-5260-aa45-877409f8a7f7_categories")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| v.iter().any(|n| n == Match::LABEL_TAGS_SELF_ID))
+            .unwrap_or_default()
+    }
+
+    pub const LABEL_TAGS_SELF_2_ID: ID = 612;
+
+    /// Returns true if 'Self' is set on 'Tags'
+    ///
+    pub fn is_tags_self_2(&self) -> bool {
+        self.obj
+            .fields
+            .get("e00203da-37d5-5260-aa45-877409f8a7f7_categories")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| v.iter().any(|n| n == Match::LABEL_TAGS_SELF_2_ID))
+            .unwrap_or_default()
+    }
+
+    /// Returns the id for a label, or None if not valid for this field
+    pub fn label_id_for_tags(label: &str) -> Option<ID> {
+        lookup_label(&Match::LABELS_TAGS, label)
+    }
+
+    /// label index used for converting from label name to id (sorted)
+    const LABELS_TAGS: [(&'static str, u64); 2] = [
+        ("Self", Match::LABEL_TAGS_SELF_2_ID),
+        ("self", Match::LABEL_TAGS_SELF_ID),
+    ];
+
+    /// Returns set of labels set on 'Tags'.
+    /// Labels that are not variants of MatchTags are skipped.
+    ///
+    pub fn get_tags(&self) -> std::collections::BTreeSet<MatchTags> {
+        self.get_tags_ids()
+            .into_iter()
+            .filter_map(MatchTags::from_id)
+            .collect()
+    }
+
+    /// Returns list of labels (as ids) set on 'Tags'
+    ///
+    pub fn get_tags_ids(&self) -> Vec<ID> {
+        self.obj
+            .fields
+            .get("e00203da-37d5-5260-aa45-877409f8a7f7_categories_sort")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| {
+                v.iter()
+                    .filter_map(|val| val.as_object())
+                    .filter_map(|val| val.get("id"))
+                    .filter_map(|val| val.as_u64())
+                    .collect()
+            })
+            .unwrap_or_else(Vec::new)
+    }
+    /// returns list of values set on 'Tags'
+    ///
+    pub fn get_tags_labels(&self) -> Vec<&str> {
+        self.obj
+            .fields
+            .get("e00203da-37d5-5260-aa45-877409f8a7f7_categories_sort")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| {
+                v.iter()
+                    .filter_map(|val| val.as_object())
+                    .filter_map(|val| val.get("name"))
+                    .filter_map(|val| val.as_str())
+                    .collect()
+            })
+            .unwrap_or_else(Vec::new)
+    }
+
+    /// Returns url to zenkit item
+    pub fn get_zenkit_url(&self) -> String {
+        format!(
+            "{}{}/{}/",
+            super::item_url_base(),
+            LIST_TYPE_SHORT_ID,
+            self.obj.short_id
+        )
+    }
+
+    /// Returns the item's display string (based on list's primary field)
+    pub fn get_display_string(&self) -> &str {
+        &self.obj.display_string
+    }
+
+    /// Returns user id that created the item
+    pub fn get_created_by_id(&self) -> ID {
+        self.obj.created_by
+    }
+
+    /// Returns user name that created the item
+    pub fn get_created_by_name(&self) -> Option<&str> {
+        self.obj.created_by_displayname.as_deref()
+    }
+
+    /// Returns user id that created the item
+    pub fn get_updated_by_id(&self) -> ID {
+        self.obj.updated_by
+    }
+
+    /// Returns user name that last updated the item
+    pub fn get_updated_by_name(&self) -> Option<&str> {
+        self.obj.updated_by_displayname.as_deref()
+    }
+
+    /// Returns date the item was created, in UTC
+    pub fn get_created_date(&self) -> &DateTime<Utc> {
+        &self.obj.created_at
+    }
+
+    /// Returns date the item was last updated, in UTC
+    pub fn get_updated_date(&self) -> &DateTime<Utc> {
+        &self.obj.updated_at
+    }
+
+    /// Returns date the item was deprecated, in UTC, or None if item isn't deprecated
+    pub fn get_deprecated_date(&self) -> Option<&DateTime<Utc>> {
+        self.obj.deprecated_at.as_ref()
+    }
+
+    /// Returns user id that deprecated the item, or None if item isn't deprecated
+    pub fn get_deprecated_by_id(&self) -> Option<ID> {
+        self.obj.deprecated_by
+    }
+
+    /// Returns the default sort rank within the list
+    pub fn get_sort_order(&self) -> f32 {
+        self.obj.sort_order
+    }
+
+    /// Returns the item's id within the list
+    pub fn get_id(&self) -> MatchId {
+        MatchId::new(self.obj.id)
+    }
+
+    /// Returns the item's uuid
+    pub fn get_uuid(&self) -> MatchUuid {
+        MatchUuid::new(self.obj.uuid.as_str())
+    }
+
+    /// Returns the underlying Entry object
+    pub fn get_entry(&self) -> Arc<Entry> {
+        self.obj.clone()
+    }
+
+    /// Archives the item, and returns the archived item
+    pub async fn archive(&self) -> Result<Match, Error> {
+        TypeClient::new(self.api.clone())
+            .archive(self.get_id())
+            .await
+    }
+
+    /// Restores the archived item, and returns the restored item
+    pub async fn restore(&self) -> Result<Match, Error> {
+        TypeClient::new(self.api.clone())
+            .restore(self.get_id())
+            .await
+    }
+
+    /// Deletes the item permanently
+    pub async fn delete(&self) -> Result<(), Error> {
+        TypeClient::new(self.api.clone())
+            .delete(self.get_id())
+            .await
+    }
+} // impl Match
+
+/// Builder for creating new Match
+pub struct NewMatchBuilder {
+    api: Api,
+    fields: JsonMap,
+    errs: Vec<String>,
+}
+
+impl NewMatchBuilder {
+    /// Create builder for new Match
+    fn new(api: Api) -> Self {
+        Self {
+            api,
+            fields: JsonMap::new(),
+            errs: Vec::new(),
+        }
+    }
+
+    // internal helper for setting string field
+    #[inline]
+    fn set_s(&mut self, k: &str, v: String) {
+        self.fields.insert(k.to_string(), Value::String(v));
+    }
+    // internal helper for setting json value
+    #[inline]
+    fn set_v(&mut self, k: &str, v: Value) {
+        self.fields.insert(k.to_string(), v);
+    }
+
+    /// Sets text value for Type.
+    /// Uses the field's default TextFormat. To specify format, use _with_format()
+    pub fn set_type<T: AsRef<str>>(&mut self, s: T) -> &mut NewMatchBuilder {
+        self.set_s(
+            "1f36a43e-0cab-5964-ba28-ebf271b3f8f0_text",
+            s.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sets text value for Type with format
+    ///
+    pub fn set_type_with_format<T: AsRef<str>>(
+        &mut self,
+        s: T,
+        tf: TextFormat,
+    ) -> &mut NewMatchBuilder {
+        self.set_s(
+            "1f36a43e-0cab-5964-ba28-ebf271b3f8f0_text",
+            s.as_ref().to_string(),
+        );
+        self.set_s(
+            "1f36a43e-0cab-5964-ba28-ebf271b3f8f0_textType",
+            tf.to_string(),
+        );
+        self
+    }
+
+    /// Sets Match with integer value.
+    ///
+    pub fn set_match(&mut self, i: i64) -> &mut NewMatchBuilder {
+        let jn = serde_json::Number::from(i);
+        self.set_v(
+            "a86e0820-aab2-5a23-a834-3fc59e7622ea_number",
+            Value::Number(jn),
+        );
+        self
+    }
+
+    /// Sets date Due Date.
+    pub fn set_due_date(&mut self, date: &DateTime<Utc>) -> &mut NewMatchBuilder {
+        self.set_s(
+            "5f9b7c04-85c1-538d-a688-a64e1448ba2d_date",
+            date.to_string(),
+        );
+        self
+    }
+
+    /// Sets date Due-Date.
+    pub fn set_due_date_2(&mut self, date: &DateTime<Utc>) -> &mut NewMatchBuilder {
+        self.set_s(
+            "8bc033ec-438d-5c25-ba7f-7f0488703a6f_date",
+            date.to_string(),
+        );
+        self
+    }
+
+    /// Sets text value for due_date.
+    /// Uses the field's default TextFormat. To specify format, use _with_format()
+    pub fn set_due_date_3<T: AsRef<str>>(&mut self, s: T) -> &mut NewMatchBuilder {
+        self.set_s(
+            "5eb2956c-6260-5fb8-b7a6-15e94d43d726_text",
+            s.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sets text value for due_date with format
+    ///
+    pub fn set_due_date_3_with_format<T: AsRef<str>>(
+        &mut self,
+        s: T,
+        tf: TextFormat,
+    ) -> &mut NewMatchBuilder {
+        self.set_s(
+            "5eb2956c-6260-5fb8-b7a6-15e94d43d726_text",
+            s.as_ref().to_string(),
+        );
+        self.set_s(
+            "5eb2956c-6260-5fb8-b7a6-15e94d43d726_textType",
+            tf.to_string(),
+        );
+        self
+    }
+
+    /// Sets text value for ID.
+    /// Uses the field's default TextFormat. To specify format, use _with_format()
+    pub fn set_id_2<T: AsRef<str>>(&mut self, s: T) -> &mut NewMatchBuilder {
+        self.set_s(
+            "7788560d-d97e-5a71-8750-8666c9451333_text",
+            s.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sets text value for ID with format
+    ///
+    pub fn set_id_2_with_format<T: AsRef<str>>(
+        &mut self,
+        s: T,
+        tf: TextFormat,
+    ) -> &mut NewMatchBuilder {
+        self.set_s(
+            "7788560d-d97e-5a71-8750-8666c9451333_text",
+            s.as_ref().to_string(),
+        );
+        self.set_s(
+            "7788560d-d97e-5a71-8750-8666c9451333_textType",
+            tf.to_string(),
+        );
+        self
+    }
+
+    /// Sets text value for ???.
+    /// Uses the field's default TextFormat. To specify format, use _with_format()
+    pub fn set_field_57<T: AsRef<str>>(&mut self, s: T) -> &mut NewMatchBuilder {
+        self.set_s(
+            "5af88775-3950-5973-88db-1ceee7a2668b_text",
+            s.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sets text value for ??? with format
+    ///
+    pub fn set_field_57_with_format<T: AsRef<str>>(
+        &mut self,
+        s: T,
+        tf: TextFormat,
+    ) -> &mut NewMatchBuilder {
+        self.set_s(
+            "5af88775-3950-5973-88db-1ceee7a2668b_text",
+            s.as_ref().to_string(),
+        );
+        self.set_s(
+            "5af88775-3950-5973-88db-1ceee7a2668b_textType",
+            tf.to_string(),
+        );
+        self
+    }
+
+    /// sets checkbox 🎉
+    ///
+    pub fn check_tada(&mut self, b: bool) -> &mut NewMatchBuilder {
+        self.set_v(
+            "b03ce1b3-5707-5987-a3e6-fc86fbcfbe86_checked",
+            Value::Bool(b),
+        );
+        self
+    }
+
+    /// Sets URL 2nd Round.
+    pub fn set_2nd_round<T: AsRef<str>>(&mut self, url: T) -> &mut NewMatchBuilder {
+        self.set_s(
+            "e508af18-e376-531b-b9ad-05f2694f7564_link",
+            url.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sets Größe with float value.
+    ///
+    /// Generates error if float value is Infinite or NaN.
+    pub fn set_grosse(&mut self, f: f64) -> &mut NewMatchBuilder {
+        match serde_json::Number::from_f64(f) {
+            Some(n) => self.set_v(
+                "bf1683b2-8710-5699-a85a-56c78aece0bd_number",
+                Value::Number(n),
+            ),
+            None => self
+                .errs
+                .push("Float values cannot be Infinite or NaN".to_string()),
+        }
+        self
+    }
+
+    /// Sets Stage to 2nd Round.
+    pub fn set_stage_2nd_round(&mut self) -> &mut NewMatchBuilder {
+        self.set_v(
+            "0c65f3d2-bbf7-599a-9b77-5ff3ea6dc8a4_categories",
+            json!(vec![Match::LABEL_STAGE_2ND_ROUND_ID]),
+        );
+        self
+    }
+
+    /// Sets Stage to In progress.
+    pub fn set_stage_in_progress(&mut self) -> &mut NewMatchBuilder {
+        self.set_v(
+            "0c65f3d2-bbf7-599a-9b77-5ff3ea6dc8a4_categories",
+            json!(vec![Match::LABEL_STAGE_IN_PROGRESS_ID]),
+        );
+        self
+    }
+
+    /// Sets Stage to In-Progress.
+    pub fn set_stage_in_progress_2(&mut self) -> &mut NewMatchBuilder {
+        self.set_v(
+            "0c65f3d2-bbf7-599a-9b77-5ff3ea6dc8a4_categories",
+            json!(vec![Match::LABEL_STAGE_IN_PROGRESS_2_ID]),
+        );
+        self
+    }
+
+    /// Sets Stage to Id.
+    pub fn set_stage_id_2(&mut self) -> &mut NewMatchBuilder {
+        self.set_v(
+            "0c65f3d2-bbf7-599a-9b77-5ff3ea6dc8a4_categories",
+            json!(vec![Match::LABEL_STAGE_ID_2_ID]),
+        );
+        self
+    }
+
+    /// Sets Stage to ???.
+    pub fn set_stage_label_605(&mut self) -> &mut NewMatchBuilder {
+        self.set_v(
+            "0c65f3d2-bbf7-599a-9b77-5ff3ea6dc8a4_categories",
+            json!(vec![Match::LABEL_STAGE_LABEL_605_ID]),
+        );
+        self
+    }
+
+    /// Set Stage by label-id.
+    pub fn set_stage_id(&mut self, id: ID) -> &mut NewMatchBuilder {
+        self.set_v(
+            "0c65f3d2-bbf7-599a-9b77-5ff3ea6dc8a4_categories",
+            json!(vec![id]),
+        );
+        self
+    }
+    /// Set Stage.
+    pub fn set_stage(&mut self, value: MatchStage) -> &mut NewMatchBuilder {
+        self.set_stage_id(value.id())
+    }
+    /// Set Stage by label-name.
+    pub fn set_stage_label(&mut self, label: &str) -> &mut NewMatchBuilder {
+        match lookup_label(&Match::LABELS_STAGE, label) {
+            Some(id) => {
+                self.set_v(
+                    "0c65f3d2-bbf7-599a-9b77-5ff3ea6dc8a4_categories",
+                    json!(vec![id]),
+                );
+            }
+            None => {
+                self.errs
+                    .push(format!("Label '{}' not found for set_stage_label", label));
+            }
+        }
+        self
+    }
+
+    /// Sets text value for Created Date.
+    /// Uses the field's default TextFormat. To specify format, use _with_format()
+    pub fn set_created_date_2<T: AsRef<str>>(&mut self, s: T) -> &mut NewMatchBuilder {
+        self.set_s(
+            "485234c5-6ceb-59c7-b786-1fabaa305f29_text",
+            s.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sets text value for Created Date with format
+    ///
+    pub fn set_created_date_2_with_format<T: AsRef<str>>(
+        &mut self,
+        s: T,
+        tf: TextFormat,
+    ) -> &mut NewMatchBuilder {
+        self.set_s(
+            "485234c5-6ceb-59c7-b786-1fabaa305f29_text",
+            s.as_ref().to_string(),
+        );
+        self.set_s(
+            "485234c5-6ceb-59c7-b786-1fabaa305f29_textType",
+            tf.to_string(),
+        );
+        self
+    }
+
+    /// Sets Tags with label ids.
+    ///
+    pub fn set_tags_ids(&mut self, ids: Vec<ID>) -> &mut NewMatchBuilder {
+        self.set_v(
+            "e00203da-37d5-5260-aa45-877409f8a7f7_categories",
+            json!(ids),
+        );
+        self
+    }
+    /// Sets Tags.
+    ///
+    pub fn set_tags(
+        &mut self,
+        values: &std::collections::BTreeSet<MatchTags>,
+    ) -> &mut NewMatchBuilder {
+        self.set_tags_ids(values.iter().map(MatchTags::id).collect())
+    }
+
+    /// Sends create request to server, returning new Match.
+    pub async fn execute(&mut self) -> Result<Match, Error> {
+        if !self.errs.is_empty() {
+            return Err(Error::Message(format!(
+                "Errors occurred in NewMatchBuilder: {:?}",
+                &self.errs
+            )));
+        }
+        let map = self.fields.to_owned();
+        let match_ = self.api.create_entry(501, Value::Object(map)).await?;
+        Ok(Match::new(Arc::new(match_), self.api.clone()))
+    }
+} // end impl NewMatchBuilder
+
+/// Builder for updating existing Match
+pub struct UpdateMatchBuilder {
+    api: Api,
+    fields: JsonMap,
+    errs: Vec<String>,
+    item_id: MatchId,
+}
+
+impl UpdateMatchBuilder {
+    /// Create update builder for Match with id
+    fn new(api: Api, item_id: MatchId) -> Self {
+        Self {
+            api,
+            fields: JsonMap::new(),
+            errs: Vec::new(),
+            item_id,
+        }
+    }
+
+    // internal helper for setting the updateAction of multi-value fields.
+    // Zenkit applies one action to all fields of a request, so a request can't
+    // append or remove values of one field and replace another.
+    fn set_update_action(&mut self, action: &str) {
+        if let Some(Value::String(prev)) = self.fields.get("updateAction") {
+            if prev != action {
+                self.errs.push(format!(
+                    "Conflicting update actions '{}' and '{}': fields that add or remove values \
+                                 must be updated separately from fields that are set",
+                    prev, action
+                ));
+                return;
+            }
+        }
+        self.set_s("updateAction", action.to_string());
+    }
+
+    // internal helper for setting string field
+    #[inline]
+    fn set_s(&mut self, k: &str, v: String) {
+        self.fields.insert(k.to_string(), Value::String(v));
+    }
+    // internal helper for setting json value
+    #[inline]
+    fn set_v(&mut self, k: &str, v: Value) {
+        self.fields.insert(k.to_string(), v);
+    }
+
+    /// Sets text value for Type.
+    /// TextFormat is unchanged (to specify format, use _with_format())
+    pub fn set_type<T: AsRef<str>>(&mut self, s: T) -> &mut UpdateMatchBuilder {
+        self.set_s(
+            "1f36a43e-0cab-5964-ba28-ebf271b3f8f0_text",
+            s.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sets text value for Type with format
+    ///
+    pub fn set_type_with_format<T: AsRef<str>>(
+        &mut self,
+        s: T,
+        tf: TextFormat,
+    ) -> &mut UpdateMatchBuilder {
+        self.set_s(
+            "1f36a43e-0cab-5964-ba28-ebf271b3f8f0_text",
+            s.as_ref().to_string(),
+        );
+        self.set_s(
+            "1f36a43e-0cab-5964-ba28-ebf271b3f8f0_textType",
+            tf.to_string(),
+        );
+        self
+    }
+
+    /// Sets Match with integer value.
+    ///
+    pub fn set_match(&mut self, i: i64) -> &mut UpdateMatchBuilder {
+        let jn = serde_json::Number::from(i);
+        self.set_v(
+            "a86e0820-aab2-5a23-a834-3fc59e7622ea_number",
+            Value::Number(jn),
+        );
+        self
+    }
+
+    /// Sets date Due Date.
+    pub fn set_due_date(&mut self, date: &DateTime<Utc>) -> &mut UpdateMatchBuilder {
+        self.set_s(
+            "5f9b7c04-85c1-538d-a688-a64e1448ba2d_date",
+            date.to_string(),
+        );
+        self
+    }
+
+    /// Sets date Due-Date.
+    pub fn set_due_date_2(&mut self, date: &DateTime<Utc>) -> &mut UpdateMatchBuilder {
+        self.set_s(
+            "8bc033ec-438d-5c25-ba7f-7f0488703a6f_date",
+            date.to_string(),
+        );
+        self
+    }
+
+    /// Sets text value for due_date.
+    /// TextFormat is unchanged (to specify format, use _with_format())
+    pub fn set_due_date_3<T: AsRef<str>>(&mut self, s: T) -> &mut UpdateMatchBuilder {
+        self.set_s(
+            "5eb2956c-6260-5fb8-b7a6-15e94d43d726_text",
+            s.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sets text value for due_date with format
+    ///
+    pub fn set_due_date_3_with_format<T: AsRef<str>>(
+        &mut self,
+        s: T,
+        tf: TextFormat,
+    ) -> &mut UpdateMatchBuilder {
+        self.set_s(
+            "5eb2956c-6260-5fb8-b7a6-15e94d43d726_text",
+            s.as_ref().to_string(),
+        );
+        self.set_s(
+            "5eb2956c-6260-5fb8-b7a6-15e94d43d726_textType",
+            tf.to_string(),
+        );
+        self
+    }
+
+    /// Sets text value for ID.
+    /// TextFormat is unchanged (to specify format, use _with_format())
+    pub fn set_id_2<T: AsRef<str>>(&mut self, s: T) -> &mut UpdateMatchBuilder {
+        self.set_s(
+            "7788560d-d97e-5a71-8750-8666c9451333_text",
+            s.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sets text value for ID with format
+    ///
+    pub fn set_id_2_with_format<T: AsRef<str>>(
+        &mut self,
+        s: T,
+        tf: TextFormat,
+    ) -> &mut UpdateMatchBuilder {
+        self.set_s(
+            "7788560d-d97e-5a71-8750-8666c9451333_text",
+            s.as_ref().to_string(),
+        );
+        self.set_s(
+            "7788560d-d97e-5a71-8750-8666c9451333_textType",
+            tf.to_string(),
+        );
+        self
+    }
+
+    /// Sets text value for ???.
+    /// TextFormat is unchanged (to specify format, use _with_format())
+    pub fn set_field_57<T: AsRef<str>>(&mut self, s: T) -> &mut UpdateMatchBuilder {
+        self.set_s(
+            "5af88775-3950-5973-88db-1ceee7a2668b_text",
+            s.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sets text value for ??? with format
+    ///
+    pub fn set_field_57_with_format<T: AsRef<str>>(
+        &mut self,
+        s: T,
+        tf: TextFormat,
+    ) -> &mut UpdateMatchBuilder {
+        self.set_s(
+            "5af88775-3950-5973-88db-1ceee7a2668b_text",
+            s.as_ref().to_string(),
+        );
+        self.set_s(
+            "5af88775-3950-5973-88db-1ceee7a2668b_textType",
+            tf.to_string(),
+        );
+        self
+    }
+
+    /// sets checkbox 🎉
+    ///
+    pub fn check_tada(&mut self, b: bool) -> &mut UpdateMatchBuilder {
+        self.set_v(
+            "b03ce1b3-5707-5987-a3e6-fc86fbcfbe86_checked",
+            Value::Bool(b),
+        );
+        self
+    }
+
+    /// Sets URL 2nd Round.
+    pub fn set_2nd_round<T: AsRef<str>>(&mut self, url: T) -> &mut UpdateMatchBuilder {
+        self.set_s(
+            "e508af18-e376-531b-b9ad-05f2694f7564_link",
+            url.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sets Größe with float value.
+    ///
+    /// Generates error if float value is Infinite or NaN.
+    pub fn set_grosse(&mut self, f: f64) -> &mut UpdateMatchBuilder {
+        match serde_json::Number::from_f64(f) {
+            Some(n) => self.set_v(
+                "bf1683b2-8710-5699-a85a-56c78aece0bd_number",
+                Value::Number(n),
+            ),
+            None => self
+                .errs
+                .push("Float values cannot be Infinite or NaN".to_string()),
+        }
+        self
+    }
+
+    /// Sets Stage to 2nd Round.
+    pub fn set_stage_2nd_round(&mut self) -> &mut UpdateMatchBuilder {
+        self.set_v(
+            "0c65f3d2-bbf7-599a-9b77-5ff3ea6dc8a4_categories",
+            json!(vec![Match::LABEL_STAGE_2ND_ROUND_ID]),
+        );
+        self.set_update_action("replace");
+        self
+    }
+
+    /// Sets Stage to In progress.
+    pub fn set_stage_in_progress(&mut self) -> &mut UpdateMatchBuilder {
+        self.set_v(
+            "0c65f3d2-bbf7-599a-9b77-5ff3ea6dc8a4_categories",
+            json!(vec![Match::LABEL_STAGE_IN_PROGRESS_ID]),
+        );
+        self.set_update_action("replace");
+        self
+    }
+
+    /// Sets Stage to In-Progress.
+    pub fn set_stage_in_progress_2(&mut self) -> &mut UpdateMatchBuilder {
+        self.set_v(
+            "0c65f3d2-bbf7-599a-9b77-5ff3ea6dc8a4_categories",
+            json!(vec![Match::LABEL_STAGE_IN_PROGRESS_2_ID]),
+        );
+        self.set_update_action("replace");
+        self
+    }
+
+    /// Sets Stage to Id.
+    pub fn set_stage_id_2(&mut self) -> &mut UpdateMatchBuilder {
+        self.set_v(
+            "0c65f3d2-bbf7-599a-9b77-5ff3ea6dc8a4_categories",
+            json!(vec![Match::LABEL_STAGE_ID_2_ID]),
+        );
+        self.set_update_action("replace");
+        self
+    }
+
+    /// Sets Stage to ???.
+    pub fn set_stage_label_605(&mut self) -> &mut UpdateMatchBuilder {
+        self.set_v(
+            "0c65f3d2-bbf7-599a-9b77-5ff3ea6dc8a4_categories",
+            json!(vec![Match::LABEL_STAGE_LABEL_605_ID]),
+        );
+        self.set_update_action("replace");
+        self
+    }
+
+    /// Set Stage by label-id.
+    pub fn set_stage_id(&mut self, id: ID) -> &mut UpdateMatchBuilder {
+        self.set_v(
+            "0c65f3d2-bbf7-599a-9b77-5ff3ea6dc8a4_categories",
+            json!(vec![id]),
+        );
+        self.set_update_action("replace");
+        self
+    }
+    /// Set Stage.
+    pub fn set_stage(&mut self, value: MatchStage) -> &mut UpdateMatchBuilder {
+        self.set_stage_id(value.id())
+    }
+    /// Set Stage by label-name.
+    pub fn set_stage_label(&mut self, label: &str) -> &mut UpdateMatchBuilder {
+        match lookup_label(&Match::LABELS_STAGE, label) {
+            Some(id) => {
+                self.set_v(
+                    "0c65f3d2-bbf7-599a-9b77-5ff3ea6dc8a4_categories",
+                    json!(vec![id]),
+                );
+                self.set_update_action("replace");
+            }
+            None => {
+                self.errs
+                    .push(format!("Label '{}' not found for set_stage_label", label));
+            }
+        }
+        self
+    }
+    /// Clears (unsets) Stage
+    pub fn unset_stage(&mut self) -> &mut UpdateMatchBuilder {
+        self.set_v(
+            "0c65f3d2-bbf7-599a-9b77-5ff3ea6dc8a4_categories",
+            json!(Vec::<String>::new()),
+        );
+        self.set_update_action("replace");
+        self
+    }
+
+    /// Sets text value for Created Date.
+    /// TextFormat is unchanged (to specify format, use _with_format())
+    pub fn set_created_date_2<T: AsRef<str>>(&mut self, s: T) -> &mut UpdateMatchBuilder {
+        self.set_s(
+            "485234c5-6ceb-59c7-b786-1fabaa305f29_text",
+            s.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sets text value for Created Date with format
+    ///
+    pub fn set_created_date_2_with_format<T: AsRef<str>>(
+        &mut self,
+        s: T,
+        tf: TextFormat,
+    ) -> &mut UpdateMatchBuilder {
+        self.set_s(
+            "485234c5-6ceb-59c7-b786-1fabaa305f29_text",
+            s.as_ref().to_string(),
+        );
+        self.set_s(
+            "485234c5-6ceb-59c7-b786-1fabaa305f29_textType",
+            tf.to_string(),
+        );
+        self
+    }
+
+    /// Sets Tags with label ids. Replaces any previous values
+    ///
+    pub fn set_tags_ids(&mut self, ids: Vec<ID>) -> &mut UpdateMatchBuilder {
+        self.set_v(
+            "e00203da-37d5-5260-aa45-877409f8a7f7_categories",
+            json!(ids),
+        );
+        self.set_update_action("replace");
+        self
+    }
+    /// Sets Tags. Replaces any previous values
+    ///
+    pub fn set_tags(
+        &mut self,
+        values: &std::collections::BTreeSet<MatchTags>,
+    ) -> &mut UpdateMatchBuilder {
+        self.set_tags_ids(values.iter().map(MatchTags::id).collect())
+    }
+    /// Adds label ids to Tags. Appends any previous values
+    ///
+    pub fn add_tags_ids(&mut self, ids: Vec<ID>) -> &mut UpdateMatchBuilder {
+        self.set_v(
+            "e00203da-37d5-5260-aa45-877409f8a7f7_categories",
+            json!(ids),
+        );
+        self.set_update_action("append");
+        self
+    }
+    /// Removes label ids from Tags.
+    ///
+    pub fn remove_tags_ids(&mut self, ids: Vec<ID>) -> &mut UpdateMatchBuilder {
+        self.set_v(
+            "e00203da-37d5-5260-aa45-877409f8a7f7_categories",
+            json!(ids),
+        );
+        self.set_update_action("remove");
+        self
+    }
+    /// Adds labels to Tags. Appends any previous values
+    ///
+    pub fn add_tags(
+        &mut self,
+        values: &std::collections::BTreeSet<MatchTags>,
+    ) -> &mut UpdateMatchBuilder {
+        self.add_tags_ids(values.iter().map(MatchTags::id).collect())
+    }
+    /// Removes labels from Tags.
+    ///
+    pub fn remove_tags(
+        &mut self,
+        values: &std::collections::BTreeSet<MatchTags>,
+    ) -> &mut UpdateMatchBuilder {
+        self.remove_tags_ids(values.iter().map(MatchTags::id).collect())
+    }
+    /// Changes Tags from the labels of `item` to `new`.
+    /// If labels are only added or only removed, just the difference is sent,
+    /// so that concurrent changes to other labels are kept. Otherwise the value is replaced,
+    /// keeping the item's labels that are not variants of MatchTags.
+    /// Zenkit applies one update action to the whole request, so if only the difference
+    /// is sent, setting other fields in the same update is reported as an error.
+    ///
+    pub fn change_tags(
+        &mut self,
+        item: &Match,
+        new: &std::collections::BTreeSet<MatchTags>,
+    ) -> &mut UpdateMatchBuilder {
+        let old = item.get_tags();
+        if &old == new {
+            self
+        } else if new.is_superset(&old) {
+            self.add_tags(&new.difference(&old).copied().collect())
+        } else if new.is_subset(&old) {
+            self.remove_tags(&old.difference(new).copied().collect())
+        } else {
+            let removed: Vec<ID> = old.difference(new).map(MatchTags::id).collect();
+            let mut ids = item.get_tags_ids();
+            ids.retain(|id| !removed.contains(id));
+            ids.extend(new.difference(&old).map(MatchTags::id));
+            self.set_tags_ids(ids)
+        }
+    }
+    /// Clears (unsets) Tags
+    pub fn unset_tags(&mut self) -> &mut UpdateMatchBuilder {
+        self.set_v(
+            "e00203da-37d5-5260-aa45-877409f8a7f7_categories",
+            json!(Vec::<String>::new()),
+        );
+        self.set_update_action("replace");
+        self
+    }
+
+    /// Sends update request to server, returning updated Match.
+    pub async fn execute(&mut self) -> Result<Match, Error> {
+        if !self.errs.is_empty() {
+            return Err(Error::Message(format!(
+                "Errors occurred in UpdateMatchBuilder: {:?}",
+                &self.errs
+            )));
+        }
+        let map = self.fields.to_owned();
+        let match_ = self
+            .api
+            .update_entry(501, self.item_id.value(), Value::Object(map))
+            .await?;
+        Ok(Match::new(Arc::new(match_), self.api.clone()))
+    }
+} // end impl UpdateMatchBuilder
+
+/// Query for Match in List 'Type'. Filters and sort order
+/// are sent with the request, so only matching items are downloaded.
+/// All conditions must match.
+#[derive(Clone)]
+pub struct MatchQuery {
+    api: Api,
+    q: EntryQuery,
+    errs: Vec<String>,
+}
+
+impl MatchQuery {
+    /// Creates query with no conditions
+    fn new(api: Api) -> Self {
+        Self {
+            api,
+            q: EntryQuery::default(),
+            errs: Vec::new(),
+        }
+    }
+
+    /// Returns at most n Match. The default (0) returns all matching items.
+    pub fn limit(mut self, n: usize) -> Self {
+        self.q.limit = n;
+        self
+    }
+
+    /// Skips the first n matching Match, for example, to resume
+    /// a stream after the items already read
+    pub fn skip(mut self, n: usize) -> Self {
+        self.q.skip = n;
+        self
+    }
+
+    /// Sets the number of Match requested per call. The default is 500.
+    pub fn page_size(mut self, n: usize) -> Self {
+        self.q.page_size = n;
+        self
+    }
+
+    /// Includes archived Match if true. By default, they are left out.
+    pub fn include_archived(mut self, include: bool) -> Self {
+        self.q.include_archived = include;
+        self
+    }
+
+    /// Sorts by date created, oldest first
+    pub fn order_by_created_asc(mut self) -> Self {
+        self.q.order_by("created_at", false);
+        self
+    }
+
+    /// Sorts by date created, newest first
+    pub fn order_by_created_desc(mut self) -> Self {
+        self.q.order_by("created_at", true);
+        self
+    }
+
+    /// Sorts by date last updated, oldest first
+    pub fn order_by_updated_asc(mut self) -> Self {
+        self.q.order_by("updated_at", false);
+        self
+    }
+
+    /// Sorts by date last updated, newest first
+    pub fn order_by_updated_desc(mut self) -> Self {
+        self.q.order_by("updated_at", true);
+        self
+    }
+
+    /// Returns the request parameters for all matching items. `get_items` and
+    /// `stream_items` send these in pages of up to `page_size` items.
+    pub fn request(&self) -> GetEntriesRequest {
+        self.q.request()
+    }
+
+    /// Returns the matching Match
+    pub async fn get_items(&self) -> Result<Vec<Match>, Error> {
+        self.check()?;
+        let entries = self.q.load(&self.api, LIST_TYPE_ID).await?;
+        Ok(entries
+            .into_iter()
+            .map(|e| Match::new(Arc::new(e), self.api.clone()))
+            .collect())
+    }
+
+    /// Returns stream of the matching Match. Pages are fetched as the stream
+    /// is read, so only one page is in memory at a time. The stream ends after the first
+    /// error; to resume, create the query again with `skip` set to the number of items read.
+    /// Items added or removed while reading can shift the offsets.
+    pub fn stream_items(&self) -> impl futures::Stream<Item = Result<Match, Error>> + Send {
+        use futures::StreamExt;
+        if let Err(e) = self.check() {
+            return futures::stream::once(futures::future::ready(Err(e))).left_stream();
+        }
+        let api = self.api.clone();
+        self.q
+            .stream(self.api.clone(), LIST_TYPE_ID)
+            .map(move |r| r.map(|e| Match::new(Arc::new(e), api.clone())))
+            .right_stream()
+    }
+
+    /// Returns error if a condition was invalid, e.g., an unknown label
+    fn check(&self) -> Result<(), Error> {
+        if self.errs.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Message(format!(
+                "Errors occurred in MatchQuery: {:?}",
+                &self.errs
+            )))
+        }
+    }
+
+    /// Matches Match where 'Type' is the text
+    pub fn type_is(mut self, text: &str) -> Self {
+        self.q.filter(
+            Match::FIELD_TYPE_ID,
+            json!({ "modus": "equals", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Match where 'Type' contains the text
+    pub fn type_contains(mut self, text: &str) -> Self {
+        self.q.filter(
+            Match::FIELD_TYPE_ID,
+            json!({ "modus": "contains", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Match where 'Type' starts with the text
+    pub fn type_starts_with(mut self, text: &str) -> Self {
+        self.q.filter(
+            Match::FIELD_TYPE_ID,
+            json!({ "modus": "startsWith", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Match where 'Type' is not set
+    pub fn type_is_empty(mut self) -> Self {
+        self.q
+            .filter(Match::FIELD_TYPE_ID, json!({ "modus": "isEmpty" }));
+        self
+    }
+
+    /// Matches Match where 'Type' is set
+    pub fn type_is_not_empty(mut self) -> Self {
+        self.q
+            .filter(Match::FIELD_TYPE_ID, json!({ "modus": "isNotEmpty" }));
+        self
+    }
+
+    /// Sorts by 'Type', ascending
+    pub fn order_by_type_asc(mut self) -> Self {
+        self.q
+            .order_by("1f36a43e-0cab-5964-ba28-ebf271b3f8f0_text", false);
+        self
+    }
+
+    /// Sorts by 'Type', descending
+    pub fn order_by_type_desc(mut self) -> Self {
+        self.q
+            .order_by("1f36a43e-0cab-5964-ba28-ebf271b3f8f0_text", true);
+        self
+    }
+
+    /// Matches Match where 'Match' is the value
+    pub fn match_eq(self, value: i64) -> Self {
+        self.match_between(value, value)
+    }
+
+    /// Matches Match where 'Match' is greater than the value
+    pub fn match_gt(self, value: i64) -> Self {
+        match value.checked_add(1) {
+            Some(value) => self.match_ge(value),
+            // nothing is greater than i64::MAX. An empty range matches nothing.
+            None => self.match_between(1, 0),
+        }
+    }
+
+    /// Matches Match where 'Match' is greater than or equal to the value
+    pub fn match_ge(mut self, value: i64) -> Self {
+        self.q.filter(
+            Match::FIELD_MATCH_ID,
+            json!({ "modus": "greaterOrEqual", "numberFrom": value }),
+        );
+        self
+    }
+
+    /// Matches Match where 'Match' is less than the value
+    pub fn match_lt(self, value: i64) -> Self {
+        match value.checked_sub(1) {
+            Some(value) => self.match_le(value),
+            // nothing is less than i64::MIN. An empty range matches nothing.
+            None => self.match_between(1, 0),
+        }
+    }
+
+    /// Matches Match where 'Match' is less than or equal to the value
+    pub fn match_le(mut self, value: i64) -> Self {
+        self.q.filter(
+            Match::FIELD_MATCH_ID,
+            json!({ "modus": "lessOrEqual", "numberTo": value }),
+        );
+        self
+    }
+
+    /// Matches Match where 'Match' is between the values (inclusive)
+    pub fn match_between(mut self, from: i64, to: i64) -> Self {
+        self.q.filter(
+            Match::FIELD_MATCH_ID,
+            json!({ "modus": "inRange", "numberFrom": from, "numberTo": to }),
+        );
+        self
+    }
+
+    /// Matches Match where 'Match' is not set
+    pub fn match_is_empty(mut self) -> Self {
+        self.q
+            .filter(Match::FIELD_MATCH_ID, json!({ "modus": "isEmpty" }));
+        self
+    }
+
+    /// Matches Match where 'Match' is set
+    pub fn match_is_not_empty(mut self) -> Self {
+        self.q
+            .filter(Match::FIELD_MATCH_ID, json!({ "modus": "isNotEmpty" }));
+        self
+    }
+
+    /// Sorts by 'Match', ascending
+    pub fn order_by_match_asc(mut self) -> Self {
+        self.q
+            .order_by("a86e0820-aab2-5a23-a834-3fc59e7622ea_number", false);
+        self
+    }
+
+    /// Sorts by 'Match', descending
+    pub fn order_by_match_desc(mut self) -> Self {
+        self.q
+            .order_by("a86e0820-aab2-5a23-a834-3fc59e7622ea_number", true);
+        self
+    }
+
+    /// Matches Match where 'Due Date' is at or after the time
+    pub fn due_date_since(mut self, from: DateTime<Utc>) -> Self {
+        // dateType 10: custom range
+        self.q.filter(
+            Match::FIELD_DUE_DATE_ID,
+            json!({ "modus": "inRange", "dateType": 10, "dateFrom": from }),
+        );
+        self
+    }
+
+    /// Matches Match where 'Due Date' is at or before the time
+    pub fn due_date_until(mut self, to: DateTime<Utc>) -> Self {
+        self.q.filter(
+            Match::FIELD_DUE_DATE_ID,
+            json!({ "modus": "inRange", "dateType": 10, "dateTo": to }),
+        );
+        self
+    }
+
+    /// Matches Match where 'Due Date' is in the time range (inclusive)
+    pub fn due_date_between(mut self, from: DateTime<Utc>, to: DateTime<Utc>) -> Self {
+        self.q.filter(
+            Match::FIELD_DUE_DATE_ID,
+            json!({ "modus": "inRange", "dateType": 10, "dateFrom": from, "dateTo": to }),
+        );
+        self
+    }
+
+    /// Matches Match where 'Due Date' is not set
+    pub fn due_date_is_empty(mut self) -> Self {
+        self.q
+            .filter(Match::FIELD_DUE_DATE_ID, json!({ "modus": "isEmpty" }));
+        self
+    }
+
+    /// Matches Match where 'Due Date' is set
+    pub fn due_date_is_not_empty(mut self) -> Self {
+        self.q
+            .filter(Match::FIELD_DUE_DATE_ID, json!({ "modus": "isNotEmpty" }));
+        self
+    }
+
+    /// Sorts by 'Due Date', ascending
+    pub fn order_by_due_date_asc(mut self) -> Self {
+        self.q
+            .order_by("5f9b7c04-85c1-538d-a688-a64e1448ba2d_date", false);
+        self
+    }
+
+    /// Sorts by 'Due Date', descending
+    pub fn order_by_due_date_desc(mut self) -> Self {
+        self.q
+            .order_by("5f9b7c04-85c1-538d-a688-a64e1448ba2d_date", true);
+        self
+    }
+
+    /// Matches Match where 'Due-Date' is at or after the time
+    pub fn due_date_2_since(mut self, from: DateTime<Utc>) -> Self {
+        // dateType 10: custom range
+        self.q.filter(
+            Match::FIELD_DUE_DATE_2_ID,
+            json!({ "modus": "inRange", "dateType": 10, "dateFrom": from }),
+        );
+        self
+    }
+
+    /// Matches Match where 'Due-Date' is at or before the time
+    pub fn due_date_2_until(mut self, to: DateTime<Utc>) -> Self {
+        self.q.filter(
+            Match::FIELD_DUE_DATE_2_ID,
+            json!({ "modus": "inRange", "dateType": 10, "dateTo": to }),
+        );
+        self
+    }
+
+    /// Matches Match where 'Due-Date' is in the time range (inclusive)
+    pub fn due_date_2_between(mut self, from: DateTime<Utc>, to: DateTime<Utc>) -> Self {
+        self.q.filter(
+            Match::FIELD_DUE_DATE_2_ID,
+            json!({ "modus": "inRange", "dateType": 10, "dateFrom": from, "dateTo": to }),
+        );
+        self
+    }
+
+    /// Matches Match where 'Due-Date' is not set
+    pub fn due_date_2_is_empty(mut self) -> Self {
+        self.q
+            .filter(Match::FIELD_DUE_DATE_2_ID, json!({ "modus": "isEmpty" }));
+        self
+    }
+
+    /// Matches Match where 'Due-Date' is set
+    pub fn due_date_2_is_not_empty(mut self) -> Self {
+        self.q
+            .filter(Match::FIELD_DUE_DATE_2_ID, json!({ "modus": "isNotEmpty" }));
+        self
+    }
+
+    /// Sorts by 'Due-Date', ascending
+    pub fn order_by_due_date_2_asc(mut self) -> Self {
+        self.q
+            .order_by("8bc033ec-438d-5c25-ba7f-7f0488703a6f_date", false);
+        self
+    }
+
+    /// Sorts by 'Due-Date', descending
+    pub fn order_by_due_date_2_desc(mut self) -> Self {
+        self.q
+            .order_by("8bc033ec-438d-5c25-ba7f-7f0488703a6f_date", true);
+        self
+    }
+
+    /// Matches Match where 'due_date' is the text
+    pub fn due_date_3_is(mut self, text: &str) -> Self {
+        self.q.filter(
+            Match::FIELD_DUE_DATE_3_ID,
+            json!({ "modus": "equals", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Match where 'due_date' contains the text
+    pub fn due_date_3_contains(mut self, text: &str) -> Self {
+        self.q.filter(
+            Match::FIELD_DUE_DATE_3_ID,
+            json!({ "modus": "contains", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Match where 'due_date' starts with the text
+    pub fn due_date_3_starts_with(mut self, text: &str) -> Self {
+        self.q.filter(
+            Match::FIELD_DUE_DATE_3_ID,
+            json!({ "modus": "startsWith", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Match where 'due_date' is not set
+    pub fn due_date_3_is_empty(mut self) -> Self {
+        self.q
+            .filter(Match::FIELD_DUE_DATE_3_ID, json!({ "modus": "isEmpty" }));
+        self
+    }
+
+    /// Matches Match where 'due_date' is set
+    pub fn due_date_3_is_not_empty(mut self) -> Self {
+        self.q
+            .filter(Match::FIELD_DUE_DATE_3_ID, json!({ "modus": "isNotEmpty" }));
+        self
+    }
+
+    /// Sorts by 'due_date', ascending
+    pub fn order_by_due_date_3_asc(mut self) -> Self {
+        self.q
+            .order_by("5eb2956c-6260-5fb8-b7a6-15e94d43d726_text", false);
+        self
+    }
+
+    /// Sorts by 'due_date', descending
+    pub fn order_by_due_date_3_desc(mut self) -> Self {
+        self.q
+            .order_by("5eb2956c-6260-5fb8-b7a6-15e94d43d726_text", true);
+        self
+    }
+
+    /// Matches Match where 'ID' is the text
+    pub fn id_2_is(mut self, text: &str) -> Self {
+        self.q.filter(
+            Match::FIELD_ID_2_ID,
+            json!({ "modus": "equals", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Match where 'ID' contains the text
+    pub fn id_2_contains(mut self, text: &str) -> Self {
+        self.q.filter(
+            Match::FIELD_ID_2_ID,
+            json!({ "modus": "contains", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Match where 'ID' starts with the text
+    pub fn id_2_starts_with(mut self, text: &str) -> Self {
+        self.q.filter(
+            Match::FIELD_ID_2_ID,
+            json!({ "modus": "startsWith", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Match where 'ID' is not set
+    pub fn id_2_is_empty(mut self) -> Self {
+        self.q
+            .filter(Match::FIELD_ID_2_ID, json!({ "modus": "isEmpty" }));
+        self
+    }
+
+    /// Matches Match where 'ID' is set
+    pub fn id_2_is_not_empty(mut self) -> Self {
+        self.q
+            .filter(Match::FIELD_ID_2_ID, json!({ "modus": "isNotEmpty" }));
+        self
+    }
+
+    /// Sorts by 'ID', ascending
+    pub fn order_by_id_2_asc(mut self) -> Self {
+        self.q
+            .order_by("7788560d-d97e-5a71-8750-8666c9451333_text", false);
+        self
+    }
+
+    /// Sorts by 'ID', descending
+    pub fn order_by_id_2_desc(mut self) -> Self {
+        self.q
+            .order_by("7788560d-d97e-5a71-8750-8666c9451333_text", true);
+        self
+    }
+
+    /// Matches Match where '???' is the text
+    pub fn field_57_is(mut self, text: &str) -> Self {
+        self.q.filter(
+            Match::FIELD_FIELD_57_ID,
+            json!({ "modus": "equals", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Match where '???' contains the text
+    pub fn field_57_contains(mut self, text: &str) -> Self {
+        self.q.filter(
+            Match::FIELD_FIELD_57_ID,
+            json!({ "modus": "contains", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Match where '???' starts with the text
+    pub fn field_57_starts_with(mut self, text: &str) -> Self {
+        self.q.filter(
+            Match::FIELD_FIELD_57_ID,
+            json!({ "modus": "startsWith", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Match where '???' is not set
+    pub fn field_57_is_empty(mut self) -> Self {
+        self.q
+            .filter(Match::FIELD_FIELD_57_ID, json!({ "modus": "isEmpty" }));
+        self
+    }
+
+    /// Matches Match where '???' is set
+    pub fn field_57_is_not_empty(mut self) -> Self {
+        self.q
+            .filter(Match::FIELD_FIELD_57_ID, json!({ "modus": "isNotEmpty" }));
+        self
+    }
+
+    /// Sorts by '???', ascending
+    pub fn order_by_field_57_asc(mut self) -> Self {
+        self.q
+            .order_by("5af88775-3950-5973-88db-1ceee7a2668b_text", false);
+        self
+    }
+
+    /// Sorts by '???', descending
+    pub fn order_by_field_57_desc(mut self) -> Self {
+        self.q
+            .order_by("5af88775-3950-5973-88db-1ceee7a2668b_text", true);
+        self
+    }
+
+    /// Matches Match where '🎉' is checked (true) or unchecked (false)
+    pub fn tada_is(mut self, checked: bool) -> Self {
+        self.q
+            .filter(Match::FIELD_TADA_ID, json!({ "checked": checked }));
+        self
+    }
+
+    /// Sorts by '🎉', ascending
+    pub fn order_by_tada_asc(mut self) -> Self {
+        self.q
+            .order_by("b03ce1b3-5707-5987-a3e6-fc86fbcfbe86_checked", false);
+        self
+    }
+
+    /// Sorts by '🎉', descending
+    pub fn order_by_tada_desc(mut self) -> Self {
+        self.q
+            .order_by("b03ce1b3-5707-5987-a3e6-fc86fbcfbe86_checked", true);
+        self
+    }
+
+    /// Matches Match where '2nd Round' is the text
+    pub fn _2nd_round_is(mut self, text: &str) -> Self {
+        self.q.filter(
+            Match::FIELD_2ND_ROUND_ID,
+            json!({ "modus": "equals", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Match where '2nd Round' contains the text
+    pub fn _2nd_round_contains(mut self, text: &str) -> Self {
+        self.q.filter(
+            Match::FIELD_2ND_ROUND_ID,
+            json!({ "modus": "contains", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Match where '2nd Round' starts with the text
+    pub fn _2nd_round_starts_with(mut self, text: &str) -> Self {
+        self.q.filter(
+            Match::FIELD_2ND_ROUND_ID,
+            json!({ "modus": "startsWith", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Match where '2nd Round' is not set
+    pub fn _2nd_round_is_empty(mut self) -> Self {
+        self.q
+            .filter(Match::FIELD_2ND_ROUND_ID, json!({ "modus": "isEmpty" }));
+        self
+    }
+
+    /// Matches Match where '2nd Round' is set
+    pub fn _2nd_round_is_not_empty(mut self) -> Self {
+        self.q
+            .filter(Match::FIELD_2ND_ROUND_ID, json!({ "modus": "isNotEmpty" }));
+        self
+    }
+
+    /// Sorts by '2nd Round', ascending
+    pub fn order_by_2nd_round_asc(mut self) -> Self {
+        self.q
+            .order_by("e508af18-e376-531b-b9ad-05f2694f7564_link", false);
+        self
+    }
+
+    /// Sorts by '2nd Round', descending
+    pub fn order_by_2nd_round_desc(mut self) -> Self {
+        self.q
+            .order_by("e508af18-e376-531b-b9ad-05f2694f7564_link", true);
+        self
+    }
+
+    /// Matches Match where 'Größe' is greater than the value
+    pub fn grosse_gt(self, value: f64) -> Self {
+        match super::next_f64(value, true) {
+            next if next.is_finite() => self.grosse_ge(next),
+            // nothing is greater than f64::MAX, or NaN. An empty range matches nothing.
+            _ => self.grosse_between(1.0, 0.0),
+        }
+    }
+
+    /// Matches Match where 'Größe' is greater than or equal to the value
+    pub fn grosse_ge(mut self, value: f64) -> Self {
+        self.q.filter(
+            Match::FIELD_GROSSE_ID,
+            json!({ "modus": "greaterOrEqual", "numberFrom": value }),
+        );
+        self
+    }
+
+    /// Matches Match where 'Größe' is less than the value
+    pub fn grosse_lt(self, value: f64) -> Self {
+        match super::next_f64(value, false) {
+            next if next.is_finite() => self.grosse_le(next),
+            // nothing is less than f64::MIN, or NaN. An empty range matches nothing.
+            _ => self.grosse_between(1.0, 0.0),
+        }
+    }
+
+    /// Matches Match where 'Größe' is less than or equal to the value
+    pub fn grosse_le(mut self, value: f64) -> Self {
+        self.q.filter(
+            Match::FIELD_GROSSE_ID,
+            json!({ "modus": "lessOrEqual", "numberTo": value }),
+        );
+        self
+    }
+
+    /// Matches Match where 'Größe' is between the values (inclusive)
+    pub fn grosse_between(mut self, from: f64, to: f64) -> Self {
+        self.q.filter(
+            Match::FIELD_GROSSE_ID,
+            json!({ "modus": "inRange", "numberFrom": from, "numberTo": to }),
+        );
+        self
+    }
+
+    /// Matches Match where 'Größe' is not set
+    pub fn grosse_is_empty(mut self) -> Self {
+        self.q
+            .filter(Match::FIELD_GROSSE_ID, json!({ "modus": "isEmpty" }));
+        self
+    }
+
+    /// Matches Match where 'Größe' is set
+    pub fn grosse_is_not_empty(mut self) -> Self {
+        self.q
+            .filter(Match::FIELD_GROSSE_ID, json!({ "modus": "isNotEmpty" }));
+        self
+    }
+
+    /// Sorts by 'Größe', ascending
+    pub fn order_by_grosse_asc(mut self) -> Self {
+        self.q
+            .order_by("bf1683b2-8710-5699-a85a-56c78aece0bd_number", false);
+        self
+    }
+
+    /// Sorts by 'Größe', descending
+    pub fn order_by_grosse_desc(mut self) -> Self {
+        self.q
+            .order_by("bf1683b2-8710-5699-a85a-56c78aece0bd_number", true);
+        self
+    }
+
+    /// Matches Match where 'Stage' has the label id
+    pub fn stage_is_id(mut self, id: ID) -> Self {
+        self.q
+            .filter(Match::FIELD_STAGE_ID, json!({ "filterCategories": [id] }));
+        self
+    }
+
+    /// Matches Match where 'Stage' has the label
+    pub fn stage_is(self, value: MatchStage) -> Self {
+        self.stage_is_id(value.id())
+    }
+
+    /// Matches Match where 'Stage' has the label name
+    pub fn stage_is_label(mut self, label: &str) -> Self {
+        match Match::label_id_for_stage(label) {
+            Some(id) => self.stage_is_id(id),
+            None => {
+                self.errs
+                    .push(format!("Label '{}' not found for stage", label));
+                self
+            }
+        }
+    }
+
+    /// Matches Match where 'Stage' is not set
+    pub fn stage_is_empty(mut self) -> Self {
+        self.q
+            .filter(Match::FIELD_STAGE_ID, json!({ "modus": "isEmpty" }));
+        self
+    }
+
+    /// Matches Match where 'Stage' is set
+    pub fn stage_is_not_empty(mut self) -> Self {
+        self.q
+            .filter(Match::FIELD_STAGE_ID, json!({ "modus": "isNotEmpty" }));
+        self
+    }
+
+    /// Matches Match where 'Created Date' is the text
+    pub fn created_date_2_is(mut self, text: &str) -> Self {
+        self.q.filter(
+            Match::FIELD_CREATED_DATE_2_ID,
+            json!({ "modus": "equals", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Match where 'Created Date' contains the text
+    pub fn created_date_2_contains(mut self, text: &str) -> Self {
+        self.q.filter(
+            Match::FIELD_CREATED_DATE_2_ID,
+            json!({ "modus": "contains", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Match where 'Created Date' starts with the text
+    pub fn created_date_2_starts_with(mut self, text: &str) -> Self {
+        self.q.filter(
+            Match::FIELD_CREATED_DATE_2_ID,
+            json!({ "modus": "startsWith", "text": text }),
+        );
+        self
+    }
+
+    /// Matches Match where 'Created Date' is not set
+    pub fn created_date_2_is_empty(mut self) -> Self {
+        self.q.filter(
+            Match::FIELD_CREATED_DATE_2_ID,
+            json!({ "modus": "isEmpty" }),
+        );
+        self
+    }
+
+    /// Matches Match where 'Created Date' is set
+    pub fn created_date_2_is_not_empty(mut self) -> Self {
+        self.q.filter(
+            Match::FIELD_CREATED_DATE_2_ID,
+            json!({ "modus": "isNotEmpty" }),
+        );
+        self
+    }
+
+    /// Sorts by 'Created Date', ascending
+    pub fn order_by_created_date_2_asc(mut self) -> Self {
+        self.q
+            .order_by("485234c5-6ceb-59c7-b786-1fabaa305f29_text", false);
+        self
+    }
+
+    /// Sorts by 'Created Date', descending
+    pub fn order_by_created_date_2_desc(mut self) -> Self {
+        self.q
+            .order_by("485234c5-6ceb-59c7-b786-1fabaa305f29_text", true);
+        self
+    }
+
+    /// Matches Match where 'Tags' has the label id
+    pub fn tags_is_id(mut self, id: ID) -> Self {
+        self.q
+            .filter(Match::FIELD_TAGS_ID, json!({ "filterCategories": [id] }));
+        self
+    }
+
+    /// Matches Match where 'Tags' has the label
+    pub fn tags_is(self, value: MatchTags) -> Self {
+        self.tags_is_id(value.id())
+    }
+
+    /// Matches Match where 'Tags' has the label name
+    pub fn tags_is_label(mut self, label: &str) -> Self {
+        match Match::label_id_for_tags(label) {
+            Some(id) => self.tags_is_id(id),
+            None => {
+                self.errs
+                    .push(format!("Label '{}' not found for tags", label));
+                self
+            }
+        }
+    }
+
+    /// Matches Match where 'Tags' is not set
+    pub fn tags_is_empty(mut self) -> Self {
+        self.q
+            .filter(Match::FIELD_TAGS_ID, json!({ "modus": "isEmpty" }));
+        self
+    }
+
+    /// Matches Match where 'Tags' is set
+    pub fn tags_is_not_empty(mut self) -> Self {
+        self.q
+            .filter(Match::FIELD_TAGS_ID, json!({ "modus": "isNotEmpty" }));
+        self
+    }
+} // end impl MatchQuery
+
+/// Labels of 'Stage' in List 'Type'
+///
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+pub enum MatchStage {
+    /// 2nd Round
+    _2NdRound,
+    /// In progress
+    InProgress,
+    /// In-Progress
+    InProgress2,
+    /// Id
+    Id2,
+    /// ???
+    Label605,
+}
+
+impl MatchStage {
+    /// All labels, in Zenkit order
+    pub const ALL: [MatchStage; 5] = [
+        MatchStage::_2NdRound,
+        MatchStage::InProgress,
+        MatchStage::InProgress2,
+        MatchStage::Id2,
+        MatchStage::Label605,
+    ];
+
+    /// Returns the label id
+    pub fn id(&self) -> ID {
+        match *self {
+            MatchStage::_2NdRound => Match::LABEL_STAGE_2ND_ROUND_ID,
+            MatchStage::InProgress => Match::LABEL_STAGE_IN_PROGRESS_ID,
+            MatchStage::InProgress2 => Match::LABEL_STAGE_IN_PROGRESS_2_ID,
+            MatchStage::Id2 => Match::LABEL_STAGE_ID_2_ID,
+            MatchStage::Label605 => Match::LABEL_STAGE_LABEL_605_ID,
+        }
+    }
+
+    /// Returns the label name
+    pub fn name(&self) -> &'static str {
+        match *self {
+            MatchStage::_2NdRound => "2nd Round",
+            MatchStage::InProgress => "In progress",
+            MatchStage::InProgress2 => "In-Progress",
+            MatchStage::Id2 => "Id",
+            MatchStage::Label605 => "???",
+        }
+    }
+
+    /// Returns the label with the id, or None if the id is not a label of this field
+    pub fn from_id(id: ID) -> Option<Self> {
+        Self::ALL.iter().find(|v| v.id() == id).copied()
+    }
+}
+
+impl std::fmt::Display for MatchStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for MatchStage {
+    type Err = Error;
+
+    /// Parses label name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .find(|v| v.name() == s)
+            .copied()
+            .ok_or_else(|| Error::Message(format!("'{}' is not a label of '{}'", s, "Stage")))
+    }
+}
+
+impl std::convert::TryFrom<ID> for MatchStage {
+    type Error = Error;
+
+    fn try_from(id: ID) -> Result<Self, Self::Error> {
+        Self::from_id(id)
+            .ok_or_else(|| Error::Message(format!("{} is not a label id of '{}'", id, "Stage")))
+    }
+}
+
+impl From<MatchStage> for ID {
+    fn from(value: MatchStage) -> ID {
+        value.id()
+    }
+}
+
+/// Labels of 'Tags' in List 'Type'
+///
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+pub enum MatchTags {
+    /// self
+    Self_,
+    /// Self
+    Self2,
+}
+
+impl MatchTags {
+    /// All labels, in Zenkit order
+    pub const ALL: [MatchTags; 2] = [MatchTags::Self_, MatchTags::Self2];
+
+    /// Returns the label id
+    pub fn id(&self) -> ID {
+        match *self {
+            MatchTags::Self_ => Match::LABEL_TAGS_SELF_ID,
+            MatchTags::Self2 => Match::LABEL_TAGS_SELF_2_ID,
+        }
+    }
+
+    /// Returns the label name
+    pub fn name(&self) -> &'static str {
+        match *self {
+            MatchTags::Self_ => "self",
+            MatchTags::Self2 => "Self",
+        }
+    }
+
+    /// Returns the label with the id, or None if the id is not a label of this field
+    pub fn from_id(id: ID) -> Option<Self> {
+        Self::ALL.iter().find(|v| v.id() == id).copied()
+    }
+}
+
+impl std::fmt::Display for MatchTags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for MatchTags {
+    type Err = Error;
+
+    /// Parses label name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .find(|v| v.name() == s)
+            .copied()
+            .ok_or_else(|| Error::Message(format!("'{}' is not a label of '{}'", s, "Tags")))
+    }
+}
+
+impl std::convert::TryFrom<ID> for MatchTags {
+    type Error = Error;
+
+    fn try_from(id: ID) -> Result<Self, Self::Error> {
+        Self::from_id(id)
+            .ok_or_else(|| Error::Message(format!("{} is not a label id of '{}'", id, "Tags")))
+    }
+}
+
+impl From<MatchTags> for ID {
+    fn from(value: MatchTags) -> ID {
+        value.id()
+    }
+}
+
+/// Match data, with one field per Zenkit field, that can be serialized,
+/// cached, or sent between threads. Create from an item with `From<&Match>`,
+/// and save with `to_new_builder` or `to_update_builder` (or the `*_with` versions,
+/// which take the client to save with).
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct MatchRecord {
+    /// Item id, or None if the item hasn't been created
+    pub id: Option<MatchId>,
+    /// Item uuid, or None if the item hasn't been created
+    pub uuid: Option<MatchUuid>,
+    /// Type
+    pub type_: Option<String>,
+    /// Match
+    pub match_: Option<i64>,
+    /// Due Date
+    pub due_date: Option<DateTime<Utc>>,
+    /// Due-Date
+    pub due_date_2: Option<DateTime<Utc>>,
+    /// due_date
+    pub due_date_3: Option<String>,
+    /// ID
+    pub id_2: Option<String>,
+    /// ???
+    pub field_57: Option<String>,
+    /// 🎉
+    pub tada: bool,
+    /// 2nd Round
+    pub _2nd_round: Option<String>,
+    /// Größe
+    pub grosse: Option<f64>,
+    /// Stage
+    pub stage: Option<MatchStage>,
+    /// Created Date
+    pub created_date_2: Option<String>,
+    /// Tags
+    pub tags: std::collections::BTreeSet<MatchTags>,
+}
+
+impl From<&Match> for MatchRecord {
+    fn from(item: &Match) -> Self {
+        Self {
+            id: Some(item.get_id()),
+            uuid: Some(item.get_uuid()),
+            type_: item.get_type().map(String::from),
+            match_: item.get_match(),
+            due_date: item.get_due_date(),
+            due_date_2: item.get_due_date_2(),
+            due_date_3: item.get_due_date_3().map(String::from),
+            id_2: item.get_id_2().map(String::from),
+            field_57: item.get_field_57().map(String::from),
+            tada: item.is_checked_tada(),
+            _2nd_round: item.get_2nd_round().map(String::from),
+            grosse: item.get_grosse(),
+            stage: item.get_stage(),
+            created_date_2: item.get_created_date_2().map(String::from),
+            tags: item.get_tags(),
+        }
+    }
+}
+
+impl MatchRecord {
+    /// Returns builder for creating a new Match with the record's values,
+    /// using the global api client. Fields that are None, and read-only fields, are not set.
+    pub fn to_new_builder(&self) -> NewMatchBuilder {
+        self.to_new_builder_with(&TypeClient::new(Api::global()))
+    }
+
+    /// Returns builder for creating a new Match with the record's values,
+    /// using the client. Fields that are None, and read-only fields, are not set.
+    pub fn to_new_builder_with(&self, client: &TypeClient) -> NewMatchBuilder {
+        let mut builder = client.create();
+        if let Some(ref v) = self.type_ {
+            builder.set_type(v);
+        }
+        if let Some(ref v) = self.match_ {
+            builder.set_match(*v);
+        }
+        if let Some(ref v) = self.due_date {
+            builder.set_due_date(v);
+        }
+        if let Some(ref v) = self.due_date_2 {
+            builder.set_due_date_2(v);
+        }
+        if let Some(ref v) = self.due_date_3 {
+            builder.set_due_date_3(v);
+        }
+        if let Some(ref v) = self.id_2 {
+            builder.set_id_2(v);
+        }
+        if let Some(ref v) = self.field_57 {
+            builder.set_field_57(v);
+        }
+        {
+            let v = &self.tada;
+            builder.check_tada(*v);
+        }
+        if let Some(ref v) = self._2nd_round {
+            builder.set_2nd_round(v);
+        }
+        if let Some(ref v) = self.grosse {
+            builder.set_grosse(*v);
+        }
+        if let Some(ref v) = self.stage {
+            builder.set_stage(*v);
+        }
+        if let Some(ref v) = self.created_date_2 {
+            builder.set_created_date_2(v);
+        }
+        {
+            let v = &self.tags;
+            builder.set_tags(v);
+        }
+        builder
+    }
+
+    /// Returns builder for updating the Match with the record's id to the record's values,
+    /// using the global api client. Fields that are None, and read-only fields, are not changed.
+    /// Multi-value fields are replaced. Returns an error if the record has no id.
+    pub fn to_update_builder(&self) -> Result<UpdateMatchBuilder, Error> {
+        self.to_update_builder_with(&TypeClient::new(Api::global()))
+    }
+
+    /// Returns builder for updating the Match with the record's id to the record's values,
+    /// using the client. Fields that are None, and read-only fields, are not changed.
+    /// Multi-value fields are replaced. Returns an error if the record has no id.
+    pub fn to_update_builder_with(&self, client: &TypeClient) -> Result<UpdateMatchBuilder, Error> {
+        let id = self
+            .id
+            .ok_or_else(|| Error::Message("MatchRecord has no id".to_string()))?;
+        let mut builder = client.update(id);
+        if let Some(ref v) = self.type_ {
+            builder.set_type(v);
+        }
+        if let Some(ref v) = self.match_ {
+            builder.set_match(*v);
+        }
+        if let Some(ref v) = self.due_date {
+            builder.set_due_date(v);
+        }
+        if let Some(ref v) = self.due_date_2 {
+            builder.set_due_date_2(v);
+        }
+        if let Some(ref v) = self.due_date_3 {
+            builder.set_due_date_3(v);
+        }
+        if let Some(ref v) = self.id_2 {
+            builder.set_id_2(v);
+        }
+        if let Some(ref v) = self.field_57 {
+            builder.set_field_57(v);
+        }
+        {
+            let v = &self.tada;
+            builder.check_tada(*v);
+        }
+        if let Some(ref v) = self._2nd_round {
+            builder.set_2nd_round(v);
+        }
+        if let Some(ref v) = self.grosse {
+            builder.set_grosse(*v);
+        }
+        if let Some(ref v) = self.stage {
+            builder.set_stage(*v);
+        }
+        if let Some(ref v) = self.created_date_2 {
+            builder.set_created_date_2(v);
+        }
+        {
+            let v = &self.tags;
+            builder.set_tags(v);
+        }
+        Ok(builder)
+    }
+}
+
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    fn assert_send<T: Send>(_: &T) {}
+    assert_send_sync::<Match>();
+    assert_send_sync::<NewMatchBuilder>();
+    assert_send_sync::<UpdateMatchBuilder>();
+    assert_send_sync::<MatchRecord>();
+    assert_send(&TypeList::get_items());
+    assert_send(&TypeList::create().execute());
+    assert_send(&TypeList::create_many(Vec::new(), 1));
+};
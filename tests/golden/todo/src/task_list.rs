@@ -0,0 +1,1298 @@
+#![allow(dead_code, unused_imports)]
+//! TaskListList
+//  Generated by zk-codegen v0.5.4 from schema 08a5c928a4813531
+use super::{lookup_label, run_batch, Api, EntryQuery, Error};
+use serde_json::{self, json, Value};
+use std::{convert::AsRef, str::FromStr, sync::Arc};
+use zenkit::types::{DateTime, Entry, File, GetEntriesRequest, JsonMap, TextFormat, Utc, ID};
+
+/// TaskListList
+///
+pub const LIST_TASK_LIST_ID: ID = 401;
+pub const LIST_TASK_LIST_SHORT_ID: &str = "L401";
+pub const LIST_TASK_LIST_UUID: &str = "2e1b2a5c-9391-58a5-8dc2-2d2e146c1b73";
+pub const LIST_TASK_LIST_NAME: &str = "Task List";
+
+/// ID of a TaskList (item of List 'Task List')
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(transparent)]
+pub struct TaskListId(ID);
+
+impl TaskListId {
+    /// Creates TaskListId from the item's numeric id
+    pub fn new(id: ID) -> Self {
+        Self(id)
+    }
+
+    /// Returns the numeric id
+    pub fn value(&self) -> ID {
+        self.0
+    }
+}
+
+impl std::fmt::Display for TaskListId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for TaskListId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<ID>()
+            .map(Self)
+            .map_err(|_| Error::Message(format!("invalid TaskList id '{}'", s)))
+    }
+}
+
+impl From<TaskListId> for ID {
+    fn from(id: TaskListId) -> ID {
+        id.0
+    }
+}
+
+/// UUID of a TaskList (item of List 'Task List').
+/// Reference setters accept this type or a `&TaskList`.
+#[derive(
+    Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(transparent)]
+pub struct TaskListUuid(String);
+
+impl TaskListUuid {
+    /// Creates TaskListUuid from a uuid string. The uuid is not validated;
+    /// use `parse` to check the format.
+    pub fn new<S: Into<String>>(uuid: S) -> Self {
+        Self(uuid.into())
+    }
+
+    /// Returns the uuid string
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for TaskListUuid {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for TaskListUuid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for TaskListUuid {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if super::is_uuid(s) {
+            Ok(Self(s.to_string()))
+        } else {
+            Err(Error::Message(format!("invalid TaskList uuid '{}'", s)))
+        }
+    }
+}
+
+impl From<&TaskList> for TaskListUuid {
+    fn from(item: &TaskList) -> Self {
+        item.get_uuid()
+    }
+}
+
+impl From<&TaskListUuid> for TaskListUuid {
+    fn from(uuid: &TaskListUuid) -> Self {
+        uuid.clone()
+    }
+}
+
+/// TaskListList - List of TaskLists, using the global client.
+/// For a separate connection, use `WorkspaceClient::task_list()`.
+///
+pub struct TaskListList {}
+
+impl TaskListList {
+    /// fetch TaskList by its ID
+    pub async fn get(task_list_id: TaskListId) -> Result<TaskList, Error> {
+        TaskClient::new(Api::global()).get(task_list_id).await
+    }
+
+    /// fetch TaskList by its UUID
+    pub async fn get_by_uuid(task_list_uuid: &TaskListUuid) -> Result<TaskList, Error> {
+        TaskClient::new(Api::global())
+            .get_by_uuid(task_list_uuid)
+            .await
+    }
+
+    /// Returns all TaskLists
+    pub async fn get_items() -> Result<Vec<TaskList>, Error> {
+        TaskClient::new(Api::global()).get_items().await
+    }
+
+    /// Returns stream of all TaskLists, fetched a page at a time as the stream is read.
+    /// For other page sizes, or to resume at an offset, use `query()`.
+    pub fn stream_items() -> impl futures::Stream<Item = Result<TaskList, Error>> + Send {
+        TaskClient::new(Api::global()).stream_items()
+    }
+
+    /// Creates query for TaskLists matching conditions, which are checked by the server
+    pub fn query() -> TaskListQuery {
+        TaskListQuery::new(Api::global())
+    }
+
+    /// Initialize builder for creating a new TaskList
+    pub fn create() -> NewTaskListBuilder {
+        NewTaskListBuilder::new(Api::global())
+    }
+
+    /// Creates builder for updating an existing TaskList
+    pub fn update(task_list_id: TaskListId) -> UpdateTaskListBuilder {
+        UpdateTaskListBuilder::new(Api::global(), task_list_id)
+    }
+
+    /// Archives TaskList, and returns the archived TaskList
+    pub async fn archive(task_list_id: TaskListId) -> Result<TaskList, Error> {
+        TaskClient::new(Api::global()).archive(task_list_id).await
+    }
+
+    /// Restores archived TaskList, and returns the restored TaskList
+    pub async fn restore(task_list_id: TaskListId) -> Result<TaskList, Error> {
+        TaskClient::new(Api::global()).restore(task_list_id).await
+    }
+
+    /// Deletes TaskList permanently
+    pub async fn delete(task_list_id: TaskListId) -> Result<(), Error> {
+        TaskClient::new(Api::global()).delete(task_list_id).await
+    }
+
+    /// Creates TaskLists, sending up to `concurrency` requests at a time.
+    /// Returns the result of each builder, in the order of `builders`, so that
+    /// a failure doesn't affect the other items. Each builder uses the client
+    /// it was created with. Creates are not retried (see `RetryPolicy`).
+    pub async fn create_many(
+        builders: Vec<NewTaskListBuilder>,
+        concurrency: usize,
+    ) -> Vec<Result<TaskList, Error>> {
+        run_batch(
+            builders
+                .into_iter()
+                .map(|mut b| async move { b.execute().await }),
+            concurrency,
+        )
+        .await
+    }
+
+    /// Updates TaskLists, sending up to `concurrency` requests at a time.
+    /// Returns the result of each builder, in the order of `builders`, so that
+    /// a failure doesn't affect the other items. Each builder uses the client
+    /// it was created with. Updates are not retried (see `RetryPolicy`).
+    pub async fn update_many(
+        builders: Vec<UpdateTaskListBuilder>,
+        concurrency: usize,
+    ) -> Vec<Result<TaskList, Error>> {
+        run_batch(
+            builders
+                .into_iter()
+                .map(|mut b| async move { b.execute().await }),
+            concurrency,
+        )
+        .await
+    }
+}
+
+/// Client for List 'Task List', from `WorkspaceClient::task_list()`.
+/// Items and builders from this client use the client's connection.
+#[derive(Clone)]
+pub struct TaskClient {
+    api: Api,
+}
+
+impl TaskClient {
+    pub(crate) fn new(api: Api) -> Self {
+        Self { api }
+    }
+
+    /// fetch TaskList by its ID
+    pub async fn get(&self, task_list_id: TaskListId) -> Result<TaskList, Error> {
+        let obj = self
+            .api
+            .get_entry(LIST_TASK_LIST_ID, task_list_id.value())
+            .await?;
+        Ok(TaskList::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// fetch TaskList by its UUID
+    pub async fn get_by_uuid(&self, task_list_uuid: &TaskListUuid) -> Result<TaskList, Error> {
+        let obj = self
+            .api
+            .get_entry(LIST_TASK_LIST_ID, task_list_uuid.as_str())
+            .await?;
+        Ok(TaskList::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// Returns all TaskLists
+    pub async fn get_items(&self) -> Result<Vec<TaskList>, Error> {
+        let entries = self.api.load_entries(LIST_TASK_LIST_ID).await?;
+        let items: Vec<TaskList> = entries
+            .into_iter()
+            .map(|e| TaskList::new(Arc::new(e), self.api.clone()))
+            .collect();
+        Ok(items)
+    }
+
+    /// Returns stream of all TaskLists, fetched a page at a time as the stream is read.
+    /// For other page sizes, or to resume at an offset, use `query()`.
+    pub fn stream_items(&self) -> impl futures::Stream<Item = Result<TaskList, Error>> + Send {
+        self.query().stream_items()
+    }
+
+    /// Creates query for TaskLists matching conditions, which are checked by the server
+    pub fn query(&self) -> TaskListQuery {
+        TaskListQuery::new(self.api.clone())
+    }
+
+    /// Initialize builder for creating a new TaskList
+    pub fn create(&self) -> NewTaskListBuilder {
+        NewTaskListBuilder::new(self.api.clone())
+    }
+
+    /// Creates builder for updating an existing TaskList
+    pub fn update(&self, task_list_id: TaskListId) -> UpdateTaskListBuilder {
+        UpdateTaskListBuilder::new(self.api.clone(), task_list_id)
+    }
+
+    /// Archives TaskList, and returns the archived TaskList. Archived items are
+    /// left out of `get_items` and queries, unless a query has `include_archived(true)`.
+    pub async fn archive(&self, task_list_id: TaskListId) -> Result<TaskList, Error> {
+        let obj = self
+            .api
+            .archive_entry(LIST_TASK_LIST_ID, task_list_id.value())
+            .await?;
+        Ok(TaskList::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// Restores archived TaskList, and returns the restored TaskList
+    pub async fn restore(&self, task_list_id: TaskListId) -> Result<TaskList, Error> {
+        let obj = self
+            .api
+            .restore_entry(LIST_TASK_LIST_ID, task_list_id.value())
+            .await?;
+        Ok(TaskList::new(Arc::new(obj), self.api.clone()))
+    }
+
+    /// Deletes TaskList permanently
+    pub async fn delete(&self, task_list_id: TaskListId) -> Result<(), Error> {
+        self.api
+            .delete_entry(LIST_TASK_LIST_ID, task_list_id.value())
+            .await
+    }
+}
+
+/// TaskList (item of List 'Task List')
+pub struct TaskList {
+    obj: Arc<Entry>,
+    api: Api,
+}
+
+impl TaskList {
+    /// Creates TaskList wrapping Zenkit Entry
+    fn new(obj: Arc<Entry>, api: Api) -> Self {
+        Self { obj, api }
+    }
+
+    /// 'Task' in 'Task List'
+    pub const FIELD_TASK_ID: ID = 41;
+    pub const FIELD_TASK_UUID: &'static str = "0a3681d8-46e5-5ff4-9c71-bae81891be66";
+    pub const FIELD_TASK_NAME: &'static str = "Task";
+
+    /// Returns 'Task' -  or None if unset
+    pub fn get_task(&self) -> Option<&str> {
+        self.obj
+            .fields
+            .get("0a3681d8-46e5-5ff4-9c71-bae81891be66_text")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+    }
+
+    /// Returns the text format for 'Task'
+    pub fn get_task_format(&self) -> TextFormat {
+        self.obj
+            .fields
+            .get("0a3681d8-46e5-5ff4-9c71-bae81891be66_textType")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+            // if undefined or unexpected value, return "plain"
+            .map(|f| TextFormat::from_str(f).unwrap_or_default())
+            .unwrap_or_default()
+    }
+
+    /// 'Done' in 'Task List'
+    pub const FIELD_DONE_ID: ID = 42;
+    pub const FIELD_DONE_UUID: &'static str = "7e6149db-0df5-5c6f-b40c-776ed750641f";
+    pub const FIELD_DONE_NAME: &'static str = "Done";
+
+    /// Returns true if 'Done' is checked.  
+    pub fn is_checked_done(&self) -> bool {
+        self.obj
+            .fields
+            .get("7e6149db-0df5-5c6f-b40c-776ed750641f_checked")
+            .map(|n| n.as_bool())
+            .unwrap_or_default()
+            .unwrap_or_default()
+    }
+
+    /// 'Due' in 'Task List'
+    pub const FIELD_DUE_ID: ID = 43;
+    pub const FIELD_DUE_UUID: &'static str = "0676cdc0-9929-5f70-9f95-a6bb3d884c31";
+    pub const FIELD_DUE_NAME: &'static str = "Due";
+
+    /// Returns 'Due' in UTC , or None if unset
+    pub fn get_due(&self) -> Option<DateTime<Utc>> {
+        self.obj
+            .fields
+            .get("0676cdc0-9929-5f70-9f95-a6bb3d884c31_date")
+            .map(|v| v.as_str())
+            .unwrap_or_default()
+            .map(|s| s.parse::<DateTime<Utc>>().ok())
+            .unwrap_or_default()
+    }
+
+    /// 'Priority' in 'Task List'
+    pub const FIELD_PRIORITY_ID: ID = 44;
+    pub const FIELD_PRIORITY_UUID: &'static str = "f7ca829f-854f-5d01-9cc2-8834391b4c2b";
+    pub const FIELD_PRIORITY_NAME: &'static str = "Priority";
+
+    pub const LABEL_PRIORITY_HIGH_ID: ID = 451;
+
+    /// Returns true if 'High' is set on 'Priority'
+    ///
+    pub fn is_priority_high(&self) -> bool {
+        self.obj
+            .fields
+            .get("f7ca829f-854f-5d01-9cc2-8834391b4c2b_categories")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| v.iter().any(|n| n == TaskList::LABEL_PRIORITY_HIGH_ID))
+            .unwrap_or_default()
+    }
+
+    pub const LABEL_PRIORITY_LOW_ID: ID = 452;
+
+    /// Returns true if 'Low' is set on 'Priority'
+    ///
+    pub fn is_priority_low(&self) -> bool {
+        self.obj
+            .fields
+            .get("f7ca829f-854f-5d01-9cc2-8834391b4c2b_categories")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| v.iter().any(|n| n == TaskList::LABEL_PRIORITY_LOW_ID))
+            .unwrap_or_default()
+    }
+
+    /// Returns the id for a label, or None if not valid for this field
+    pub fn label_id_for_priority(label: &str) -> Option<ID> {
+        lookup_label(&TaskList::LABELS_PRIORITY, label)
+    }
+
+    /// label index used for converting from label name to id (sorted)
+    const LABELS_PRIORITY: [(&'static str, u64); 2] = [
+        ("High", TaskList::LABEL_PRIORITY_HIGH_ID),
+        ("Low", TaskList::LABEL_PRIORITY_LOW_ID),
+    ];
+
+    /// Returns label value of 'Priority', or None if field is unset
+    ///
+    pub fn get_priority(&self) -> Option<&str> {
+        self.obj
+            .fields
+            .get("f7ca829f-854f-5d01-9cc2-8834391b4c2b_categories_sort")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| {
+                v.iter()
+                    .take(1)
+                    .filter_map(|val| val.as_object())
+                    .filter_map(|val| val.get("name"))
+                    .filter_map(|val| val.as_str())
+                    .next()
+                    .unwrap_or_default()
+            })
+    }
+
+    /// Returns id value of 'Priority', or None if field is unset
+    ///
+    pub fn get_priority_id(&self) -> Option<ID> {
+        self.obj
+            .fields
+            .get("f7ca829f-854f-5d01-9cc2-8834391b4c2b_categories")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| {
+                v.iter()
+                    .take(1)
+                    .filter_map(|val| val.as_i64())
+                    .next()
+                    .map(|val| val as ID)
+            })
+            .unwrap_or_default()
+    }
+
+    /// 'Project' in 'Task List' Project in the planning workspace (not generated)
+    pub const FIELD_PROJECT_ID: ID = 45;
+    pub const FIELD_PROJECT_UUID: &'static str = "1c76bc12-ba9b-55a1-b523-6929ad793e3d";
+    pub const FIELD_PROJECT_NAME: &'static str = "Project";
+
+    /// Returns the reference in Project (to Projects, or None if unset
+    /// Project in the planning workspace (not generated)
+    pub fn get_project_uuid(&self) -> Option<&str> {
+        self.obj
+            .fields
+            .get("1c76bc12-ba9b-55a1-b523-6929ad793e3d_references_sort")
+            .map(|v| v.as_array())
+            .unwrap_or_default()
+            .map(|v| {
+                v.iter()
+                    .take(1)
+                    .filter_map(|val| val.as_object())
+                    .filter_map(|val| val.get("uuid"))
+                    .filter_map(|val| val.as_str())
+                    .next()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns url to zenkit item
+    pub fn get_zenkit_url(&self) -> String {
+        format!(
+            "{}{}/{}/",
+            super::item_url_base(),
+            LIST_TASK_LIST_SHORT_ID,
+            self.obj.short_id
+        )
+    }
+
+    /// Returns the item's display string (based on list's primary field)
+    pub fn get_display_string(&self) -> &str {
+        &self.obj.display_string
+    }
+
+    /// Returns user id that created the item
+    pub fn get_created_by_id(&self) -> ID {
+        self.obj.created_by
+    }
+
+    /// Returns user name that created the item
+    pub fn get_created_by_name(&self) -> Option<&str> {
+        self.obj.created_by_displayname.as_deref()
+    }
+
+    /// Returns user id that created the item
+    pub fn get_updated_by_id(&self) -> ID {
+        self.obj.updated_by
+    }
+
+    /// Returns user name that last updated the item
+    pub fn get_updated_by_name(&self) -> Option<&str> {
+        self.obj.updated_by_displayname.as_deref()
+    }
+
+    /// Returns date the item was created, in UTC
+    pub fn get_created_date(&self) -> &DateTime<Utc> {
+        &self.obj.created_at
+    }
+
+    /// Returns date the item was last updated, in UTC
+    pub fn get_updated_date(&self) -> &DateTime<Utc> {
+        &self.obj.updated_at
+    }
+
+    /// Returns date the item was deprecated, in UTC, or None if item isn't deprecated
+    pub fn get_deprecated_date(&self) -> Option<&DateTime<Utc>> {
+        self.obj.deprecated_at.as_ref()
+    }
+
+    /// Returns user id that deprecated the item, or None if item isn't deprecated
+    pub fn get_deprecated_by_id(&self) -> Option<ID> {
+        self.obj.deprecated_by
+    }
+
+    /// Returns the default sort rank within the list
+    pub fn get_sort_order(&self) -> f32 {
+        self.obj.sort_order
+    }
+
+    /// Returns the item's id within the list
+    pub fn get_id(&self) -> TaskListId {
+        TaskListId::new(self.obj.id)
+    }
+
+    /// Returns the item's uuid
+    pub fn get_uuid(&self) -> TaskListUuid {
+        TaskListUuid::new(self.obj.uuid.as_str())
+    }
+
+    /// Returns the underlying Entry object
+    pub fn get_entry(&self) -> Arc<Entry> {
+        self.obj.clone()
+    }
+
+    /// Archives the item, and returns the archived item
+    pub async fn archive(&self) -> Result<TaskList, Error> {
+        TaskClient::new(self.api.clone())
+            .archive(self.get_id())
+            .await
+    }
+
+    /// Restores the archived item, and returns the restored item
+    pub async fn restore(&self) -> Result<TaskList, Error> {
+        TaskClient::new(self.api.clone())
+            .restore(self.get_id())
+            .await
+    }
+
+    /// Deletes the item permanently
+    pub async fn delete(&self) -> Result<(), Error> {
+        TaskClient::new(self.api.clone())
+            .delete(self.get_id())
+            .await
+    }
+} // impl TaskList
+
+/// Builder for creating new TaskList
+pub struct NewTaskListBuilder {
+    api: Api,
+    fields: JsonMap,
+    errs: Vec<String>,
+}
+
+impl NewTaskListBuilder {
+    /// Create builder for new TaskList
+    fn new(api: Api) -> Self {
+        Self {
+            api,
+            fields: JsonMap::new(),
+            errs: Vec::new(),
+        }
+    }
+
+    // internal helper for setting string field
+    #[inline]
+    fn set_s(&mut self, k: &str, v: String) {
+        self.fields.insert(k.to_string(), Value::String(v));
+    }
+    // internal helper for setting json value
+    #[inline]
+    fn set_v(&mut self, k: &str, v: Value) {
+        self.fields.insert(k.to_string(), v);
+    }
+
+    /// Sets text value for Task.
+    /// Uses the field's default TextFormat. To specify format, use _with_format()
+    pub fn set_task<T: AsRef<str>>(&mut self, s: T) -> &mut NewTaskListBuilder {
+        self.set_s(
+            "0a3681d8-46e5-5ff4-9c71-bae81891be66_text",
+            s.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sets text value for Task with format
+    ///
+    pub fn set_task_with_format<T: AsRef<str>>(
+        &mut self,
+        s: T,
+        tf: TextFormat,
+    ) -> &mut NewTaskListBuilder {
+        self.set_s(
+            "0a3681d8-46e5-5ff4-9c71-bae81891be66_text",
+            s.as_ref().to_string(),
+        );
+        self.set_s(
+            "0a3681d8-46e5-5ff4-9c71-bae81891be66_textType",
+            tf.to_string(),
+        );
+        self
+    }
+
+    /// sets checkbox Done
+    ///
+    pub fn check_done(&mut self, b: bool) -> &mut NewTaskListBuilder {
+        self.set_v(
+            "7e6149db-0df5-5c6f-b40c-776ed750641f_checked",
+            Value::Bool(b),
+        );
+        self
+    }
+
+    /// Sets date Due.
+    pub fn set_due(&mut self, date: &DateTime<Utc>) -> &mut NewTaskListBuilder {
+        self.set_s(
+            "0676cdc0-9929-5f70-9f95-a6bb3d884c31_date",
+            date.to_string(),
+        );
+        self
+    }
+
+    /// Sets Priority to High.
+    pub fn set_priority_high(&mut self) -> &mut NewTaskListBuilder {
+        self.set_v(
+            "f7ca829f-854f-5d01-9cc2-8834391b4c2b_categories",
+            json!(vec![TaskList::LABEL_PRIORITY_HIGH_ID]),
+        );
+        self
+    }
+
+    /// Sets Priority to Low.
+    pub fn set_priority_low(&mut self) -> &mut NewTaskListBuilder {
+        self.set_v(
+            "f7ca829f-854f-5d01-9cc2-8834391b4c2b_categories",
+            json!(vec![TaskList::LABEL_PRIORITY_LOW_ID]),
+        );
+        self
+    }
+
+    /// Set Priority by label-id.
+    pub fn set_priority_id(&mut self, id: ID) -> &mut NewTaskListBuilder {
+        self.set_v(
+            "f7ca829f-854f-5d01-9cc2-8834391b4c2b_categories",
+            json!(vec![id]),
+        );
+        self
+    }
+    /// Set Priority by label-name.
+    pub fn set_priority(&mut self, label: &str) -> &mut NewTaskListBuilder {
+        match lookup_label(&TaskList::LABELS_PRIORITY, label) {
+            Some(id) => {
+                self.set_v(
+                    "f7ca829f-854f-5d01-9cc2-8834391b4c2b_categories",
+                    json!(vec![id]),
+                );
+            }
+            None => {
+                self.errs.push(format!(
+                    "Label '{}' not found for set_priority_label",
+                    label
+                ));
+            }
+        }
+        self
+    }
+
+    /// Sets reference Project to item in list Projects
+    /// Project in the planning workspace (not generated)
+    pub fn set_project(&mut self, uuid: &str) -> &mut NewTaskListBuilder {
+        self.set_v(
+            "1c76bc12-ba9b-55a1-b523-6929ad793e3d_references",
+            json!(vec![uuid]),
+        );
+        self
+    }
+
+    /// Sends create request to server, returning new TaskList.
+    pub async fn execute(&mut self) -> Result<TaskList, Error> {
+        if !self.errs.is_empty() {
+            return Err(Error::Message(format!(
+                "Errors occurred in NewTaskListBuilder: {:?}",
+                &self.errs
+            )));
+        }
+        let map = self.fields.to_owned();
+        let task_list = self.api.create_entry(401, Value::Object(map)).await?;
+        Ok(TaskList::new(Arc::new(task_list), self.api.clone()))
+    }
+} // end impl NewTaskListBuilder
+
+/// Builder for updating existing TaskList
+pub struct UpdateTaskListBuilder {
+    api: Api,
+    fields: JsonMap,
+    errs: Vec<String>,
+    item_id: TaskListId,
+}
+
+impl UpdateTaskListBuilder {
+    /// Create update builder for TaskList with id
+    fn new(api: Api, item_id: TaskListId) -> Self {
+        Self {
+            api,
+            fields: JsonMap::new(),
+            errs: Vec::new(),
+            item_id,
+        }
+    }
+
+    // internal helper for setting the updateAction of multi-value fields.
+    // Zenkit applies one action to all fields of a request, so a request can't
+    // append or remove values of one field and replace another.
+    fn set_update_action(&mut self, action: &str) {
+        if let Some(Value::String(prev)) = self.fields.get("updateAction") {
+            if prev != action {
+                self.errs.push(format!(
+                    "Conflicting update actions '{}' and '{}': fields that add or remove values \
+                                 must be updated separately from fields that are set",
+                    prev, action
+                ));
+                return;
+            }
+        }
+        self.set_s("updateAction", action.to_string());
+    }
+
+    // internal helper for setting string field
+    #[inline]
+    fn set_s(&mut self, k: &str, v: String) {
+        self.fields.insert(k.to_string(), Value::String(v));
+    }
+    // internal helper for setting json value
+    #[inline]
+    fn set_v(&mut self, k: &str, v: Value) {
+        self.fields.insert(k.to_string(), v);
+    }
+
+    /// Sets text value for Task.
+    /// TextFormat is unchanged (to specify format, use _with_format())
+    pub fn set_task<T: AsRef<str>>(&mut self, s: T) -> &mut UpdateTaskListBuilder {
+        self.set_s(
+            "0a3681d8-46e5-5ff4-9c71-bae81891be66_text",
+            s.as_ref().to_string(),
+        );
+        self
+    }
+
+    /// Sets text value for Task with format
+    ///
+    pub fn set_task_with_format<T: AsRef<str>>(
+        &mut self,
+        s: T,
+        tf: TextFormat,
+    ) -> &mut UpdateTaskListBuilder {
+        self.set_s(
+            "0a3681d8-46e5-5ff4-9c71-bae81891be66_text",
+            s.as_ref().to_string(),
+        );
+        self.set_s(
+            "0a3681d8-46e5-5ff4-9c71-bae81891be66_textType",
+            tf.to_string(),
+        );
+        self
+    }
+
+    /// sets checkbox Done
+    ///
+    pub fn check_done(&mut self, b: bool) -> &mut UpdateTaskListBuilder {
+        self.set_v(
+            "7e6149db-0df5-5c6f-b40c-776ed750641f_checked",
+            Value::Bool(b),
+        );
+        self
+    }
+
+    /// Sets date Due.
+    pub fn set_due(&mut self, date: &DateTime<Utc>) -> &mut UpdateTaskListBuilder {
+        self.set_s(
+            "0676cdc0-9929-5f70-9f95-a6bb3d884c31_date",
+            date.to_string(),
+        );
+        self
+    }
+
+    /// Sets Priority to High.
+    pub fn set_priority_high(&mut self) -> &mut UpdateTaskListBuilder {
+        self.set_v(
+            "f7ca829f-854f-5d01-9cc2-8834391b4c2b_categories",
+            json!(vec![TaskList::LABEL_PRIORITY_HIGH_ID]),
+        );
+        self.set_update_action("replace");
+        self
+    }
+
+    /// Sets Priority to Low.
+    pub fn set_priority_low(&mut self) -> &mut UpdateTaskListBuilder {
+        self.set_v(
+            "f7ca829f-854f-5d01-9cc2-8834391b4c2b_categories",
+            json!(vec![TaskList::LABEL_PRIORITY_LOW_ID]),
+        );
+        self.set_update_action("replace");
+        self
+    }
+
+    /// Set Priority by label-id.
+    pub fn set_priority_id(&mut self, id: ID) -> &mut UpdateTaskListBuilder {
+        self.set_v(
+            "f7ca829f-854f-5d01-9cc2-8834391b4c2b_categories",
+            json!(vec![id]),
+        );
+        self.set_update_action("replace");
+        self
+    }
+    /// Set Priority by label-name.
+    pub fn set_priority(&mut self, label: &str) -> &mut UpdateTaskListBuilder {
+        match lookup_label(&TaskList::LABELS_PRIORITY, label) {
+            Some(id) => {
+                self.set_v(
+                    "f7ca829f-854f-5d01-9cc2-8834391b4c2b_categories",
+                    json!(vec![id]),
+                );
+                self.set_update_action("replace");
+            }
+            None => {
+                self.errs.push(format!(
+                    "Label '{}' not found for set_priority_label",
+                    label
+                ));
+            }
+        }
+        self
+    }
+    /// Clears (unsets) Priority
+    pub fn unset_priority(&mut self) -> &mut UpdateTaskListBuilder {
+        self.set_v(
+            "f7ca829f-854f-5d01-9cc2-8834391b4c2b_categories",
+            json!(Vec::<String>::new()),
+        );
+        self.set_update_action("replace");
+        self
+    }
+
+    /// Sets reference Project to item in list Projects
+    /// Project in the planning workspace (not generated)
+    pub fn set_project(&mut self, uuid: &str) -> &mut UpdateTaskListBuilder {
+        self.set_v(
+            "1c76bc12-ba9b-55a1-b523-6929ad793e3d_references",
+            json!(vec![uuid]),
+        );
+        self.set_update_action("replace");
+        self
+    }
+    /// Removes references to Project
+    /// Project in the planning workspace (not generated)
+    pub fn unset_project(&mut self) -> &mut UpdateTaskListBuilder {
+        self.set_v(
+            "1c76bc12-ba9b-55a1-b523-6929ad793e3d_references",
+            json!(Vec::<String>::new()),
+        );
+        self.set_update_action("replace");
+        self
+    }
+
+    /// Sends update request to server, returning updated TaskList.
+    pub async fn execute(&mut self) -> Result<TaskList, Error> {
+        if !self.errs.is_empty() {
+            return Err(Error::Message(format!(
+                "Errors occurred in UpdateTaskListBuilder: {:?}",
+                &self.errs
+            )));
+        }
+        let map = self.fields.to_owned();
+        let task_list = self
+            .api
+            .update_entry(401, self.item_id.value(), Value::Object(map))
+            .await?;
+        Ok(TaskList::new(Arc::new(task_list), self.api.clone()))
+    }
+} // end impl UpdateTaskListBuilder
+
+/// Query for TaskLists in List 'Task List'. Filters and sort order
+/// are sent with the request, so only matching items are downloaded.
+/// All conditions must match.
+#[derive(Clone)]
+pub struct TaskListQuery {
+    api: Api,
+    q: EntryQuery,
+    errs: Vec<String>,
+}
+
+impl TaskListQuery {
+    /// Creates query with no conditions
+    fn new(api: Api) -> Self {
+        Self {
+            api,
+            q: EntryQuery::default(),
+            errs: Vec::new(),
+        }
+    }
+
+    /// Returns at most n TaskLists. The default (0) returns all matching items.
+    pub fn limit(mut self, n: usize) -> Self {
+        self.q.limit = n;
+        self
+    }
+
+    /// Skips the first n matching TaskLists, for example, to resume
+    /// a stream after the items already read
+    pub fn skip(mut self, n: usize) -> Self {
+        self.q.skip = n;
+        self
+    }
+
+    /// Sets the number of TaskLists requested per call. The default is 500.
+    pub fn page_size(mut self, n: usize) -> Self {
+        self.q.page_size = n;
+        self
+    }
+
+    /// Includes archived TaskLists if true. By default, they are left out.
+    pub fn include_archived(mut self, include: bool) -> Self {
+        self.q.include_archived = include;
+        self
+    }
+
+    /// Sorts by date created, oldest first
+    pub fn order_by_created_asc(mut self) -> Self {
+        self.q.order_by("created_at", false);
+        self
+    }
+
+    /// Sorts by date created, newest first
+    pub fn order_by_created_desc(mut self) -> Self {
+        self.q.order_by("created_at", true);
+        self
+    }
+
+    /// Sorts by date last updated, oldest first
+    pub fn order_by_updated_asc(mut self) -> Self {
+        self.q.order_by("updated_at", false);
+        self
+    }
+
+    /// Sorts by date last updated, newest first
+    pub fn order_by_updated_desc(mut self) -> Self {
+        self.q.order_by("updated_at", true);
+        self
+    }
+
+    /// Returns the request parameters for all matching items. `get_items` and
+    /// `stream_items` send these in pages of up to `page_size` items.
+    pub fn request(&self) -> GetEntriesRequest {
+        self.q.request()
+    }
+
+    /// Returns the matching TaskLists
+    pub async fn get_items(&self) -> Result<Vec<TaskList>, Error> {
+        self.check()?;
+        let entries = self.q.load(&self.api, LIST_TASK_LIST_ID).await?;
+        Ok(entries
+            .into_iter()
+            .map(|e| TaskList::new(Arc::new(e), self.api.clone()))
+            .collect())
+    }
+
+    /// Returns stream of the matching TaskLists. Pages are fetched as the stream
+    /// is read, so only one page is in memory at a time. The stream ends after the first
+    /// error; to resume, create the query again with `skip` set to the number of items read.
+    /// Items added or removed while reading can shift the offsets.
+    pub fn stream_items(&self) -> impl futures::Stream<Item = Result<TaskList, Error>> + Send {
+        use futures::StreamExt;
+        if let Err(e) = self.check() {
+            return futures::stream::once(futures::future::ready(Err(e))).left_stream();
+        }
+        let api = self.api.clone();
+        self.q
+            .stream(self.api.clone(), LIST_TASK_LIST_ID)
+            .map(move |r| r.map(|e| TaskList::new(Arc::new(e), api.clone())))
+            .right_stream()
+    }
+
+    /// Returns error if a condition was invalid, e.g., an unknown label
+    fn check(&self) -> Result<(), Error> {
+        if self.errs.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Message(format!(
+                "Errors occurred in TaskListQuery: {:?}",
+                &self.errs
+            )))
+        }
+    }
+
+    /// Matches TaskLists where 'Task' is the text
+    pub fn task_is(mut self, text: &str) -> Self {
+        self.q.filter(
+            TaskList::FIELD_TASK_ID,
+            json!({ "modus": "equals", "text": text }),
+        );
+        self
+    }
+
+    /// Matches TaskLists where 'Task' contains the text
+    pub fn task_contains(mut self, text: &str) -> Self {
+        self.q.filter(
+            TaskList::FIELD_TASK_ID,
+            json!({ "modus": "contains", "text": text }),
+        );
+        self
+    }
+
+    /// Matches TaskLists where 'Task' starts with the text
+    pub fn task_starts_with(mut self, text: &str) -> Self {
+        self.q.filter(
+            TaskList::FIELD_TASK_ID,
+            json!({ "modus": "startsWith", "text": text }),
+        );
+        self
+    }
+
+    /// Matches TaskLists where 'Task' is not set
+    pub fn task_is_empty(mut self) -> Self {
+        self.q
+            .filter(TaskList::FIELD_TASK_ID, json!({ "modus": "isEmpty" }));
+        self
+    }
+
+    /// Matches TaskLists where 'Task' is set
+    pub fn task_is_not_empty(mut self) -> Self {
+        self.q
+            .filter(TaskList::FIELD_TASK_ID, json!({ "modus": "isNotEmpty" }));
+        self
+    }
+
+    /// Sorts by 'Task', ascending
+    pub fn order_by_task_asc(mut self) -> Self {
+        self.q
+            .order_by("0a3681d8-46e5-5ff4-9c71-bae81891be66_text", false);
+        self
+    }
+
+    /// Sorts by 'Task', descending
+    pub fn order_by_task_desc(mut self) -> Self {
+        self.q
+            .order_by("0a3681d8-46e5-5ff4-9c71-bae81891be66_text", true);
+        self
+    }
+
+    /// Matches TaskLists where 'Done' is checked (true) or unchecked (false)
+    pub fn done_is(mut self, checked: bool) -> Self {
+        self.q
+            .filter(TaskList::FIELD_DONE_ID, json!({ "checked": checked }));
+        self
+    }
+
+    /// Sorts by 'Done', ascending
+    pub fn order_by_done_asc(mut self) -> Self {
+        self.q
+            .order_by("7e6149db-0df5-5c6f-b40c-776ed750641f_checked", false);
+        self
+    }
+
+    /// Sorts by 'Done', descending
+    pub fn order_by_done_desc(mut self) -> Self {
+        self.q
+            .order_by("7e6149db-0df5-5c6f-b40c-776ed750641f_checked", true);
+        self
+    }
+
+    /// Matches TaskLists where 'Due' is at or after the time
+    pub fn due_since(mut self, from: DateTime<Utc>) -> Self {
+        // dateType 10: custom range
+        self.q.filter(
+            TaskList::FIELD_DUE_ID,
+            json!({ "modus": "inRange", "dateType": 10, "dateFrom": from }),
+        );
+        self
+    }
+
+    /// Matches TaskLists where 'Due' is at or before the time
+    pub fn due_until(mut self, to: DateTime<Utc>) -> Self {
+        self.q.filter(
+            TaskList::FIELD_DUE_ID,
+            json!({ "modus": "inRange", "dateType": 10, "dateTo": to }),
+        );
+        self
+    }
+
+    /// Matches TaskLists where 'Due' is in the time range (inclusive)
+    pub fn due_between(mut self, from: DateTime<Utc>, to: DateTime<Utc>) -> Self {
+        self.q.filter(
+            TaskList::FIELD_DUE_ID,
+            json!({ "modus": "inRange", "dateType": 10, "dateFrom": from, "dateTo": to }),
+        );
+        self
+    }
+
+    /// Matches TaskLists where 'Due' is not set
+    pub fn due_is_empty(mut self) -> Self {
+        self.q
+            .filter(TaskList::FIELD_DUE_ID, json!({ "modus": "isEmpty" }));
+        self
+    }
+
+    /// Matches TaskLists where 'Due' is set
+    pub fn due_is_not_empty(mut self) -> Self {
+        self.q
+            .filter(TaskList::FIELD_DUE_ID, json!({ "modus": "isNotEmpty" }));
+        self
+    }
+
+    /// Sorts by 'Due', ascending
+    pub fn order_by_due_asc(mut self) -> Self {
+        self.q
+            .order_by("0676cdc0-9929-5f70-9f95-a6bb3d884c31_date", false);
+        self
+    }
+
+    /// Sorts by 'Due', descending
+    pub fn order_by_due_desc(mut self) -> Self {
+        self.q
+            .order_by("0676cdc0-9929-5f70-9f95-a6bb3d884c31_date", true);
+        self
+    }
+
+    /// Matches TaskLists where 'Priority' has the label id
+    pub fn priority_is_id(mut self, id: ID) -> Self {
+        self.q.filter(
+            TaskList::FIELD_PRIORITY_ID,
+            json!({ "filterCategories": [id] }),
+        );
+        self
+    }
+
+    /// Matches TaskLists where 'Priority' has the label name
+    pub fn priority_is(mut self, label: &str) -> Self {
+        match TaskList::label_id_for_priority(label) {
+            Some(id) => self.priority_is_id(id),
+            None => {
+                self.errs
+                    .push(format!("Label '{}' not found for priority", label));
+                self
+            }
+        }
+    }
+
+    /// Matches TaskLists where 'Priority' is not set
+    pub fn priority_is_empty(mut self) -> Self {
+        self.q
+            .filter(TaskList::FIELD_PRIORITY_ID, json!({ "modus": "isEmpty" }));
+        self
+    }
+
+    /// Matches TaskLists where 'Priority' is set
+    pub fn priority_is_not_empty(mut self) -> Self {
+        self.q.filter(
+            TaskList::FIELD_PRIORITY_ID,
+            json!({ "modus": "isNotEmpty" }),
+        );
+        self
+    }
+} // end impl TaskListQuery
+
+/// TaskList data, with one field per Zenkit field, that can be serialized,
+/// cached, or sent between threads. Create from an item with `From<&TaskList>`,
+/// and save with `to_new_builder` or `to_update_builder` (or the `*_with` versions,
+/// which take the client to save with).
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct TaskListRecord {
+    /// Item id, or None if the item hasn't been created
+    pub id: Option<TaskListId>,
+    /// Item uuid, or None if the item hasn't been created
+    pub uuid: Option<TaskListUuid>,
+    /// Task
+    pub task: Option<String>,
+    /// Done
+    pub done: bool,
+    /// Due
+    pub due: Option<DateTime<Utc>>,
+    /// Priority
+    pub priority: Option<ID>,
+    /// Project
+    pub project: Option<String>,
+}
+
+impl From<&TaskList> for TaskListRecord {
+    fn from(item: &TaskList) -> Self {
+        Self {
+            id: Some(item.get_id()),
+            uuid: Some(item.get_uuid()),
+            task: item.get_task().map(String::from),
+            done: item.is_checked_done(),
+            due: item.get_due(),
+            priority: item.get_priority_id(),
+            project: item.get_project_uuid().map(String::from),
+        }
+    }
+}
+
+impl TaskListRecord {
+    /// Returns builder for creating a new TaskList with the record's values,
+    /// using the global api client. Fields that are None, and read-only fields, are not set.
+    pub fn to_new_builder(&self) -> NewTaskListBuilder {
+        self.to_new_builder_with(&TaskClient::new(Api::global()))
+    }
+
+    /// Returns builder for creating a new TaskList with the record's values,
+    /// using the client. Fields that are None, and read-only fields, are not set.
+    pub fn to_new_builder_with(&self, client: &TaskClient) -> NewTaskListBuilder {
+        let mut builder = client.create();
+        if let Some(ref v) = self.task {
+            builder.set_task(v);
+        }
+        {
+            let v = &self.done;
+            builder.check_done(*v);
+        }
+        if let Some(ref v) = self.due {
+            builder.set_due(v);
+        }
+        if let Some(ref v) = self.priority {
+            builder.set_priority_id(*v);
+        }
+        if let Some(ref v) = self.project {
+            builder.set_project(v);
+        }
+        builder
+    }
+
+    /// Returns builder for updating the TaskList with the record's id to the record's values,
+    /// using the global api client. Fields that are None, and read-only fields, are not changed.
+    /// Multi-value fields are replaced. Returns an error if the record has no id.
+    pub fn to_update_builder(&self) -> Result<UpdateTaskListBuilder, Error> {
+        self.to_update_builder_with(&TaskClient::new(Api::global()))
+    }
+
+    /// Returns builder for updating the TaskList with the record's id to the record's values,
+    /// using the client. Fields that are None, and read-only fields, are not changed.
+    /// Multi-value fields are replaced. Returns an error if the record has no id.
+    pub fn to_update_builder_with(
+        &self,
+        client: &TaskClient,
+    ) -> Result<UpdateTaskListBuilder, Error> {
+        let id = self
+            .id
+            .ok_or_else(|| Error::Message("TaskListRecord has no id".to_string()))?;
+        let mut builder = client.update(id);
+        if let Some(ref v) = self.task {
+            builder.set_task(v);
+        }
+        {
+            let v = &self.done;
+            builder.check_done(*v);
+        }
+        if let Some(ref v) = self.due {
+            builder.set_due(v);
+        }
+        if let Some(ref v) = self.priority {
+            builder.set_priority_id(*v);
+        }
+        if let Some(ref v) = self.project {
+            builder.set_project(v);
+        }
+        Ok(builder)
+    }
+}
+
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    fn assert_send<T: Send>(_: &T) {}
+    assert_send_sync::<TaskList>();
+    assert_send_sync::<NewTaskListBuilder>();
+    assert_send_sync::<UpdateTaskListBuilder>();
+    assert_send_sync::<TaskListRecord>();
+    assert_send(&TaskListList::get_items());
+    assert_send(&TaskListList::create().execute());
+    assert_send(&TaskListList::create_many(Vec::new(), 1));
+};
@@ -0,0 +1,2715 @@
+// Zenkit Workspace Todo
+//
+//  Generated by zk-codegen v0.5.4 from schema 08a5c928a4813531
+
+use std::{
+    fmt,
+    future::Future,
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+// use and re-export
+pub use zenkit::{
+    get_api, init_api,
+    types::{AllId, DateTime, Entry, GetEntriesRequest, JsonMap, TextFormat, Utc, ID},
+    ApiClient, ApiConfig,
+};
+
+mod task_list {
+    #![allow(dead_code, unused_imports)]
+    //! TaskListList
+    //  Generated by zk-codegen v0.5.4 from schema 08a5c928a4813531
+    use super::{lookup_label, run_batch, Api, EntryQuery, Error};
+    use serde_json::{self, json, Value};
+    use std::{convert::AsRef, str::FromStr, sync::Arc};
+    use zenkit::types::{DateTime, Entry, File, GetEntriesRequest, JsonMap, TextFormat, Utc, ID};
+
+    /// TaskListList
+    ///
+    pub const LIST_TASK_LIST_ID: ID = 401;
+    pub const LIST_TASK_LIST_SHORT_ID: &str = "L401";
+    pub const LIST_TASK_LIST_UUID: &str = "2e1b2a5c-9391-58a5-8dc2-2d2e146c1b73";
+    pub const LIST_TASK_LIST_NAME: &str = "Task List";
+
+    /// ID of a TaskList (item of List 'Task List')
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        serde::Serialize,
+        serde::Deserialize,
+    )]
+    #[serde(transparent)]
+    pub struct TaskListId(ID);
+
+    impl TaskListId {
+        /// Creates TaskListId from the item's numeric id
+        pub fn new(id: ID) -> Self {
+            Self(id)
+        }
+
+        /// Returns the numeric id
+        pub fn value(&self) -> ID {
+            self.0
+        }
+    }
+
+    impl std::fmt::Display for TaskListId {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", self.0)
+        }
+    }
+
+    impl FromStr for TaskListId {
+        type Err = Error;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            s.parse::<ID>()
+                .map(Self)
+                .map_err(|_| Error::Message(format!("invalid TaskList id '{}'", s)))
+        }
+    }
+
+    impl From<TaskListId> for ID {
+        fn from(id: TaskListId) -> ID {
+            id.0
+        }
+    }
+
+    /// UUID of a TaskList (item of List 'Task List').
+    /// Reference setters accept this type or a `&TaskList`.
+    #[derive(
+        Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+    )]
+    #[serde(transparent)]
+    pub struct TaskListUuid(String);
+
+    impl TaskListUuid {
+        /// Creates TaskListUuid from a uuid string. The uuid is not validated;
+        /// use `parse` to check the format.
+        pub fn new<S: Into<String>>(uuid: S) -> Self {
+            Self(uuid.into())
+        }
+
+        /// Returns the uuid string
+        pub fn as_str(&self) -> &str {
+            &self.0
+        }
+    }
+
+    impl AsRef<str> for TaskListUuid {
+        fn as_ref(&self) -> &str {
+            &self.0
+        }
+    }
+
+    impl std::fmt::Display for TaskListUuid {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(&self.0)
+        }
+    }
+
+    impl FromStr for TaskListUuid {
+        type Err = Error;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            if super::is_uuid(s) {
+                Ok(Self(s.to_string()))
+            } else {
+                Err(Error::Message(format!("invalid TaskList uuid '{}'", s)))
+            }
+        }
+    }
+
+    impl From<&TaskList> for TaskListUuid {
+        fn from(item: &TaskList) -> Self {
+            item.get_uuid()
+        }
+    }
+
+    impl From<&TaskListUuid> for TaskListUuid {
+        fn from(uuid: &TaskListUuid) -> Self {
+            uuid.clone()
+        }
+    }
+
+    /// TaskListList - List of TaskLists, using the global client.
+    /// For a separate connection, use `WorkspaceClient::task_list()`.
+    ///
+    pub struct TaskListList {}
+
+    impl TaskListList {
+        /// fetch TaskList by its ID
+        pub async fn get(task_list_id: TaskListId) -> Result<TaskList, Error> {
+            TaskClient::new(Api::global()).get(task_list_id).await
+        }
+
+        /// fetch TaskList by its UUID
+        pub async fn get_by_uuid(task_list_uuid: &TaskListUuid) -> Result<TaskList, Error> {
+            TaskClient::new(Api::global())
+                .get_by_uuid(task_list_uuid)
+                .await
+        }
+
+        /// Returns all TaskLists
+        pub async fn get_items() -> Result<Vec<TaskList>, Error> {
+            TaskClient::new(Api::global()).get_items().await
+        }
+
+        /// Returns stream of all TaskLists, fetched a page at a time as the stream is read.
+        /// For other page sizes, or to resume at an offset, use `query()`.
+        pub fn stream_items() -> impl futures::Stream<Item = Result<TaskList, Error>> + Send {
+            TaskClient::new(Api::global()).stream_items()
+        }
+
+        /// Creates query for TaskLists matching conditions, which are checked by the server
+        pub fn query() -> TaskListQuery {
+            TaskListQuery::new(Api::global())
+        }
+
+        /// Initialize builder for creating a new TaskList
+        pub fn create() -> NewTaskListBuilder {
+            NewTaskListBuilder::new(Api::global())
+        }
+
+        /// Creates builder for updating an existing TaskList
+        pub fn update(task_list_id: TaskListId) -> UpdateTaskListBuilder {
+            UpdateTaskListBuilder::new(Api::global(), task_list_id)
+        }
+
+        /// Archives TaskList, and returns the archived TaskList
+        pub async fn archive(task_list_id: TaskListId) -> Result<TaskList, Error> {
+            TaskClient::new(Api::global()).archive(task_list_id).await
+        }
+
+        /// Restores archived TaskList, and returns the restored TaskList
+        pub async fn restore(task_list_id: TaskListId) -> Result<TaskList, Error> {
+            TaskClient::new(Api::global()).restore(task_list_id).await
+        }
+
+        /// Deletes TaskList permanently
+        pub async fn delete(task_list_id: TaskListId) -> Result<(), Error> {
+            TaskClient::new(Api::global()).delete(task_list_id).await
+        }
+
+        /// Creates TaskLists, sending up to `concurrency` requests at a time.
+        /// Returns the result of each builder, in the order of `builders`, so that
+        /// a failure doesn't affect the other items. Each builder uses the client
+        /// it was created with. Creates are not retried (see `RetryPolicy`).
+        pub async fn create_many(
+            builders: Vec<NewTaskListBuilder>,
+            concurrency: usize,
+        ) -> Vec<Result<TaskList, Error>> {
+            run_batch(
+                builders
+                    .into_iter()
+                    .map(|mut b| async move { b.execute().await }),
+                concurrency,
+            )
+            .await
+        }
+
+        /// Updates TaskLists, sending up to `concurrency` requests at a time.
+        /// Returns the result of each builder, in the order of `builders`, so that
+        /// a failure doesn't affect the other items. Each builder uses the client
+        /// it was created with. Updates are not retried (see `RetryPolicy`).
+        pub async fn update_many(
+            builders: Vec<UpdateTaskListBuilder>,
+            concurrency: usize,
+        ) -> Vec<Result<TaskList, Error>> {
+            run_batch(
+                builders
+                    .into_iter()
+                    .map(|mut b| async move { b.execute().await }),
+                concurrency,
+            )
+            .await
+        }
+    }
+
+    /// Client for List 'Task List', from `WorkspaceClient::task_list()`.
+    /// Items and builders from this client use the client's connection.
+    #[derive(Clone)]
+    pub struct TaskClient {
+        api: Api,
+    }
+
+    impl TaskClient {
+        pub(crate) fn new(api: Api) -> Self {
+            Self { api }
+        }
+
+        /// fetch TaskList by its ID
+        pub async fn get(&self, task_list_id: TaskListId) -> Result<TaskList, Error> {
+            let obj = self
+                .api
+                .get_entry(LIST_TASK_LIST_ID, task_list_id.value())
+                .await?;
+            Ok(TaskList::new(Arc::new(obj), self.api.clone()))
+        }
+
+        /// fetch TaskList by its UUID
+        pub async fn get_by_uuid(&self, task_list_uuid: &TaskListUuid) -> Result<TaskList, Error> {
+            let obj = self
+                .api
+                .get_entry(LIST_TASK_LIST_ID, task_list_uuid.as_str())
+                .await?;
+            Ok(TaskList::new(Arc::new(obj), self.api.clone()))
+        }
+
+        /// Returns all TaskLists
+        pub async fn get_items(&self) -> Result<Vec<TaskList>, Error> {
+            let entries = self.api.load_entries(LIST_TASK_LIST_ID).await?;
+            let items: Vec<TaskList> = entries
+                .into_iter()
+                .map(|e| TaskList::new(Arc::new(e), self.api.clone()))
+                .collect();
+            Ok(items)
+        }
+
+        /// Returns stream of all TaskLists, fetched a page at a time as the stream is read.
+        /// For other page sizes, or to resume at an offset, use `query()`.
+        pub fn stream_items(&self) -> impl futures::Stream<Item = Result<TaskList, Error>> + Send {
+            self.query().stream_items()
+        }
+
+        /// Creates query for TaskLists matching conditions, which are checked by the server
+        pub fn query(&self) -> TaskListQuery {
+            TaskListQuery::new(self.api.clone())
+        }
+
+        /// Initialize builder for creating a new TaskList
+        pub fn create(&self) -> NewTaskListBuilder {
+            NewTaskListBuilder::new(self.api.clone())
+        }
+
+        /// Creates builder for updating an existing TaskList
+        pub fn update(&self, task_list_id: TaskListId) -> UpdateTaskListBuilder {
+            UpdateTaskListBuilder::new(self.api.clone(), task_list_id)
+        }
+
+        /// Archives TaskList, and returns the archived TaskList. Archived items are
+        /// left out of `get_items` and queries, unless a query has `include_archived(true)`.
+        pub async fn archive(&self, task_list_id: TaskListId) -> Result<TaskList, Error> {
+            let obj = self
+                .api
+                .archive_entry(LIST_TASK_LIST_ID, task_list_id.value())
+                .await?;
+            Ok(TaskList::new(Arc::new(obj), self.api.clone()))
+        }
+
+        /// Restores archived TaskList, and returns the restored TaskList
+        pub async fn restore(&self, task_list_id: TaskListId) -> Result<TaskList, Error> {
+            let obj = self
+                .api
+                .restore_entry(LIST_TASK_LIST_ID, task_list_id.value())
+                .await?;
+            Ok(TaskList::new(Arc::new(obj), self.api.clone()))
+        }
+
+        /// Deletes TaskList permanently
+        pub async fn delete(&self, task_list_id: TaskListId) -> Result<(), Error> {
+            self.api
+                .delete_entry(LIST_TASK_LIST_ID, task_list_id.value())
+                .await
+        }
+    }
+
+    /// TaskList (item of List 'Task List')
+    pub struct TaskList {
+        obj: Arc<Entry>,
+        api: Api,
+    }
+
+    impl TaskList {
+        /// Creates TaskList wrapping Zenkit Entry
+        fn new(obj: Arc<Entry>, api: Api) -> Self {
+            Self { obj, api }
+        }
+
+        /// 'Task' in 'Task List'
+        pub const FIELD_TASK_ID: ID = 41;
+        pub const FIELD_TASK_UUID: &'static str = "0a3681d8-46e5-5ff4-9c71-bae81891be66";
+        pub const FIELD_TASK_NAME: &'static str = "Task";
+
+        /// Returns 'Task' -  or None if unset
+        pub fn get_task(&self) -> Option<&str> {
+            self.obj
+                .fields
+                .get("0a3681d8-46e5-5ff4-9c71-bae81891be66_text")
+                .map(|v| v.as_str())
+                .unwrap_or_default()
+        }
+
+        /// Returns the text format for 'Task'
+        pub fn get_task_format(&self) -> TextFormat {
+            self.obj
+                .fields
+                .get("0a3681d8-46e5-5ff4-9c71-bae81891be66_textType")
+                .map(|v| v.as_str())
+                .unwrap_or_default()
+                // if undefined or unexpected value, return "plain"
+                .map(|f| TextFormat::from_str(f).unwrap_or_default())
+                .unwrap_or_default()
+        }
+
+        /// 'Done' in 'Task List'
+        pub const FIELD_DONE_ID: ID = 42;
+        pub const FIELD_DONE_UUID: &'static str = "7e6149db-0df5-5c6f-b40c-776ed750641f";
+        pub const FIELD_DONE_NAME: &'static str = "Done";
+
+        /// Returns true if 'Done' is checked.  
+        pub fn is_checked_done(&self) -> bool {
+            self.obj
+                .fields
+                .get("7e6149db-0df5-5c6f-b40c-776ed750641f_checked")
+                .map(|n| n.as_bool())
+                .unwrap_or_default()
+                .unwrap_or_default()
+        }
+
+        /// 'Due' in 'Task List'
+        pub const FIELD_DUE_ID: ID = 43;
+        pub const FIELD_DUE_UUID: &'static str = "0676cdc0-9929-5f70-9f95-a6bb3d884c31";
+        pub const FIELD_DUE_NAME: &'static str = "Due";
+
+        /// Returns 'Due' in UTC , or None if unset
+        pub fn get_due(&self) -> Option<DateTime<Utc>> {
+            self.obj
+                .fields
+                .get("0676cdc0-9929-5f70-9f95-a6bb3d884c31_date")
+                .map(|v| v.as_str())
+                .unwrap_or_default()
+                .map(|s| s.parse::<DateTime<Utc>>().ok())
+                .unwrap_or_default()
+        }
+
+        /// 'Priority' in 'Task List'
+        pub const FIELD_PRIORITY_ID: ID = 44;
+        pub const FIELD_PRIORITY_UUID: &'static str = "f7ca829f-854f-5d01-9cc2-8834391b4c2b";
+        pub const FIELD_PRIORITY_NAME: &'static str = "Priority";
+
+        pub const LABEL_PRIORITY_HIGH_ID: ID = 451;
+
+        /// Returns true if 'High' is set on 'Priority'
+        ///
+        pub fn is_priority_high(&self) -> bool {
+            self.obj
+                .fields
+                .get("f7ca829f-854f-5d01-9cc2-8834391b4c2b_categories")
+                .map(|v| v.as_array())
+                .unwrap_or_default()
+                .map(|v| v.iter().any(|n| n == TaskList::LABEL_PRIORITY_HIGH_ID))
+                .unwrap_or_default()
+        }
+
+        pub const LABEL_PRIORITY_LOW_ID: ID = 452;
+
+        /// Returns true if 'Low' is set on 'Priority'
+        ///
+        pub fn is_priority_low(&self) -> bool {
+            self.obj
+                .fields
+                .get("f7ca829f-854f-5d01-9cc2-8834391b4c2b_categories")
+                .map(|v| v.as_array())
+                .unwrap_or_default()
+                .map(|v| v.iter().any(|n| n == TaskList::LABEL_PRIORITY_LOW_ID))
+                .unwrap_or_default()
+        }
+
+        /// Returns the id for a label, or None if not valid for this field
+        pub fn label_id_for_priority(label: &str) -> Option<ID> {
+            lookup_label(&TaskList::LABELS_PRIORITY, label)
+        }
+
+        /// label index used for converting from label name to id (sorted)
+        const LABELS_PRIORITY: [(&'static str, u64); 2] = [
+            ("High", TaskList::LABEL_PRIORITY_HIGH_ID),
+            ("Low", TaskList::LABEL_PRIORITY_LOW_ID),
+        ];
+
+        /// Returns label value of 'Priority', or None if field is unset
+        ///
+        pub fn get_priority(&self) -> Option<&str> {
+            self.obj
+                .fields
+                .get("f7ca829f-854f-5d01-9cc2-8834391b4c2b_categories_sort")
+                .map(|v| v.as_array())
+                .unwrap_or_default()
+                .map(|v| {
+                    v.iter()
+                        .take(1)
+                        .filter_map(|val| val.as_object())
+                        .filter_map(|val| val.get("name"))
+                        .filter_map(|val| val.as_str())
+                        .next()
+                        .unwrap_or_default()
+                })
+        }
+
+        /// Returns id value of 'Priority', or None if field is unset
+        ///
+        pub fn get_priority_id(&self) -> Option<ID> {
+            self.obj
+                .fields
+                .get("f7ca829f-854f-5d01-9cc2-8834391b4c2b_categories")
+                .map(|v| v.as_array())
+                .unwrap_or_default()
+                .map(|v| {
+                    v.iter()
+                        .take(1)
+                        .filter_map(|val| val.as_i64())
+                        .next()
+                        .map(|val| val as ID)
+                })
+                .unwrap_or_default()
+        }
+
+        /// 'Project' in 'Task List' Project in the planning workspace (not generated)
+        pub const FIELD_PROJECT_ID: ID = 45;
+        pub const FIELD_PROJECT_UUID: &'static str = "1c76bc12-ba9b-55a1-b523-6929ad793e3d";
+        pub const FIELD_PROJECT_NAME: &'static str = "Project";
+
+        /// Returns the reference in Project (to Projects, or None if unset
+        /// Project in the planning workspace (not generated)
+        pub fn get_project_uuid(&self) -> Option<&str> {
+            self.obj
+                .fields
+                .get("1c76bc12-ba9b-55a1-b523-6929ad793e3d_references_sort")
+                .map(|v| v.as_array())
+                .unwrap_or_default()
+                .map(|v| {
+                    v.iter()
+                        .take(1)
+                        .filter_map(|val| val.as_object())
+                        .filter_map(|val| val.get("uuid"))
+                        .filter_map(|val| val.as_str())
+                        .next()
+                })
+                .unwrap_or_default()
+        }
+
+        /// Returns url to zenkit item
+        pub fn get_zenkit_url(&self) -> String {
+            format!(
+                "{}{}/{}/",
+                super::item_url_base(),
+                LIST_TASK_LIST_SHORT_ID,
+                self.obj.short_id
+            )
+        }
+
+        /// Returns the item's display string (based on list's primary field)
+        pub fn get_display_string(&self) -> &str {
+            &self.obj.display_string
+        }
+
+        /// Returns user id that created the item
+        pub fn get_created_by_id(&self) -> ID {
+            self.obj.created_by
+        }
+
+        /// Returns user name that created the item
+        pub fn get_created_by_name(&self) -> Option<&str> {
+            self.obj.created_by_displayname.as_deref()
+        }
+
+        /// Returns user id that created the item
+        pub fn get_updated_by_id(&self) -> ID {
+            self.obj.updated_by
+        }
+
+        /// Returns user name that last updated the item
+        pub fn get_updated_by_name(&self) -> Option<&str> {
+            self.obj.updated_by_displayname.as_deref()
+        }
+
+        /// Returns date the item was created, in UTC
+        pub fn get_created_date(&self) -> &DateTime<Utc> {
+            &self.obj.created_at
+        }
+
+        /// Returns date the item was last updated, in UTC
+        pub fn get_updated_date(&self) -> &DateTime<Utc> {
+            &self.obj.updated_at
+        }
+
+        /// Returns date the item was deprecated, in UTC, or None if item isn't deprecated
+        pub fn get_deprecated_date(&self) -> Option<&DateTime<Utc>> {
+            self.obj.deprecated_at.as_ref()
+        }
+
+        /// Returns user id that deprecated the item, or None if item isn't deprecated
+        pub fn get_deprecated_by_id(&self) -> Option<ID> {
+            self.obj.deprecated_by
+        }
+
+        /// Returns the default sort rank within the list
+        pub fn get_sort_order(&self) -> f32 {
+            self.obj.sort_order
+        }
+
+        /// Returns the item's id within the list
+        pub fn get_id(&self) -> TaskListId {
+            TaskListId::new(self.obj.id)
+        }
+
+        /// Returns the item's uuid
+        pub fn get_uuid(&self) -> TaskListUuid {
+            TaskListUuid::new(self.obj.uuid.as_str())
+        }
+
+        /// Returns the underlying Entry object
+        pub fn get_entry(&self) -> Arc<Entry> {
+            self.obj.clone()
+        }
+
+        /// Archives the item, and returns the archived item
+        pub async fn archive(&self) -> Result<TaskList, Error> {
+            TaskClient::new(self.api.clone())
+                .archive(self.get_id())
+                .await
+        }
+
+        /// Restores the archived item, and returns the restored item
+        pub async fn restore(&self) -> Result<TaskList, Error> {
+            TaskClient::new(self.api.clone())
+                .restore(self.get_id())
+                .await
+        }
+
+        /// Deletes the item permanently
+        pub async fn delete(&self) -> Result<(), Error> {
+            TaskClient::new(self.api.clone())
+                .delete(self.get_id())
+                .await
+        }
+    } // impl TaskList
+
+    /// Builder for creating new TaskList
+    pub struct NewTaskListBuilder {
+        api: Api,
+        fields: JsonMap,
+        errs: Vec<String>,
+    }
+
+    impl NewTaskListBuilder {
+        /// Create builder for new TaskList
+        fn new(api: Api) -> Self {
+            Self {
+                api,
+                fields: JsonMap::new(),
+                errs: Vec::new(),
+            }
+        }
+
+        // internal helper for setting string field
+        #[inline]
+        fn set_s(&mut self, k: &str, v: String) {
+            self.fields.insert(k.to_string(), Value::String(v));
+        }
+        // internal helper for setting json value
+        #[inline]
+        fn set_v(&mut self, k: &str, v: Value) {
+            self.fields.insert(k.to_string(), v);
+        }
+
+        /// Sets text value for Task.
+        /// Uses the field's default TextFormat. To specify format, use _with_format()
+        pub fn set_task<T: AsRef<str>>(&mut self, s: T) -> &mut NewTaskListBuilder {
+            self.set_s(
+                "0a3681d8-46e5-5ff4-9c71-bae81891be66_text",
+                s.as_ref().to_string(),
+            );
+            self
+        }
+
+        /// Sets text value for Task with format
+        ///
+        pub fn set_task_with_format<T: AsRef<str>>(
+            &mut self,
+            s: T,
+            tf: TextFormat,
+        ) -> &mut NewTaskListBuilder {
+            self.set_s(
+                "0a3681d8-46e5-5ff4-9c71-bae81891be66_text",
+                s.as_ref().to_string(),
+            );
+            self.set_s(
+                "0a3681d8-46e5-5ff4-9c71-bae81891be66_textType",
+                tf.to_string(),
+            );
+            self
+        }
+
+        /// sets checkbox Done
+        ///
+        pub fn check_done(&mut self, b: bool) -> &mut NewTaskListBuilder {
+            self.set_v(
+                "7e6149db-0df5-5c6f-b40c-776ed750641f_checked",
+                Value::Bool(b),
+            );
+            self
+        }
+
+        /// Sets date Due.
+        pub fn set_due(&mut self, date: &DateTime<Utc>) -> &mut NewTaskListBuilder {
+            self.set_s(
+                "0676cdc0-9929-5f70-9f95-a6bb3d884c31_date",
+                date.to_string(),
+            );
+            self
+        }
+
+        /// Sets Priority to High.
+        pub fn set_priority_high(&mut self) -> &mut NewTaskListBuilder {
+            self.set_v(
+                "f7ca829f-854f-5d01-9cc2-8834391b4c2b_categories",
+                json!(vec![TaskList::LABEL_PRIORITY_HIGH_ID]),
+            );
+            self
+        }
+
+        /// Sets Priority to Low.
+        pub fn set_priority_low(&mut self) -> &mut NewTaskListBuilder {
+            self.set_v(
+                "f7ca829f-854f-5d01-9cc2-8834391b4c2b_categories",
+                json!(vec![TaskList::LABEL_PRIORITY_LOW_ID]),
+            );
+            self
+        }
+
+        /// Set Priority by label-id.
+        pub fn set_priority_id(&mut self, id: ID) -> &mut NewTaskListBuilder {
+            self.set_v(
+                "f7ca829f-854f-5d01-9cc2-8834391b4c2b_categories",
+                json!(vec![id]),
+            );
+            self
+        }
+        /// Set Priority by label-name.
+        pub fn set_priority(&mut self, label: &str) -> &mut NewTaskListBuilder {
+            match lookup_label(&TaskList::LABELS_PRIORITY, label) {
+                Some(id) => {
+                    self.set_v(
+                        "f7ca829f-854f-5d01-9cc2-8834391b4c2b_categories",
+                        json!(vec![id]),
+                    );
+                }
+                None => {
+                    self.errs.push(format!(
+                        "Label '{}' not found for set_priority_label",
+                        label
+                    ));
+                }
+            }
+            self
+        }
+
+        /// Sets reference Project to item in list Projects
+        /// Project in the planning workspace (not generated)
+        pub fn set_project(&mut self, uuid: &str) -> &mut NewTaskListBuilder {
+            self.set_v(
+                "1c76bc12-ba9b-55a1-b523-6929ad793e3d_references",
+                json!(vec![uuid]),
+            );
+            self
+        }
+
+        /// Sends create request to server, returning new TaskList.
+        pub async fn execute(&mut self) -> Result<TaskList, Error> {
+            if !self.errs.is_empty() {
+                return Err(Error::Message(format!(
+                    "Errors occurred in NewTaskListBuilder: {:?}",
+                    &self.errs
+                )));
+            }
+            let map = self.fields.to_owned();
+            let task_list = self.api.create_entry(401, Value::Object(map)).await?;
+            Ok(TaskList::new(Arc::new(task_list), self.api.clone()))
+        }
+    } // end impl NewTaskListBuilder
+
+    /// Builder for updating existing TaskList
+    pub struct UpdateTaskListBuilder {
+        api: Api,
+        fields: JsonMap,
+        errs: Vec<String>,
+        item_id: TaskListId,
+    }
+
+    impl UpdateTaskListBuilder {
+        /// Create update builder for TaskList with id
+        fn new(api: Api, item_id: TaskListId) -> Self {
+            Self {
+                api,
+                fields: JsonMap::new(),
+                errs: Vec::new(),
+                item_id,
+            }
+        }
+
+        // internal helper for setting the updateAction of multi-value fields.
+        // Zenkit applies one action to all fields of a request, so a request can't
+        // append or remove values of one field and replace another.
+        fn set_update_action(&mut self, action: &str) {
+            if let Some(Value::String(prev)) = self.fields.get("updateAction") {
+                if prev != action {
+                    self.errs.push(format!(
+                                "Conflicting update actions '{}' and '{}': fields that add or remove values \
+                                 must be updated separately from fields that are set",
+                                prev, action
+                            ));
+                    return;
+                }
+            }
+            self.set_s("updateAction", action.to_string());
+        }
+
+        // internal helper for setting string field
+        #[inline]
+        fn set_s(&mut self, k: &str, v: String) {
+            self.fields.insert(k.to_string(), Value::String(v));
+        }
+        // internal helper for setting json value
+        #[inline]
+        fn set_v(&mut self, k: &str, v: Value) {
+            self.fields.insert(k.to_string(), v);
+        }
+
+        /// Sets text value for Task.
+        /// TextFormat is unchanged (to specify format, use _with_format())
+        pub fn set_task<T: AsRef<str>>(&mut self, s: T) -> &mut UpdateTaskListBuilder {
+            self.set_s(
+                "0a3681d8-46e5-5ff4-9c71-bae81891be66_text",
+                s.as_ref().to_string(),
+            );
+            self
+        }
+
+        /// Sets text value for Task with format
+        ///
+        pub fn set_task_with_format<T: AsRef<str>>(
+            &mut self,
+            s: T,
+            tf: TextFormat,
+        ) -> &mut UpdateTaskListBuilder {
+            self.set_s(
+                "0a3681d8-46e5-5ff4-9c71-bae81891be66_text",
+                s.as_ref().to_string(),
+            );
+            self.set_s(
+                "0a3681d8-46e5-5ff4-9c71-bae81891be66_textType",
+                tf.to_string(),
+            );
+            self
+        }
+
+        /// sets checkbox Done
+        ///
+        pub fn check_done(&mut self, b: bool) -> &mut UpdateTaskListBuilder {
+            self.set_v(
+                "7e6149db-0df5-5c6f-b40c-776ed750641f_checked",
+                Value::Bool(b),
+            );
+            self
+        }
+
+        /// Sets date Due.
+        pub fn set_due(&mut self, date: &DateTime<Utc>) -> &mut UpdateTaskListBuilder {
+            self.set_s(
+                "0676cdc0-9929-5f70-9f95-a6bb3d884c31_date",
+                date.to_string(),
+            );
+            self
+        }
+
+        /// Sets Priority to High.
+        pub fn set_priority_high(&mut self) -> &mut UpdateTaskListBuilder {
+            self.set_v(
+                "f7ca829f-854f-5d01-9cc2-8834391b4c2b_categories",
+                json!(vec![TaskList::LABEL_PRIORITY_HIGH_ID]),
+            );
+            self.set_update_action("replace");
+            self
+        }
+
+        /// Sets Priority to Low.
+        pub fn set_priority_low(&mut self) -> &mut UpdateTaskListBuilder {
+            self.set_v(
+                "f7ca829f-854f-5d01-9cc2-8834391b4c2b_categories",
+                json!(vec![TaskList::LABEL_PRIORITY_LOW_ID]),
+            );
+            self.set_update_action("replace");
+            self
+        }
+
+        /// Set Priority by label-id.
+        pub fn set_priority_id(&mut self, id: ID) -> &mut UpdateTaskListBuilder {
+            self.set_v(
+                "f7ca829f-854f-5d01-9cc2-8834391b4c2b_categories",
+                json!(vec![id]),
+            );
+            self.set_update_action("replace");
+            self
+        }
+        /// Set Priority by label-name.
+        pub fn set_priority(&mut self, label: &str) -> &mut UpdateTaskListBuilder {
+            match lookup_label(&TaskList::LABELS_PRIORITY, label) {
+                Some(id) => {
+                    self.set_v(
+                        "f7ca829f-854f-5d01-9cc2-8834391b4c2b_categories",
+                        json!(vec![id]),
+                    );
+                    self.set_update_action("replace");
+                }
+                None => {
+                    self.errs.push(format!(
+                        "Label '{}' not found for set_priority_label",
+                        label
+                    ));
+                }
+            }
+            self
+        }
+        /// Clears (unsets) Priority
+        pub fn unset_priority(&mut self) -> &mut UpdateTaskListBuilder {
+            self.set_v(
+                "f7ca829f-854f-5d01-9cc2-8834391b4c2b_categories",
+                json!(Vec::<String>::new()),
+            );
+            self.set_update_action("replace");
+            self
+        }
+
+        /// Sets reference Project to item in list Projects
+        /// Project in the planning workspace (not generated)
+        pub fn set_project(&mut self, uuid: &str) -> &mut UpdateTaskListBuilder {
+            self.set_v(
+                "1c76bc12-ba9b-55a1-b523-6929ad793e3d_references",
+                json!(vec![uuid]),
+            );
+            self.set_update_action("replace");
+            self
+        }
+        /// Removes references to Project
+        /// Project in the planning workspace (not generated)
+        pub fn unset_project(&mut self) -> &mut UpdateTaskListBuilder {
+            self.set_v(
+                "1c76bc12-ba9b-55a1-b523-6929ad793e3d_references",
+                json!(Vec::<String>::new()),
+            );
+            self.set_update_action("replace");
+            self
+        }
+
+        /// Sends update request to server, returning updated TaskList.
+        pub async fn execute(&mut self) -> Result<TaskList, Error> {
+            if !self.errs.is_empty() {
+                return Err(Error::Message(format!(
+                    "Errors occurred in UpdateTaskListBuilder: {:?}",
+                    &self.errs
+                )));
+            }
+            let map = self.fields.to_owned();
+            let task_list = self
+                .api
+                .update_entry(401, self.item_id.value(), Value::Object(map))
+                .await?;
+            Ok(TaskList::new(Arc::new(task_list), self.api.clone()))
+        }
+    } // end impl UpdateTaskListBuilder
+
+    /// Query for TaskLists in List 'Task List'. Filters and sort order
+    /// are sent with the request, so only matching items are downloaded.
+    /// All conditions must match.
+    #[derive(Clone)]
+    pub struct TaskListQuery {
+        api: Api,
+        q: EntryQuery,
+        errs: Vec<String>,
+    }
+
+    impl TaskListQuery {
+        /// Creates query with no conditions
+        fn new(api: Api) -> Self {
+            Self {
+                api,
+                q: EntryQuery::default(),
+                errs: Vec::new(),
+            }
+        }
+
+        /// Returns at most n TaskLists. The default (0) returns all matching items.
+        pub fn limit(mut self, n: usize) -> Self {
+            self.q.limit = n;
+            self
+        }
+
+        /// Skips the first n matching TaskLists, for example, to resume
+        /// a stream after the items already read
+        pub fn skip(mut self, n: usize) -> Self {
+            self.q.skip = n;
+            self
+        }
+
+        /// Sets the number of TaskLists requested per call. The default is 500.
+        pub fn page_size(mut self, n: usize) -> Self {
+            self.q.page_size = n;
+            self
+        }
+
+        /// Includes archived TaskLists if true. By default, they are left out.
+        pub fn include_archived(mut self, include: bool) -> Self {
+            self.q.include_archived = include;
+            self
+        }
+
+        /// Sorts by date created, oldest first
+        pub fn order_by_created_asc(mut self) -> Self {
+            self.q.order_by("created_at", false);
+            self
+        }
+
+        /// Sorts by date created, newest first
+        pub fn order_by_created_desc(mut self) -> Self {
+            self.q.order_by("created_at", true);
+            self
+        }
+
+        /// Sorts by date last updated, oldest first
+        pub fn order_by_updated_asc(mut self) -> Self {
+            self.q.order_by("updated_at", false);
+            self
+        }
+
+        /// Sorts by date last updated, newest first
+        pub fn order_by_updated_desc(mut self) -> Self {
+            self.q.order_by("updated_at", true);
+            self
+        }
+
+        /// Returns the request parameters for all matching items. `get_items` and
+        /// `stream_items` send these in pages of up to `page_size` items.
+        pub fn request(&self) -> GetEntriesRequest {
+            self.q.request()
+        }
+
+        /// Returns the matching TaskLists
+        pub async fn get_items(&self) -> Result<Vec<TaskList>, Error> {
+            self.check()?;
+            let entries = self.q.load(&self.api, LIST_TASK_LIST_ID).await?;
+            Ok(entries
+                .into_iter()
+                .map(|e| TaskList::new(Arc::new(e), self.api.clone()))
+                .collect())
+        }
+
+        /// Returns stream of the matching TaskLists. Pages are fetched as the stream
+        /// is read, so only one page is in memory at a time. The stream ends after the first
+        /// error; to resume, create the query again with `skip` set to the number of items read.
+        /// Items added or removed while reading can shift the offsets.
+        pub fn stream_items(&self) -> impl futures::Stream<Item = Result<TaskList, Error>> + Send {
+            use futures::StreamExt;
+            if let Err(e) = self.check() {
+                return futures::stream::once(futures::future::ready(Err(e))).left_stream();
+            }
+            let api = self.api.clone();
+            self.q
+                .stream(self.api.clone(), LIST_TASK_LIST_ID)
+                .map(move |r| r.map(|e| TaskList::new(Arc::new(e), api.clone())))
+                .right_stream()
+        }
+
+        /// Returns error if a condition was invalid, e.g., an unknown label
+        fn check(&self) -> Result<(), Error> {
+            if self.errs.is_empty() {
+                Ok(())
+            } else {
+                Err(Error::Message(format!(
+                    "Errors occurred in TaskListQuery: {:?}",
+                    &self.errs
+                )))
+            }
+        }
+
+        /// Matches TaskLists where 'Task' is the text
+        pub fn task_is(mut self, text: &str) -> Self {
+            self.q.filter(
+                TaskList::FIELD_TASK_ID,
+                json!({ "modus": "equals", "text": text }),
+            );
+            self
+        }
+
+        /// Matches TaskLists where 'Task' contains the text
+        pub fn task_contains(mut self, text: &str) -> Self {
+            self.q.filter(
+                TaskList::FIELD_TASK_ID,
+                json!({ "modus": "contains", "text": text }),
+            );
+            self
+        }
+
+        /// Matches TaskLists where 'Task' starts with the text
+        pub fn task_starts_with(mut self, text: &str) -> Self {
+            self.q.filter(
+                TaskList::FIELD_TASK_ID,
+                json!({ "modus": "startsWith", "text": text }),
+            );
+            self
+        }
+
+        /// Matches TaskLists where 'Task' is not set
+        pub fn task_is_empty(mut self) -> Self {
+            self.q
+                .filter(TaskList::FIELD_TASK_ID, json!({ "modus": "isEmpty" }));
+            self
+        }
+
+        /// Matches TaskLists where 'Task' is set
+        pub fn task_is_not_empty(mut self) -> Self {
+            self.q
+                .filter(TaskList::FIELD_TASK_ID, json!({ "modus": "isNotEmpty" }));
+            self
+        }
+
+        /// Sorts by 'Task', ascending
+        pub fn order_by_task_asc(mut self) -> Self {
+            self.q
+                .order_by("0a3681d8-46e5-5ff4-9c71-bae81891be66_text", false);
+            self
+        }
+
+        /// Sorts by 'Task', descending
+        pub fn order_by_task_desc(mut self) -> Self {
+            self.q
+                .order_by("0a3681d8-46e5-5ff4-9c71-bae81891be66_text", true);
+            self
+        }
+
+        /// Matches TaskLists where 'Done' is checked (true) or unchecked (false)
+        pub fn done_is(mut self, checked: bool) -> Self {
+            self.q
+                .filter(TaskList::FIELD_DONE_ID, json!({ "checked": checked }));
+            self
+        }
+
+        /// Sorts by 'Done', ascending
+        pub fn order_by_done_asc(mut self) -> Self {
+            self.q
+                .order_by("7e6149db-0df5-5c6f-b40c-776ed750641f_checked", false);
+            self
+        }
+
+        /// Sorts by 'Done', descending
+        pub fn order_by_done_desc(mut self) -> Self {
+            self.q
+                .order_by("7e6149db-0df5-5c6f-b40c-776ed750641f_checked", true);
+            self
+        }
+
+        /// Matches TaskLists where 'Due' is at or after the time
+        pub fn due_since(mut self, from: DateTime<Utc>) -> Self {
+            // dateType 10: custom range
+            self.q.filter(
+                TaskList::FIELD_DUE_ID,
+                json!({ "modus": "inRange", "dateType": 10, "dateFrom": from }),
+            );
+            self
+        }
+
+        /// Matches TaskLists where 'Due' is at or before the time
+        pub fn due_until(mut self, to: DateTime<Utc>) -> Self {
+            self.q.filter(
+                TaskList::FIELD_DUE_ID,
+                json!({ "modus": "inRange", "dateType": 10, "dateTo": to }),
+            );
+            self
+        }
+
+        /// Matches TaskLists where 'Due' is in the time range (inclusive)
+        pub fn due_between(mut self, from: DateTime<Utc>, to: DateTime<Utc>) -> Self {
+            self.q.filter(
+                TaskList::FIELD_DUE_ID,
+                json!({ "modus": "inRange", "dateType": 10, "dateFrom": from, "dateTo": to }),
+            );
+            self
+        }
+
+        /// Matches TaskLists where 'Due' is not set
+        pub fn due_is_empty(mut self) -> Self {
+            self.q
+                .filter(TaskList::FIELD_DUE_ID, json!({ "modus": "isEmpty" }));
+            self
+        }
+
+        /// Matches TaskLists where 'Due' is set
+        pub fn due_is_not_empty(mut self) -> Self {
+            self.q
+                .filter(TaskList::FIELD_DUE_ID, json!({ "modus": "isNotEmpty" }));
+            self
+        }
+
+        /// Sorts by 'Due', ascending
+        pub fn order_by_due_asc(mut self) -> Self {
+            self.q
+                .order_by("0676cdc0-9929-5f70-9f95-a6bb3d884c31_date", false);
+            self
+        }
+
+        /// Sorts by 'Due', descending
+        pub fn order_by_due_desc(mut self) -> Self {
+            self.q
+                .order_by("0676cdc0-9929-5f70-9f95-a6bb3d884c31_date", true);
+            self
+        }
+
+        /// Matches TaskLists where 'Priority' has the label id
+        pub fn priority_is_id(mut self, id: ID) -> Self {
+            self.q.filter(
+                TaskList::FIELD_PRIORITY_ID,
+                json!({ "filterCategories": [id] }),
+            );
+            self
+        }
+
+        /// Matches TaskLists where 'Priority' has the label name
+        pub fn priority_is(mut self, label: &str) -> Self {
+            match TaskList::label_id_for_priority(label) {
+                Some(id) => self.priority_is_id(id),
+                None => {
+                    self.errs
+                        .push(format!("Label '{}' not found for priority", label));
+                    self
+                }
+            }
+        }
+
+        /// Matches TaskLists where 'Priority' is not set
+        pub fn priority_is_empty(mut self) -> Self {
+            self.q
+                .filter(TaskList::FIELD_PRIORITY_ID, json!({ "modus": "isEmpty" }));
+            self
+        }
+
+        /// Matches TaskLists where 'Priority' is set
+        pub fn priority_is_not_empty(mut self) -> Self {
+            self.q.filter(
+                TaskList::FIELD_PRIORITY_ID,
+                json!({ "modus": "isNotEmpty" }),
+            );
+            self
+        }
+    } // end impl TaskListQuery
+
+    /// TaskList data, with one field per Zenkit field, that can be serialized,
+    /// cached, or sent between threads. Create from an item with `From<&TaskList>`,
+    /// and save with `to_new_builder` or `to_update_builder` (or the `*_with` versions,
+    /// which take the client to save with).
+    #[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+    pub struct TaskListRecord {
+        /// Item id, or None if the item hasn't been created
+        pub id: Option<TaskListId>,
+        /// Item uuid, or None if the item hasn't been created
+        pub uuid: Option<TaskListUuid>,
+        /// Task
+        pub task: Option<String>,
+        /// Done
+        pub done: bool,
+        /// Due
+        pub due: Option<DateTime<Utc>>,
+        /// Priority
+        pub priority: Option<ID>,
+        /// Project
+        pub project: Option<String>,
+    }
+
+    impl From<&TaskList> for TaskListRecord {
+        fn from(item: &TaskList) -> Self {
+            Self {
+                id: Some(item.get_id()),
+                uuid: Some(item.get_uuid()),
+                task: item.get_task().map(String::from),
+                done: item.is_checked_done(),
+                due: item.get_due(),
+                priority: item.get_priority_id(),
+                project: item.get_project_uuid().map(String::from),
+            }
+        }
+    }
+
+    impl TaskListRecord {
+        /// Returns builder for creating a new TaskList with the record's values,
+        /// using the global api client. Fields that are None, and read-only fields, are not set.
+        pub fn to_new_builder(&self) -> NewTaskListBuilder {
+            self.to_new_builder_with(&TaskClient::new(Api::global()))
+        }
+
+        /// Returns builder for creating a new TaskList with the record's values,
+        /// using the client. Fields that are None, and read-only fields, are not set.
+        pub fn to_new_builder_with(&self, client: &TaskClient) -> NewTaskListBuilder {
+            let mut builder = client.create();
+            if let Some(ref v) = self.task {
+                builder.set_task(v);
+            }
+            {
+                let v = &self.done;
+                builder.check_done(*v);
+            }
+            if let Some(ref v) = self.due {
+                builder.set_due(v);
+            }
+            if let Some(ref v) = self.priority {
+                builder.set_priority_id(*v);
+            }
+            if let Some(ref v) = self.project {
+                builder.set_project(v);
+            }
+            builder
+        }
+
+        /// Returns builder for updating the TaskList with the record's id to the record's values,
+        /// using the global api client. Fields that are None, and read-only fields, are not changed.
+        /// Multi-value fields are replaced. Returns an error if the record has no id.
+        pub fn to_update_builder(&self) -> Result<UpdateTaskListBuilder, Error> {
+            self.to_update_builder_with(&TaskClient::new(Api::global()))
+        }
+
+        /// Returns builder for updating the TaskList with the record's id to the record's values,
+        /// using the client. Fields that are None, and read-only fields, are not changed.
+        /// Multi-value fields are replaced. Returns an error if the record has no id.
+        pub fn to_update_builder_with(
+            &self,
+            client: &TaskClient,
+        ) -> Result<UpdateTaskListBuilder, Error> {
+            let id = self
+                .id
+                .ok_or_else(|| Error::Message("TaskListRecord has no id".to_string()))?;
+            let mut builder = client.update(id);
+            if let Some(ref v) = self.task {
+                builder.set_task(v);
+            }
+            {
+                let v = &self.done;
+                builder.check_done(*v);
+            }
+            if let Some(ref v) = self.due {
+                builder.set_due(v);
+            }
+            if let Some(ref v) = self.priority {
+                builder.set_priority_id(*v);
+            }
+            if let Some(ref v) = self.project {
+                builder.set_project(v);
+            }
+            Ok(builder)
+        }
+    }
+
+    const _: fn() = || {
+        fn assert_send_sync<T: Send + Sync>() {}
+        fn assert_send<T: Send>(_: &T) {}
+        assert_send_sync::<TaskList>();
+        assert_send_sync::<NewTaskListBuilder>();
+        assert_send_sync::<UpdateTaskListBuilder>();
+        assert_send_sync::<TaskListRecord>();
+        assert_send(&TaskListList::get_items());
+        assert_send(&TaskListList::create().execute());
+        assert_send(&TaskListList::create_many(Vec::new(), 1));
+    };
+}
+pub use task_list::*;
+
+const ZENKIT_API_TOKEN_VAR: &str = "ZENKIT_API_TOKEN";
+const ZENKIT_ITEM_URL_BASE_VAR: &str = "ZENKIT_ITEM_URL_BASE";
+
+/// Default base of the item urls returned by `get_zenkit_url`
+pub const DEFAULT_ITEM_URL_BASE: &str = "https://base.zenkit.com/i/";
+
+/// Base of item urls, if set with `set_item_url_base`
+static ITEM_URL_BASE: RwLock<Option<String>> = RwLock::new(None);
+
+/// Sets the base of the item urls returned by `get_zenkit_url`, for on-premises
+/// or proxied deployments, e.g., "https://zenkit.example.com/i/".
+/// `None` restores the default.
+pub fn set_item_url_base(base: Option<&str>) {
+    let mut item_url_base = ITEM_URL_BASE.write().unwrap_or_else(|e| e.into_inner());
+    *item_url_base = base.map(|s| s.to_string());
+}
+
+/// Returns the base of item urls: the value set with `set_item_url_base`,
+/// or the environment variable ZENKIT_ITEM_URL_BASE, or DEFAULT_ITEM_URL_BASE
+pub fn item_url_base() -> String {
+    if let Some(ref base) = *ITEM_URL_BASE.read().unwrap_or_else(|e| e.into_inner()) {
+        return base.clone();
+    }
+    std::env::var(ZENKIT_ITEM_URL_BASE_VAR)
+        .ok()
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| DEFAULT_ITEM_URL_BASE.to_string())
+}
+
+/// Errors returned by this crate
+#[derive(Debug)]
+pub enum Error {
+    Message(String),
+    Zenkit(String),
+    NoApi,
+    /// Error status returned by Zenkit, with the delay requested by the server's
+    /// Retry-After header, if any
+    Status {
+        status: u16,
+        message: String,
+        retry_after: Option<Duration>,
+    },
+    /// The request could not be sent, or the response was not received
+    Network(String),
+}
+
+impl Error {
+    /// Returns true if the call may succeed when repeated: rate limits (429),
+    /// timeouts (408), server errors (500, 502, 503, 504), and network errors
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Error::Status { status, .. } => matches!(status, 408 | 429 | 500 | 502 | 503 | 504),
+            Error::Network(_) => true,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "{:?}",
+            match self {
+                Error::Message(s) => s,
+                Error::Zenkit(s) => s,
+                Error::Status { message, .. } => message,
+                Error::Network(s) => s,
+                Error::NoApi => "Api must be initialized with 'initialize_zenkit_api()' before use",
+            }
+        )
+    }
+}
+
+impl From<zenkit::Error> for Error {
+    fn from(e: zenkit::Error) -> Error {
+        match e {
+            zenkit::Error::ApiError(status, _) => Error::Status {
+                status,
+                message: e.to_string(),
+                retry_after: None,
+            },
+            zenkit::Error::Reqwest(ref re)
+                if re.is_connect() || re.is_timeout() || re.is_request() =>
+            {
+                Error::Network(e.to_string())
+            }
+            _ => Error::Zenkit(e.to_string()),
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Error {
+        Error::Message(e.to_string())
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Initialize zenkit using api token.
+/// If token is None, the value will be taken from the environment variable ZENKIT_API_TOKEN.
+/// Returns error if token is undefined
+pub fn initialize_zenkit_api(
+    token: Option<&'_ str>,
+    endpoint: Option<&'_ str>,
+) -> Result<&'static ApiClient, Error> {
+    let defaults = ApiConfig::default();
+    let token = token.unwrap_or(&defaults.token).to_string();
+    let endpoint = endpoint.unwrap_or(&defaults.endpoint).to_string();
+    // connect first: once init_api has set the zenkit client it can't be set again
+    let http = HttpApi::connect(&token, Some(&endpoint))?;
+    let zk = init_api(ApiConfig { token, endpoint }).map_err(|e| Error::Message(e.to_string()))?;
+    let mut global_http = GLOBAL_HTTP.write().unwrap_or_else(|e| e.into_inner());
+    *global_http = Some(Arc::new(http));
+    Ok(zk)
+}
+
+/// Find id for label name using binary search
+pub(crate) fn lookup_label(lookup_list: &[(&str, ID)], label: &str) -> Option<ID> {
+    match lookup_list.binary_search_by(|a| a.0.partial_cmp(label).unwrap()) {
+        Ok(index) => Some(lookup_list[index].1),
+        Err(_) => None,
+    }
+}
+
+/// Returns true if the string is formatted as a uuid (8-4-4-4-12 hex digits)
+pub(crate) fn is_uuid(s: &str) -> bool {
+    s.len() == 36
+        && s.char_indices().all(|(i, c)| match i {
+            8 | 13 | 18 | 23 => c == '-',
+            _ => c.is_ascii_hexdigit(),
+        })
+}
+
+///
+/// Workspace "Todo"
+///
+///
+pub const WORKSPACE_TODO_ID: ID = 400;
+pub const WORKSPACE_TODO_UUID: &str = "87071492-9bd7-5b21-8a40-7f1e093cb685";
+pub const WORKSPACE_TODO_NAME: &str = "Todo";
+
+/// Client for the lists in workspace "Todo", with its own
+/// connection to Zenkit. A program can have clients for several Zenkit accounts
+/// or endpoints. The functions of the `*List` types use the global client
+/// (see `initialize_zenkit_api`), which is also available as `WorkspaceClient::global()`.
+#[derive(Clone)]
+pub struct WorkspaceClient {
+    api: Api,
+}
+
+impl WorkspaceClient {
+    /// Creates client with a new connection. If endpoint is None, the default
+    /// Zenkit endpoint is used.
+    pub fn connect(token: &str, endpoint: Option<&str>) -> Result<Self, Error> {
+        Ok(Self::with_backend(Arc::new(HttpApi::connect(
+            token, endpoint,
+        )?)))
+    }
+
+    /// Creates client that sends requests with the http client, for example,
+    /// one configured with a proxy or for a test server. The http client must
+    /// add the `Zenkit-API-Key` header. If endpoint is None, the default Zenkit
+    /// endpoint is used.
+    pub fn with_http_client(client: reqwest::Client, endpoint: Option<&str>) -> Self {
+        Self::with_backend(Arc::new(HttpApi::new(client, endpoint)))
+    }
+
+    /// Creates client that sends requests to the backend, for example,
+    /// a `mock::MockBackend` in tests
+    pub fn with_backend(backend: Arc<dyn Backend>) -> Self {
+        Self {
+            api: Api::with_backend(backend),
+        }
+    }
+
+    /// Returns client that uses the global api client, which must be initialized
+    /// with `initialize_zenkit_api` before use
+    pub fn global() -> Self {
+        Self { api: Api::global() }
+    }
+
+    /// Sets the retry policy for calls made with this client, instead of the global
+    /// policy (see `set_retry_policy`). Use `RetryPolicy::none()` to disable retries.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.api.retry_policy = Some(policy);
+        self
+    }
+
+    /// Returns client for List 'Task List'
+    pub fn task_list(&self) -> TaskClient {
+        TaskClient::new(self.api.clone())
+    }
+}
+
+/// Operations on list entries used by the generated clients. Implemented by the Zenkit
+/// api client, by the connection of `WorkspaceClient::connect`, and, with the "mock"
+/// feature, by the in-memory `mock::MockBackend`.
+#[async_trait::async_trait]
+pub trait Backend: Send + Sync {
+    /// Fetches an entry by id, short id, or uuid
+    async fn get_entry(&self, list_id: ID, entry: AllId) -> Result<Entry, Error>;
+
+    /// Fetches a page of entries
+    async fn get_list_entries(
+        &self,
+        list_id: ID,
+        params: &GetEntriesRequest,
+    ) -> Result<Vec<Entry>, Error>;
+
+    /// Creates an entry from the fields set by a builder
+    async fn create_entry(&self, list_id: ID, val: serde_json::Value) -> Result<Entry, Error>;
+
+    /// Updates an entry with the fields set by a builder
+    async fn update_entry(
+        &self,
+        list_id: ID,
+        entry_id: ID,
+        val: serde_json::Value,
+    ) -> Result<Entry, Error>;
+
+    /// Deletes an entry permanently
+    async fn delete_entry(&self, list_id: ID, entry_id: ID) -> Result<(), Error>;
+
+    /// Archives (deprecates) an entry, and returns the archived entry.
+    /// The default implementation returns an error, for backends that can't archive.
+    async fn archive_entry(&self, list_id: ID, entry_id: ID) -> Result<Entry, Error> {
+        let _ = (list_id, entry_id);
+        Err(Error::Message(
+            "archive is not supported by this backend".to_string(),
+        ))
+    }
+
+    /// Restores an archived entry, and returns the restored entry.
+    /// The default implementation returns an error, for backends that can't restore.
+    async fn restore_entry(&self, list_id: ID, entry_id: ID) -> Result<Entry, Error> {
+        let _ = (list_id, entry_id);
+        Err(Error::Message(
+            "restore is not supported by this backend".to_string(),
+        ))
+    }
+}
+
+/// The zenkit api client has no calls to archive or restore entries, so `archive_entry`
+/// and `restore_entry` return an error. The global client (after `initialize_zenkit_api`)
+/// and clients from `WorkspaceClient::connect` or `with_http_client` support them.
+#[async_trait::async_trait]
+impl Backend for ApiClient {
+    async fn get_entry(&self, list_id: ID, entry: AllId) -> Result<Entry, Error> {
+        Ok(ApiClient::get_entry(self, list_id, entry).await?)
+    }
+
+    async fn get_list_entries(
+        &self,
+        list_id: ID,
+        params: &GetEntriesRequest,
+    ) -> Result<Vec<Entry>, Error> {
+        Ok(ApiClient::get_list_entries(self, list_id, params).await?)
+    }
+
+    async fn create_entry(&self, list_id: ID, val: serde_json::Value) -> Result<Entry, Error> {
+        Ok(ApiClient::create_entry(self, list_id, val).await?)
+    }
+
+    async fn update_entry(
+        &self,
+        list_id: ID,
+        entry_id: ID,
+        val: serde_json::Value,
+    ) -> Result<Entry, Error> {
+        Ok(ApiClient::update_entry(self, list_id, entry_id, val).await?)
+    }
+
+    async fn delete_entry(&self, list_id: ID, entry_id: ID) -> Result<(), Error> {
+        ApiClient::delete_entry(self, list_id, entry_id).await?;
+        Ok(())
+    }
+}
+
+/// Backend used by the global client, if set with `set_global_backend`
+static GLOBAL_BACKEND: RwLock<Option<Arc<dyn Backend>>> = RwLock::new(None);
+
+/// Sets the backend used by the `*List` functions and `WorkspaceClient::global()`
+/// instead of the api client from `initialize_zenkit_api`, for example, to run
+/// tests with a `mock::MockBackend`. `None` restores the api client.
+/// Tests that run in parallel share the global backend; to give each test its own
+/// backend, use `WorkspaceClient::with_backend`.
+pub fn set_global_backend(backend: Option<Arc<dyn Backend>>) {
+    let mut global = GLOBAL_BACKEND.write().unwrap_or_else(|e| e.into_inner());
+    *global = backend;
+}
+
+/// Retry policy for api calls that fail with a transient error (see `Error::is_transient`).
+/// Calls are repeated after an exponential backoff, or after the delay requested
+/// by the server's Retry-After header. Only calls that are safe to repeat are retried
+/// automatically: fetching entries by id, and entry queries. Creates, updates, and
+/// deletes are sent once, since a failed response doesn't mean the change wasn't made;
+/// to retry them anyway, wrap the call in `RetryPolicy::retry`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first call. 1 disables retries.
+    pub max_attempts: u32,
+    /// Delay before the first retry. The delay doubles for each later retry.
+    pub initial_backoff: Duration,
+    /// Maximum delay before a retry, including delays requested with Retry-After
+    pub max_backoff: Duration,
+    /// If true, each backoff is randomized between half and all of its length,
+    /// so that clients that failed at the same time don't retry at the same time
+    pub jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    /// Up to 4 attempts, with backoff starting at 500ms, and at most 30s between attempts
+    fn default() -> Self {
+        Self {
+            max_attempts: 4,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Returns policy that never retries
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Calls `f` until it succeeds, returns an error that is not transient,
+    /// or the attempts are used up. Returns the result of the last call.
+    pub async fn retry<T, F, Fut>(&self, mut f: F) -> Result<T, Error>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let mut attempt = 1;
+        loop {
+            match f().await {
+                Err(e) if e.is_transient() && attempt < self.max_attempts => {
+                    tokio::time::sleep(self.delay(attempt, &e)).await;
+                    attempt += 1;
+                }
+                res => return res,
+            }
+        }
+    }
+
+    /// Returns the delay before the next call, after `attempt` calls have failed
+    fn delay(&self, attempt: u32, err: &Error) -> Duration {
+        if let Error::Status {
+            retry_after: Some(retry_after),
+            ..
+        } = err
+        {
+            return (*retry_after).min(self.max_backoff);
+        }
+        let backoff = self
+            .initial_backoff
+            .checked_mul(1 << (attempt - 1).min(31))
+            .unwrap_or(self.max_backoff)
+            .min(self.max_backoff);
+        if self.jitter {
+            backoff / 2 + backoff.mul_f64(random_fraction() / 2.0)
+        } else {
+            backoff
+        }
+    }
+}
+
+/// Returns a pseudo-random number in [0, 1), for backoff jitter
+fn random_fraction() -> f64 {
+    use std::hash::{BuildHasher, Hasher};
+    let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+    hasher.write_u128(
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos(),
+    );
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Retry policy of the global client and of clients without their own policy,
+/// if set with `set_retry_policy`
+static RETRY_POLICY: RwLock<Option<RetryPolicy>> = RwLock::new(None);
+
+/// Sets the retry policy used by the `*List` functions and by clients that don't have
+/// their own (see `WorkspaceClient::with_retry_policy`). `None` restores the default.
+pub fn set_retry_policy(policy: Option<RetryPolicy>) {
+    let mut global = RETRY_POLICY.write().unwrap_or_else(|e| e.into_inner());
+    *global = policy;
+}
+
+/// Global api client, from `get_api()`
+struct GlobalApi;
+
+#[async_trait::async_trait]
+impl Backend for GlobalApi {
+    // reads use the connection made by `initialize_zenkit_api`, which reports the
+    // Retry-After header of errors; the zenkit api client is used if the api
+    // was initialized with `init_api`
+    async fn get_entry(&self, list_id: ID, entry: AllId) -> Result<Entry, Error> {
+        match global_http() {
+            Ok(http) => http.get_entry(list_id, entry).await,
+            Err(_) => Backend::get_entry(get_api()?, list_id, entry).await,
+        }
+    }
+
+    async fn get_list_entries(
+        &self,
+        list_id: ID,
+        params: &GetEntriesRequest,
+    ) -> Result<Vec<Entry>, Error> {
+        match global_http() {
+            Ok(http) => http.get_list_entries(list_id, params).await,
+            Err(_) => Backend::get_list_entries(get_api()?, list_id, params).await,
+        }
+    }
+
+    async fn create_entry(&self, list_id: ID, val: serde_json::Value) -> Result<Entry, Error> {
+        Backend::create_entry(get_api()?, list_id, val).await
+    }
+
+    async fn update_entry(
+        &self,
+        list_id: ID,
+        entry_id: ID,
+        val: serde_json::Value,
+    ) -> Result<Entry, Error> {
+        Backend::update_entry(get_api()?, list_id, entry_id, val).await
+    }
+
+    async fn delete_entry(&self, list_id: ID, entry_id: ID) -> Result<(), Error> {
+        Backend::delete_entry(get_api()?, list_id, entry_id).await
+    }
+
+    // the zenkit api client can't archive or restore entries,
+    // so these use the connection made by `initialize_zenkit_api`
+    async fn archive_entry(&self, list_id: ID, entry_id: ID) -> Result<Entry, Error> {
+        global_http()?.archive_entry(list_id, entry_id).await
+    }
+
+    async fn restore_entry(&self, list_id: ID, entry_id: ID) -> Result<Entry, Error> {
+        global_http()?.restore_entry(list_id, entry_id).await
+    }
+}
+
+/// Http connection with the token and endpoint of `initialize_zenkit_api`
+static GLOBAL_HTTP: RwLock<Option<Arc<HttpApi>>> = RwLock::new(None);
+
+/// Returns the connection made by `initialize_zenkit_api`
+fn global_http() -> Result<Arc<HttpApi>, Error> {
+    GLOBAL_HTTP
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+        .ok_or(Error::NoApi)
+}
+
+/// Backend and retry policy used for api calls
+#[derive(Clone)]
+pub(crate) struct Api {
+    /// backend owned by a WorkspaceClient, or None for the global backend
+    /// (see `set_global_backend`) or the api client from `get_api()`
+    backend: Option<Arc<dyn Backend>>,
+    /// retry policy of a WorkspaceClient, or None for the global policy
+    retry_policy: Option<RetryPolicy>,
+}
+
+impl Api {
+    /// Returns api that uses the global backend
+    pub(crate) fn global() -> Self {
+        Self {
+            backend: None,
+            retry_policy: None,
+        }
+    }
+
+    /// Returns api that uses a separate backend
+    pub(crate) fn with_backend(backend: Arc<dyn Backend>) -> Self {
+        Self {
+            backend: Some(backend),
+            retry_policy: None,
+        }
+    }
+
+    /// Returns the backend for the next call
+    fn backend(&self) -> Arc<dyn Backend> {
+        match &self.backend {
+            None => GLOBAL_BACKEND
+                .read()
+                .unwrap_or_else(|e| e.into_inner())
+                .clone()
+                .unwrap_or_else(|| Arc::new(GlobalApi)),
+            Some(backend) => backend.clone(),
+        }
+    }
+
+    /// Returns the retry policy for the next call
+    fn retry_policy(&self) -> RetryPolicy {
+        match &self.retry_policy {
+            None => RETRY_POLICY
+                .read()
+                .unwrap_or_else(|e| e.into_inner())
+                .clone()
+                .unwrap_or_default(),
+            Some(policy) => policy.clone(),
+        }
+    }
+
+    /// Fetches an entry by id or uuid, with retries
+    pub(crate) async fn get_entry<E: Into<AllId>>(
+        &self,
+        list_id: ID,
+        entry: E,
+    ) -> Result<Entry, Error> {
+        let backend = self.backend();
+        let entry = entry.into();
+        self.retry_policy()
+            .retry(|| backend.get_entry(list_id, copy_id(&entry)))
+            .await
+    }
+
+    /// Fetches a page of entries, with retries
+    pub(crate) async fn get_list_entries(
+        &self,
+        list_id: ID,
+        params: &GetEntriesRequest,
+    ) -> Result<Vec<Entry>, Error> {
+        let backend = self.backend();
+        self.retry_policy()
+            .retry(|| backend.get_list_entries(list_id, params))
+            .await
+    }
+
+    /// Creates an entry
+    pub(crate) async fn create_entry(
+        &self,
+        list_id: ID,
+        val: serde_json::Value,
+    ) -> Result<Entry, Error> {
+        self.backend().create_entry(list_id, val).await
+    }
+
+    /// Updates an entry
+    pub(crate) async fn update_entry(
+        &self,
+        list_id: ID,
+        entry_id: ID,
+        val: serde_json::Value,
+    ) -> Result<Entry, Error> {
+        self.backend().update_entry(list_id, entry_id, val).await
+    }
+
+    /// Deletes an entry permanently
+    pub(crate) async fn delete_entry(&self, list_id: ID, entry_id: ID) -> Result<(), Error> {
+        self.backend().delete_entry(list_id, entry_id).await
+    }
+
+    /// Archives an entry
+    pub(crate) async fn archive_entry(&self, list_id: ID, entry_id: ID) -> Result<Entry, Error> {
+        self.backend().archive_entry(list_id, entry_id).await
+    }
+
+    /// Restores an archived entry
+    pub(crate) async fn restore_entry(&self, list_id: ID, entry_id: ID) -> Result<Entry, Error> {
+        self.backend().restore_entry(list_id, entry_id).await
+    }
+
+    /// Loads all entries for a list into memory
+    pub(crate) async fn load_entries(&self, list_id: ID) -> Result<Vec<Entry>, Error> {
+        EntryQuery::default().load(self, list_id).await
+    }
+}
+
+/// Returns a copy of the id (AllId isn't Clone)
+fn copy_id(id: &AllId) -> AllId {
+    match id {
+        AllId::ID(id) => AllId::ID(*id),
+        AllId::ShortId(s) => AllId::ShortId(s.clone()),
+        AllId::UUID(s) => AllId::UUID(s.clone()),
+        AllId::Any(s) => AllId::Any(s.clone()),
+    }
+}
+
+/// Runs the calls with at most `concurrency` in progress at a time (at least 1),
+/// and returns their results in the order of the calls
+pub(crate) async fn run_batch<T, I>(calls: I, concurrency: usize) -> Vec<Result<T, Error>>
+where
+    I: IntoIterator,
+    I::Item: Future<Output = Result<T, Error>>,
+{
+    use futures::StreamExt;
+    futures::stream::iter(calls)
+        .buffered(concurrency.max(1))
+        .collect()
+        .await
+}
+
+/// Returns the next f64 after the value, toward positive infinity if `up`, otherwise
+/// toward negative infinity. Filters only have inclusive bounds, so query conditions
+/// like `_gt` use the next value. Returns NaN for NaN.
+pub(crate) fn next_f64(value: f64, up: bool) -> f64 {
+    if value.is_nan() || (value.is_infinite() && (value > 0.0) == up) {
+        value
+    } else if value == 0.0 {
+        let tiny = f64::from_bits(1);
+        if up {
+            tiny
+        } else {
+            -tiny
+        }
+    } else if (value > 0.0) == up {
+        // away from zero
+        f64::from_bits(value.to_bits() + 1)
+    } else {
+        f64::from_bits(value.to_bits() - 1)
+    }
+}
+
+/// Filters, sort order, and range of a list query, used by the generated `*Query` types.
+/// Filters are sent as `{"AND": {"TERMS": [...]}}`. Each term has the field's `elementId`,
+/// an optional `modus` (e.g., "contains" or "isEmpty"), and values for the field's
+/// filter keys (e.g., "text", "numberFrom" and "numberTo", or "filterCategories").
+#[derive(Clone, Debug, Default)]
+pub(crate) struct EntryQuery {
+    terms: Vec<serde_json::Value>,
+    /// (column, descending)
+    order_by: Vec<(&'static str, bool)>,
+    /// maximum number of entries, or 0 for all
+    pub(crate) limit: usize,
+    /// number of matching entries to skip
+    pub(crate) skip: usize,
+    /// number of entries requested per call, or 0 for PAGE_SIZE
+    pub(crate) page_size: usize,
+    /// if true, archived (deprecated) entries are included
+    pub(crate) include_archived: bool,
+}
+
+impl EntryQuery {
+    /// Default number of entries requested per call
+    pub(crate) const PAGE_SIZE: usize = 500;
+
+    /// Adds a filter term for a field
+    pub(crate) fn filter(&mut self, element_id: ID, mut term: serde_json::Value) {
+        term["elementId"] = serde_json::json!(element_id);
+        self.terms.push(term);
+    }
+
+    /// Adds a sort column, e.g., "created_at" or "<field uuid>_number".
+    /// Earlier columns take precedence.
+    pub(crate) fn order_by(&mut self, column: &'static str, descending: bool) {
+        self.order_by.push((column, descending));
+    }
+
+    /// Returns the request parameters, with the query's limit and skip
+    pub(crate) fn request(&self) -> GetEntriesRequest {
+        self.page(self.skip, self.limit)
+    }
+
+    /// Returns the request parameters for a page of results
+    fn page(&self, skip: usize, limit: usize) -> GetEntriesRequest {
+        use zenkit::types::{OrderBy, SortDirection};
+        let filter = if self.terms.is_empty() {
+            serde_json::json!({})
+        } else {
+            serde_json::json!({ "AND": { "TERMS": self.terms } })
+        };
+        let order_by = self
+            .order_by
+            .iter()
+            .map(|(column, descending)| OrderBy {
+                column: Some(column.to_string()),
+                direction: if *descending {
+                    SortDirection::Desc
+                } else {
+                    SortDirection::Asc
+                },
+            })
+            .collect();
+        GetEntriesRequest {
+            filter,
+            limit,
+            skip,
+            order_by,
+            allow_deprecated: self.include_archived,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the number of entries to request after `fetched` entries,
+    /// or 0 if the limit has been reached
+    fn next_page_size(&self, fetched: usize) -> usize {
+        let page_size = match self.page_size {
+            0 => Self::PAGE_SIZE,
+            n => n,
+        };
+        match self.limit {
+            0 => page_size,
+            limit => page_size.min(limit.saturating_sub(fetched)),
+        }
+    }
+
+    /// Loads all matching entries into memory
+    pub(crate) async fn load(&self, api: &Api, list_id: ID) -> Result<Vec<Entry>, Error> {
+        use futures::TryStreamExt;
+        self.stream(api.clone(), list_id).try_collect().await
+    }
+
+    /// Returns stream of the matching entries. Pages are requested as the stream
+    /// is read, so only one page is in memory at a time. The stream ends after
+    /// the first error.
+    pub(crate) fn stream(
+        &self,
+        api: Api,
+        list_id: ID,
+    ) -> impl futures::Stream<Item = Result<Entry, Error>> + Send {
+        let pages = EntryPages {
+            api,
+            query: self.clone(),
+            list_id,
+            fetched: 0,
+            page: std::collections::VecDeque::new(),
+            done: false,
+        };
+        futures::stream::unfold(pages, |mut pages| async move {
+            loop {
+                if let Some(entry) = pages.page.pop_front() {
+                    return Some((Ok(entry), pages));
+                }
+                let page_size = pages.query.next_page_size(pages.fetched);
+                if pages.done || page_size == 0 {
+                    return None;
+                }
+                let params = pages
+                    .query
+                    .page(pages.query.skip + pages.fetched, page_size);
+                match pages.api.get_list_entries(pages.list_id, &params).await {
+                    Ok(mut entries) => {
+                        // a page with fewer entries than requested is the last
+                        pages.done = entries.len() < page_size;
+                        entries.truncate(page_size);
+                        pages.fetched += entries.len();
+                        pages.page.extend(entries);
+                    }
+                    Err(e) => {
+                        pages.done = true;
+                        return Some((Err(e), pages));
+                    }
+                }
+            }
+        })
+    }
+}
+
+/// State of an entry stream
+struct EntryPages {
+    api: Api,
+    query: EntryQuery,
+    list_id: ID,
+    /// number of entries received
+    fetched: usize,
+    /// entries received but not yet returned
+    page: std::collections::VecDeque<Entry>,
+    /// true after the last page (one with fewer entries than requested) or an error
+    done: bool,
+}
+
+/// Http connection to the Zenkit api
+pub(crate) struct HttpApi {
+    client: reqwest::Client,
+    endpoint: String,
+}
+
+impl HttpApi {
+    /// Creates connection that sends the token with each request.
+    /// If endpoint is None, the default Zenkit endpoint is used.
+    fn connect(token: &str, endpoint: Option<&str>) -> Result<Self, Error> {
+        use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+        if token.is_empty() {
+            return Err(Error::Message("Missing API token".to_string()));
+        }
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        headers.insert(
+            "Zenkit-API-Key",
+            HeaderValue::from_str(token)
+                .map_err(|_| Error::Message("token has non-ascii chars".to_string()))?,
+        );
+        let client = reqwest::Client::builder()
+            .default_headers(headers)
+            .build()
+            .map_err(zenkit::Error::from)?;
+        Ok(Self::new(client, endpoint))
+    }
+
+    /// Creates connection that sends requests with the http client
+    fn new(client: reqwest::Client, endpoint: Option<&str>) -> Self {
+        let endpoint = match endpoint {
+            Some(endpoint) => endpoint.trim_end_matches('/').to_string(),
+            None => ApiConfig::default().endpoint,
+        };
+        Self { client, endpoint }
+    }
+}
+
+#[async_trait::async_trait]
+impl Backend for HttpApi {
+    async fn get_entry(&self, list_id: ID, entry: AllId) -> Result<Entry, Error> {
+        let url = format!("{}/lists/{}/entries/{}", self.endpoint, list_id, entry);
+        HttpApi::json(self.client.get(&url).send().await).await
+    }
+
+    async fn get_list_entries(
+        &self,
+        list_id: ID,
+        params: &GetEntriesRequest,
+    ) -> Result<Vec<Entry>, Error> {
+        let url = format!("{}/lists/{}/entries/filter", self.endpoint, list_id);
+        HttpApi::json(self.client.post(&url).json(params).send().await).await
+    }
+
+    async fn create_entry(&self, list_id: ID, val: serde_json::Value) -> Result<Entry, Error> {
+        let url = format!("{}/lists/{}/entries", self.endpoint, list_id);
+        HttpApi::json(self.client.post(&url).json(&val).send().await).await
+    }
+
+    async fn update_entry(
+        &self,
+        list_id: ID,
+        entry_id: ID,
+        val: serde_json::Value,
+    ) -> Result<Entry, Error> {
+        let url = format!("{}/lists/{}/entries/{}", self.endpoint, list_id, entry_id);
+        HttpApi::json(self.client.put(&url).json(&val).send().await).await
+    }
+
+    async fn delete_entry(&self, list_id: ID, entry_id: ID) -> Result<(), Error> {
+        let url = format!(
+            "{}/lists/{}/deprecated-entries/{}",
+            self.endpoint, list_id, entry_id
+        );
+        let _: serde_json::Value = HttpApi::json(self.client.delete(&url).send().await).await?;
+        Ok(())
+    }
+
+    async fn archive_entry(&self, list_id: ID, entry_id: ID) -> Result<Entry, Error> {
+        let url = format!("{}/lists/{}/entries/{}", self.endpoint, list_id, entry_id);
+        let _: serde_json::Value = HttpApi::json(self.client.delete(&url).send().await).await?;
+        self.get_entry(list_id, AllId::ID(entry_id)).await
+    }
+
+    async fn restore_entry(&self, list_id: ID, entry_id: ID) -> Result<Entry, Error> {
+        let url = format!(
+            "{}/lists/{}/deprecated-entries/{}/restore",
+            self.endpoint, list_id, entry_id
+        );
+        let _: serde_json::Value = HttpApi::json(self.client.post(&url).send().await).await?;
+        self.get_entry(list_id, AllId::ID(entry_id)).await
+    }
+}
+
+impl HttpApi {
+    /// Checks response for http errors, and deserializes the response body
+    async fn json<T: serde::de::DeserializeOwned>(
+        resp: Result<reqwest::Response, reqwest::Error>,
+    ) -> Result<T, Error> {
+        let resp = resp.map_err(zenkit::Error::from)?;
+        let status = resp.status();
+        // Retry-After in seconds (the http-date form is ignored)
+        let retry_after = resp
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.trim().parse().ok())
+            .map(Duration::from_secs);
+        let bytes = resp.bytes().await.map_err(zenkit::Error::from)?;
+        if !status.is_success() {
+            let message = match serde_json::from_slice::<zenkit::types::ErrorResult>(&bytes) {
+                Ok(res) => zenkit::Error::ApiError(status.as_u16(), Some(res.error)).to_string(),
+                Err(_) => format!(
+                    "Server returned status {}:{}",
+                    status.as_u16(),
+                    String::from_utf8_lossy(&bytes)
+                ),
+            };
+            return Err(Error::Status {
+                status: status.as_u16(),
+                message,
+                retry_after,
+            });
+        }
+        serde_json::from_slice(&bytes)
+            .map_err(|e| Error::Zenkit(format!("invalid response: {}", e)))
+    }
+}
+
+/// In-memory backend for tests, enabled with the "mock" feature
+#[cfg(feature = "mock")]
+pub mod mock {
+    use super::{AllId, Backend, DateTime, Entry, Error, GetEntriesRequest, JsonMap, Utc, ID};
+    use serde_json::{json, Value};
+    use std::{
+        cmp::Ordering,
+        collections::BTreeMap,
+        sync::{Mutex, MutexGuard},
+    };
+
+    /// Lists in the workspace, as (id, uuid)
+    const LISTS: &[(ID, &str)] = &[(401, "2e1b2a5c-9391-58a5-8dc2-2d2e146c1b73")];
+
+    /// Fields of the lists, as (id, uuid), sorted by id
+    const FIELDS: &[(ID, &str)] = &[
+        (41, "0a3681d8-46e5-5ff4-9c71-bae81891be66"),
+        (42, "7e6149db-0df5-5c6f-b40c-776ed750641f"),
+        (43, "0676cdc0-9929-5f70-9f95-a6bb3d884c31"),
+        (44, "f7ca829f-854f-5d01-9cc2-8834391b4c2b"),
+        (45, "1c76bc12-ba9b-55a1-b523-6929ad793e3d"),
+    ];
+
+    /// Names of category labels, sorted by id
+    const LABELS: &[(ID, &str)] = &[(451, "High"), (452, "Low")];
+
+    /// Backend that keeps entries in memory, so that code using this crate can be tested
+    /// without network access. Creates and updates apply the fields set by builders,
+    /// including the `updateAction` of multi-value fields: "append" adds values,
+    /// "remove" removes them, and "replace" sets the field.
+    /// Entry queries apply the filters and sort order of the generated `*Query` types.
+    /// Archived entries are kept, and only returned by queries that include them.
+    #[derive(Default)]
+    pub struct MockBackend {
+        state: Mutex<MockState>,
+    }
+
+    #[derive(Default)]
+    struct MockState {
+        /// entries of each list, in the order added
+        lists: BTreeMap<ID, Vec<JsonMap>>,
+        /// highest entry id in use
+        last_id: ID,
+    }
+
+    impl MockBackend {
+        /// Creates backend with no entries
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Creates backend with entries from a json fixture: an object whose keys are
+        /// list ids or uuids, and whose values are arrays of entries (see `insert`)
+        pub fn from_json(json: &str) -> Result<Self, Error> {
+            let fixture: BTreeMap<String, Vec<Value>> = serde_json::from_str(json)
+                .map_err(|e| Error::Message(format!("invalid mock fixture: {}", e)))?;
+            let backend = Self::new();
+            // entries without an id are numbered after the highest id in the fixture
+            backend.lock().last_id = fixture
+                .values()
+                .flatten()
+                .filter_map(|entry| entry.get("id").and_then(|v| v.as_u64()))
+                .max()
+                .unwrap_or_default();
+            for (key, entries) in fixture.into_iter() {
+                let list_id = list_id(&key).ok_or_else(|| {
+                    Error::Message(format!("invalid mock fixture: unknown list '{}'", key))
+                })?;
+                for entry in entries.into_iter() {
+                    backend.insert(list_id, entry)?;
+                }
+            }
+            Ok(backend)
+        }
+
+        /// Adds an entry to a list. The entry is json in the format returned by Zenkit.
+        /// Missing properties, such as id, uuid, and timestamps, are filled in,
+        /// and multi-value fields may be given as ids only
+        /// (e.g., `"<field uuid>_categories": [12]`).
+        pub fn insert(&self, list_id: ID, entry: Value) -> Result<Entry, Error> {
+            let fields = as_object(entry)?;
+            let mut state = self.lock();
+            let id = match fields.get("id").and_then(|v| v.as_u64()) {
+                Some(id) => id,
+                None => state.last_id + 1,
+            };
+            state.last_id = state.last_id.max(id);
+            let mut obj = new_entry(list_id, id);
+            apply_fields(&mut obj, &fields, "replace");
+            state.lists.entry(list_id).or_default().push(obj.clone());
+            to_entry(obj)
+        }
+
+        /// Returns the json of the entries in a list, for checking the results of a test
+        pub fn entries(&self, list_id: ID) -> Vec<Value> {
+            self.lock()
+                .lists
+                .get(&list_id)
+                .map(|entries| entries.iter().cloned().map(Value::Object).collect())
+                .unwrap_or_default()
+        }
+
+        fn lock(&self) -> MutexGuard<'_, MockState> {
+            self.state.lock().unwrap_or_else(|e| e.into_inner())
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl Backend for MockBackend {
+        async fn get_entry(&self, list_id: ID, entry: AllId) -> Result<Entry, Error> {
+            let key = entry.to_string();
+            let obj = self
+                .lock()
+                .lists
+                .get(&list_id)
+                .and_then(|entries| entries.iter().find(|obj| has_key(obj, &key)))
+                .cloned()
+                .ok_or_else(|| not_found(list_id, &key))?;
+            to_entry(obj)
+        }
+
+        async fn get_list_entries(
+            &self,
+            list_id: ID,
+            params: &GetEntriesRequest,
+        ) -> Result<Vec<Entry>, Error> {
+            let limit = if params.limit == 0 {
+                usize::MAX
+            } else {
+                params.limit
+            };
+            let state = self.lock();
+            let mut entries: Vec<&JsonMap> = state
+                .lists
+                .get(&list_id)
+                .map(|entries| entries.as_slice())
+                .unwrap_or_default()
+                .iter()
+                .filter(|obj| {
+                    params.allow_deprecated || obj.get("deprecated_at").map_or(true, Value::is_null)
+                })
+                .filter(|obj| matches_filter(obj, &params.filter))
+                .collect();
+            for order in params.order_by.iter().rev() {
+                let column = order.column.as_deref().unwrap_or("sortOrder");
+                let descending = matches!(order.direction, zenkit::types::SortDirection::Desc);
+                entries.sort_by(|a, b| compare_values(a.get(column), b.get(column), descending));
+            }
+            entries
+                .into_iter()
+                .skip(params.skip)
+                .take(limit)
+                .cloned()
+                .map(to_entry)
+                .collect()
+        }
+
+        async fn create_entry(&self, list_id: ID, val: Value) -> Result<Entry, Error> {
+            let fields = as_object(val)?;
+            let mut state = self.lock();
+            state.last_id += 1;
+            let mut obj = new_entry(list_id, state.last_id);
+            apply_fields(&mut obj, &fields, update_action(&fields));
+            state.lists.entry(list_id).or_default().push(obj.clone());
+            to_entry(obj)
+        }
+
+        async fn update_entry(
+            &self,
+            list_id: ID,
+            entry_id: ID,
+            val: Value,
+        ) -> Result<Entry, Error> {
+            let fields = as_object(val)?;
+            let mut state = self.lock();
+            let obj = state
+                .lists
+                .get_mut(&list_id)
+                .and_then(|entries| {
+                    entries
+                        .iter_mut()
+                        .find(|obj| has_key(obj, &entry_id.to_string()))
+                })
+                .ok_or_else(|| not_found(list_id, &entry_id.to_string()))?;
+            apply_fields(obj, &fields, update_action(&fields));
+            obj.insert("updated_at".to_string(), json!(Utc::now()));
+            to_entry(obj.clone())
+        }
+
+        async fn delete_entry(&self, list_id: ID, entry_id: ID) -> Result<(), Error> {
+            let mut state = self.lock();
+            let key = entry_id.to_string();
+            let entries = state
+                .lists
+                .get_mut(&list_id)
+                .ok_or_else(|| not_found(list_id, &key))?;
+            let index = entries
+                .iter()
+                .position(|obj| has_key(obj, &key))
+                .ok_or_else(|| not_found(list_id, &key))?;
+            entries.remove(index);
+            Ok(())
+        }
+
+        async fn archive_entry(&self, list_id: ID, entry_id: ID) -> Result<Entry, Error> {
+            self.set_deprecated(list_id, entry_id, json!(Utc::now()))
+        }
+
+        async fn restore_entry(&self, list_id: ID, entry_id: ID) -> Result<Entry, Error> {
+            self.set_deprecated(list_id, entry_id, Value::Null)
+        }
+    }
+
+    impl MockBackend {
+        /// Sets the date an entry was archived, or null to restore it
+        fn set_deprecated(
+            &self,
+            list_id: ID,
+            entry_id: ID,
+            deprecated_at: Value,
+        ) -> Result<Entry, Error> {
+            let mut state = self.lock();
+            let obj = state
+                .lists
+                .get_mut(&list_id)
+                .and_then(|entries| {
+                    entries
+                        .iter_mut()
+                        .find(|obj| has_key(obj, &entry_id.to_string()))
+                })
+                .ok_or_else(|| not_found(list_id, &entry_id.to_string()))?;
+            obj.insert("deprecated_at".to_string(), deprecated_at);
+            to_entry(obj.clone())
+        }
+    }
+
+    /// Returns id of a list in the workspace, from its id or uuid
+    fn list_id(key: &str) -> Option<ID> {
+        LISTS
+            .iter()
+            .find(|(id, uuid)| id.to_string() == key || *uuid == key)
+            .map(|(id, _)| *id)
+    }
+
+    /// Returns uuid of a field
+    fn field_uuid(id: ID) -> Option<&'static str> {
+        FIELDS
+            .binary_search_by_key(&id, |(id, _)| *id)
+            .ok()
+            .map(|index| FIELDS[index].1)
+    }
+
+    /// Returns true if the entry matches all terms of a filter (see `EntryQuery`)
+    fn matches_filter(obj: &JsonMap, filter: &Value) -> bool {
+        filter["AND"]["TERMS"]
+            .as_array()
+            .map(|terms| terms.iter().all(|term| matches_term(obj, term)))
+            .unwrap_or(true)
+    }
+
+    /// Returns true if the entry matches a filter term
+    fn matches_term(obj: &JsonMap, term: &Value) -> bool {
+        let uuid = match term["elementId"].as_u64().and_then(field_uuid) {
+            Some(uuid) => uuid,
+            None => return false,
+        };
+        let value = |kind: &str| {
+            obj.get(&format!("{}_{}", uuid, kind))
+                .filter(|v| !is_empty(v))
+        };
+        let modus = term["modus"].as_str().unwrap_or_default();
+        let is_set = [
+            "text",
+            "link",
+            "number",
+            "date",
+            "checked",
+            "categories",
+            "persons",
+            "references",
+        ]
+        .iter()
+        .any(|kind| value(kind).is_some());
+        let any_of = |kind: &str, key: &str| match (value(kind), term[key].as_array()) {
+            (Some(Value::Array(values)), Some(wanted)) => values.iter().any(|v| wanted.contains(v)),
+            _ => false,
+        };
+        if modus == "isEmpty" {
+            !is_set
+        } else if modus == "isNotEmpty" {
+            is_set
+        } else if let Some(wanted) = term["text"].as_str() {
+            let text = value("text")
+                .or_else(|| value("link"))
+                .and_then(|v| v.as_str())
+                .unwrap_or_default();
+            match modus {
+                "contains" => text.contains(wanted),
+                "notContains" => !text.contains(wanted),
+                "startsWith" => text.starts_with(wanted),
+                "endsWith" => text.ends_with(wanted),
+                "notEquals" => text != wanted,
+                _ => text == wanted,
+            }
+        } else if term.get("numberFrom").is_some() || term.get("numberTo").is_some() {
+            let in_range = match value("number").and_then(|v| v.as_f64()) {
+                Some(n) => {
+                    term["numberFrom"].as_f64().map_or(true, |from| n >= from)
+                        && term["numberTo"].as_f64().map_or(true, |to| n <= to)
+                }
+                None => false,
+            };
+            in_range != (modus == "notInRange")
+        } else if term.get("dateFrom").is_some() || term.get("dateTo").is_some() {
+            let date = |v: &Value| {
+                v.as_str()
+                    .and_then(|s| s.parse::<DateTime<Utc>>().ok())
+                    .map(|d| d.timestamp_millis())
+            };
+            match value("date").and_then(date) {
+                Some(d) => {
+                    date(&term["dateFrom"]).map_or(true, |from| d >= from)
+                        && date(&term["dateTo"]).map_or(true, |to| d <= to)
+                }
+                None => false,
+            }
+        } else if let Some(checked) = term["checked"].as_bool() {
+            value("checked")
+                .and_then(|v| v.as_bool())
+                .unwrap_or_default()
+                == checked
+        } else if term.get("filterCategories").is_some() {
+            any_of("categories", "filterCategories")
+        } else if term.get("filterPersons").is_some() {
+            any_of("persons", "filterPersons")
+        } else {
+            true
+        }
+    }
+
+    /// Returns true for null, empty strings, and empty arrays
+    fn is_empty(val: &Value) -> bool {
+        match val {
+            Value::Null => true,
+            Value::String(s) => s.is_empty(),
+            Value::Array(a) => a.is_empty(),
+            _ => false,
+        }
+    }
+
+    /// Compares values of a sort column. Missing values are last in either direction.
+    fn compare_values(a: Option<&Value>, b: Option<&Value>, descending: bool) -> Ordering {
+        let ord = match (a.filter(|v| !is_empty(v)), b.filter(|v| !is_empty(v))) {
+            (Some(Value::Number(a)), Some(Value::Number(b))) => a
+                .as_f64()
+                .partial_cmp(&b.as_f64())
+                .unwrap_or(Ordering::Equal),
+            (Some(Value::String(a)), Some(Value::String(b))) => a.cmp(b),
+            (Some(Value::Bool(a)), Some(Value::Bool(b))) => a.cmp(b),
+            (Some(_), None) => return Ordering::Less,
+            (None, Some(_)) => return Ordering::Greater,
+            _ => Ordering::Equal,
+        };
+        if descending {
+            ord.reverse()
+        } else {
+            ord
+        }
+    }
+
+    /// Returns name of a category label
+    fn label_name(id: ID) -> Option<&'static str> {
+        LABELS
+            .binary_search_by_key(&id, |(id, _)| *id)
+            .ok()
+            .map(|index| LABELS[index].1)
+    }
+
+    /// Returns true if the entry's id, uuid, or short id is the key
+    fn has_key(obj: &JsonMap, key: &str) -> bool {
+        ["id", "uuid", "shortId"]
+            .iter()
+            .any(|prop| match obj.get(*prop) {
+                Some(Value::String(s)) => s == key,
+                Some(Value::Number(n)) => n.to_string() == key,
+                _ => false,
+            })
+    }
+
+    /// Returns the updateAction of a request: "append", "remove", or "replace"
+    fn update_action(fields: &JsonMap) -> &str {
+        fields
+            .get("updateAction")
+            .and_then(|v| v.as_str())
+            .unwrap_or("replace")
+    }
+
+    /// Creates entry with no field values
+    fn new_entry(list_id: ID, id: ID) -> JsonMap {
+        let now = json!(Utc::now());
+        let mut obj = JsonMap::new();
+        obj.insert("id".to_string(), json!(id));
+        obj.insert("shortId".to_string(), json!(format!("mock-{}", id)));
+        obj.insert(
+            "uuid".to_string(),
+            json!(format!("00000000-0000-4000-8000-{:012x}", id)),
+        );
+        obj.insert("listId".to_string(), json!(list_id));
+        obj.insert("created_at".to_string(), now.clone());
+        obj.insert("updated_at".to_string(), now);
+        obj.insert("deprecated_at".to_string(), Value::Null);
+        obj.insert("created_by".to_string(), json!(0));
+        obj.insert("updated_by".to_string(), json!(0));
+        obj.insert("displayString".to_string(), json!(""));
+        obj.insert("sortOrder".to_string(), json!(id));
+        obj.insert("comment_count".to_string(), json!(0));
+        obj.insert("checklists".to_string(), json!([]));
+        obj
+    }
+
+    /// Applies the fields set by a builder (or from a fixture) to an entry
+    fn apply_fields(obj: &mut JsonMap, fields: &JsonMap, action: &str) {
+        for (key, value) in fields.iter() {
+            if key == "updateAction" {
+                continue;
+            }
+            let value = match (action, obj.get(key), value) {
+                ("append", Some(Value::Array(old)), Value::Array(added)) => {
+                    let mut values = old.clone();
+                    values.extend(added.iter().filter(|v| !old.contains(v)).cloned());
+                    Value::Array(values)
+                }
+                ("remove", Some(Value::Array(old)), Value::Array(removed)) => Value::Array(
+                    old.iter()
+                        .filter(|v| !removed.contains(v))
+                        .cloned()
+                        .collect(),
+                ),
+                ("remove", None, Value::Array(_)) => Value::Array(Vec::new()),
+                _ => value.clone(),
+            };
+            if let Some(details) = sort_details(key, &value) {
+                obj.insert(format!("{}_sort", key), details);
+            }
+            obj.insert(key.clone(), value);
+        }
+    }
+
+    /// Returns the "_sort" property of a multi-value field, which has the details
+    /// of each value, or None for other fields
+    fn sort_details(key: &str, value: &Value) -> Option<Value> {
+        let values = value.as_array()?;
+        let details = if key.ends_with("_categories") {
+            values
+                .iter()
+                .map(|id| json!({ "id": id, "name": id.as_u64().and_then(label_name) }))
+                .collect()
+        } else if key.ends_with("_persons") {
+            values.iter().map(|id| json!({ "id": id })).collect()
+        } else if key.ends_with("_references") {
+            values.iter().map(|uuid| json!({ "uuid": uuid })).collect()
+        } else {
+            return None;
+        };
+        Some(Value::Array(details))
+    }
+
+    fn as_object(val: Value) -> Result<JsonMap, Error> {
+        match val {
+            Value::Object(map) => Ok(map),
+            _ => Err(Error::Message(
+                "mock entry must be a json object".to_string(),
+            )),
+        }
+    }
+
+    fn to_entry(obj: JsonMap) -> Result<Entry, Error> {
+        serde_json::from_value(Value::Object(obj))
+            .map_err(|e| Error::Message(format!("invalid mock entry: {}", e)))
+    }
+
+    fn not_found(list_id: ID, key: &str) -> Error {
+        Error::Zenkit(format!("entry {} not found in list {}", key, list_id))
+    }
+}
@@ -0,0 +1,37 @@
+//! Identifiers generated from Zenkit names
+use zenkit_codegen::naming::{
+    class_ident, leading_fragment, pascal_ident, screaming_fragment, snake_fragment, snake_ident,
+};
+
+#[test]
+fn keywords() {
+    assert_eq!(snake_ident("Type", "x"), "type_");
+    assert_eq!(snake_ident("match", "x"), "match_");
+    assert_eq!(class_ident("self", "x"), "Self_");
+    // fragments are used with a prefix, e.g., get_type
+    assert_eq!(snake_fragment("Type", "x"), "type");
+}
+
+#[test]
+fn leading_digits() {
+    assert_eq!(snake_ident("2nd Round", "x"), "_2nd_round");
+    assert_eq!(pascal_ident("2nd Round", "x"), "_2NdRound");
+    assert_eq!(snake_fragment("2nd Round", "x"), "2nd_round");
+    // fragments that begin a method name, e.g., 2nd_round_is
+    assert_eq!(leading_fragment("2nd_round"), "_2nd_round");
+    assert_eq!(leading_fragment("type"), "type");
+}
+
+#[test]
+fn transliteration() {
+    assert_eq!(snake_ident("Größe", "x"), "grosse");
+    assert_eq!(screaming_fragment("Café Menu", "x"), "CAFE_MENU");
+    assert!(!snake_fragment("🎉", "x").is_empty());
+}
+
+#[test]
+fn fallback() {
+    assert_eq!(snake_fragment("???", "field_57"), "field_57");
+    assert_eq!(screaming_fragment("", "label_3"), "LABEL_3");
+    assert_eq!(pascal_ident("!!", "list_7"), "List7");
+}
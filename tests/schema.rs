@@ -0,0 +1,71 @@
+//! Schema snapshots
+use serde_json::{json, Value};
+use std::path::Path;
+use zenkit_codegen::WorkspaceSchema;
+
+fn fixture() -> Value {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/crm_for_sales.json");
+    serde_json::from_slice(&std::fs::read(path).expect("read fixture")).expect("parse fixture")
+}
+
+fn hash(v: &Value) -> String {
+    WorkspaceSchema::from_slice(&serde_json::to_vec(v).unwrap())
+        .expect("load schema")
+        .content_hash()
+        .expect("hash")
+}
+
+/// Reverses the array, then moves the first element to the end
+fn shuffle(v: &mut Value) {
+    let items = v.as_array_mut().unwrap();
+    items.reverse();
+    if !items.is_empty() {
+        items.rotate_left(1);
+    }
+}
+
+#[test]
+fn hash_ignores_order() {
+    let original = fixture();
+    let mut shuffled = original.clone();
+    shuffle(&mut shuffled["workspace"]["lists"]);
+    shuffle(&mut shuffled["lists"]);
+    for list in shuffled["lists"].as_array_mut().unwrap() {
+        shuffle(&mut list["fields"]);
+        for field in list["fields"].as_array_mut().unwrap() {
+            let labels = &mut field["elementData"]["predefinedCategories"];
+            if labels.is_array() {
+                shuffle(labels);
+            }
+        }
+    }
+    assert_ne!(original, shuffled);
+    assert_eq!(hash(&original), hash(&shuffled));
+}
+
+#[test]
+fn hash_ignores_generator() {
+    let original = fixture();
+    let mut other = original.clone();
+    other["generator"] = json!("zk-codegen v0.1.0");
+    assert_eq!(hash(&original), hash(&other));
+}
+
+#[test]
+fn hash_changes_with_content() {
+    let original = fixture();
+    let mut renamed = original.clone();
+    renamed["lists"][1]["fields"][1]["elementData"]["predefinedCategories"][0]["name"] =
+        json!("Open");
+    assert_ne!(hash(&original), hash(&renamed));
+}
+
+#[test]
+fn hash_with_version() {
+    let schema = WorkspaceSchema::from_slice(&serde_json::to_vec(&fixture()).unwrap()).unwrap();
+    let v1 = schema.content_hash_with_version("0.1.0").unwrap();
+    let v2 = schema.content_hash_with_version("0.2.0").unwrap();
+    assert_ne!(v1, v2);
+    assert_ne!(v1, schema.content_hash().unwrap());
+    assert_eq!(v1, schema.content_hash_with_version("0.1.0").unwrap());
+}
@@ -0,0 +1,273 @@
+//! Tests of the stand-in Zenkit server, with the zenkit api client
+use serde_json::json;
+use std::path::{Path, PathBuf};
+use zenkit::{
+    init_api,
+    types::{GetEntriesRequest, SortDirection},
+    ApiConfig,
+};
+use zenkit_codegen::{
+    server::StandInServer,
+    source::{SchemaSource, ZenkitApi},
+    WorkspaceSchema,
+};
+
+const TASK_LIST_ID: u64 = 401;
+const TASK_UUID: &str = "0a3681d8-46e5-5ff4-9c71-bae81891be66";
+const PRIORITY_UUID: &str = "f7ca829f-854f-5d01-9cc2-8834391b4c2b";
+const DONE_FIELD_ID: u64 = 42;
+
+fn fixtures_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures")
+}
+
+// The zenkit api client is global, so all tests that use it are in this function
+#[tokio::test]
+async fn zenkit_api_client() {
+    let server = StandInServer::from_dir(fixtures_dir()).expect("start server");
+    let api = init_api(ApiConfig {
+        token: "test-token".to_string(),
+        endpoint: server.endpoint(),
+    })
+    .expect("init api");
+
+    // schema is the same as the snapshot
+    let schema = ZenkitApi::new(api, "Todo").load_schema().await.unwrap();
+    let expected = WorkspaceSchema::load(fixtures_dir().join("todo.json")).unwrap();
+    assert_eq!(
+        schema.content_hash().unwrap(),
+        expected.content_hash().unwrap()
+    );
+
+    // entries from todo.entries.json
+    let entries = api
+        .get_list_entries(TASK_LIST_ID, &GetEntriesRequest::default())
+        .await
+        .unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(
+        entries[0].get_text_value(TASK_UUID).unwrap(),
+        Some("Write tests")
+    );
+    assert_eq!(entries[0].get_category_names(PRIORITY_UUID), vec!["High"]);
+    let entry = api.get_entry(TASK_LIST_ID, 1002).await.unwrap();
+    assert_eq!(entry.get_category_ids(PRIORITY_UUID), vec![452]);
+
+    // filters and sort order
+    let entries = api
+        .get_list_entries(
+            TASK_LIST_ID,
+            &GetEntriesRequest {
+                filter: json!({ "AND": { "TERMS": [{ "elementId": DONE_FIELD_ID, "checked": false }] } }),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].id, 1001);
+    let task_column = format!("{}_text", TASK_UUID);
+    let entries = api
+        .get_list_entries_sorted(TASK_LIST_ID, Some((&task_column, SortDirection::Asc)), 0, 0)
+        .await
+        .unwrap();
+    let ids: Vec<u64> = entries.iter().map(|e| e.id).collect();
+    assert_eq!(ids, vec![1002, 1001]);
+    let entries = api
+        .get_list_entries_sorted(
+            TASK_LIST_ID,
+            Some((&task_column, SortDirection::Desc)),
+            1,
+            0,
+        )
+        .await
+        .unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].id, 1001);
+
+    // create, update, and delete
+    let entry = api
+        .create_entry(
+            TASK_LIST_ID,
+            json!({ format!("{}_text", TASK_UUID): "New task" }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(entry.id, 1003);
+    let entry = api
+        .update_entry(
+            TASK_LIST_ID,
+            entry.id,
+            json!({ format!("{}_categories", PRIORITY_UUID): [452], "updateAction": "append" }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(entry.get_text_value(TASK_UUID).unwrap(), Some("New task"));
+    assert_eq!(entry.get_category_names(PRIORITY_UUID), vec!["Low"]);
+    assert_eq!(server.entries(TASK_LIST_ID).len(), 3);
+    api.delete_entry(TASK_LIST_ID, entry.id).await.unwrap();
+    assert_eq!(server.entries(TASK_LIST_ID).len(), 2);
+    assert!(api.get_entry(TASK_LIST_ID, entry.id).await.is_err());
+
+    // requests are recorded
+    let requests = server.requests();
+    assert!(requests
+        .iter()
+        .all(|r| r.api_key.as_deref() == Some("test-token")));
+    let update = requests.iter().find(|r| r.method == "PUT").unwrap();
+    assert_eq!(update.path, "/lists/401/entries/1003");
+    assert_eq!(update.body.as_ref().unwrap()["updateAction"], "append");
+}
+
+#[tokio::test]
+async fn errors() {
+    let server = StandInServer::from_dir(fixtures_dir()).expect("start server");
+    let client = reqwest::Client::new();
+    let url = format!("{}/lists/{}/entries/1001", server.endpoint(), TASK_LIST_ID);
+
+    // missing api key
+    let resp = client.get(&url).send().await.unwrap();
+    assert_eq!(resp.status().as_u16(), 401);
+
+    let resp = client
+        .get(&url)
+        .header("Zenkit-API-Key", "test-token")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status().as_u16(), 200);
+
+    // errors are in Zenkit's format
+    let resp = client
+        .get(format!("{}/lists/999/elements", server.endpoint()))
+        .header("Zenkit-API-Key", "test-token")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status().as_u16(), 404);
+    let err: zenkit::types::ErrorResult = resp.json().await.unwrap();
+    assert_eq!(err.error.code, "C2");
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn injected_failures() {
+    let server = StandInServer::from_dir(fixtures_dir()).expect("start server");
+    let client = reqwest::Client::new();
+    let url = format!("{}/lists/{}/entries/1001", server.endpoint(), TASK_LIST_ID);
+    server.fail_next(2, 429, Some(3));
+    server.fail_next(1, 503, None);
+
+    for _ in 0..2 {
+        let resp = client
+            .get(&url)
+            .header("Zenkit-API-Key", "test-token")
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status().as_u16(), 429);
+        assert_eq!(resp.headers()["retry-after"], "3");
+        let err: zenkit::types::ErrorResult = resp.json().await.unwrap();
+        assert_eq!(err.error.code, "D2");
+    }
+    let resp = client
+        .get(&url)
+        .header("Zenkit-API-Key", "test-token")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status().as_u16(), 503);
+    assert!(resp.headers().get("retry-after").is_none());
+
+    // then requests are handled as usual
+    let resp = client
+        .get(&url)
+        .header("Zenkit-API-Key", "test-token")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status().as_u16(), 200);
+    assert_eq!(server.requests().len(), 4);
+}
+
+#[tokio::test]
+async fn archive_and_restore() {
+    let server = StandInServer::from_dir(fixtures_dir()).expect("start server");
+    let client = reqwest::Client::new();
+    let entries_url = format!("{}/lists/{}/entries", server.endpoint(), TASK_LIST_ID);
+    let list_entries = |allow_deprecated: bool| {
+        client
+            .post(format!("{}/filter", entries_url))
+            .header("Zenkit-API-Key", "test-token")
+            .json(&json!({ "allowDeprecated": allow_deprecated }))
+            .send()
+    };
+
+    let resp = client
+        .delete(format!("{}/1001", entries_url))
+        .header("Zenkit-API-Key", "test-token")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status().as_u16(), 200);
+    let entry: serde_json::Value = resp.json().await.unwrap();
+    assert!(entry["deprecated_at"].is_string());
+    // archived entries are only listed with allowDeprecated
+    let entries: Vec<serde_json::Value> = list_entries(false).await.unwrap().json().await.unwrap();
+    assert_eq!(entries.len(), 1);
+    let entries: Vec<serde_json::Value> = list_entries(true).await.unwrap().json().await.unwrap();
+    assert_eq!(entries.len(), 2);
+
+    let resp = client
+        .post(format!(
+            "{}/lists/{}/deprecated-entries/1001/restore",
+            server.endpoint(),
+            TASK_LIST_ID
+        ))
+        .header("Zenkit-API-Key", "test-token")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status().as_u16(), 200);
+    let entries: Vec<serde_json::Value> = list_entries(false).await.unwrap().json().await.unwrap();
+    assert_eq!(entries.len(), 2);
+    assert!(server.entries(TASK_LIST_ID)[0]["deprecated_at"].is_null());
+}
+
+/// Runs zk-codegen to save the schema of the Todo workspace from the server
+fn dump_schema(server: &StandInServer, file: &str, endpoint_arg: bool) -> WorkspaceSchema {
+    let path = Path::new(env!("CARGO_TARGET_TMPDIR")).join(file);
+    let mut cmd = std::process::Command::new(env!("CARGO_BIN_EXE_zk-codegen"));
+    cmd.args(["-t", "test-token", "-w", "Todo", "--dump-schema"])
+        .arg(&path)
+        .env_remove("ZENKIT_ENDPOINT");
+    if endpoint_arg {
+        cmd.args(["--endpoint", &server.endpoint()]);
+    } else {
+        cmd.env("ZENKIT_ENDPOINT", server.endpoint());
+    }
+    let status = cmd.status().expect("run zk-codegen");
+    assert!(status.success());
+    WorkspaceSchema::load(path).unwrap()
+}
+
+#[test]
+fn zk_codegen_endpoint() {
+    let server = StandInServer::from_dir(fixtures_dir()).expect("start server");
+    let expected = WorkspaceSchema::load(fixtures_dir().join("todo.json")).unwrap();
+
+    let schema = dump_schema(&server, "endpoint_arg.json", true);
+    assert_eq!(
+        schema.content_hash().unwrap(),
+        expected.content_hash().unwrap()
+    );
+    let schema = dump_schema(&server, "endpoint_env.json", false);
+    assert_eq!(
+        schema.content_hash().unwrap(),
+        expected.content_hash().unwrap()
+    );
+    assert!(server
+        .requests()
+        .iter()
+        .all(|r| r.api_key.as_deref() == Some("test-token")));
+}
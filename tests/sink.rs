@@ -0,0 +1,66 @@
+//! Output sinks
+use std::path::Path;
+use zenkit_codegen::{
+    sink::{DirSink, MemorySink, OutputSink},
+    GeneratedFile,
+};
+
+fn file(path: &str, contents: &str) -> GeneratedFile {
+    GeneratedFile {
+        path: path.to_string(),
+        contents: contents.to_string(),
+    }
+}
+
+#[test]
+fn dir_sink() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("dir_sink");
+    let _ = std::fs::remove_dir_all(&dir);
+    let read = |path: &str| std::fs::read_to_string(dir.join(path)).ok();
+
+    // first generation creates subdirectories and Cargo.toml
+    let mut sink = DirSink::new(&dir);
+    sink.write_file(file("Cargo.toml", "[package]\nname = \"v1\"\n"))
+        .unwrap();
+    sink.write_file(file("src/lib.rs", "// v1\n")).unwrap();
+    assert_eq!(sink.written().len(), 2);
+    assert_eq!(
+        read("Cargo.toml").as_deref(),
+        Some("[package]\nname = \"v1\"\n")
+    );
+    assert_eq!(read("src/lib.rs").as_deref(), Some("// v1\n"));
+    assert_eq!(read("Cargo.toml.gen"), None);
+
+    // regenerating keeps Cargo.toml, which may have local changes, and overwrites sources
+    let mut sink = DirSink::new(&dir);
+    sink.write_file(file("Cargo.toml", "[package]\nname = \"v2\"\n"))
+        .unwrap();
+    sink.write_file(file("src/lib.rs", "// v2\n")).unwrap();
+    assert_eq!(
+        read("Cargo.toml").as_deref(),
+        Some("[package]\nname = \"v1\"\n")
+    );
+    assert_eq!(
+        read("Cargo.toml.gen").as_deref(),
+        Some("[package]\nname = \"v2\"\n")
+    );
+    assert_eq!(read("src/lib.rs").as_deref(), Some("// v2\n"));
+    assert!(sink.written()[0].ends_with("Cargo.toml.gen"));
+    assert!(sink.written()[1].ends_with("lib.rs"));
+
+    // only Cargo.toml in the crate root is kept
+    sink.write_file(file("sub/Cargo.toml", "# v2\n")).unwrap();
+    sink.write_file(file("sub/Cargo.toml", "# v3\n")).unwrap();
+    assert_eq!(read("sub/Cargo.toml").as_deref(), Some("# v3\n"));
+}
+
+#[test]
+fn memory_sink() {
+    let mut sink = MemorySink::new();
+    sink.write_file(file("src/lib.rs", "// lib\n")).unwrap();
+    sink.write_file(file("Cargo.toml", "[package]\n")).unwrap();
+    assert_eq!(sink.files.len(), 2);
+    assert_eq!(sink.files[0].path, "src/lib.rs");
+    assert_eq!(sink.get("Cargo.toml"), Some("[package]\n"));
+    assert_eq!(sink.get("src/missing.rs"), None);
+}
@@ -0,0 +1,103 @@
+//! Schema sources
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use zenkit_codegen::{
+    source::{MemorySource, SchemaSource, SnapshotFile},
+    Error, WorkspaceSchema,
+};
+
+fn fixture_path(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(format!("{}.json", name))
+}
+
+fn fixture(name: &str) -> WorkspaceSchema {
+    WorkspaceSchema::load(fixture_path(name)).expect("load fixture")
+}
+
+fn hash(schema: &WorkspaceSchema) -> String {
+    schema.content_hash().expect("hash")
+}
+
+#[tokio::test]
+async fn memory_source() {
+    let expected = fixture("crm_for_sales");
+    let source = MemorySource::from(fixture("crm_for_sales"));
+    let workspace = source.workspace().await.unwrap();
+    assert_eq!(workspace.name, expected.workspace.name);
+    let fields = source
+        .list_fields(&workspace, &workspace.lists[1])
+        .await
+        .unwrap();
+    assert_eq!(fields.len(), expected.lists[1].fields.len());
+    assert_eq!(hash(&source.load_schema().await.unwrap()), hash(&expected));
+}
+
+#[tokio::test]
+async fn memory_source_add_list() {
+    let expected = fixture("crm_for_sales");
+    let schema = fixture("crm_for_sales");
+    let mut source = MemorySource::new(schema.workspace);
+    // lists can be added in any order
+    for list in schema.lists.into_iter().rev() {
+        source.add_list(list);
+    }
+    assert_eq!(hash(&source.load_schema().await.unwrap()), hash(&expected));
+}
+
+#[tokio::test]
+async fn memory_source_from_json() {
+    let expected = fixture("todo");
+    let json: Value =
+        serde_json::from_str(&std::fs::read_to_string(fixture_path("todo")).unwrap()).unwrap();
+    let lists: Vec<String> = json["lists"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|l| l.to_string())
+        .collect();
+    let lists: Vec<&str> = lists.iter().map(|l| l.as_str()).collect();
+    let source = MemorySource::from_json(&json["workspace"].to_string(), &lists).unwrap();
+    assert_eq!(hash(&source.load_schema().await.unwrap()), hash(&expected));
+
+    match MemorySource::from_json("{}", &[]) {
+        Err(Error::Message(_)) => {}
+        Ok(_) => panic!("invalid workspace json was accepted"),
+    }
+}
+
+#[tokio::test]
+async fn unknown_list() {
+    // the workspace has a list without field definitions
+    let schema = fixture("todo");
+    let source = MemorySource::new(schema.workspace);
+    match source.load_schema().await {
+        Err(Error::Message(m)) => {
+            assert!(
+                m.contains("missing field definitions for list 'Task List'"),
+                "{}",
+                m
+            );
+        }
+        Ok(_) => panic!("loaded schema without field definitions"),
+    }
+}
+
+#[tokio::test]
+async fn snapshot_file() {
+    let expected = fixture("crm_for_sales");
+    let source = SnapshotFile::new(fixture_path("crm_for_sales"));
+    assert_eq!(hash(&source.load_schema().await.unwrap()), hash(&expected));
+
+    // the default implementation of load_schema, one list at a time
+    let workspace = source.workspace().await.unwrap();
+    assert_eq!(workspace.lists.len(), expected.lists.len());
+    for (list, expected) in workspace.lists.iter().zip(expected.lists.iter()) {
+        let fields = source.list_fields(&workspace, list).await.unwrap();
+        assert_eq!(fields, expected.fields);
+    }
+
+    let missing = SnapshotFile::new(fixture_path("no_such_fixture"));
+    assert!(missing.load_schema().await.is_err());
+}